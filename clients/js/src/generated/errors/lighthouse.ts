@@ -41,6 +41,10 @@ export const enum LighthouseProgramErrorCode {
   ACCOUNT_VALIDATION_FAILED = 0x177f, // 6015
   /** CrossProgramInvokeViolation: CrossProgramInvokeViolation */
   CROSS_PROGRAM_INVOKE_VIOLATION = 0x1780, // 6016
+  /** ArithmeticOverflow: ArithmeticOverflow */
+  ARITHMETIC_OVERFLOW = 0x1781, // 6017
+  /** MemoryLocked: MemoryLocked */
+  MEMORY_LOCKED = 0x1782, // 6018
 }
 
 export class LighthouseProgramError extends Error {
@@ -135,6 +139,14 @@ if (__DEV__) {
       'CrossProgramInvokeViolation',
       `CrossProgramInvokeViolation`,
     ],
+    [LighthouseProgramErrorCode.ARITHMETIC_OVERFLOW]: [
+      'ArithmeticOverflow',
+      `ArithmeticOverflow`,
+    ],
+    [LighthouseProgramErrorCode.MEMORY_LOCKED]: [
+      'MemoryLocked',
+      `MemoryLocked`,
+    ],
   };
 }
 
@@ -11,7 +11,11 @@ import {
   Codec,
   Decoder,
   Encoder,
+  Option,
+  OptionOrNullable,
   combineCodec,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU16Decoder,
@@ -19,6 +23,7 @@ import {
   getU8Decoder,
   getU8Encoder,
   mapEncoder,
+  none,
 } from '@solana/codecs';
 import {
   AccountRole,
@@ -79,12 +84,14 @@ export type AssertAccountDataInstructionData = {
   logLevel: LogLevel;
   offset: number;
   assertion: DataValueAssertion;
+  errorCode: Option<number>;
 };
 
 export type AssertAccountDataInstructionDataArgs = {
   logLevel?: LogLevelArgs;
   offset: number;
   assertion: DataValueAssertionArgs;
+  errorCode?: OptionOrNullable<number>;
 };
 
 export function getAssertAccountDataInstructionDataEncoder(): Encoder<AssertAccountDataInstructionDataArgs> {
@@ -94,11 +101,13 @@ export function getAssertAccountDataInstructionDataEncoder(): Encoder<AssertAcco
       ['logLevel', getLogLevelEncoder()],
       ['offset', getU16Encoder()],
       ['assertion', getDataValueAssertionEncoder()],
+      ['errorCode', getOptionEncoder(getU16Encoder())],
     ]),
     (value) => ({
       ...value,
       discriminator: 2,
       logLevel: value.logLevel ?? LogLevel.Silent,
+      errorCode: value.errorCode ?? none(),
     })
   );
 }
@@ -109,6 +118,7 @@ export function getAssertAccountDataInstructionDataDecoder(): Decoder<AssertAcco
     ['logLevel', getLogLevelDecoder()],
     ['offset', getU16Decoder()],
     ['assertion', getDataValueAssertionDecoder()],
+    ['errorCode', getOptionDecoder(getU16Decoder())],
   ]);
 }
 
@@ -128,6 +138,7 @@ export type AssertAccountDataInput<TAccountTargetAccount extends string> = {
   logLevel?: AssertAccountDataInstructionDataArgs['logLevel'];
   offset: AssertAccountDataInstructionDataArgs['offset'];
   assertion: AssertAccountDataInstructionDataArgs['assertion'];
+  errorCode?: AssertAccountDataInstructionDataArgs['errorCode'];
 };
 
 export type AssertAccountDataInputWithSigners<
@@ -138,6 +149,7 @@ export type AssertAccountDataInputWithSigners<
   logLevel?: AssertAccountDataInstructionDataArgs['logLevel'];
   offset: AssertAccountDataInstructionDataArgs['offset'];
   assertion: AssertAccountDataInstructionDataArgs['assertion'];
+  errorCode?: AssertAccountDataInstructionDataArgs['errorCode'];
 };
 
 export function getAssertAccountDataInstruction<
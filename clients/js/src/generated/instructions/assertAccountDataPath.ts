@@ -0,0 +1,284 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Address } from '@solana/addresses';
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  Option,
+  OptionOrNullable,
+  combineCodec,
+  getArrayDecoder,
+  getArrayEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  mapEncoder,
+  none,
+} from '@solana/codecs';
+import {
+  AccountRole,
+  IAccountMeta,
+  IInstruction,
+  IInstructionWithAccounts,
+  IInstructionWithData,
+  ReadonlyAccount,
+} from '@solana/instructions';
+import {
+  ResolvedAccount,
+  accountMetaWithDefault,
+  getAccountMetasWithSigners,
+} from '../shared';
+import {
+  DataPathSegment,
+  DataPathSegmentArgs,
+  DataValueAssertion,
+  DataValueAssertionArgs,
+  LogLevel,
+  LogLevelArgs,
+  getDataPathSegmentDecoder,
+  getDataPathSegmentEncoder,
+  getDataValueAssertionDecoder,
+  getDataValueAssertionEncoder,
+  getLogLevelDecoder,
+  getLogLevelEncoder,
+} from '../types';
+
+export type AssertAccountDataPathInstruction<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountTargetAccount extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountTargetAccount extends string
+        ? ReadonlyAccount<TAccountTargetAccount>
+        : TAccountTargetAccount,
+      ...TRemainingAccounts
+    ]
+  >;
+
+export type AssertAccountDataPathInstructionWithSigners<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountTargetAccount extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountTargetAccount extends string
+        ? ReadonlyAccount<TAccountTargetAccount>
+        : TAccountTargetAccount,
+      ...TRemainingAccounts
+    ]
+  >;
+
+export type AssertAccountDataPathInstructionData = {
+  discriminator: number;
+  logLevel: LogLevel;
+  offset: number;
+  path: Array<DataPathSegment>;
+  assertion: DataValueAssertion;
+  errorCode: Option<number>;
+};
+
+export type AssertAccountDataPathInstructionDataArgs = {
+  logLevel?: LogLevelArgs;
+  offset: number;
+  path: Array<DataPathSegmentArgs>;
+  assertion: DataValueAssertionArgs;
+  errorCode?: OptionOrNullable<number>;
+};
+
+export function getAssertAccountDataPathInstructionDataEncoder(): Encoder<AssertAccountDataPathInstructionDataArgs> {
+  return mapEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['logLevel', getLogLevelEncoder()],
+      ['offset', getU16Encoder()],
+      ['path', getArrayEncoder(getDataPathSegmentEncoder())],
+      ['assertion', getDataValueAssertionEncoder()],
+      ['errorCode', getOptionEncoder(getU16Encoder())],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: 21,
+      logLevel: value.logLevel ?? LogLevel.Silent,
+      errorCode: value.errorCode ?? none(),
+    })
+  );
+}
+
+export function getAssertAccountDataPathInstructionDataDecoder(): Decoder<AssertAccountDataPathInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['logLevel', getLogLevelDecoder()],
+    ['offset', getU16Decoder()],
+    ['path', getArrayDecoder(getDataPathSegmentDecoder())],
+    ['assertion', getDataValueAssertionDecoder()],
+    ['errorCode', getOptionDecoder(getU16Decoder())],
+  ]);
+}
+
+export function getAssertAccountDataPathInstructionDataCodec(): Codec<
+  AssertAccountDataPathInstructionDataArgs,
+  AssertAccountDataPathInstructionData
+> {
+  return combineCodec(
+    getAssertAccountDataPathInstructionDataEncoder(),
+    getAssertAccountDataPathInstructionDataDecoder()
+  );
+}
+
+export type AssertAccountDataPathInput<TAccountTargetAccount extends string> = {
+  /** Target account to be asserted */
+  targetAccount: Address<TAccountTargetAccount>;
+  logLevel?: AssertAccountDataPathInstructionDataArgs['logLevel'];
+  offset: AssertAccountDataPathInstructionDataArgs['offset'];
+  path: AssertAccountDataPathInstructionDataArgs['path'];
+  assertion: AssertAccountDataPathInstructionDataArgs['assertion'];
+  errorCode?: AssertAccountDataPathInstructionDataArgs['errorCode'];
+};
+
+export type AssertAccountDataPathInputWithSigners<
+  TAccountTargetAccount extends string
+> = {
+  /** Target account to be asserted */
+  targetAccount: Address<TAccountTargetAccount>;
+  logLevel?: AssertAccountDataPathInstructionDataArgs['logLevel'];
+  offset: AssertAccountDataPathInstructionDataArgs['offset'];
+  path: AssertAccountDataPathInstructionDataArgs['path'];
+  assertion: AssertAccountDataPathInstructionDataArgs['assertion'];
+  errorCode?: AssertAccountDataPathInstructionDataArgs['errorCode'];
+};
+
+export function getAssertAccountDataPathInstruction<
+  TAccountTargetAccount extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: AssertAccountDataPathInputWithSigners<TAccountTargetAccount>
+): AssertAccountDataPathInstructionWithSigners<TProgram, TAccountTargetAccount>;
+export function getAssertAccountDataPathInstruction<
+  TAccountTargetAccount extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: AssertAccountDataPathInput<TAccountTargetAccount>
+): AssertAccountDataPathInstruction<TProgram, TAccountTargetAccount>;
+export function getAssertAccountDataPathInstruction<
+  TAccountTargetAccount extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(input: AssertAccountDataPathInput<TAccountTargetAccount>): IInstruction {
+  // Program address.
+  const programAddress =
+    'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK' as Address<'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'>;
+
+  // Original accounts.
+  type AccountMetas = Parameters<
+    typeof getAssertAccountDataPathInstructionRaw<
+      TProgram,
+      TAccountTargetAccount
+    >
+  >[0];
+  const accounts: Record<keyof AccountMetas, ResolvedAccount> = {
+    targetAccount: { value: input.targetAccount ?? null, isWritable: false },
+  };
+
+  // Original args.
+  const args = { ...input };
+
+  // Get account metas and signers.
+  const accountMetas = getAccountMetasWithSigners(
+    accounts,
+    'programId',
+    programAddress
+  );
+
+  const instruction = getAssertAccountDataPathInstructionRaw(
+    accountMetas as Record<keyof AccountMetas, IAccountMeta>,
+    args as AssertAccountDataPathInstructionDataArgs,
+    programAddress
+  );
+
+  return instruction;
+}
+
+export function getAssertAccountDataPathInstructionRaw<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountTargetAccount extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+>(
+  accounts: {
+    targetAccount: TAccountTargetAccount extends string
+      ? Address<TAccountTargetAccount>
+      : TAccountTargetAccount;
+  },
+  args: AssertAccountDataPathInstructionDataArgs,
+  programAddress: Address<TProgram> = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK' as Address<TProgram>,
+  remainingAccounts?: TRemainingAccounts
+) {
+  return {
+    accounts: [
+      accountMetaWithDefault(accounts.targetAccount, AccountRole.READONLY),
+      ...(remainingAccounts ?? []),
+    ],
+    data: getAssertAccountDataPathInstructionDataEncoder().encode(args),
+    programAddress,
+  } as AssertAccountDataPathInstruction<
+    TProgram,
+    TAccountTargetAccount,
+    TRemainingAccounts
+  >;
+}
+
+export type ParsedAssertAccountDataPathInstruction<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[]
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Target account to be asserted */
+    targetAccount: TAccountMetas[0];
+  };
+  data: AssertAccountDataPathInstructionData;
+};
+
+export function parseAssertAccountDataPathInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[]
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAssertAccountDataPathInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 1) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      targetAccount: getNextAccount(),
+    },
+    data: getAssertAccountDataPathInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
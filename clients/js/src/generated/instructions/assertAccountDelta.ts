@@ -11,12 +11,19 @@ import {
   Codec,
   Decoder,
   Encoder,
+  Option,
+  OptionOrNullable,
   combineCodec,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU8Decoder,
   getU8Encoder,
   mapEncoder,
+  none,
 } from '@solana/codecs';
 import {
   AccountRole,
@@ -84,11 +91,13 @@ export type AssertAccountDeltaInstructionData = {
   discriminator: number;
   logLevel: LogLevel;
   assertion: AccountDeltaAssertion;
+  errorCode: Option<number>;
 };
 
 export type AssertAccountDeltaInstructionDataArgs = {
   logLevel?: LogLevelArgs;
   assertion: AccountDeltaAssertionArgs;
+  errorCode?: OptionOrNullable<number>;
 };
 
 export function getAssertAccountDeltaInstructionDataEncoder(): Encoder<AssertAccountDeltaInstructionDataArgs> {
@@ -97,11 +106,13 @@ export function getAssertAccountDeltaInstructionDataEncoder(): Encoder<AssertAcc
       ['discriminator', getU8Encoder()],
       ['logLevel', getLogLevelEncoder()],
       ['assertion', getAccountDeltaAssertionEncoder()],
+      ['errorCode', getOptionEncoder(getU16Encoder())],
     ]),
     (value) => ({
       ...value,
       discriminator: 3,
       logLevel: value.logLevel ?? LogLevel.Silent,
+      errorCode: value.errorCode ?? none(),
     })
   );
 }
@@ -111,6 +122,7 @@ export function getAssertAccountDeltaInstructionDataDecoder(): Decoder<AssertAcc
     ['discriminator', getU8Decoder()],
     ['logLevel', getLogLevelDecoder()],
     ['assertion', getAccountDeltaAssertionDecoder()],
+    ['errorCode', getOptionDecoder(getU16Decoder())],
   ]);
 }
 
@@ -134,6 +146,7 @@ export type AssertAccountDeltaInput<
   accountB: Address<TAccountAccountB>;
   logLevel?: AssertAccountDeltaInstructionDataArgs['logLevel'];
   assertion: AssertAccountDeltaInstructionDataArgs['assertion'];
+  errorCode?: AssertAccountDeltaInstructionDataArgs['errorCode'];
 };
 
 export type AssertAccountDeltaInputWithSigners<
@@ -146,6 +159,7 @@ export type AssertAccountDeltaInputWithSigners<
   accountB: Address<TAccountAccountB>;
   logLevel?: AssertAccountDeltaInstructionDataArgs['logLevel'];
   assertion: AssertAccountDeltaInstructionDataArgs['assertion'];
+  errorCode?: AssertAccountDeltaInstructionDataArgs['errorCode'];
 };
 
 export function getAssertAccountDeltaInstruction<
@@ -11,12 +11,19 @@ import {
   Codec,
   Decoder,
   Encoder,
+  Option,
+  OptionOrNullable,
   combineCodec,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU8Decoder,
   getU8Encoder,
   mapEncoder,
+  none,
 } from '@solana/codecs';
 import {
   AccountRole,
@@ -76,11 +83,13 @@ export type AssertAccountInfoInstructionData = {
   discriminator: number;
   logLevel: LogLevel;
   assertion: AccountInfoAssertion;
+  errorCode: Option<number>;
 };
 
 export type AssertAccountInfoInstructionDataArgs = {
   logLevel?: LogLevelArgs;
   assertion: AccountInfoAssertionArgs;
+  errorCode?: OptionOrNullable<number>;
 };
 
 export function getAssertAccountInfoInstructionDataEncoder(): Encoder<AssertAccountInfoInstructionDataArgs> {
@@ -89,11 +98,13 @@ export function getAssertAccountInfoInstructionDataEncoder(): Encoder<AssertAcco
       ['discriminator', getU8Encoder()],
       ['logLevel', getLogLevelEncoder()],
       ['assertion', getAccountInfoAssertionEncoder()],
+      ['errorCode', getOptionEncoder(getU16Encoder())],
     ]),
     (value) => ({
       ...value,
       discriminator: 4,
       logLevel: value.logLevel ?? LogLevel.Silent,
+      errorCode: value.errorCode ?? none(),
     })
   );
 }
@@ -103,6 +114,7 @@ export function getAssertAccountInfoInstructionDataDecoder(): Decoder<AssertAcco
     ['discriminator', getU8Decoder()],
     ['logLevel', getLogLevelDecoder()],
     ['assertion', getAccountInfoAssertionDecoder()],
+    ['errorCode', getOptionDecoder(getU16Decoder())],
   ]);
 }
 
@@ -121,6 +133,7 @@ export type AssertAccountInfoInput<TAccountTargetAccount extends string> = {
   targetAccount: Address<TAccountTargetAccount>;
   logLevel?: AssertAccountInfoInstructionDataArgs['logLevel'];
   assertion: AssertAccountInfoInstructionDataArgs['assertion'];
+  errorCode?: AssertAccountInfoInstructionDataArgs['errorCode'];
 };
 
 export type AssertAccountInfoInputWithSigners<
@@ -130,6 +143,7 @@ export type AssertAccountInfoInputWithSigners<
   targetAccount: Address<TAccountTargetAccount>;
   logLevel?: AssertAccountInfoInstructionDataArgs['logLevel'];
   assertion: AssertAccountInfoInstructionDataArgs['assertion'];
+  errorCode?: AssertAccountInfoInstructionDataArgs['errorCode'];
 };
 
 export function getAssertAccountInfoInstruction<
@@ -11,14 +11,21 @@ import {
   Codec,
   Decoder,
   Encoder,
+  Option,
+  OptionOrNullable,
   combineCodec,
   getArrayDecoder,
   getArrayEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU8Decoder,
   getU8Encoder,
   mapEncoder,
+  none,
 } from '@solana/codecs';
 import {
   AccountRole,
@@ -36,10 +43,14 @@ import {
 import {
   AccountInfoAssertion,
   AccountInfoAssertionArgs,
+  EvaluationMode,
+  EvaluationModeArgs,
   LogLevel,
   LogLevelArgs,
   getAccountInfoAssertionDecoder,
   getAccountInfoAssertionEncoder,
+  getEvaluationModeDecoder,
+  getEvaluationModeEncoder,
   getLogLevelDecoder,
   getLogLevelEncoder,
 } from '../types';
@@ -78,11 +89,15 @@ export type AssertAccountInfoMultiInstructionData = {
   discriminator: number;
   logLevel: LogLevel;
   assertions: Array<AccountInfoAssertion>;
+  evaluationMode: EvaluationMode;
+  errorCodes: Option<Array<number>>;
 };
 
 export type AssertAccountInfoMultiInstructionDataArgs = {
   logLevel?: LogLevelArgs;
   assertions: Array<AccountInfoAssertionArgs>;
+  evaluationMode?: EvaluationModeArgs;
+  errorCodes?: OptionOrNullable<Array<number>>;
 };
 
 export function getAssertAccountInfoMultiInstructionDataEncoder(): Encoder<AssertAccountInfoMultiInstructionDataArgs> {
@@ -91,11 +106,15 @@ export function getAssertAccountInfoMultiInstructionDataEncoder(): Encoder<Asser
       ['discriminator', getU8Encoder()],
       ['logLevel', getLogLevelEncoder()],
       ['assertions', getArrayEncoder(getAccountInfoAssertionEncoder())],
+      ['evaluationMode', getEvaluationModeEncoder()],
+      ['errorCodes', getOptionEncoder(getArrayEncoder(getU16Encoder()))],
     ]),
     (value) => ({
       ...value,
       discriminator: 5,
       logLevel: value.logLevel ?? LogLevel.Silent,
+      evaluationMode: value.evaluationMode ?? EvaluationMode.Strict,
+      errorCodes: value.errorCodes ?? none(),
     })
   );
 }
@@ -105,6 +124,8 @@ export function getAssertAccountInfoMultiInstructionDataDecoder(): Decoder<Asser
     ['discriminator', getU8Decoder()],
     ['logLevel', getLogLevelDecoder()],
     ['assertions', getArrayDecoder(getAccountInfoAssertionDecoder())],
+    ['evaluationMode', getEvaluationModeDecoder()],
+    ['errorCodes', getOptionDecoder(getArrayDecoder(getU16Decoder()))],
   ]);
 }
 
@@ -124,6 +145,8 @@ export type AssertAccountInfoMultiInput<TAccountTargetAccount extends string> =
     targetAccount: Address<TAccountTargetAccount>;
     logLevel?: AssertAccountInfoMultiInstructionDataArgs['logLevel'];
     assertions: AssertAccountInfoMultiInstructionDataArgs['assertions'];
+    evaluationMode?: AssertAccountInfoMultiInstructionDataArgs['evaluationMode'];
+    errorCodes?: AssertAccountInfoMultiInstructionDataArgs['errorCodes'];
   };
 
 export type AssertAccountInfoMultiInputWithSigners<
@@ -133,6 +156,8 @@ export type AssertAccountInfoMultiInputWithSigners<
   targetAccount: Address<TAccountTargetAccount>;
   logLevel?: AssertAccountInfoMultiInstructionDataArgs['logLevel'];
   assertions: AssertAccountInfoMultiInstructionDataArgs['assertions'];
+  evaluationMode?: AssertAccountInfoMultiInstructionDataArgs['evaluationMode'];
+  errorCodes?: AssertAccountInfoMultiInstructionDataArgs['errorCodes'];
 };
 
 export function getAssertAccountInfoMultiInstruction<
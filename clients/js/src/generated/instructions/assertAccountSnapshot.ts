@@ -0,0 +1,309 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Address } from '@solana/addresses';
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  Option,
+  OptionOrNullable,
+  combineCodec,
+  getOptionDecoder,
+  getOptionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  mapEncoder,
+  none,
+} from '@solana/codecs';
+import {
+  AccountRole,
+  IAccountMeta,
+  IInstruction,
+  IInstructionWithAccounts,
+  IInstructionWithData,
+  ReadonlyAccount,
+} from '@solana/instructions';
+import {
+  ResolvedAccount,
+  accountMetaWithDefault,
+  getAccountMetasWithSigners,
+} from '../shared';
+import {
+  AccountSnapshotAssertion,
+  AccountSnapshotAssertionArgs,
+  LogLevel,
+  LogLevelArgs,
+  getAccountSnapshotAssertionDecoder,
+  getAccountSnapshotAssertionEncoder,
+  getLogLevelDecoder,
+  getLogLevelEncoder,
+} from '../types';
+
+export type AssertAccountSnapshotInstruction<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountMemory extends string | IAccountMeta<string> = string,
+  TAccountTargetAccount extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountMemory extends string
+        ? ReadonlyAccount<TAccountMemory>
+        : TAccountMemory,
+      TAccountTargetAccount extends string
+        ? ReadonlyAccount<TAccountTargetAccount>
+        : TAccountTargetAccount,
+      ...TRemainingAccounts
+    ]
+  >;
+
+export type AssertAccountSnapshotInstructionWithSigners<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountMemory extends string | IAccountMeta<string> = string,
+  TAccountTargetAccount extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountMemory extends string
+        ? ReadonlyAccount<TAccountMemory>
+        : TAccountMemory,
+      TAccountTargetAccount extends string
+        ? ReadonlyAccount<TAccountTargetAccount>
+        : TAccountTargetAccount,
+      ...TRemainingAccounts
+    ]
+  >;
+
+export type AssertAccountSnapshotInstructionData = {
+  discriminator: number;
+  logLevel: LogLevel;
+  assertion: AccountSnapshotAssertion;
+  errorCode: Option<number>;
+};
+
+export type AssertAccountSnapshotInstructionDataArgs = {
+  logLevel?: LogLevelArgs;
+  assertion: AccountSnapshotAssertionArgs;
+  errorCode?: OptionOrNullable<number>;
+};
+
+export function getAssertAccountSnapshotInstructionDataEncoder(): Encoder<AssertAccountSnapshotInstructionDataArgs> {
+  return mapEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['logLevel', getLogLevelEncoder()],
+      ['assertion', getAccountSnapshotAssertionEncoder()],
+      ['errorCode', getOptionEncoder(getU16Encoder())],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: 25,
+      logLevel: value.logLevel ?? LogLevel.Silent,
+      errorCode: value.errorCode ?? none(),
+    })
+  );
+}
+
+export function getAssertAccountSnapshotInstructionDataDecoder(): Decoder<AssertAccountSnapshotInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['logLevel', getLogLevelDecoder()],
+    ['assertion', getAccountSnapshotAssertionDecoder()],
+    ['errorCode', getOptionDecoder(getU16Decoder())],
+  ]);
+}
+
+export function getAssertAccountSnapshotInstructionDataCodec(): Codec<
+  AssertAccountSnapshotInstructionDataArgs,
+  AssertAccountSnapshotInstructionData
+> {
+  return combineCodec(
+    getAssertAccountSnapshotInstructionDataEncoder(),
+    getAssertAccountSnapshotInstructionDataDecoder()
+  );
+}
+
+export type AssertAccountSnapshotInput<
+  TAccountMemory extends string,
+  TAccountTargetAccount extends string
+> = {
+  /** Memory account holding the account snapshot */
+  memory: Address<TAccountMemory>;
+  /** Live account compared against the snapshot */
+  targetAccount: Address<TAccountTargetAccount>;
+  logLevel?: AssertAccountSnapshotInstructionDataArgs['logLevel'];
+  assertion: AssertAccountSnapshotInstructionDataArgs['assertion'];
+  errorCode?: AssertAccountSnapshotInstructionDataArgs['errorCode'];
+};
+
+export type AssertAccountSnapshotInputWithSigners<
+  TAccountMemory extends string,
+  TAccountTargetAccount extends string
+> = {
+  /** Memory account holding the account snapshot */
+  memory: Address<TAccountMemory>;
+  /** Live account compared against the snapshot */
+  targetAccount: Address<TAccountTargetAccount>;
+  logLevel?: AssertAccountSnapshotInstructionDataArgs['logLevel'];
+  assertion: AssertAccountSnapshotInstructionDataArgs['assertion'];
+  errorCode?: AssertAccountSnapshotInstructionDataArgs['errorCode'];
+};
+
+export function getAssertAccountSnapshotInstruction<
+  TAccountMemory extends string,
+  TAccountTargetAccount extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: AssertAccountSnapshotInputWithSigners<
+    TAccountMemory,
+    TAccountTargetAccount
+  >
+): AssertAccountSnapshotInstructionWithSigners<
+  TProgram,
+  TAccountMemory,
+  TAccountTargetAccount
+>;
+export function getAssertAccountSnapshotInstruction<
+  TAccountMemory extends string,
+  TAccountTargetAccount extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: AssertAccountSnapshotInput<TAccountMemory, TAccountTargetAccount>
+): AssertAccountSnapshotInstruction<
+  TProgram,
+  TAccountMemory,
+  TAccountTargetAccount
+>;
+export function getAssertAccountSnapshotInstruction<
+  TAccountMemory extends string,
+  TAccountTargetAccount extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: AssertAccountSnapshotInput<TAccountMemory, TAccountTargetAccount>
+): IInstruction {
+  // Program address.
+  const programAddress =
+    'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK' as Address<'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'>;
+
+  // Original accounts.
+  type AccountMetas = Parameters<
+    typeof getAssertAccountSnapshotInstructionRaw<
+      TProgram,
+      TAccountMemory,
+      TAccountTargetAccount
+    >
+  >[0];
+  const accounts: Record<keyof AccountMetas, ResolvedAccount> = {
+    memory: { value: input.memory ?? null, isWritable: false },
+    targetAccount: { value: input.targetAccount ?? null, isWritable: false },
+  };
+
+  // Original args.
+  const args = { ...input };
+
+  // Get account metas and signers.
+  const accountMetas = getAccountMetasWithSigners(
+    accounts,
+    'programId',
+    programAddress
+  );
+
+  const instruction = getAssertAccountSnapshotInstructionRaw(
+    accountMetas as Record<keyof AccountMetas, IAccountMeta>,
+    args as AssertAccountSnapshotInstructionDataArgs,
+    programAddress
+  );
+
+  return instruction;
+}
+
+export function getAssertAccountSnapshotInstructionRaw<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountMemory extends string | IAccountMeta<string> = string,
+  TAccountTargetAccount extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+>(
+  accounts: {
+    memory: TAccountMemory extends string
+      ? Address<TAccountMemory>
+      : TAccountMemory;
+    targetAccount: TAccountTargetAccount extends string
+      ? Address<TAccountTargetAccount>
+      : TAccountTargetAccount;
+  },
+  args: AssertAccountSnapshotInstructionDataArgs,
+  programAddress: Address<TProgram> = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK' as Address<TProgram>,
+  remainingAccounts?: TRemainingAccounts
+) {
+  return {
+    accounts: [
+      accountMetaWithDefault(accounts.memory, AccountRole.READONLY),
+      accountMetaWithDefault(accounts.targetAccount, AccountRole.READONLY),
+      ...(remainingAccounts ?? []),
+    ],
+    data: getAssertAccountSnapshotInstructionDataEncoder().encode(args),
+    programAddress,
+  } as AssertAccountSnapshotInstruction<
+    TProgram,
+    TAccountMemory,
+    TAccountTargetAccount,
+    TRemainingAccounts
+  >;
+}
+
+export type ParsedAssertAccountSnapshotInstruction<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[]
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Memory account holding the account snapshot */
+    memory: TAccountMetas[0];
+    /** Live account compared against the snapshot */
+    targetAccount: TAccountMetas[1];
+  };
+  data: AssertAccountSnapshotInstructionData;
+};
+
+export function parseAssertAccountSnapshotInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[]
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAssertAccountSnapshotInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      memory: getNextAccount(),
+      targetAccount: getNextAccount(),
+    },
+    data: getAssertAccountSnapshotInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
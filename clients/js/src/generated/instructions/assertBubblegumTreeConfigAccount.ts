@@ -11,12 +11,19 @@ import {
   Codec,
   Decoder,
   Encoder,
+  Option,
+  OptionOrNullable,
   combineCodec,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU8Decoder,
   getU8Encoder,
   mapEncoder,
+  none,
 } from '@solana/codecs';
 import {
   AccountRole,
@@ -76,11 +83,13 @@ export type AssertBubblegumTreeConfigAccountInstructionData = {
   discriminator: number;
   logLevel: LogLevel;
   assertion: BubblegumTreeConfigAssertion;
+  errorCode: Option<number>;
 };
 
 export type AssertBubblegumTreeConfigAccountInstructionDataArgs = {
   logLevel?: LogLevelArgs;
   assertion: BubblegumTreeConfigAssertionArgs;
+  errorCode?: OptionOrNullable<number>;
 };
 
 export function getAssertBubblegumTreeConfigAccountInstructionDataEncoder(): Encoder<AssertBubblegumTreeConfigAccountInstructionDataArgs> {
@@ -89,11 +98,13 @@ export function getAssertBubblegumTreeConfigAccountInstructionDataEncoder(): Enc
       ['discriminator', getU8Encoder()],
       ['logLevel', getLogLevelEncoder()],
       ['assertion', getBubblegumTreeConfigAssertionEncoder()],
+      ['errorCode', getOptionEncoder(getU16Encoder())],
     ]),
     (value) => ({
       ...value,
       discriminator: 16,
       logLevel: value.logLevel ?? LogLevel.Silent,
+      errorCode: value.errorCode ?? none(),
     })
   );
 }
@@ -103,6 +114,7 @@ export function getAssertBubblegumTreeConfigAccountInstructionDataDecoder(): Dec
     ['discriminator', getU8Decoder()],
     ['logLevel', getLogLevelDecoder()],
     ['assertion', getBubblegumTreeConfigAssertionDecoder()],
+    ['errorCode', getOptionDecoder(getU16Decoder())],
   ]);
 }
 
@@ -123,6 +135,7 @@ export type AssertBubblegumTreeConfigAccountInput<
   targetAccount: Address<TAccountTargetAccount>;
   logLevel?: AssertBubblegumTreeConfigAccountInstructionDataArgs['logLevel'];
   assertion: AssertBubblegumTreeConfigAccountInstructionDataArgs['assertion'];
+  errorCode?: AssertBubblegumTreeConfigAccountInstructionDataArgs['errorCode'];
 };
 
 export type AssertBubblegumTreeConfigAccountInputWithSigners<
@@ -132,6 +145,7 @@ export type AssertBubblegumTreeConfigAccountInputWithSigners<
   targetAccount: Address<TAccountTargetAccount>;
   logLevel?: AssertBubblegumTreeConfigAccountInstructionDataArgs['logLevel'];
   assertion: AssertBubblegumTreeConfigAccountInstructionDataArgs['assertion'];
+  errorCode?: AssertBubblegumTreeConfigAccountInstructionDataArgs['errorCode'];
 };
 
 export function getAssertBubblegumTreeConfigAccountInstruction<
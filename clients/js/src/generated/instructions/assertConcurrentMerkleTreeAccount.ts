@@ -0,0 +1,281 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Address } from '@solana/addresses';
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  Option,
+  OptionOrNullable,
+  combineCodec,
+  getOptionDecoder,
+  getOptionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  mapEncoder,
+  none,
+} from '@solana/codecs';
+import {
+  AccountRole,
+  IAccountMeta,
+  IInstruction,
+  IInstructionWithAccounts,
+  IInstructionWithData,
+  ReadonlyAccount,
+} from '@solana/instructions';
+import {
+  ResolvedAccount,
+  accountMetaWithDefault,
+  getAccountMetasWithSigners,
+} from '../shared';
+import {
+  ConcurrentMerkleTreeAssertion,
+  ConcurrentMerkleTreeAssertionArgs,
+  LogLevel,
+  LogLevelArgs,
+  getConcurrentMerkleTreeAssertionDecoder,
+  getConcurrentMerkleTreeAssertionEncoder,
+  getLogLevelDecoder,
+  getLogLevelEncoder,
+} from '../types';
+
+export type AssertConcurrentMerkleTreeAccountInstruction<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountTargetMerkleTree extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountTargetMerkleTree extends string
+        ? ReadonlyAccount<TAccountTargetMerkleTree>
+        : TAccountTargetMerkleTree,
+      ...TRemainingAccounts
+    ]
+  >;
+
+export type AssertConcurrentMerkleTreeAccountInstructionWithSigners<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountTargetMerkleTree extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountTargetMerkleTree extends string
+        ? ReadonlyAccount<TAccountTargetMerkleTree>
+        : TAccountTargetMerkleTree,
+      ...TRemainingAccounts
+    ]
+  >;
+
+export type AssertConcurrentMerkleTreeAccountInstructionData = {
+  discriminator: number;
+  logLevel: LogLevel;
+  assertion: ConcurrentMerkleTreeAssertion;
+  errorCode: Option<number>;
+};
+
+export type AssertConcurrentMerkleTreeAccountInstructionDataArgs = {
+  logLevel?: LogLevelArgs;
+  assertion: ConcurrentMerkleTreeAssertionArgs;
+  errorCode?: OptionOrNullable<number>;
+};
+
+export function getAssertConcurrentMerkleTreeAccountInstructionDataEncoder(): Encoder<AssertConcurrentMerkleTreeAccountInstructionDataArgs> {
+  return mapEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['logLevel', getLogLevelEncoder()],
+      ['assertion', getConcurrentMerkleTreeAssertionEncoder()],
+      ['errorCode', getOptionEncoder(getU16Encoder())],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: 19,
+      logLevel: value.logLevel ?? LogLevel.Silent,
+      errorCode: value.errorCode ?? none(),
+    })
+  );
+}
+
+export function getAssertConcurrentMerkleTreeAccountInstructionDataDecoder(): Decoder<AssertConcurrentMerkleTreeAccountInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['logLevel', getLogLevelDecoder()],
+    ['assertion', getConcurrentMerkleTreeAssertionDecoder()],
+    ['errorCode', getOptionDecoder(getU16Decoder())],
+  ]);
+}
+
+export function getAssertConcurrentMerkleTreeAccountInstructionDataCodec(): Codec<
+  AssertConcurrentMerkleTreeAccountInstructionDataArgs,
+  AssertConcurrentMerkleTreeAccountInstructionData
+> {
+  return combineCodec(
+    getAssertConcurrentMerkleTreeAccountInstructionDataEncoder(),
+    getAssertConcurrentMerkleTreeAccountInstructionDataDecoder()
+  );
+}
+
+export type AssertConcurrentMerkleTreeAccountInput<
+  TAccountTargetMerkleTree extends string
+> = {
+  /** Target spl-account-compression merkle tree account to be asserted */
+  targetMerkleTree: Address<TAccountTargetMerkleTree>;
+  logLevel?: AssertConcurrentMerkleTreeAccountInstructionDataArgs['logLevel'];
+  assertion: AssertConcurrentMerkleTreeAccountInstructionDataArgs['assertion'];
+  errorCode?: AssertConcurrentMerkleTreeAccountInstructionDataArgs['errorCode'];
+};
+
+export type AssertConcurrentMerkleTreeAccountInputWithSigners<
+  TAccountTargetMerkleTree extends string
+> = {
+  /** Target spl-account-compression merkle tree account to be asserted */
+  targetMerkleTree: Address<TAccountTargetMerkleTree>;
+  logLevel?: AssertConcurrentMerkleTreeAccountInstructionDataArgs['logLevel'];
+  assertion: AssertConcurrentMerkleTreeAccountInstructionDataArgs['assertion'];
+  errorCode?: AssertConcurrentMerkleTreeAccountInstructionDataArgs['errorCode'];
+};
+
+export function getAssertConcurrentMerkleTreeAccountInstruction<
+  TAccountTargetMerkleTree extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: AssertConcurrentMerkleTreeAccountInputWithSigners<TAccountTargetMerkleTree>
+): AssertConcurrentMerkleTreeAccountInstructionWithSigners<
+  TProgram,
+  TAccountTargetMerkleTree
+>;
+export function getAssertConcurrentMerkleTreeAccountInstruction<
+  TAccountTargetMerkleTree extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: AssertConcurrentMerkleTreeAccountInput<TAccountTargetMerkleTree>
+): AssertConcurrentMerkleTreeAccountInstruction<
+  TProgram,
+  TAccountTargetMerkleTree
+>;
+export function getAssertConcurrentMerkleTreeAccountInstruction<
+  TAccountTargetMerkleTree extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: AssertConcurrentMerkleTreeAccountInput<TAccountTargetMerkleTree>
+): IInstruction {
+  // Program address.
+  const programAddress =
+    'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK' as Address<'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'>;
+
+  // Original accounts.
+  type AccountMetas = Parameters<
+    typeof getAssertConcurrentMerkleTreeAccountInstructionRaw<
+      TProgram,
+      TAccountTargetMerkleTree
+    >
+  >[0];
+  const accounts: Record<keyof AccountMetas, ResolvedAccount> = {
+    targetMerkleTree: {
+      value: input.targetMerkleTree ?? null,
+      isWritable: false,
+    },
+  };
+
+  // Original args.
+  const args = { ...input };
+
+  // Get account metas and signers.
+  const accountMetas = getAccountMetasWithSigners(
+    accounts,
+    'programId',
+    programAddress
+  );
+
+  const instruction = getAssertConcurrentMerkleTreeAccountInstructionRaw(
+    accountMetas as Record<keyof AccountMetas, IAccountMeta>,
+    args as AssertConcurrentMerkleTreeAccountInstructionDataArgs,
+    programAddress
+  );
+
+  return instruction;
+}
+
+export function getAssertConcurrentMerkleTreeAccountInstructionRaw<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountTargetMerkleTree extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+>(
+  accounts: {
+    targetMerkleTree: TAccountTargetMerkleTree extends string
+      ? Address<TAccountTargetMerkleTree>
+      : TAccountTargetMerkleTree;
+  },
+  args: AssertConcurrentMerkleTreeAccountInstructionDataArgs,
+  programAddress: Address<TProgram> = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK' as Address<TProgram>,
+  remainingAccounts?: TRemainingAccounts
+) {
+  return {
+    accounts: [
+      accountMetaWithDefault(accounts.targetMerkleTree, AccountRole.READONLY),
+      ...(remainingAccounts ?? []),
+    ],
+    data: getAssertConcurrentMerkleTreeAccountInstructionDataEncoder().encode(
+      args
+    ),
+    programAddress,
+  } as AssertConcurrentMerkleTreeAccountInstruction<
+    TProgram,
+    TAccountTargetMerkleTree,
+    TRemainingAccounts
+  >;
+}
+
+export type ParsedAssertConcurrentMerkleTreeAccountInstruction<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[]
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Target spl-account-compression merkle tree account to be asserted */
+    targetMerkleTree: TAccountMetas[0];
+  };
+  data: AssertConcurrentMerkleTreeAccountInstructionData;
+};
+
+export function parseAssertConcurrentMerkleTreeAccountInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[]
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAssertConcurrentMerkleTreeAccountInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 1) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      targetMerkleTree: getNextAccount(),
+    },
+    data: getAssertConcurrentMerkleTreeAccountInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
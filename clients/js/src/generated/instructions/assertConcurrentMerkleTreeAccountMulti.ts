@@ -0,0 +1,300 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Address } from '@solana/addresses';
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  Option,
+  OptionOrNullable,
+  combineCodec,
+  getArrayDecoder,
+  getArrayEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  mapEncoder,
+  none,
+} from '@solana/codecs';
+import {
+  AccountRole,
+  IAccountMeta,
+  IInstruction,
+  IInstructionWithAccounts,
+  IInstructionWithData,
+  ReadonlyAccount,
+} from '@solana/instructions';
+import {
+  ResolvedAccount,
+  accountMetaWithDefault,
+  getAccountMetasWithSigners,
+} from '../shared';
+import {
+  ConcurrentMerkleTreeAssertion,
+  ConcurrentMerkleTreeAssertionArgs,
+  EvaluationMode,
+  EvaluationModeArgs,
+  LogLevel,
+  LogLevelArgs,
+  getConcurrentMerkleTreeAssertionDecoder,
+  getConcurrentMerkleTreeAssertionEncoder,
+  getEvaluationModeDecoder,
+  getEvaluationModeEncoder,
+  getLogLevelDecoder,
+  getLogLevelEncoder,
+} from '../types';
+
+export type AssertConcurrentMerkleTreeAccountMultiInstruction<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountTargetMerkleTree extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountTargetMerkleTree extends string
+        ? ReadonlyAccount<TAccountTargetMerkleTree>
+        : TAccountTargetMerkleTree,
+      ...TRemainingAccounts
+    ]
+  >;
+
+export type AssertConcurrentMerkleTreeAccountMultiInstructionWithSigners<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountTargetMerkleTree extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountTargetMerkleTree extends string
+        ? ReadonlyAccount<TAccountTargetMerkleTree>
+        : TAccountTargetMerkleTree,
+      ...TRemainingAccounts
+    ]
+  >;
+
+export type AssertConcurrentMerkleTreeAccountMultiInstructionData = {
+  discriminator: number;
+  logLevel: LogLevel;
+  assertions: Array<ConcurrentMerkleTreeAssertion>;
+  evaluationMode: EvaluationMode;
+  errorCodes: Option<Array<number>>;
+};
+
+export type AssertConcurrentMerkleTreeAccountMultiInstructionDataArgs = {
+  logLevel?: LogLevelArgs;
+  assertions: Array<ConcurrentMerkleTreeAssertionArgs>;
+  evaluationMode?: EvaluationModeArgs;
+  errorCodes?: OptionOrNullable<Array<number>>;
+};
+
+export function getAssertConcurrentMerkleTreeAccountMultiInstructionDataEncoder(): Encoder<AssertConcurrentMerkleTreeAccountMultiInstructionDataArgs> {
+  return mapEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['logLevel', getLogLevelEncoder()],
+      [
+        'assertions',
+        getArrayEncoder(getConcurrentMerkleTreeAssertionEncoder()),
+      ],
+      ['evaluationMode', getEvaluationModeEncoder()],
+      ['errorCodes', getOptionEncoder(getArrayEncoder(getU16Encoder()))],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: 20,
+      logLevel: value.logLevel ?? LogLevel.Silent,
+      evaluationMode: value.evaluationMode ?? EvaluationMode.Strict,
+      errorCodes: value.errorCodes ?? none(),
+    })
+  );
+}
+
+export function getAssertConcurrentMerkleTreeAccountMultiInstructionDataDecoder(): Decoder<AssertConcurrentMerkleTreeAccountMultiInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['logLevel', getLogLevelDecoder()],
+    ['assertions', getArrayDecoder(getConcurrentMerkleTreeAssertionDecoder())],
+    ['evaluationMode', getEvaluationModeDecoder()],
+    ['errorCodes', getOptionDecoder(getArrayDecoder(getU16Decoder()))],
+  ]);
+}
+
+export function getAssertConcurrentMerkleTreeAccountMultiInstructionDataCodec(): Codec<
+  AssertConcurrentMerkleTreeAccountMultiInstructionDataArgs,
+  AssertConcurrentMerkleTreeAccountMultiInstructionData
+> {
+  return combineCodec(
+    getAssertConcurrentMerkleTreeAccountMultiInstructionDataEncoder(),
+    getAssertConcurrentMerkleTreeAccountMultiInstructionDataDecoder()
+  );
+}
+
+export type AssertConcurrentMerkleTreeAccountMultiInput<
+  TAccountTargetMerkleTree extends string
+> = {
+  /** Target spl-account-compression merkle tree account to be asserted */
+  targetMerkleTree: Address<TAccountTargetMerkleTree>;
+  logLevel?: AssertConcurrentMerkleTreeAccountMultiInstructionDataArgs['logLevel'];
+  assertions: AssertConcurrentMerkleTreeAccountMultiInstructionDataArgs['assertions'];
+  evaluationMode?: AssertConcurrentMerkleTreeAccountMultiInstructionDataArgs['evaluationMode'];
+  errorCodes?: AssertConcurrentMerkleTreeAccountMultiInstructionDataArgs['errorCodes'];
+};
+
+export type AssertConcurrentMerkleTreeAccountMultiInputWithSigners<
+  TAccountTargetMerkleTree extends string
+> = {
+  /** Target spl-account-compression merkle tree account to be asserted */
+  targetMerkleTree: Address<TAccountTargetMerkleTree>;
+  logLevel?: AssertConcurrentMerkleTreeAccountMultiInstructionDataArgs['logLevel'];
+  assertions: AssertConcurrentMerkleTreeAccountMultiInstructionDataArgs['assertions'];
+  evaluationMode?: AssertConcurrentMerkleTreeAccountMultiInstructionDataArgs['evaluationMode'];
+  errorCodes?: AssertConcurrentMerkleTreeAccountMultiInstructionDataArgs['errorCodes'];
+};
+
+export function getAssertConcurrentMerkleTreeAccountMultiInstruction<
+  TAccountTargetMerkleTree extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: AssertConcurrentMerkleTreeAccountMultiInputWithSigners<TAccountTargetMerkleTree>
+): AssertConcurrentMerkleTreeAccountMultiInstructionWithSigners<
+  TProgram,
+  TAccountTargetMerkleTree
+>;
+export function getAssertConcurrentMerkleTreeAccountMultiInstruction<
+  TAccountTargetMerkleTree extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: AssertConcurrentMerkleTreeAccountMultiInput<TAccountTargetMerkleTree>
+): AssertConcurrentMerkleTreeAccountMultiInstruction<
+  TProgram,
+  TAccountTargetMerkleTree
+>;
+export function getAssertConcurrentMerkleTreeAccountMultiInstruction<
+  TAccountTargetMerkleTree extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: AssertConcurrentMerkleTreeAccountMultiInput<TAccountTargetMerkleTree>
+): IInstruction {
+  // Program address.
+  const programAddress =
+    'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK' as Address<'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'>;
+
+  // Original accounts.
+  type AccountMetas = Parameters<
+    typeof getAssertConcurrentMerkleTreeAccountMultiInstructionRaw<
+      TProgram,
+      TAccountTargetMerkleTree
+    >
+  >[0];
+  const accounts: Record<keyof AccountMetas, ResolvedAccount> = {
+    targetMerkleTree: {
+      value: input.targetMerkleTree ?? null,
+      isWritable: false,
+    },
+  };
+
+  // Original args.
+  const args = { ...input };
+
+  // Get account metas and signers.
+  const accountMetas = getAccountMetasWithSigners(
+    accounts,
+    'programId',
+    programAddress
+  );
+
+  const instruction = getAssertConcurrentMerkleTreeAccountMultiInstructionRaw(
+    accountMetas as Record<keyof AccountMetas, IAccountMeta>,
+    args as AssertConcurrentMerkleTreeAccountMultiInstructionDataArgs,
+    programAddress
+  );
+
+  return instruction;
+}
+
+export function getAssertConcurrentMerkleTreeAccountMultiInstructionRaw<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountTargetMerkleTree extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+>(
+  accounts: {
+    targetMerkleTree: TAccountTargetMerkleTree extends string
+      ? Address<TAccountTargetMerkleTree>
+      : TAccountTargetMerkleTree;
+  },
+  args: AssertConcurrentMerkleTreeAccountMultiInstructionDataArgs,
+  programAddress: Address<TProgram> = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK' as Address<TProgram>,
+  remainingAccounts?: TRemainingAccounts
+) {
+  return {
+    accounts: [
+      accountMetaWithDefault(accounts.targetMerkleTree, AccountRole.READONLY),
+      ...(remainingAccounts ?? []),
+    ],
+    data: getAssertConcurrentMerkleTreeAccountMultiInstructionDataEncoder().encode(
+      args
+    ),
+    programAddress,
+  } as AssertConcurrentMerkleTreeAccountMultiInstruction<
+    TProgram,
+    TAccountTargetMerkleTree,
+    TRemainingAccounts
+  >;
+}
+
+export type ParsedAssertConcurrentMerkleTreeAccountMultiInstruction<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[]
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Target spl-account-compression merkle tree account to be asserted */
+    targetMerkleTree: TAccountMetas[0];
+  };
+  data: AssertConcurrentMerkleTreeAccountMultiInstructionData;
+};
+
+export function parseAssertConcurrentMerkleTreeAccountMultiInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[]
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAssertConcurrentMerkleTreeAccountMultiInstruction<
+  TProgram,
+  TAccountMetas
+> {
+  if (instruction.accounts.length < 1) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      targetMerkleTree: getNextAccount(),
+    },
+    data: getAssertConcurrentMerkleTreeAccountMultiInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
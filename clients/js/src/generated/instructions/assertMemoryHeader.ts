@@ -0,0 +1,263 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Address } from '@solana/addresses';
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  Option,
+  OptionOrNullable,
+  combineCodec,
+  getOptionDecoder,
+  getOptionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  mapEncoder,
+  none,
+} from '@solana/codecs';
+import {
+  AccountRole,
+  IAccountMeta,
+  IInstruction,
+  IInstructionWithAccounts,
+  IInstructionWithData,
+  ReadonlyAccount,
+} from '@solana/instructions';
+import {
+  ResolvedAccount,
+  accountMetaWithDefault,
+  getAccountMetasWithSigners,
+} from '../shared';
+import {
+  LogLevel,
+  LogLevelArgs,
+  MemoryHeaderAssertion,
+  MemoryHeaderAssertionArgs,
+  getLogLevelDecoder,
+  getLogLevelEncoder,
+  getMemoryHeaderAssertionDecoder,
+  getMemoryHeaderAssertionEncoder,
+} from '../types';
+
+export type AssertMemoryHeaderInstruction<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountTargetAccount extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountTargetAccount extends string
+        ? ReadonlyAccount<TAccountTargetAccount>
+        : TAccountTargetAccount,
+      ...TRemainingAccounts
+    ]
+  >;
+
+export type AssertMemoryHeaderInstructionWithSigners<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountTargetAccount extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountTargetAccount extends string
+        ? ReadonlyAccount<TAccountTargetAccount>
+        : TAccountTargetAccount,
+      ...TRemainingAccounts
+    ]
+  >;
+
+export type AssertMemoryHeaderInstructionData = {
+  discriminator: number;
+  logLevel: LogLevel;
+  assertion: MemoryHeaderAssertion;
+  errorCode: Option<number>;
+};
+
+export type AssertMemoryHeaderInstructionDataArgs = {
+  logLevel?: LogLevelArgs;
+  assertion: MemoryHeaderAssertionArgs;
+  errorCode?: OptionOrNullable<number>;
+};
+
+export function getAssertMemoryHeaderInstructionDataEncoder(): Encoder<AssertMemoryHeaderInstructionDataArgs> {
+  return mapEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['logLevel', getLogLevelEncoder()],
+      ['assertion', getMemoryHeaderAssertionEncoder()],
+      ['errorCode', getOptionEncoder(getU16Encoder())],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: 24,
+      logLevel: value.logLevel ?? LogLevel.Silent,
+      errorCode: value.errorCode ?? none(),
+    })
+  );
+}
+
+export function getAssertMemoryHeaderInstructionDataDecoder(): Decoder<AssertMemoryHeaderInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['logLevel', getLogLevelDecoder()],
+    ['assertion', getMemoryHeaderAssertionDecoder()],
+    ['errorCode', getOptionDecoder(getU16Decoder())],
+  ]);
+}
+
+export function getAssertMemoryHeaderInstructionDataCodec(): Codec<
+  AssertMemoryHeaderInstructionDataArgs,
+  AssertMemoryHeaderInstructionData
+> {
+  return combineCodec(
+    getAssertMemoryHeaderInstructionDataEncoder(),
+    getAssertMemoryHeaderInstructionDataDecoder()
+  );
+}
+
+export type AssertMemoryHeaderInput<TAccountTargetAccount extends string> = {
+  /** Target memory account to be asserted */
+  targetAccount: Address<TAccountTargetAccount>;
+  logLevel?: AssertMemoryHeaderInstructionDataArgs['logLevel'];
+  assertion: AssertMemoryHeaderInstructionDataArgs['assertion'];
+  errorCode?: AssertMemoryHeaderInstructionDataArgs['errorCode'];
+};
+
+export type AssertMemoryHeaderInputWithSigners<
+  TAccountTargetAccount extends string
+> = {
+  /** Target memory account to be asserted */
+  targetAccount: Address<TAccountTargetAccount>;
+  logLevel?: AssertMemoryHeaderInstructionDataArgs['logLevel'];
+  assertion: AssertMemoryHeaderInstructionDataArgs['assertion'];
+  errorCode?: AssertMemoryHeaderInstructionDataArgs['errorCode'];
+};
+
+export function getAssertMemoryHeaderInstruction<
+  TAccountTargetAccount extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: AssertMemoryHeaderInputWithSigners<TAccountTargetAccount>
+): AssertMemoryHeaderInstructionWithSigners<TProgram, TAccountTargetAccount>;
+export function getAssertMemoryHeaderInstruction<
+  TAccountTargetAccount extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: AssertMemoryHeaderInput<TAccountTargetAccount>
+): AssertMemoryHeaderInstruction<TProgram, TAccountTargetAccount>;
+export function getAssertMemoryHeaderInstruction<
+  TAccountTargetAccount extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(input: AssertMemoryHeaderInput<TAccountTargetAccount>): IInstruction {
+  // Program address.
+  const programAddress =
+    'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK' as Address<'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'>;
+
+  // Original accounts.
+  type AccountMetas = Parameters<
+    typeof getAssertMemoryHeaderInstructionRaw<TProgram, TAccountTargetAccount>
+  >[0];
+  const accounts: Record<keyof AccountMetas, ResolvedAccount> = {
+    targetAccount: { value: input.targetAccount ?? null, isWritable: false },
+  };
+
+  // Original args.
+  const args = { ...input };
+
+  // Get account metas and signers.
+  const accountMetas = getAccountMetasWithSigners(
+    accounts,
+    'programId',
+    programAddress
+  );
+
+  const instruction = getAssertMemoryHeaderInstructionRaw(
+    accountMetas as Record<keyof AccountMetas, IAccountMeta>,
+    args as AssertMemoryHeaderInstructionDataArgs,
+    programAddress
+  );
+
+  return instruction;
+}
+
+export function getAssertMemoryHeaderInstructionRaw<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountTargetAccount extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+>(
+  accounts: {
+    targetAccount: TAccountTargetAccount extends string
+      ? Address<TAccountTargetAccount>
+      : TAccountTargetAccount;
+  },
+  args: AssertMemoryHeaderInstructionDataArgs,
+  programAddress: Address<TProgram> = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK' as Address<TProgram>,
+  remainingAccounts?: TRemainingAccounts
+) {
+  return {
+    accounts: [
+      accountMetaWithDefault(accounts.targetAccount, AccountRole.READONLY),
+      ...(remainingAccounts ?? []),
+    ],
+    data: getAssertMemoryHeaderInstructionDataEncoder().encode(args),
+    programAddress,
+  } as AssertMemoryHeaderInstruction<
+    TProgram,
+    TAccountTargetAccount,
+    TRemainingAccounts
+  >;
+}
+
+export type ParsedAssertMemoryHeaderInstruction<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[]
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Target memory account to be asserted */
+    targetAccount: TAccountMetas[0];
+  };
+  data: AssertMemoryHeaderInstructionData;
+};
+
+export function parseAssertMemoryHeaderInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[]
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAssertMemoryHeaderInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 1) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      targetAccount: getNextAccount(),
+    },
+    data: getAssertMemoryHeaderInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
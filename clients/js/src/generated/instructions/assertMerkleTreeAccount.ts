@@ -11,12 +11,19 @@ import {
   Codec,
   Decoder,
   Encoder,
+  Option,
+  OptionOrNullable,
   combineCodec,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU8Decoder,
   getU8Encoder,
   mapEncoder,
+  none,
 } from '@solana/codecs';
 import {
   AccountRole,
@@ -92,11 +99,13 @@ export type AssertMerkleTreeAccountInstructionData = {
   discriminator: number;
   logLevel: LogLevel;
   assertion: MerkleTreeAssertion;
+  errorCode: Option<number>;
 };
 
 export type AssertMerkleTreeAccountInstructionDataArgs = {
   logLevel?: LogLevelArgs;
   assertion: MerkleTreeAssertionArgs;
+  errorCode?: OptionOrNullable<number>;
 };
 
 export function getAssertMerkleTreeAccountInstructionDataEncoder(): Encoder<AssertMerkleTreeAccountInstructionDataArgs> {
@@ -105,11 +114,13 @@ export function getAssertMerkleTreeAccountInstructionDataEncoder(): Encoder<Asse
       ['discriminator', getU8Encoder()],
       ['logLevel', getLogLevelEncoder()],
       ['assertion', getMerkleTreeAssertionEncoder()],
+      ['errorCode', getOptionEncoder(getU16Encoder())],
     ]),
     (value) => ({
       ...value,
       discriminator: 15,
       logLevel: value.logLevel ?? LogLevel.Silent,
+      errorCode: value.errorCode ?? none(),
     })
   );
 }
@@ -119,6 +130,7 @@ export function getAssertMerkleTreeAccountInstructionDataDecoder(): Decoder<Asse
     ['discriminator', getU8Decoder()],
     ['logLevel', getLogLevelDecoder()],
     ['assertion', getMerkleTreeAssertionDecoder()],
+    ['errorCode', getOptionDecoder(getU16Decoder())],
   ]);
 }
 
@@ -145,6 +157,7 @@ export type AssertMerkleTreeAccountInput<
   splAccountCompression: Address<TAccountSplAccountCompression>;
   logLevel?: AssertMerkleTreeAccountInstructionDataArgs['logLevel'];
   assertion: AssertMerkleTreeAccountInstructionDataArgs['assertion'];
+  errorCode?: AssertMerkleTreeAccountInstructionDataArgs['errorCode'];
 };
 
 export type AssertMerkleTreeAccountInputWithSigners<
@@ -160,6 +173,7 @@ export type AssertMerkleTreeAccountInputWithSigners<
   splAccountCompression: Address<TAccountSplAccountCompression>;
   logLevel?: AssertMerkleTreeAccountInstructionDataArgs['logLevel'];
   assertion: AssertMerkleTreeAccountInstructionDataArgs['assertion'];
+  errorCode?: AssertMerkleTreeAccountInstructionDataArgs['errorCode'];
 };
 
 export function getAssertMerkleTreeAccountInstruction<
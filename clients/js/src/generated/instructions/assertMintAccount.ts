@@ -11,12 +11,19 @@ import {
   Codec,
   Decoder,
   Encoder,
+  Option,
+  OptionOrNullable,
   combineCodec,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU8Decoder,
   getU8Encoder,
   mapEncoder,
+  none,
 } from '@solana/codecs';
 import {
   AccountRole,
@@ -76,11 +83,13 @@ export type AssertMintAccountInstructionData = {
   discriminator: number;
   logLevel: LogLevel;
   assertion: MintAccountAssertion;
+  errorCode: Option<number>;
 };
 
 export type AssertMintAccountInstructionDataArgs = {
   logLevel?: LogLevelArgs;
   assertion: MintAccountAssertionArgs;
+  errorCode?: OptionOrNullable<number>;
 };
 
 export function getAssertMintAccountInstructionDataEncoder(): Encoder<AssertMintAccountInstructionDataArgs> {
@@ -89,11 +98,13 @@ export function getAssertMintAccountInstructionDataEncoder(): Encoder<AssertMint
       ['discriminator', getU8Encoder()],
       ['logLevel', getLogLevelEncoder()],
       ['assertion', getMintAccountAssertionEncoder()],
+      ['errorCode', getOptionEncoder(getU16Encoder())],
     ]),
     (value) => ({
       ...value,
       discriminator: 6,
       logLevel: value.logLevel ?? LogLevel.Silent,
+      errorCode: value.errorCode ?? none(),
     })
   );
 }
@@ -103,6 +114,7 @@ export function getAssertMintAccountInstructionDataDecoder(): Decoder<AssertMint
     ['discriminator', getU8Decoder()],
     ['logLevel', getLogLevelDecoder()],
     ['assertion', getMintAccountAssertionDecoder()],
+    ['errorCode', getOptionDecoder(getU16Decoder())],
   ]);
 }
 
@@ -121,6 +133,7 @@ export type AssertMintAccountInput<TAccountTargetAccount extends string> = {
   targetAccount: Address<TAccountTargetAccount>;
   logLevel?: AssertMintAccountInstructionDataArgs['logLevel'];
   assertion: AssertMintAccountInstructionDataArgs['assertion'];
+  errorCode?: AssertMintAccountInstructionDataArgs['errorCode'];
 };
 
 export type AssertMintAccountInputWithSigners<
@@ -130,6 +143,7 @@ export type AssertMintAccountInputWithSigners<
   targetAccount: Address<TAccountTargetAccount>;
   logLevel?: AssertMintAccountInstructionDataArgs['logLevel'];
   assertion: AssertMintAccountInstructionDataArgs['assertion'];
+  errorCode?: AssertMintAccountInstructionDataArgs['errorCode'];
 };
 
 export function getAssertMintAccountInstruction<
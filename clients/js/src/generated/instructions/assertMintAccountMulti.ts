@@ -11,14 +11,21 @@ import {
   Codec,
   Decoder,
   Encoder,
+  Option,
+  OptionOrNullable,
   combineCodec,
   getArrayDecoder,
   getArrayEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU8Decoder,
   getU8Encoder,
   mapEncoder,
+  none,
 } from '@solana/codecs';
 import {
   AccountRole,
@@ -34,10 +41,14 @@ import {
   getAccountMetasWithSigners,
 } from '../shared';
 import {
+  EvaluationMode,
+  EvaluationModeArgs,
   LogLevel,
   LogLevelArgs,
   MintAccountAssertion,
   MintAccountAssertionArgs,
+  getEvaluationModeDecoder,
+  getEvaluationModeEncoder,
   getLogLevelDecoder,
   getLogLevelEncoder,
   getMintAccountAssertionDecoder,
@@ -78,11 +89,15 @@ export type AssertMintAccountMultiInstructionData = {
   discriminator: number;
   logLevel: LogLevel;
   assertions: Array<MintAccountAssertion>;
+  evaluationMode: EvaluationMode;
+  errorCodes: Option<Array<number>>;
 };
 
 export type AssertMintAccountMultiInstructionDataArgs = {
   logLevel?: LogLevelArgs;
   assertions: Array<MintAccountAssertionArgs>;
+  evaluationMode?: EvaluationModeArgs;
+  errorCodes?: OptionOrNullable<Array<number>>;
 };
 
 export function getAssertMintAccountMultiInstructionDataEncoder(): Encoder<AssertMintAccountMultiInstructionDataArgs> {
@@ -91,11 +106,15 @@ export function getAssertMintAccountMultiInstructionDataEncoder(): Encoder<Asser
       ['discriminator', getU8Encoder()],
       ['logLevel', getLogLevelEncoder()],
       ['assertions', getArrayEncoder(getMintAccountAssertionEncoder())],
+      ['evaluationMode', getEvaluationModeEncoder()],
+      ['errorCodes', getOptionEncoder(getArrayEncoder(getU16Encoder()))],
     ]),
     (value) => ({
       ...value,
       discriminator: 7,
       logLevel: value.logLevel ?? LogLevel.Silent,
+      evaluationMode: value.evaluationMode ?? EvaluationMode.Strict,
+      errorCodes: value.errorCodes ?? none(),
     })
   );
 }
@@ -105,6 +124,8 @@ export function getAssertMintAccountMultiInstructionDataDecoder(): Decoder<Asser
     ['discriminator', getU8Decoder()],
     ['logLevel', getLogLevelDecoder()],
     ['assertions', getArrayDecoder(getMintAccountAssertionDecoder())],
+    ['evaluationMode', getEvaluationModeDecoder()],
+    ['errorCodes', getOptionDecoder(getArrayDecoder(getU16Decoder()))],
   ]);
 }
 
@@ -124,6 +145,8 @@ export type AssertMintAccountMultiInput<TAccountTargetAccount extends string> =
     targetAccount: Address<TAccountTargetAccount>;
     logLevel?: AssertMintAccountMultiInstructionDataArgs['logLevel'];
     assertions: AssertMintAccountMultiInstructionDataArgs['assertions'];
+    evaluationMode?: AssertMintAccountMultiInstructionDataArgs['evaluationMode'];
+    errorCodes?: AssertMintAccountMultiInstructionDataArgs['errorCodes'];
   };
 
 export type AssertMintAccountMultiInputWithSigners<
@@ -133,6 +156,8 @@ export type AssertMintAccountMultiInputWithSigners<
   targetAccount: Address<TAccountTargetAccount>;
   logLevel?: AssertMintAccountMultiInstructionDataArgs['logLevel'];
   assertions: AssertMintAccountMultiInstructionDataArgs['assertions'];
+  evaluationMode?: AssertMintAccountMultiInstructionDataArgs['evaluationMode'];
+  errorCodes?: AssertMintAccountMultiInstructionDataArgs['errorCodes'];
 };
 
 export function getAssertMintAccountMultiInstruction<
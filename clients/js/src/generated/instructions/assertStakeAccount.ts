@@ -11,12 +11,19 @@ import {
   Codec,
   Decoder,
   Encoder,
+  Option,
+  OptionOrNullable,
   combineCodec,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU8Decoder,
   getU8Encoder,
   mapEncoder,
+  none,
 } from '@solana/codecs';
 import {
   AccountRole,
@@ -76,11 +83,13 @@ export type AssertStakeAccountInstructionData = {
   discriminator: number;
   logLevel: LogLevel;
   assertion: StakeAccountAssertion;
+  errorCode: Option<number>;
 };
 
 export type AssertStakeAccountInstructionDataArgs = {
   logLevel?: LogLevelArgs;
   assertion: StakeAccountAssertionArgs;
+  errorCode?: OptionOrNullable<number>;
 };
 
 export function getAssertStakeAccountInstructionDataEncoder(): Encoder<AssertStakeAccountInstructionDataArgs> {
@@ -89,11 +98,13 @@ export function getAssertStakeAccountInstructionDataEncoder(): Encoder<AssertSta
       ['discriminator', getU8Encoder()],
       ['logLevel', getLogLevelEncoder()],
       ['assertion', getStakeAccountAssertionEncoder()],
+      ['errorCode', getOptionEncoder(getU16Encoder())],
     ]),
     (value) => ({
       ...value,
       discriminator: 10,
       logLevel: value.logLevel ?? LogLevel.Silent,
+      errorCode: value.errorCode ?? none(),
     })
   );
 }
@@ -103,6 +114,7 @@ export function getAssertStakeAccountInstructionDataDecoder(): Decoder<AssertSta
     ['discriminator', getU8Decoder()],
     ['logLevel', getLogLevelDecoder()],
     ['assertion', getStakeAccountAssertionDecoder()],
+    ['errorCode', getOptionDecoder(getU16Decoder())],
   ]);
 }
 
@@ -121,6 +133,7 @@ export type AssertStakeAccountInput<TAccountTargetAccount extends string> = {
   targetAccount: Address<TAccountTargetAccount>;
   logLevel?: AssertStakeAccountInstructionDataArgs['logLevel'];
   assertion: AssertStakeAccountInstructionDataArgs['assertion'];
+  errorCode?: AssertStakeAccountInstructionDataArgs['errorCode'];
 };
 
 export type AssertStakeAccountInputWithSigners<
@@ -130,6 +143,7 @@ export type AssertStakeAccountInputWithSigners<
   targetAccount: Address<TAccountTargetAccount>;
   logLevel?: AssertStakeAccountInstructionDataArgs['logLevel'];
   assertion: AssertStakeAccountInstructionDataArgs['assertion'];
+  errorCode?: AssertStakeAccountInstructionDataArgs['errorCode'];
 };
 
 export function getAssertStakeAccountInstruction<
@@ -11,14 +11,21 @@ import {
   Codec,
   Decoder,
   Encoder,
+  Option,
+  OptionOrNullable,
   combineCodec,
   getArrayDecoder,
   getArrayEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU8Decoder,
   getU8Encoder,
   mapEncoder,
+  none,
 } from '@solana/codecs';
 import {
   AccountRole,
@@ -34,10 +41,14 @@ import {
   getAccountMetasWithSigners,
 } from '../shared';
 import {
+  EvaluationMode,
+  EvaluationModeArgs,
   LogLevel,
   LogLevelArgs,
   StakeAccountAssertion,
   StakeAccountAssertionArgs,
+  getEvaluationModeDecoder,
+  getEvaluationModeEncoder,
   getLogLevelDecoder,
   getLogLevelEncoder,
   getStakeAccountAssertionDecoder,
@@ -78,11 +89,15 @@ export type AssertStakeAccountMultiInstructionData = {
   discriminator: number;
   logLevel: LogLevel;
   assertions: Array<StakeAccountAssertion>;
+  evaluationMode: EvaluationMode;
+  errorCodes: Option<Array<number>>;
 };
 
 export type AssertStakeAccountMultiInstructionDataArgs = {
   logLevel?: LogLevelArgs;
   assertions: Array<StakeAccountAssertionArgs>;
+  evaluationMode?: EvaluationModeArgs;
+  errorCodes?: OptionOrNullable<Array<number>>;
 };
 
 export function getAssertStakeAccountMultiInstructionDataEncoder(): Encoder<AssertStakeAccountMultiInstructionDataArgs> {
@@ -91,11 +106,15 @@ export function getAssertStakeAccountMultiInstructionDataEncoder(): Encoder<Asse
       ['discriminator', getU8Encoder()],
       ['logLevel', getLogLevelEncoder()],
       ['assertions', getArrayEncoder(getStakeAccountAssertionEncoder())],
+      ['evaluationMode', getEvaluationModeEncoder()],
+      ['errorCodes', getOptionEncoder(getArrayEncoder(getU16Encoder()))],
     ]),
     (value) => ({
       ...value,
       discriminator: 11,
       logLevel: value.logLevel ?? LogLevel.Silent,
+      evaluationMode: value.evaluationMode ?? EvaluationMode.Strict,
+      errorCodes: value.errorCodes ?? none(),
     })
   );
 }
@@ -105,6 +124,8 @@ export function getAssertStakeAccountMultiInstructionDataDecoder(): Decoder<Asse
     ['discriminator', getU8Decoder()],
     ['logLevel', getLogLevelDecoder()],
     ['assertions', getArrayDecoder(getStakeAccountAssertionDecoder())],
+    ['evaluationMode', getEvaluationModeDecoder()],
+    ['errorCodes', getOptionDecoder(getArrayDecoder(getU16Decoder()))],
   ]);
 }
 
@@ -124,6 +145,8 @@ export type AssertStakeAccountMultiInput<TAccountTargetAccount extends string> =
     targetAccount: Address<TAccountTargetAccount>;
     logLevel?: AssertStakeAccountMultiInstructionDataArgs['logLevel'];
     assertions: AssertStakeAccountMultiInstructionDataArgs['assertions'];
+    evaluationMode?: AssertStakeAccountMultiInstructionDataArgs['evaluationMode'];
+    errorCodes?: AssertStakeAccountMultiInstructionDataArgs['errorCodes'];
   };
 
 export type AssertStakeAccountMultiInputWithSigners<
@@ -133,6 +156,8 @@ export type AssertStakeAccountMultiInputWithSigners<
   targetAccount: Address<TAccountTargetAccount>;
   logLevel?: AssertStakeAccountMultiInstructionDataArgs['logLevel'];
   assertions: AssertStakeAccountMultiInstructionDataArgs['assertions'];
+  evaluationMode?: AssertStakeAccountMultiInstructionDataArgs['evaluationMode'];
+  errorCodes?: AssertStakeAccountMultiInstructionDataArgs['errorCodes'];
 };
 
 export function getAssertStakeAccountMultiInstruction<
@@ -0,0 +1,270 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Address } from '@solana/addresses';
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  Option,
+  OptionOrNullable,
+  combineCodec,
+  getOptionDecoder,
+  getOptionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  mapEncoder,
+  none,
+} from '@solana/codecs';
+import {
+  AccountRole,
+  IAccountMeta,
+  IInstruction,
+  IInstructionWithAccounts,
+  IInstructionWithData,
+  ReadonlyAccount,
+} from '@solana/instructions';
+import {
+  ResolvedAccount,
+  accountMetaWithDefault,
+  getAccountMetasWithSigners,
+} from '../shared';
+import {
+  LogLevel,
+  LogLevelArgs,
+  StakePoolAccountAssertion,
+  StakePoolAccountAssertionArgs,
+  getLogLevelDecoder,
+  getLogLevelEncoder,
+  getStakePoolAccountAssertionDecoder,
+  getStakePoolAccountAssertionEncoder,
+} from '../types';
+
+export type AssertStakePoolAccountInstruction<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountTargetAccount extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountTargetAccount extends string
+        ? ReadonlyAccount<TAccountTargetAccount>
+        : TAccountTargetAccount,
+      ...TRemainingAccounts
+    ]
+  >;
+
+export type AssertStakePoolAccountInstructionWithSigners<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountTargetAccount extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountTargetAccount extends string
+        ? ReadonlyAccount<TAccountTargetAccount>
+        : TAccountTargetAccount,
+      ...TRemainingAccounts
+    ]
+  >;
+
+export type AssertStakePoolAccountInstructionData = {
+  discriminator: number;
+  logLevel: LogLevel;
+  assertion: StakePoolAccountAssertion;
+  errorCode: Option<number>;
+};
+
+export type AssertStakePoolAccountInstructionDataArgs = {
+  logLevel?: LogLevelArgs;
+  assertion: StakePoolAccountAssertionArgs;
+  errorCode?: OptionOrNullable<number>;
+};
+
+export function getAssertStakePoolAccountInstructionDataEncoder(): Encoder<AssertStakePoolAccountInstructionDataArgs> {
+  return mapEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['logLevel', getLogLevelEncoder()],
+      ['assertion', getStakePoolAccountAssertionEncoder()],
+      ['errorCode', getOptionEncoder(getU16Encoder())],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: 17,
+      logLevel: value.logLevel ?? LogLevel.Silent,
+      errorCode: value.errorCode ?? none(),
+    })
+  );
+}
+
+export function getAssertStakePoolAccountInstructionDataDecoder(): Decoder<AssertStakePoolAccountInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['logLevel', getLogLevelDecoder()],
+    ['assertion', getStakePoolAccountAssertionDecoder()],
+    ['errorCode', getOptionDecoder(getU16Decoder())],
+  ]);
+}
+
+export function getAssertStakePoolAccountInstructionDataCodec(): Codec<
+  AssertStakePoolAccountInstructionDataArgs,
+  AssertStakePoolAccountInstructionData
+> {
+  return combineCodec(
+    getAssertStakePoolAccountInstructionDataEncoder(),
+    getAssertStakePoolAccountInstructionDataDecoder()
+  );
+}
+
+export type AssertStakePoolAccountInput<TAccountTargetAccount extends string> =
+  {
+    /** Target spl-stake-pool account to be asserted */
+    targetAccount: Address<TAccountTargetAccount>;
+    logLevel?: AssertStakePoolAccountInstructionDataArgs['logLevel'];
+    assertion: AssertStakePoolAccountInstructionDataArgs['assertion'];
+    errorCode?: AssertStakePoolAccountInstructionDataArgs['errorCode'];
+  };
+
+export type AssertStakePoolAccountInputWithSigners<
+  TAccountTargetAccount extends string
+> = {
+  /** Target spl-stake-pool account to be asserted */
+  targetAccount: Address<TAccountTargetAccount>;
+  logLevel?: AssertStakePoolAccountInstructionDataArgs['logLevel'];
+  assertion: AssertStakePoolAccountInstructionDataArgs['assertion'];
+  errorCode?: AssertStakePoolAccountInstructionDataArgs['errorCode'];
+};
+
+export function getAssertStakePoolAccountInstruction<
+  TAccountTargetAccount extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: AssertStakePoolAccountInputWithSigners<TAccountTargetAccount>
+): AssertStakePoolAccountInstructionWithSigners<
+  TProgram,
+  TAccountTargetAccount
+>;
+export function getAssertStakePoolAccountInstruction<
+  TAccountTargetAccount extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: AssertStakePoolAccountInput<TAccountTargetAccount>
+): AssertStakePoolAccountInstruction<TProgram, TAccountTargetAccount>;
+export function getAssertStakePoolAccountInstruction<
+  TAccountTargetAccount extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(input: AssertStakePoolAccountInput<TAccountTargetAccount>): IInstruction {
+  // Program address.
+  const programAddress =
+    'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK' as Address<'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'>;
+
+  // Original accounts.
+  type AccountMetas = Parameters<
+    typeof getAssertStakePoolAccountInstructionRaw<
+      TProgram,
+      TAccountTargetAccount
+    >
+  >[0];
+  const accounts: Record<keyof AccountMetas, ResolvedAccount> = {
+    targetAccount: { value: input.targetAccount ?? null, isWritable: false },
+  };
+
+  // Original args.
+  const args = { ...input };
+
+  // Get account metas and signers.
+  const accountMetas = getAccountMetasWithSigners(
+    accounts,
+    'programId',
+    programAddress
+  );
+
+  const instruction = getAssertStakePoolAccountInstructionRaw(
+    accountMetas as Record<keyof AccountMetas, IAccountMeta>,
+    args as AssertStakePoolAccountInstructionDataArgs,
+    programAddress
+  );
+
+  return instruction;
+}
+
+export function getAssertStakePoolAccountInstructionRaw<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountTargetAccount extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+>(
+  accounts: {
+    targetAccount: TAccountTargetAccount extends string
+      ? Address<TAccountTargetAccount>
+      : TAccountTargetAccount;
+  },
+  args: AssertStakePoolAccountInstructionDataArgs,
+  programAddress: Address<TProgram> = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK' as Address<TProgram>,
+  remainingAccounts?: TRemainingAccounts
+) {
+  return {
+    accounts: [
+      accountMetaWithDefault(accounts.targetAccount, AccountRole.READONLY),
+      ...(remainingAccounts ?? []),
+    ],
+    data: getAssertStakePoolAccountInstructionDataEncoder().encode(args),
+    programAddress,
+  } as AssertStakePoolAccountInstruction<
+    TProgram,
+    TAccountTargetAccount,
+    TRemainingAccounts
+  >;
+}
+
+export type ParsedAssertStakePoolAccountInstruction<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[]
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Target spl-stake-pool account to be asserted */
+    targetAccount: TAccountMetas[0];
+  };
+  data: AssertStakePoolAccountInstructionData;
+};
+
+export function parseAssertStakePoolAccountInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[]
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAssertStakePoolAccountInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 1) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      targetAccount: getNextAccount(),
+    },
+    data: getAssertStakePoolAccountInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
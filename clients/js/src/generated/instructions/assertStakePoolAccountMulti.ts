@@ -0,0 +1,286 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Address } from '@solana/addresses';
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  Option,
+  OptionOrNullable,
+  combineCodec,
+  getArrayDecoder,
+  getArrayEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  mapEncoder,
+  none,
+} from '@solana/codecs';
+import {
+  AccountRole,
+  IAccountMeta,
+  IInstruction,
+  IInstructionWithAccounts,
+  IInstructionWithData,
+  ReadonlyAccount,
+} from '@solana/instructions';
+import {
+  ResolvedAccount,
+  accountMetaWithDefault,
+  getAccountMetasWithSigners,
+} from '../shared';
+import {
+  EvaluationMode,
+  EvaluationModeArgs,
+  LogLevel,
+  LogLevelArgs,
+  StakePoolAccountAssertion,
+  StakePoolAccountAssertionArgs,
+  getEvaluationModeDecoder,
+  getEvaluationModeEncoder,
+  getLogLevelDecoder,
+  getLogLevelEncoder,
+  getStakePoolAccountAssertionDecoder,
+  getStakePoolAccountAssertionEncoder,
+} from '../types';
+
+export type AssertStakePoolAccountMultiInstruction<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountTargetAccount extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountTargetAccount extends string
+        ? ReadonlyAccount<TAccountTargetAccount>
+        : TAccountTargetAccount,
+      ...TRemainingAccounts
+    ]
+  >;
+
+export type AssertStakePoolAccountMultiInstructionWithSigners<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountTargetAccount extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountTargetAccount extends string
+        ? ReadonlyAccount<TAccountTargetAccount>
+        : TAccountTargetAccount,
+      ...TRemainingAccounts
+    ]
+  >;
+
+export type AssertStakePoolAccountMultiInstructionData = {
+  discriminator: number;
+  logLevel: LogLevel;
+  assertions: Array<StakePoolAccountAssertion>;
+  evaluationMode: EvaluationMode;
+  errorCodes: Option<Array<number>>;
+};
+
+export type AssertStakePoolAccountMultiInstructionDataArgs = {
+  logLevel?: LogLevelArgs;
+  assertions: Array<StakePoolAccountAssertionArgs>;
+  evaluationMode?: EvaluationModeArgs;
+  errorCodes?: OptionOrNullable<Array<number>>;
+};
+
+export function getAssertStakePoolAccountMultiInstructionDataEncoder(): Encoder<AssertStakePoolAccountMultiInstructionDataArgs> {
+  return mapEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['logLevel', getLogLevelEncoder()],
+      ['assertions', getArrayEncoder(getStakePoolAccountAssertionEncoder())],
+      ['evaluationMode', getEvaluationModeEncoder()],
+      ['errorCodes', getOptionEncoder(getArrayEncoder(getU16Encoder()))],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: 18,
+      logLevel: value.logLevel ?? LogLevel.Silent,
+      evaluationMode: value.evaluationMode ?? EvaluationMode.Strict,
+      errorCodes: value.errorCodes ?? none(),
+    })
+  );
+}
+
+export function getAssertStakePoolAccountMultiInstructionDataDecoder(): Decoder<AssertStakePoolAccountMultiInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['logLevel', getLogLevelDecoder()],
+    ['assertions', getArrayDecoder(getStakePoolAccountAssertionDecoder())],
+    ['evaluationMode', getEvaluationModeDecoder()],
+    ['errorCodes', getOptionDecoder(getArrayDecoder(getU16Decoder()))],
+  ]);
+}
+
+export function getAssertStakePoolAccountMultiInstructionDataCodec(): Codec<
+  AssertStakePoolAccountMultiInstructionDataArgs,
+  AssertStakePoolAccountMultiInstructionData
+> {
+  return combineCodec(
+    getAssertStakePoolAccountMultiInstructionDataEncoder(),
+    getAssertStakePoolAccountMultiInstructionDataDecoder()
+  );
+}
+
+export type AssertStakePoolAccountMultiInput<
+  TAccountTargetAccount extends string
+> = {
+  /** Target spl-stake-pool account to be asserted */
+  targetAccount: Address<TAccountTargetAccount>;
+  logLevel?: AssertStakePoolAccountMultiInstructionDataArgs['logLevel'];
+  assertions: AssertStakePoolAccountMultiInstructionDataArgs['assertions'];
+  evaluationMode?: AssertStakePoolAccountMultiInstructionDataArgs['evaluationMode'];
+  errorCodes?: AssertStakePoolAccountMultiInstructionDataArgs['errorCodes'];
+};
+
+export type AssertStakePoolAccountMultiInputWithSigners<
+  TAccountTargetAccount extends string
+> = {
+  /** Target spl-stake-pool account to be asserted */
+  targetAccount: Address<TAccountTargetAccount>;
+  logLevel?: AssertStakePoolAccountMultiInstructionDataArgs['logLevel'];
+  assertions: AssertStakePoolAccountMultiInstructionDataArgs['assertions'];
+  evaluationMode?: AssertStakePoolAccountMultiInstructionDataArgs['evaluationMode'];
+  errorCodes?: AssertStakePoolAccountMultiInstructionDataArgs['errorCodes'];
+};
+
+export function getAssertStakePoolAccountMultiInstruction<
+  TAccountTargetAccount extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: AssertStakePoolAccountMultiInputWithSigners<TAccountTargetAccount>
+): AssertStakePoolAccountMultiInstructionWithSigners<
+  TProgram,
+  TAccountTargetAccount
+>;
+export function getAssertStakePoolAccountMultiInstruction<
+  TAccountTargetAccount extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: AssertStakePoolAccountMultiInput<TAccountTargetAccount>
+): AssertStakePoolAccountMultiInstruction<TProgram, TAccountTargetAccount>;
+export function getAssertStakePoolAccountMultiInstruction<
+  TAccountTargetAccount extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: AssertStakePoolAccountMultiInput<TAccountTargetAccount>
+): IInstruction {
+  // Program address.
+  const programAddress =
+    'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK' as Address<'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'>;
+
+  // Original accounts.
+  type AccountMetas = Parameters<
+    typeof getAssertStakePoolAccountMultiInstructionRaw<
+      TProgram,
+      TAccountTargetAccount
+    >
+  >[0];
+  const accounts: Record<keyof AccountMetas, ResolvedAccount> = {
+    targetAccount: { value: input.targetAccount ?? null, isWritable: false },
+  };
+
+  // Original args.
+  const args = { ...input };
+
+  // Get account metas and signers.
+  const accountMetas = getAccountMetasWithSigners(
+    accounts,
+    'programId',
+    programAddress
+  );
+
+  const instruction = getAssertStakePoolAccountMultiInstructionRaw(
+    accountMetas as Record<keyof AccountMetas, IAccountMeta>,
+    args as AssertStakePoolAccountMultiInstructionDataArgs,
+    programAddress
+  );
+
+  return instruction;
+}
+
+export function getAssertStakePoolAccountMultiInstructionRaw<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountTargetAccount extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+>(
+  accounts: {
+    targetAccount: TAccountTargetAccount extends string
+      ? Address<TAccountTargetAccount>
+      : TAccountTargetAccount;
+  },
+  args: AssertStakePoolAccountMultiInstructionDataArgs,
+  programAddress: Address<TProgram> = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK' as Address<TProgram>,
+  remainingAccounts?: TRemainingAccounts
+) {
+  return {
+    accounts: [
+      accountMetaWithDefault(accounts.targetAccount, AccountRole.READONLY),
+      ...(remainingAccounts ?? []),
+    ],
+    data: getAssertStakePoolAccountMultiInstructionDataEncoder().encode(args),
+    programAddress,
+  } as AssertStakePoolAccountMultiInstruction<
+    TProgram,
+    TAccountTargetAccount,
+    TRemainingAccounts
+  >;
+}
+
+export type ParsedAssertStakePoolAccountMultiInstruction<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[]
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Target spl-stake-pool account to be asserted */
+    targetAccount: TAccountMetas[0];
+  };
+  data: AssertStakePoolAccountMultiInstructionData;
+};
+
+export function parseAssertStakePoolAccountMultiInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[]
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAssertStakePoolAccountMultiInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 1) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      targetAccount: getNextAccount(),
+    },
+    data: getAssertStakePoolAccountMultiInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -11,12 +11,19 @@ import {
   Codec,
   Decoder,
   Encoder,
+  Option,
+  OptionOrNullable,
   combineCodec,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU8Decoder,
   getU8Encoder,
   mapEncoder,
+  none,
 } from '@solana/codecs';
 import {
   IAccountMeta,
@@ -53,11 +60,13 @@ export type AssertSysvarClockInstructionData = {
   discriminator: number;
   logLevel: LogLevel;
   assertion: SysvarClockAssertion;
+  errorCode: Option<number>;
 };
 
 export type AssertSysvarClockInstructionDataArgs = {
   logLevel?: LogLevelArgs;
   assertion: SysvarClockAssertionArgs;
+  errorCode?: OptionOrNullable<number>;
 };
 
 export function getAssertSysvarClockInstructionDataEncoder(): Encoder<AssertSysvarClockInstructionDataArgs> {
@@ -66,11 +75,13 @@ export function getAssertSysvarClockInstructionDataEncoder(): Encoder<AssertSysv
       ['discriminator', getU8Encoder()],
       ['logLevel', getLogLevelEncoder()],
       ['assertion', getSysvarClockAssertionEncoder()],
+      ['errorCode', getOptionEncoder(getU16Encoder())],
     ]),
     (value) => ({
       ...value,
       discriminator: 14,
       logLevel: value.logLevel ?? LogLevel.Silent,
+      errorCode: value.errorCode ?? none(),
     })
   );
 }
@@ -80,6 +91,7 @@ export function getAssertSysvarClockInstructionDataDecoder(): Decoder<AssertSysv
     ['discriminator', getU8Decoder()],
     ['logLevel', getLogLevelDecoder()],
     ['assertion', getSysvarClockAssertionDecoder()],
+    ['errorCode', getOptionDecoder(getU16Decoder())],
   ]);
 }
 
@@ -96,11 +108,13 @@ export function getAssertSysvarClockInstructionDataCodec(): Codec<
 export type AssertSysvarClockInput = {
   logLevel?: AssertSysvarClockInstructionDataArgs['logLevel'];
   assertion: AssertSysvarClockInstructionDataArgs['assertion'];
+  errorCode?: AssertSysvarClockInstructionDataArgs['errorCode'];
 };
 
 export type AssertSysvarClockInputWithSigners = {
   logLevel?: AssertSysvarClockInstructionDataArgs['logLevel'];
   assertion: AssertSysvarClockInstructionDataArgs['assertion'];
+  errorCode?: AssertSysvarClockInstructionDataArgs['errorCode'];
 };
 
 export function getAssertSysvarClockInstruction<
@@ -11,12 +11,19 @@ import {
   Codec,
   Decoder,
   Encoder,
+  Option,
+  OptionOrNullable,
   combineCodec,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU8Decoder,
   getU8Encoder,
   mapEncoder,
+  none,
 } from '@solana/codecs';
 import {
   AccountRole,
@@ -76,11 +83,13 @@ export type AssertTokenAccountInstructionData = {
   discriminator: number;
   logLevel: LogLevel;
   assertion: TokenAccountAssertion;
+  errorCode: Option<number>;
 };
 
 export type AssertTokenAccountInstructionDataArgs = {
   logLevel?: LogLevelArgs;
   assertion: TokenAccountAssertionArgs;
+  errorCode?: OptionOrNullable<number>;
 };
 
 export function getAssertTokenAccountInstructionDataEncoder(): Encoder<AssertTokenAccountInstructionDataArgs> {
@@ -89,11 +98,13 @@ export function getAssertTokenAccountInstructionDataEncoder(): Encoder<AssertTok
       ['discriminator', getU8Encoder()],
       ['logLevel', getLogLevelEncoder()],
       ['assertion', getTokenAccountAssertionEncoder()],
+      ['errorCode', getOptionEncoder(getU16Encoder())],
     ]),
     (value) => ({
       ...value,
       discriminator: 8,
       logLevel: value.logLevel ?? LogLevel.Silent,
+      errorCode: value.errorCode ?? none(),
     })
   );
 }
@@ -103,6 +114,7 @@ export function getAssertTokenAccountInstructionDataDecoder(): Decoder<AssertTok
     ['discriminator', getU8Decoder()],
     ['logLevel', getLogLevelDecoder()],
     ['assertion', getTokenAccountAssertionDecoder()],
+    ['errorCode', getOptionDecoder(getU16Decoder())],
   ]);
 }
 
@@ -121,6 +133,7 @@ export type AssertTokenAccountInput<TAccountTargetAccount extends string> = {
   targetAccount: Address<TAccountTargetAccount>;
   logLevel?: AssertTokenAccountInstructionDataArgs['logLevel'];
   assertion: AssertTokenAccountInstructionDataArgs['assertion'];
+  errorCode?: AssertTokenAccountInstructionDataArgs['errorCode'];
 };
 
 export type AssertTokenAccountInputWithSigners<
@@ -130,6 +143,7 @@ export type AssertTokenAccountInputWithSigners<
   targetAccount: Address<TAccountTargetAccount>;
   logLevel?: AssertTokenAccountInstructionDataArgs['logLevel'];
   assertion: AssertTokenAccountInstructionDataArgs['assertion'];
+  errorCode?: AssertTokenAccountInstructionDataArgs['errorCode'];
 };
 
 export function getAssertTokenAccountInstruction<
@@ -11,14 +11,21 @@ import {
   Codec,
   Decoder,
   Encoder,
+  Option,
+  OptionOrNullable,
   combineCodec,
   getArrayDecoder,
   getArrayEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU8Decoder,
   getU8Encoder,
   mapEncoder,
+  none,
 } from '@solana/codecs';
 import {
   AccountRole,
@@ -34,10 +41,14 @@ import {
   getAccountMetasWithSigners,
 } from '../shared';
 import {
+  EvaluationMode,
+  EvaluationModeArgs,
   LogLevel,
   LogLevelArgs,
   TokenAccountAssertion,
   TokenAccountAssertionArgs,
+  getEvaluationModeDecoder,
+  getEvaluationModeEncoder,
   getLogLevelDecoder,
   getLogLevelEncoder,
   getTokenAccountAssertionDecoder,
@@ -78,11 +89,15 @@ export type AssertTokenAccountMultiInstructionData = {
   discriminator: number;
   logLevel: LogLevel;
   assertions: Array<TokenAccountAssertion>;
+  evaluationMode: EvaluationMode;
+  errorCodes: Option<Array<number>>;
 };
 
 export type AssertTokenAccountMultiInstructionDataArgs = {
   logLevel?: LogLevelArgs;
   assertions: Array<TokenAccountAssertionArgs>;
+  evaluationMode?: EvaluationModeArgs;
+  errorCodes?: OptionOrNullable<Array<number>>;
 };
 
 export function getAssertTokenAccountMultiInstructionDataEncoder(): Encoder<AssertTokenAccountMultiInstructionDataArgs> {
@@ -91,11 +106,15 @@ export function getAssertTokenAccountMultiInstructionDataEncoder(): Encoder<Asse
       ['discriminator', getU8Encoder()],
       ['logLevel', getLogLevelEncoder()],
       ['assertions', getArrayEncoder(getTokenAccountAssertionEncoder())],
+      ['evaluationMode', getEvaluationModeEncoder()],
+      ['errorCodes', getOptionEncoder(getArrayEncoder(getU16Encoder()))],
     ]),
     (value) => ({
       ...value,
       discriminator: 9,
       logLevel: value.logLevel ?? LogLevel.Silent,
+      evaluationMode: value.evaluationMode ?? EvaluationMode.Strict,
+      errorCodes: value.errorCodes ?? none(),
     })
   );
 }
@@ -105,6 +124,8 @@ export function getAssertTokenAccountMultiInstructionDataDecoder(): Decoder<Asse
     ['discriminator', getU8Decoder()],
     ['logLevel', getLogLevelDecoder()],
     ['assertions', getArrayDecoder(getTokenAccountAssertionDecoder())],
+    ['evaluationMode', getEvaluationModeDecoder()],
+    ['errorCodes', getOptionDecoder(getArrayDecoder(getU16Decoder()))],
   ]);
 }
 
@@ -124,6 +145,8 @@ export type AssertTokenAccountMultiInput<TAccountTargetAccount extends string> =
     targetAccount: Address<TAccountTargetAccount>;
     logLevel?: AssertTokenAccountMultiInstructionDataArgs['logLevel'];
     assertions: AssertTokenAccountMultiInstructionDataArgs['assertions'];
+    evaluationMode?: AssertTokenAccountMultiInstructionDataArgs['evaluationMode'];
+    errorCodes?: AssertTokenAccountMultiInstructionDataArgs['errorCodes'];
   };
 
 export type AssertTokenAccountMultiInputWithSigners<
@@ -133,6 +156,8 @@ export type AssertTokenAccountMultiInputWithSigners<
   targetAccount: Address<TAccountTargetAccount>;
   logLevel?: AssertTokenAccountMultiInstructionDataArgs['logLevel'];
   assertions: AssertTokenAccountMultiInstructionDataArgs['assertions'];
+  evaluationMode?: AssertTokenAccountMultiInstructionDataArgs['evaluationMode'];
+  errorCodes?: AssertTokenAccountMultiInstructionDataArgs['errorCodes'];
 };
 
 export function getAssertTokenAccountMultiInstruction<
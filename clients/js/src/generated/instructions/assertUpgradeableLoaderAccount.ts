@@ -11,12 +11,19 @@ import {
   Codec,
   Decoder,
   Encoder,
+  Option,
+  OptionOrNullable,
   combineCodec,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU8Decoder,
   getU8Encoder,
   mapEncoder,
+  none,
 } from '@solana/codecs';
 import {
   AccountRole,
@@ -76,11 +83,13 @@ export type AssertUpgradeableLoaderAccountInstructionData = {
   discriminator: number;
   logLevel: LogLevel;
   assertion: UpgradeableLoaderStateAssertion;
+  errorCode: Option<number>;
 };
 
 export type AssertUpgradeableLoaderAccountInstructionDataArgs = {
   logLevel?: LogLevelArgs;
   assertion: UpgradeableLoaderStateAssertionArgs;
+  errorCode?: OptionOrNullable<number>;
 };
 
 export function getAssertUpgradeableLoaderAccountInstructionDataEncoder(): Encoder<AssertUpgradeableLoaderAccountInstructionDataArgs> {
@@ -89,11 +98,13 @@ export function getAssertUpgradeableLoaderAccountInstructionDataEncoder(): Encod
       ['discriminator', getU8Encoder()],
       ['logLevel', getLogLevelEncoder()],
       ['assertion', getUpgradeableLoaderStateAssertionEncoder()],
+      ['errorCode', getOptionEncoder(getU16Encoder())],
     ]),
     (value) => ({
       ...value,
       discriminator: 12,
       logLevel: value.logLevel ?? LogLevel.Silent,
+      errorCode: value.errorCode ?? none(),
     })
   );
 }
@@ -103,6 +114,7 @@ export function getAssertUpgradeableLoaderAccountInstructionDataDecoder(): Decod
     ['discriminator', getU8Decoder()],
     ['logLevel', getLogLevelDecoder()],
     ['assertion', getUpgradeableLoaderStateAssertionDecoder()],
+    ['errorCode', getOptionDecoder(getU16Decoder())],
   ]);
 }
 
@@ -123,6 +135,7 @@ export type AssertUpgradeableLoaderAccountInput<
   targetAccount: Address<TAccountTargetAccount>;
   logLevel?: AssertUpgradeableLoaderAccountInstructionDataArgs['logLevel'];
   assertion: AssertUpgradeableLoaderAccountInstructionDataArgs['assertion'];
+  errorCode?: AssertUpgradeableLoaderAccountInstructionDataArgs['errorCode'];
 };
 
 export type AssertUpgradeableLoaderAccountInputWithSigners<
@@ -132,6 +145,7 @@ export type AssertUpgradeableLoaderAccountInputWithSigners<
   targetAccount: Address<TAccountTargetAccount>;
   logLevel?: AssertUpgradeableLoaderAccountInstructionDataArgs['logLevel'];
   assertion: AssertUpgradeableLoaderAccountInstructionDataArgs['assertion'];
+  errorCode?: AssertUpgradeableLoaderAccountInstructionDataArgs['errorCode'];
 };
 
 export function getAssertUpgradeableLoaderAccountInstruction<
@@ -11,14 +11,21 @@ import {
   Codec,
   Decoder,
   Encoder,
+  Option,
+  OptionOrNullable,
   combineCodec,
   getArrayDecoder,
   getArrayEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU8Decoder,
   getU8Encoder,
   mapEncoder,
+  none,
 } from '@solana/codecs';
 import {
   AccountRole,
@@ -34,10 +41,14 @@ import {
   getAccountMetasWithSigners,
 } from '../shared';
 import {
+  EvaluationMode,
+  EvaluationModeArgs,
   LogLevel,
   LogLevelArgs,
   UpgradeableLoaderStateAssertion,
   UpgradeableLoaderStateAssertionArgs,
+  getEvaluationModeDecoder,
+  getEvaluationModeEncoder,
   getLogLevelDecoder,
   getLogLevelEncoder,
   getUpgradeableLoaderStateAssertionDecoder,
@@ -78,11 +89,15 @@ export type AssertUpgradeableLoaderAccountMultiInstructionData = {
   discriminator: number;
   logLevel: LogLevel;
   assertions: Array<UpgradeableLoaderStateAssertion>;
+  evaluationMode: EvaluationMode;
+  errorCodes: Option<Array<number>>;
 };
 
 export type AssertUpgradeableLoaderAccountMultiInstructionDataArgs = {
   logLevel?: LogLevelArgs;
   assertions: Array<UpgradeableLoaderStateAssertionArgs>;
+  evaluationMode?: EvaluationModeArgs;
+  errorCodes?: OptionOrNullable<Array<number>>;
 };
 
 export function getAssertUpgradeableLoaderAccountMultiInstructionDataEncoder(): Encoder<AssertUpgradeableLoaderAccountMultiInstructionDataArgs> {
@@ -94,11 +109,15 @@ export function getAssertUpgradeableLoaderAccountMultiInstructionDataEncoder():
         'assertions',
         getArrayEncoder(getUpgradeableLoaderStateAssertionEncoder()),
       ],
+      ['evaluationMode', getEvaluationModeEncoder()],
+      ['errorCodes', getOptionEncoder(getArrayEncoder(getU16Encoder()))],
     ]),
     (value) => ({
       ...value,
       discriminator: 13,
       logLevel: value.logLevel ?? LogLevel.Silent,
+      evaluationMode: value.evaluationMode ?? EvaluationMode.Strict,
+      errorCodes: value.errorCodes ?? none(),
     })
   );
 }
@@ -111,6 +130,8 @@ export function getAssertUpgradeableLoaderAccountMultiInstructionDataDecoder():
       'assertions',
       getArrayDecoder(getUpgradeableLoaderStateAssertionDecoder()),
     ],
+    ['evaluationMode', getEvaluationModeDecoder()],
+    ['errorCodes', getOptionDecoder(getArrayDecoder(getU16Decoder()))],
   ]);
 }
 
@@ -131,6 +152,8 @@ export type AssertUpgradeableLoaderAccountMultiInput<
   targetAccount: Address<TAccountTargetAccount>;
   logLevel?: AssertUpgradeableLoaderAccountMultiInstructionDataArgs['logLevel'];
   assertions: AssertUpgradeableLoaderAccountMultiInstructionDataArgs['assertions'];
+  evaluationMode?: AssertUpgradeableLoaderAccountMultiInstructionDataArgs['evaluationMode'];
+  errorCodes?: AssertUpgradeableLoaderAccountMultiInstructionDataArgs['errorCodes'];
 };
 
 export type AssertUpgradeableLoaderAccountMultiInputWithSigners<
@@ -140,6 +163,8 @@ export type AssertUpgradeableLoaderAccountMultiInputWithSigners<
   targetAccount: Address<TAccountTargetAccount>;
   logLevel?: AssertUpgradeableLoaderAccountMultiInstructionDataArgs['logLevel'];
   assertions: AssertUpgradeableLoaderAccountMultiInstructionDataArgs['assertions'];
+  evaluationMode?: AssertUpgradeableLoaderAccountMultiInstructionDataArgs['evaluationMode'];
+  errorCodes?: AssertUpgradeableLoaderAccountMultiInstructionDataArgs['errorCodes'];
 };
 
 export function getAssertUpgradeableLoaderAccountMultiInstruction<
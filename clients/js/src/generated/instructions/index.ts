@@ -7,19 +7,33 @@
  */
 
 export * from './assertAccountData';
+export * from './assertAccountDataPath';
 export * from './assertAccountDelta';
 export * from './assertAccountInfo';
 export * from './assertAccountInfoMulti';
+export * from './assertAccountSnapshot';
 export * from './assertBubblegumTreeConfigAccount';
+export * from './assertConcurrentMerkleTreeAccount';
+export * from './assertConcurrentMerkleTreeAccountMulti';
+export * from './assertMemoryHeader';
 export * from './assertMerkleTreeAccount';
 export * from './assertMintAccount';
 export * from './assertMintAccountMulti';
 export * from './assertStakeAccount';
 export * from './assertStakeAccountMulti';
+export * from './assertStakePoolAccount';
+export * from './assertStakePoolAccountMulti';
 export * from './assertSysvarClock';
 export * from './assertTokenAccount';
 export * from './assertTokenAccountMulti';
 export * from './assertUpgradeableLoaderAccount';
 export * from './assertUpgradeableLoaderAccountMulti';
 export * from './memoryClose';
+export * from './memoryCloseToRecipient';
+export * from './memoryCloseWithAuthority';
+export * from './memoryInitHeader';
+export * from './memoryShrink';
 export * from './memoryWrite';
+export * from './memoryWriteMulti';
+export * from './memoryWriteWithAuthority';
+export * from './memoryZero';
@@ -93,7 +93,7 @@ export type MemoryCloseInstructionData = {
 };
 
 export type MemoryCloseInstructionDataArgs = {
-  memoryId: number;
+  memoryId?: number;
   memoryBump: number;
 };
 
@@ -104,7 +104,7 @@ export function getMemoryCloseInstructionDataEncoder(): Encoder<MemoryCloseInstr
       ['memoryId', getU8Encoder()],
       ['memoryBump', getU8Encoder()],
     ]),
-    (value) => ({ ...value, discriminator: 1 })
+    (value) => ({ ...value, discriminator: 1, memoryId: value.memoryId ?? 0 })
   );
 }
 
@@ -137,7 +137,7 @@ export type MemoryCloseInput<
   payer: Address<TAccountPayer>;
   /** Memory account */
   memory: Address<TAccountMemory>;
-  memoryId: MemoryCloseInstructionDataArgs['memoryId'];
+  memoryId?: MemoryCloseInstructionDataArgs['memoryId'];
   memoryBump: MemoryCloseInstructionDataArgs['memoryBump'];
 };
 
@@ -152,7 +152,7 @@ export type MemoryCloseInputWithSigners<
   payer: TransactionSigner<TAccountPayer>;
   /** Memory account */
   memory: Address<TAccountMemory>;
-  memoryId: MemoryCloseInstructionDataArgs['memoryId'];
+  memoryId?: MemoryCloseInstructionDataArgs['memoryId'];
   memoryBump: MemoryCloseInstructionDataArgs['memoryBump'];
 };
 
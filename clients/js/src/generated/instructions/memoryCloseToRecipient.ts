@@ -0,0 +1,421 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Address } from '@solana/addresses';
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  combineCodec,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  mapEncoder,
+} from '@solana/codecs';
+import {
+  AccountRole,
+  IAccountMeta,
+  IInstruction,
+  IInstructionWithAccounts,
+  IInstructionWithData,
+  ReadonlyAccount,
+  ReadonlySignerAccount,
+  WritableAccount,
+  WritableSignerAccount,
+} from '@solana/instructions';
+import { IAccountSignerMeta, TransactionSigner } from '@solana/signers';
+import {
+  ResolvedAccount,
+  accountMetaWithDefault,
+  getAccountMetasWithSigners,
+} from '../shared';
+
+export type MemoryCloseToRecipientInstruction<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountProgramId extends
+    | string
+    | IAccountMeta<string> = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountMemory extends string | IAccountMeta<string> = string,
+  TAccountRecipient extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgramId extends string
+        ? ReadonlyAccount<TAccountProgramId>
+        : TAccountProgramId,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer>
+        : TAccountPayer,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountMemory extends string
+        ? WritableAccount<TAccountMemory>
+        : TAccountMemory,
+      TAccountRecipient extends string
+        ? WritableAccount<TAccountRecipient>
+        : TAccountRecipient,
+      ...TRemainingAccounts
+    ]
+  >;
+
+export type MemoryCloseToRecipientInstructionWithSigners<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountProgramId extends
+    | string
+    | IAccountMeta<string> = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountMemory extends string | IAccountMeta<string> = string,
+  TAccountRecipient extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgramId extends string
+        ? ReadonlyAccount<TAccountProgramId>
+        : TAccountProgramId,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountMemory extends string
+        ? WritableAccount<TAccountMemory>
+        : TAccountMemory,
+      TAccountRecipient extends string
+        ? WritableAccount<TAccountRecipient>
+        : TAccountRecipient,
+      ...TRemainingAccounts
+    ]
+  >;
+
+export type MemoryCloseToRecipientInstructionData = {
+  discriminator: number;
+  memoryId: number;
+  memoryBump: number;
+  topLevelOnly: boolean;
+};
+
+export type MemoryCloseToRecipientInstructionDataArgs = {
+  memoryId?: number;
+  memoryBump: number;
+  topLevelOnly: boolean;
+};
+
+export function getMemoryCloseToRecipientInstructionDataEncoder(): Encoder<MemoryCloseToRecipientInstructionDataArgs> {
+  return mapEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['memoryId', getU16Encoder()],
+      ['memoryBump', getU8Encoder()],
+      ['topLevelOnly', getBooleanEncoder()],
+    ]),
+    (value) => ({ ...value, discriminator: 26, memoryId: value.memoryId ?? 0 })
+  );
+}
+
+export function getMemoryCloseToRecipientInstructionDataDecoder(): Decoder<MemoryCloseToRecipientInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['memoryId', getU16Decoder()],
+    ['memoryBump', getU8Decoder()],
+    ['topLevelOnly', getBooleanDecoder()],
+  ]);
+}
+
+export function getMemoryCloseToRecipientInstructionDataCodec(): Codec<
+  MemoryCloseToRecipientInstructionDataArgs,
+  MemoryCloseToRecipientInstructionData
+> {
+  return combineCodec(
+    getMemoryCloseToRecipientInstructionDataEncoder(),
+    getMemoryCloseToRecipientInstructionDataDecoder()
+  );
+}
+
+export type MemoryCloseToRecipientInput<
+  TAccountProgramId extends string,
+  TAccountPayer extends string,
+  TAccountAuthority extends string,
+  TAccountMemory extends string,
+  TAccountRecipient extends string
+> = {
+  /** Lighthouse program */
+  programId?: Address<TAccountProgramId>;
+  /** Payer account */
+  payer: Address<TAccountPayer>;
+  /** Memory authority, namespaces the memory account */
+  authority: Address<TAccountAuthority>;
+  /** Memory account */
+  memory: Address<TAccountMemory>;
+  /** Account receiving the memory account lamports */
+  recipient: Address<TAccountRecipient>;
+  memoryId?: MemoryCloseToRecipientInstructionDataArgs['memoryId'];
+  memoryBump: MemoryCloseToRecipientInstructionDataArgs['memoryBump'];
+  topLevelOnly: MemoryCloseToRecipientInstructionDataArgs['topLevelOnly'];
+};
+
+export type MemoryCloseToRecipientInputWithSigners<
+  TAccountProgramId extends string,
+  TAccountPayer extends string,
+  TAccountAuthority extends string,
+  TAccountMemory extends string,
+  TAccountRecipient extends string
+> = {
+  /** Lighthouse program */
+  programId?: Address<TAccountProgramId>;
+  /** Payer account */
+  payer: TransactionSigner<TAccountPayer>;
+  /** Memory authority, namespaces the memory account */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** Memory account */
+  memory: Address<TAccountMemory>;
+  /** Account receiving the memory account lamports */
+  recipient: Address<TAccountRecipient>;
+  memoryId?: MemoryCloseToRecipientInstructionDataArgs['memoryId'];
+  memoryBump: MemoryCloseToRecipientInstructionDataArgs['memoryBump'];
+  topLevelOnly: MemoryCloseToRecipientInstructionDataArgs['topLevelOnly'];
+};
+
+export function getMemoryCloseToRecipientInstruction<
+  TAccountProgramId extends string,
+  TAccountPayer extends string,
+  TAccountAuthority extends string,
+  TAccountMemory extends string,
+  TAccountRecipient extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: MemoryCloseToRecipientInputWithSigners<
+    TAccountProgramId,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMemory,
+    TAccountRecipient
+  >
+): MemoryCloseToRecipientInstructionWithSigners<
+  TProgram,
+  TAccountProgramId,
+  TAccountPayer,
+  TAccountAuthority,
+  TAccountMemory,
+  TAccountRecipient
+>;
+export function getMemoryCloseToRecipientInstruction<
+  TAccountProgramId extends string,
+  TAccountPayer extends string,
+  TAccountAuthority extends string,
+  TAccountMemory extends string,
+  TAccountRecipient extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: MemoryCloseToRecipientInput<
+    TAccountProgramId,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMemory,
+    TAccountRecipient
+  >
+): MemoryCloseToRecipientInstruction<
+  TProgram,
+  TAccountProgramId,
+  TAccountPayer,
+  TAccountAuthority,
+  TAccountMemory,
+  TAccountRecipient
+>;
+export function getMemoryCloseToRecipientInstruction<
+  TAccountProgramId extends string,
+  TAccountPayer extends string,
+  TAccountAuthority extends string,
+  TAccountMemory extends string,
+  TAccountRecipient extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: MemoryCloseToRecipientInput<
+    TAccountProgramId,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMemory,
+    TAccountRecipient
+  >
+): IInstruction {
+  // Program address.
+  const programAddress =
+    'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK' as Address<'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'>;
+
+  // Original accounts.
+  type AccountMetas = Parameters<
+    typeof getMemoryCloseToRecipientInstructionRaw<
+      TProgram,
+      TAccountProgramId,
+      TAccountPayer,
+      TAccountAuthority,
+      TAccountMemory,
+      TAccountRecipient
+    >
+  >[0];
+  const accounts: Record<keyof AccountMetas, ResolvedAccount> = {
+    programId: { value: input.programId ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    memory: { value: input.memory ?? null, isWritable: true },
+    recipient: { value: input.recipient ?? null, isWritable: true },
+  };
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.programId.value) {
+    accounts.programId.value = programAddress;
+    accounts.programId.isWritable = false;
+  }
+
+  // Get account metas and signers.
+  const accountMetas = getAccountMetasWithSigners(
+    accounts,
+    'programId',
+    programAddress
+  );
+
+  const instruction = getMemoryCloseToRecipientInstructionRaw(
+    accountMetas as Record<keyof AccountMetas, IAccountMeta>,
+    args as MemoryCloseToRecipientInstructionDataArgs,
+    programAddress
+  );
+
+  return instruction;
+}
+
+export function getMemoryCloseToRecipientInstructionRaw<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountProgramId extends
+    | string
+    | IAccountMeta<string> = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountMemory extends string | IAccountMeta<string> = string,
+  TAccountRecipient extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+>(
+  accounts: {
+    programId?: TAccountProgramId extends string
+      ? Address<TAccountProgramId>
+      : TAccountProgramId;
+    payer: TAccountPayer extends string
+      ? Address<TAccountPayer>
+      : TAccountPayer;
+    authority: TAccountAuthority extends string
+      ? Address<TAccountAuthority>
+      : TAccountAuthority;
+    memory: TAccountMemory extends string
+      ? Address<TAccountMemory>
+      : TAccountMemory;
+    recipient: TAccountRecipient extends string
+      ? Address<TAccountRecipient>
+      : TAccountRecipient;
+  },
+  args: MemoryCloseToRecipientInstructionDataArgs,
+  programAddress: Address<TProgram> = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK' as Address<TProgram>,
+  remainingAccounts?: TRemainingAccounts
+) {
+  return {
+    accounts: [
+      accountMetaWithDefault(
+        accounts.programId ?? {
+          address:
+            'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK' as Address<'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'>,
+          role: AccountRole.READONLY,
+        },
+        AccountRole.READONLY
+      ),
+      accountMetaWithDefault(accounts.payer, AccountRole.WRITABLE_SIGNER),
+      accountMetaWithDefault(accounts.authority, AccountRole.READONLY_SIGNER),
+      accountMetaWithDefault(accounts.memory, AccountRole.WRITABLE),
+      accountMetaWithDefault(accounts.recipient, AccountRole.WRITABLE),
+      ...(remainingAccounts ?? []),
+    ],
+    data: getMemoryCloseToRecipientInstructionDataEncoder().encode(args),
+    programAddress,
+  } as MemoryCloseToRecipientInstruction<
+    TProgram,
+    TAccountProgramId,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMemory,
+    TAccountRecipient,
+    TRemainingAccounts
+  >;
+}
+
+export type ParsedMemoryCloseToRecipientInstruction<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[]
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Lighthouse program */
+    programId: TAccountMetas[0];
+    /** Payer account */
+    payer: TAccountMetas[1];
+    /** Memory authority, namespaces the memory account */
+    authority: TAccountMetas[2];
+    /** Memory account */
+    memory: TAccountMetas[3];
+    /** Account receiving the memory account lamports */
+    recipient: TAccountMetas[4];
+  };
+  data: MemoryCloseToRecipientInstructionData;
+};
+
+export function parseMemoryCloseToRecipientInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[]
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedMemoryCloseToRecipientInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      programId: getNextAccount(),
+      payer: getNextAccount(),
+      authority: getNextAccount(),
+      memory: getNextAccount(),
+      recipient: getNextAccount(),
+    },
+    data: getMemoryCloseToRecipientInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
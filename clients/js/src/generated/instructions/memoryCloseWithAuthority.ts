@@ -0,0 +1,388 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Address } from '@solana/addresses';
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  combineCodec,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  mapEncoder,
+} from '@solana/codecs';
+import {
+  AccountRole,
+  IAccountMeta,
+  IInstruction,
+  IInstructionWithAccounts,
+  IInstructionWithData,
+  ReadonlyAccount,
+  ReadonlySignerAccount,
+  WritableAccount,
+  WritableSignerAccount,
+} from '@solana/instructions';
+import { IAccountSignerMeta, TransactionSigner } from '@solana/signers';
+import {
+  ResolvedAccount,
+  accountMetaWithDefault,
+  getAccountMetasWithSigners,
+} from '../shared';
+
+export type MemoryCloseWithAuthorityInstruction<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountProgramId extends
+    | string
+    | IAccountMeta<string> = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountMemory extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgramId extends string
+        ? ReadonlyAccount<TAccountProgramId>
+        : TAccountProgramId,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer>
+        : TAccountPayer,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountMemory extends string
+        ? WritableAccount<TAccountMemory>
+        : TAccountMemory,
+      ...TRemainingAccounts
+    ]
+  >;
+
+export type MemoryCloseWithAuthorityInstructionWithSigners<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountProgramId extends
+    | string
+    | IAccountMeta<string> = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountMemory extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgramId extends string
+        ? ReadonlyAccount<TAccountProgramId>
+        : TAccountProgramId,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountMemory extends string
+        ? WritableAccount<TAccountMemory>
+        : TAccountMemory,
+      ...TRemainingAccounts
+    ]
+  >;
+
+export type MemoryCloseWithAuthorityInstructionData = {
+  discriminator: number;
+  memoryId: number;
+  memoryBump: number;
+  topLevelOnly: boolean;
+};
+
+export type MemoryCloseWithAuthorityInstructionDataArgs = {
+  memoryId?: number;
+  memoryBump: number;
+  topLevelOnly: boolean;
+};
+
+export function getMemoryCloseWithAuthorityInstructionDataEncoder(): Encoder<MemoryCloseWithAuthorityInstructionDataArgs> {
+  return mapEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['memoryId', getU16Encoder()],
+      ['memoryBump', getU8Encoder()],
+      ['topLevelOnly', getBooleanEncoder()],
+    ]),
+    (value) => ({ ...value, discriminator: 30, memoryId: value.memoryId ?? 0 })
+  );
+}
+
+export function getMemoryCloseWithAuthorityInstructionDataDecoder(): Decoder<MemoryCloseWithAuthorityInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['memoryId', getU16Decoder()],
+    ['memoryBump', getU8Decoder()],
+    ['topLevelOnly', getBooleanDecoder()],
+  ]);
+}
+
+export function getMemoryCloseWithAuthorityInstructionDataCodec(): Codec<
+  MemoryCloseWithAuthorityInstructionDataArgs,
+  MemoryCloseWithAuthorityInstructionData
+> {
+  return combineCodec(
+    getMemoryCloseWithAuthorityInstructionDataEncoder(),
+    getMemoryCloseWithAuthorityInstructionDataDecoder()
+  );
+}
+
+export type MemoryCloseWithAuthorityInput<
+  TAccountProgramId extends string,
+  TAccountPayer extends string,
+  TAccountAuthority extends string,
+  TAccountMemory extends string
+> = {
+  /** Lighthouse program */
+  programId?: Address<TAccountProgramId>;
+  /** Payer account, receives the memory account lamports */
+  payer: Address<TAccountPayer>;
+  /** Memory authority, namespaces the memory account */
+  authority: Address<TAccountAuthority>;
+  /** Memory account */
+  memory: Address<TAccountMemory>;
+  memoryId?: MemoryCloseWithAuthorityInstructionDataArgs['memoryId'];
+  memoryBump: MemoryCloseWithAuthorityInstructionDataArgs['memoryBump'];
+  topLevelOnly: MemoryCloseWithAuthorityInstructionDataArgs['topLevelOnly'];
+};
+
+export type MemoryCloseWithAuthorityInputWithSigners<
+  TAccountProgramId extends string,
+  TAccountPayer extends string,
+  TAccountAuthority extends string,
+  TAccountMemory extends string
+> = {
+  /** Lighthouse program */
+  programId?: Address<TAccountProgramId>;
+  /** Payer account, receives the memory account lamports */
+  payer: TransactionSigner<TAccountPayer>;
+  /** Memory authority, namespaces the memory account */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** Memory account */
+  memory: Address<TAccountMemory>;
+  memoryId?: MemoryCloseWithAuthorityInstructionDataArgs['memoryId'];
+  memoryBump: MemoryCloseWithAuthorityInstructionDataArgs['memoryBump'];
+  topLevelOnly: MemoryCloseWithAuthorityInstructionDataArgs['topLevelOnly'];
+};
+
+export function getMemoryCloseWithAuthorityInstruction<
+  TAccountProgramId extends string,
+  TAccountPayer extends string,
+  TAccountAuthority extends string,
+  TAccountMemory extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: MemoryCloseWithAuthorityInputWithSigners<
+    TAccountProgramId,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMemory
+  >
+): MemoryCloseWithAuthorityInstructionWithSigners<
+  TProgram,
+  TAccountProgramId,
+  TAccountPayer,
+  TAccountAuthority,
+  TAccountMemory
+>;
+export function getMemoryCloseWithAuthorityInstruction<
+  TAccountProgramId extends string,
+  TAccountPayer extends string,
+  TAccountAuthority extends string,
+  TAccountMemory extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: MemoryCloseWithAuthorityInput<
+    TAccountProgramId,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMemory
+  >
+): MemoryCloseWithAuthorityInstruction<
+  TProgram,
+  TAccountProgramId,
+  TAccountPayer,
+  TAccountAuthority,
+  TAccountMemory
+>;
+export function getMemoryCloseWithAuthorityInstruction<
+  TAccountProgramId extends string,
+  TAccountPayer extends string,
+  TAccountAuthority extends string,
+  TAccountMemory extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: MemoryCloseWithAuthorityInput<
+    TAccountProgramId,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMemory
+  >
+): IInstruction {
+  // Program address.
+  const programAddress =
+    'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK' as Address<'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'>;
+
+  // Original accounts.
+  type AccountMetas = Parameters<
+    typeof getMemoryCloseWithAuthorityInstructionRaw<
+      TProgram,
+      TAccountProgramId,
+      TAccountPayer,
+      TAccountAuthority,
+      TAccountMemory
+    >
+  >[0];
+  const accounts: Record<keyof AccountMetas, ResolvedAccount> = {
+    programId: { value: input.programId ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    memory: { value: input.memory ?? null, isWritable: true },
+  };
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.programId.value) {
+    accounts.programId.value = programAddress;
+    accounts.programId.isWritable = false;
+  }
+
+  // Get account metas and signers.
+  const accountMetas = getAccountMetasWithSigners(
+    accounts,
+    'programId',
+    programAddress
+  );
+
+  const instruction = getMemoryCloseWithAuthorityInstructionRaw(
+    accountMetas as Record<keyof AccountMetas, IAccountMeta>,
+    args as MemoryCloseWithAuthorityInstructionDataArgs,
+    programAddress
+  );
+
+  return instruction;
+}
+
+export function getMemoryCloseWithAuthorityInstructionRaw<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountProgramId extends
+    | string
+    | IAccountMeta<string> = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountMemory extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+>(
+  accounts: {
+    programId?: TAccountProgramId extends string
+      ? Address<TAccountProgramId>
+      : TAccountProgramId;
+    payer: TAccountPayer extends string
+      ? Address<TAccountPayer>
+      : TAccountPayer;
+    authority: TAccountAuthority extends string
+      ? Address<TAccountAuthority>
+      : TAccountAuthority;
+    memory: TAccountMemory extends string
+      ? Address<TAccountMemory>
+      : TAccountMemory;
+  },
+  args: MemoryCloseWithAuthorityInstructionDataArgs,
+  programAddress: Address<TProgram> = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK' as Address<TProgram>,
+  remainingAccounts?: TRemainingAccounts
+) {
+  return {
+    accounts: [
+      accountMetaWithDefault(
+        accounts.programId ?? {
+          address:
+            'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK' as Address<'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'>,
+          role: AccountRole.READONLY,
+        },
+        AccountRole.READONLY
+      ),
+      accountMetaWithDefault(accounts.payer, AccountRole.WRITABLE_SIGNER),
+      accountMetaWithDefault(accounts.authority, AccountRole.READONLY_SIGNER),
+      accountMetaWithDefault(accounts.memory, AccountRole.WRITABLE),
+      ...(remainingAccounts ?? []),
+    ],
+    data: getMemoryCloseWithAuthorityInstructionDataEncoder().encode(args),
+    programAddress,
+  } as MemoryCloseWithAuthorityInstruction<
+    TProgram,
+    TAccountProgramId,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMemory,
+    TRemainingAccounts
+  >;
+}
+
+export type ParsedMemoryCloseWithAuthorityInstruction<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[]
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Lighthouse program */
+    programId: TAccountMetas[0];
+    /** Payer account, receives the memory account lamports */
+    payer: TAccountMetas[1];
+    /** Memory authority, namespaces the memory account */
+    authority: TAccountMetas[2];
+    /** Memory account */
+    memory: TAccountMetas[3];
+  };
+  data: MemoryCloseWithAuthorityInstructionData;
+};
+
+export function parseMemoryCloseWithAuthorityInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[]
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedMemoryCloseWithAuthorityInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      programId: getNextAccount(),
+      payer: getNextAccount(),
+      authority: getNextAccount(),
+      memory: getNextAccount(),
+    },
+    data: getMemoryCloseWithAuthorityInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,480 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Address } from '@solana/addresses';
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  combineCodec,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  mapEncoder,
+} from '@solana/codecs';
+import {
+  AccountRole,
+  IAccountMeta,
+  IInstruction,
+  IInstructionWithAccounts,
+  IInstructionWithData,
+  ReadonlyAccount,
+  ReadonlySignerAccount,
+  WritableAccount,
+  WritableSignerAccount,
+} from '@solana/instructions';
+import { IAccountSignerMeta, TransactionSigner } from '@solana/signers';
+import {
+  ResolvedAccount,
+  accountMetaWithDefault,
+  getAccountMetasWithSigners,
+} from '../shared';
+import {
+  MemoryWriteEntry,
+  MemoryWriteEntryArgs,
+  getMemoryWriteEntryDecoder,
+  getMemoryWriteEntryEncoder,
+} from '../types';
+
+export type MemoryWriteMultiInstruction<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountProgramId extends
+    | string
+    | IAccountMeta<string> = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountMemory extends string | IAccountMeta<string> = string,
+  TAccountSourceAccount extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgramId extends string
+        ? ReadonlyAccount<TAccountProgramId>
+        : TAccountProgramId,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer>
+        : TAccountPayer,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountMemory extends string
+        ? WritableAccount<TAccountMemory>
+        : TAccountMemory,
+      TAccountSourceAccount extends string
+        ? ReadonlyAccount<TAccountSourceAccount>
+        : TAccountSourceAccount,
+      ...TRemainingAccounts
+    ]
+  >;
+
+export type MemoryWriteMultiInstructionWithSigners<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountProgramId extends
+    | string
+    | IAccountMeta<string> = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountMemory extends string | IAccountMeta<string> = string,
+  TAccountSourceAccount extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountProgramId extends string
+        ? ReadonlyAccount<TAccountProgramId>
+        : TAccountProgramId,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountMemory extends string
+        ? WritableAccount<TAccountMemory>
+        : TAccountMemory,
+      TAccountSourceAccount extends string
+        ? ReadonlyAccount<TAccountSourceAccount>
+        : TAccountSourceAccount,
+      ...TRemainingAccounts
+    ]
+  >;
+
+export type MemoryWriteMultiInstructionData = {
+  discriminator: number;
+  memoryId: number;
+  memoryBump: number;
+  topLevelOnly: boolean;
+  entries: Array<MemoryWriteEntry>;
+};
+
+export type MemoryWriteMultiInstructionDataArgs = {
+  memoryId?: number;
+  memoryBump: number;
+  topLevelOnly: boolean;
+  entries: Array<MemoryWriteEntryArgs>;
+};
+
+export function getMemoryWriteMultiInstructionDataEncoder(): Encoder<MemoryWriteMultiInstructionDataArgs> {
+  return mapEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['memoryId', getU16Encoder()],
+      ['memoryBump', getU8Encoder()],
+      ['topLevelOnly', getBooleanEncoder()],
+      ['entries', getArrayEncoder(getMemoryWriteEntryEncoder())],
+    ]),
+    (value) => ({ ...value, discriminator: 22, memoryId: value.memoryId ?? 0 })
+  );
+}
+
+export function getMemoryWriteMultiInstructionDataDecoder(): Decoder<MemoryWriteMultiInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['memoryId', getU16Decoder()],
+    ['memoryBump', getU8Decoder()],
+    ['topLevelOnly', getBooleanDecoder()],
+    ['entries', getArrayDecoder(getMemoryWriteEntryDecoder())],
+  ]);
+}
+
+export function getMemoryWriteMultiInstructionDataCodec(): Codec<
+  MemoryWriteMultiInstructionDataArgs,
+  MemoryWriteMultiInstructionData
+> {
+  return combineCodec(
+    getMemoryWriteMultiInstructionDataEncoder(),
+    getMemoryWriteMultiInstructionDataDecoder()
+  );
+}
+
+export type MemoryWriteMultiInput<
+  TAccountProgramId extends string,
+  TAccountSystemProgram extends string,
+  TAccountPayer extends string,
+  TAccountAuthority extends string,
+  TAccountMemory extends string,
+  TAccountSourceAccount extends string
+> = {
+  /** Lighthouse program */
+  programId?: Address<TAccountProgramId>;
+  /** System program */
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** Payer account */
+  payer: Address<TAccountPayer>;
+  /** Memory authority, namespaces the memory account */
+  authority: Address<TAccountAuthority>;
+  /** Memory account */
+  memory: Address<TAccountMemory>;
+  /** First account to be written to memory, further source accounts follow in order */
+  sourceAccount: Address<TAccountSourceAccount>;
+  memoryId?: MemoryWriteMultiInstructionDataArgs['memoryId'];
+  memoryBump: MemoryWriteMultiInstructionDataArgs['memoryBump'];
+  topLevelOnly: MemoryWriteMultiInstructionDataArgs['topLevelOnly'];
+  entries: MemoryWriteMultiInstructionDataArgs['entries'];
+};
+
+export type MemoryWriteMultiInputWithSigners<
+  TAccountProgramId extends string,
+  TAccountSystemProgram extends string,
+  TAccountPayer extends string,
+  TAccountAuthority extends string,
+  TAccountMemory extends string,
+  TAccountSourceAccount extends string
+> = {
+  /** Lighthouse program */
+  programId?: Address<TAccountProgramId>;
+  /** System program */
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** Payer account */
+  payer: TransactionSigner<TAccountPayer>;
+  /** Memory authority, namespaces the memory account */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** Memory account */
+  memory: Address<TAccountMemory>;
+  /** First account to be written to memory, further source accounts follow in order */
+  sourceAccount: Address<TAccountSourceAccount>;
+  memoryId?: MemoryWriteMultiInstructionDataArgs['memoryId'];
+  memoryBump: MemoryWriteMultiInstructionDataArgs['memoryBump'];
+  topLevelOnly: MemoryWriteMultiInstructionDataArgs['topLevelOnly'];
+  entries: MemoryWriteMultiInstructionDataArgs['entries'];
+};
+
+export function getMemoryWriteMultiInstruction<
+  TAccountProgramId extends string,
+  TAccountSystemProgram extends string,
+  TAccountPayer extends string,
+  TAccountAuthority extends string,
+  TAccountMemory extends string,
+  TAccountSourceAccount extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: MemoryWriteMultiInputWithSigners<
+    TAccountProgramId,
+    TAccountSystemProgram,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMemory,
+    TAccountSourceAccount
+  >
+): MemoryWriteMultiInstructionWithSigners<
+  TProgram,
+  TAccountProgramId,
+  TAccountSystemProgram,
+  TAccountPayer,
+  TAccountAuthority,
+  TAccountMemory,
+  TAccountSourceAccount
+>;
+export function getMemoryWriteMultiInstruction<
+  TAccountProgramId extends string,
+  TAccountSystemProgram extends string,
+  TAccountPayer extends string,
+  TAccountAuthority extends string,
+  TAccountMemory extends string,
+  TAccountSourceAccount extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: MemoryWriteMultiInput<
+    TAccountProgramId,
+    TAccountSystemProgram,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMemory,
+    TAccountSourceAccount
+  >
+): MemoryWriteMultiInstruction<
+  TProgram,
+  TAccountProgramId,
+  TAccountSystemProgram,
+  TAccountPayer,
+  TAccountAuthority,
+  TAccountMemory,
+  TAccountSourceAccount
+>;
+export function getMemoryWriteMultiInstruction<
+  TAccountProgramId extends string,
+  TAccountSystemProgram extends string,
+  TAccountPayer extends string,
+  TAccountAuthority extends string,
+  TAccountMemory extends string,
+  TAccountSourceAccount extends string,
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'
+>(
+  input: MemoryWriteMultiInput<
+    TAccountProgramId,
+    TAccountSystemProgram,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMemory,
+    TAccountSourceAccount
+  >
+): IInstruction {
+  // Program address.
+  const programAddress =
+    'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK' as Address<'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'>;
+
+  // Original accounts.
+  type AccountMetas = Parameters<
+    typeof getMemoryWriteMultiInstructionRaw<
+      TProgram,
+      TAccountProgramId,
+      TAccountSystemProgram,
+      TAccountPayer,
+      TAccountAuthority,
+      TAccountMemory,
+      TAccountSourceAccount
+    >
+  >[0];
+  const accounts: Record<keyof AccountMetas, ResolvedAccount> = {
+    programId: { value: input.programId ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    memory: { value: input.memory ?? null, isWritable: true },
+    sourceAccount: { value: input.sourceAccount ?? null, isWritable: false },
+  };
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.programId.value) {
+    accounts.programId.value = programAddress;
+    accounts.programId.isWritable = false;
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  // Get account metas and signers.
+  const accountMetas = getAccountMetasWithSigners(
+    accounts,
+    'programId',
+    programAddress
+  );
+
+  const instruction = getMemoryWriteMultiInstructionRaw(
+    accountMetas as Record<keyof AccountMetas, IAccountMeta>,
+    args as MemoryWriteMultiInstructionDataArgs,
+    programAddress
+  );
+
+  return instruction;
+}
+
+export function getMemoryWriteMultiInstructionRaw<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountProgramId extends
+    | string
+    | IAccountMeta<string> = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountMemory extends string | IAccountMeta<string> = string,
+  TAccountSourceAccount extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends Array<IAccountMeta<string>> = []
+>(
+  accounts: {
+    programId?: TAccountProgramId extends string
+      ? Address<TAccountProgramId>
+      : TAccountProgramId;
+    systemProgram?: TAccountSystemProgram extends string
+      ? Address<TAccountSystemProgram>
+      : TAccountSystemProgram;
+    payer: TAccountPayer extends string
+      ? Address<TAccountPayer>
+      : TAccountPayer;
+    authority: TAccountAuthority extends string
+      ? Address<TAccountAuthority>
+      : TAccountAuthority;
+    memory: TAccountMemory extends string
+      ? Address<TAccountMemory>
+      : TAccountMemory;
+    sourceAccount: TAccountSourceAccount extends string
+      ? Address<TAccountSourceAccount>
+      : TAccountSourceAccount;
+  },
+  args: MemoryWriteMultiInstructionDataArgs,
+  programAddress: Address<TProgram> = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK' as Address<TProgram>,
+  remainingAccounts?: TRemainingAccounts
+) {
+  return {
+    accounts: [
+      accountMetaWithDefault(
+        accounts.programId ?? {
+          address:
+            'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK' as Address<'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'>,
+          role: AccountRole.READONLY,
+        },
+        AccountRole.READONLY
+      ),
+      accountMetaWithDefault(
+        accounts.systemProgram ??
+          ('11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>),
+        AccountRole.READONLY
+      ),
+      accountMetaWithDefault(accounts.payer, AccountRole.WRITABLE_SIGNER),
+      accountMetaWithDefault(accounts.authority, AccountRole.READONLY_SIGNER),
+      accountMetaWithDefault(accounts.memory, AccountRole.WRITABLE),
+      accountMetaWithDefault(accounts.sourceAccount, AccountRole.READONLY),
+      ...(remainingAccounts ?? []),
+    ],
+    data: getMemoryWriteMultiInstructionDataEncoder().encode(args),
+    programAddress,
+  } as MemoryWriteMultiInstruction<
+    TProgram,
+    TAccountProgramId,
+    TAccountSystemProgram,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMemory,
+    TAccountSourceAccount,
+    TRemainingAccounts
+  >;
+}
+
+export type ParsedMemoryWriteMultiInstruction<
+  TProgram extends string = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK',
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[]
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Lighthouse program */
+    programId: TAccountMetas[0];
+    /** System program */
+    systemProgram: TAccountMetas[1];
+    /** Payer account */
+    payer: TAccountMetas[2];
+    /** Memory authority, namespaces the memory account */
+    authority: TAccountMetas[3];
+    /** Memory account */
+    memory: TAccountMetas[4];
+    /** First account to be written to memory, further source accounts follow in order */
+    sourceAccount: TAccountMetas[5];
+  };
+  data: MemoryWriteMultiInstructionData;
+};
+
+export function parseMemoryWriteMultiInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[]
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedMemoryWriteMultiInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 6) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      programId: getNextAccount(),
+      systemProgram: getNextAccount(),
+      payer: getNextAccount(),
+      authority: getNextAccount(),
+      memory: getNextAccount(),
+      sourceAccount: getNextAccount(),
+    },
+    data: getMemoryWriteMultiInstructionDataDecoder().decode(instruction.data),
+  };
+}
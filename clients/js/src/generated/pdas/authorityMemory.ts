@@ -0,0 +1,45 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Address,
+  ProgramDerivedAddress,
+  getAddressEncoder,
+  getProgramDerivedAddress,
+} from '@solana/addresses';
+import {
+  getBooleanEncoder,
+  getStringEncoder,
+  getU16Encoder,
+} from '@solana/codecs';
+
+export type AuthorityMemorySeeds = {
+  authority: Address;
+
+  memoryId: number;
+
+  topLevelOnly: boolean;
+};
+
+export async function findAuthorityMemoryPda(
+  seeds: AuthorityMemorySeeds,
+  config: { programAddress?: Address | undefined } = {}
+): Promise<ProgramDerivedAddress> {
+  const {
+    programAddress = 'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK' as Address<'L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK'>,
+  } = config;
+  return getProgramDerivedAddress({
+    programAddress,
+    seeds: [
+      getStringEncoder({ size: 'variable' }).encode('memory'),
+      getAddressEncoder().encode(seeds.authority),
+      getU16Encoder().encode(seeds.memoryId),
+      getBooleanEncoder().encode(seeds.topLevelOnly),
+    ],
+  });
+}
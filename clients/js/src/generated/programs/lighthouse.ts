@@ -16,22 +16,36 @@ import {
 } from '../errors';
 import {
   ParsedAssertAccountDataInstruction,
+  ParsedAssertAccountDataPathInstruction,
   ParsedAssertAccountDeltaInstruction,
   ParsedAssertAccountInfoInstruction,
   ParsedAssertAccountInfoMultiInstruction,
+  ParsedAssertAccountSnapshotInstruction,
   ParsedAssertBubblegumTreeConfigAccountInstruction,
+  ParsedAssertConcurrentMerkleTreeAccountInstruction,
+  ParsedAssertConcurrentMerkleTreeAccountMultiInstruction,
+  ParsedAssertMemoryHeaderInstruction,
   ParsedAssertMerkleTreeAccountInstruction,
   ParsedAssertMintAccountInstruction,
   ParsedAssertMintAccountMultiInstruction,
   ParsedAssertStakeAccountInstruction,
   ParsedAssertStakeAccountMultiInstruction,
+  ParsedAssertStakePoolAccountInstruction,
+  ParsedAssertStakePoolAccountMultiInstruction,
   ParsedAssertSysvarClockInstruction,
   ParsedAssertTokenAccountInstruction,
   ParsedAssertTokenAccountMultiInstruction,
   ParsedAssertUpgradeableLoaderAccountInstruction,
   ParsedAssertUpgradeableLoaderAccountMultiInstruction,
   ParsedMemoryCloseInstruction,
+  ParsedMemoryCloseToRecipientInstruction,
+  ParsedMemoryCloseWithAuthorityInstruction,
+  ParsedMemoryInitHeaderInstruction,
+  ParsedMemoryShrinkInstruction,
   ParsedMemoryWriteInstruction,
+  ParsedMemoryWriteMultiInstruction,
+  ParsedMemoryWriteWithAuthorityInstruction,
+  ParsedMemoryZeroInstruction,
 } from '../instructions';
 import { memcmp } from '../shared';
 
@@ -70,6 +84,20 @@ export enum LighthouseInstruction {
   AssertSysvarClock,
   AssertMerkleTreeAccount,
   AssertBubblegumTreeConfigAccount,
+  AssertStakePoolAccount,
+  AssertStakePoolAccountMulti,
+  AssertConcurrentMerkleTreeAccount,
+  AssertConcurrentMerkleTreeAccountMulti,
+  AssertAccountDataPath,
+  MemoryWriteMulti,
+  MemoryInitHeader,
+  AssertMemoryHeader,
+  AssertAccountSnapshot,
+  MemoryCloseToRecipient,
+  MemoryZero,
+  MemoryShrink,
+  MemoryWriteWithAuthority,
+  MemoryCloseWithAuthority,
 }
 
 export function identifyLighthouseInstruction(
@@ -128,6 +156,48 @@ export function identifyLighthouseInstruction(
   if (memcmp(data, getU8Encoder().encode(16), 0)) {
     return LighthouseInstruction.AssertBubblegumTreeConfigAccount;
   }
+  if (memcmp(data, getU8Encoder().encode(17), 0)) {
+    return LighthouseInstruction.AssertStakePoolAccount;
+  }
+  if (memcmp(data, getU8Encoder().encode(18), 0)) {
+    return LighthouseInstruction.AssertStakePoolAccountMulti;
+  }
+  if (memcmp(data, getU8Encoder().encode(19), 0)) {
+    return LighthouseInstruction.AssertConcurrentMerkleTreeAccount;
+  }
+  if (memcmp(data, getU8Encoder().encode(20), 0)) {
+    return LighthouseInstruction.AssertConcurrentMerkleTreeAccountMulti;
+  }
+  if (memcmp(data, getU8Encoder().encode(21), 0)) {
+    return LighthouseInstruction.AssertAccountDataPath;
+  }
+  if (memcmp(data, getU8Encoder().encode(22), 0)) {
+    return LighthouseInstruction.MemoryWriteMulti;
+  }
+  if (memcmp(data, getU8Encoder().encode(23), 0)) {
+    return LighthouseInstruction.MemoryInitHeader;
+  }
+  if (memcmp(data, getU8Encoder().encode(24), 0)) {
+    return LighthouseInstruction.AssertMemoryHeader;
+  }
+  if (memcmp(data, getU8Encoder().encode(25), 0)) {
+    return LighthouseInstruction.AssertAccountSnapshot;
+  }
+  if (memcmp(data, getU8Encoder().encode(26), 0)) {
+    return LighthouseInstruction.MemoryCloseToRecipient;
+  }
+  if (memcmp(data, getU8Encoder().encode(27), 0)) {
+    return LighthouseInstruction.MemoryZero;
+  }
+  if (memcmp(data, getU8Encoder().encode(28), 0)) {
+    return LighthouseInstruction.MemoryShrink;
+  }
+  if (memcmp(data, getU8Encoder().encode(29), 0)) {
+    return LighthouseInstruction.MemoryWriteWithAuthority;
+  }
+  if (memcmp(data, getU8Encoder().encode(30), 0)) {
+    return LighthouseInstruction.MemoryCloseWithAuthority;
+  }
   throw new Error(
     'The provided instruction could not be identified as a lighthouse instruction.'
   );
@@ -186,4 +256,46 @@ export type ParsedLighthouseInstruction<
     } & ParsedAssertMerkleTreeAccountInstruction<TProgram>)
   | ({
       instructionType: LighthouseInstruction.AssertBubblegumTreeConfigAccount;
-    } & ParsedAssertBubblegumTreeConfigAccountInstruction<TProgram>);
+    } & ParsedAssertBubblegumTreeConfigAccountInstruction<TProgram>)
+  | ({
+      instructionType: LighthouseInstruction.AssertStakePoolAccount;
+    } & ParsedAssertStakePoolAccountInstruction<TProgram>)
+  | ({
+      instructionType: LighthouseInstruction.AssertStakePoolAccountMulti;
+    } & ParsedAssertStakePoolAccountMultiInstruction<TProgram>)
+  | ({
+      instructionType: LighthouseInstruction.AssertConcurrentMerkleTreeAccount;
+    } & ParsedAssertConcurrentMerkleTreeAccountInstruction<TProgram>)
+  | ({
+      instructionType: LighthouseInstruction.AssertConcurrentMerkleTreeAccountMulti;
+    } & ParsedAssertConcurrentMerkleTreeAccountMultiInstruction<TProgram>)
+  | ({
+      instructionType: LighthouseInstruction.AssertAccountDataPath;
+    } & ParsedAssertAccountDataPathInstruction<TProgram>)
+  | ({
+      instructionType: LighthouseInstruction.MemoryWriteMulti;
+    } & ParsedMemoryWriteMultiInstruction<TProgram>)
+  | ({
+      instructionType: LighthouseInstruction.MemoryInitHeader;
+    } & ParsedMemoryInitHeaderInstruction<TProgram>)
+  | ({
+      instructionType: LighthouseInstruction.AssertMemoryHeader;
+    } & ParsedAssertMemoryHeaderInstruction<TProgram>)
+  | ({
+      instructionType: LighthouseInstruction.AssertAccountSnapshot;
+    } & ParsedAssertAccountSnapshotInstruction<TProgram>)
+  | ({
+      instructionType: LighthouseInstruction.MemoryCloseToRecipient;
+    } & ParsedMemoryCloseToRecipientInstruction<TProgram>)
+  | ({
+      instructionType: LighthouseInstruction.MemoryZero;
+    } & ParsedMemoryZeroInstruction<TProgram>)
+  | ({
+      instructionType: LighthouseInstruction.MemoryShrink;
+    } & ParsedMemoryShrinkInstruction<TProgram>)
+  | ({
+      instructionType: LighthouseInstruction.MemoryWriteWithAuthority;
+    } & ParsedMemoryWriteWithAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: LighthouseInstruction.MemoryCloseWithAuthority;
+    } & ParsedMemoryCloseWithAuthorityInstruction<TProgram>);
@@ -12,9 +12,13 @@ import {
   Encoder,
   GetDataEnumKind,
   GetDataEnumKindContent,
+  Option,
+  OptionOrNullable,
   combineCodec,
   getDataEnumDecoder,
   getDataEnumEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU16Decoder,
@@ -23,12 +27,20 @@ import {
 import {
   AccountInfoDeltaAssertion,
   AccountInfoDeltaAssertionArgs,
+  DataHashType,
+  DataHashTypeArgs,
   DataValueDeltaAssertion,
   DataValueDeltaAssertionArgs,
+  EquatableOperator,
+  EquatableOperatorArgs,
   getAccountInfoDeltaAssertionDecoder,
   getAccountInfoDeltaAssertionEncoder,
+  getDataHashTypeDecoder,
+  getDataHashTypeEncoder,
   getDataValueDeltaAssertionDecoder,
   getDataValueDeltaAssertionEncoder,
+  getEquatableOperatorDecoder,
+  getEquatableOperatorEncoder,
 } from '.';
 
 export type AccountDeltaAssertion =
@@ -42,6 +54,14 @@ export type AccountDeltaAssertion =
       aOffset: number;
       bOffset: number;
       assertion: DataValueDeltaAssertion;
+    }
+  | {
+      __kind: 'DataHash';
+      aOffset: number;
+      hashType: DataHashType;
+      start: Option<number>;
+      length: Option<number>;
+      operator: EquatableOperator;
     };
 
 export type AccountDeltaAssertionArgs =
@@ -55,6 +75,14 @@ export type AccountDeltaAssertionArgs =
       aOffset: number;
       bOffset: number;
       assertion: DataValueDeltaAssertionArgs;
+    }
+  | {
+      __kind: 'DataHash';
+      aOffset: number;
+      hashType: DataHashTypeArgs;
+      start: OptionOrNullable<number>;
+      length: OptionOrNullable<number>;
+      operator: EquatableOperatorArgs;
     };
 
 export function getAccountDeltaAssertionEncoder(): Encoder<AccountDeltaAssertionArgs> {
@@ -74,6 +102,16 @@ export function getAccountDeltaAssertionEncoder(): Encoder<AccountDeltaAssertion
         ['assertion', getDataValueDeltaAssertionEncoder()],
       ]),
     ],
+    [
+      'DataHash',
+      getStructEncoder([
+        ['aOffset', getU16Encoder()],
+        ['hashType', getDataHashTypeEncoder()],
+        ['start', getOptionEncoder(getU16Encoder())],
+        ['length', getOptionEncoder(getU16Encoder())],
+        ['operator', getEquatableOperatorEncoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -94,6 +132,16 @@ export function getAccountDeltaAssertionDecoder(): Decoder<AccountDeltaAssertion
         ['assertion', getDataValueDeltaAssertionDecoder()],
       ]),
     ],
+    [
+      'DataHash',
+      getStructDecoder([
+        ['aOffset', getU16Decoder()],
+        ['hashType', getDataHashTypeDecoder()],
+        ['start', getOptionDecoder(getU16Decoder())],
+        ['length', getOptionDecoder(getU16Decoder())],
+        ['operator', getEquatableOperatorDecoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -116,6 +164,10 @@ export function accountDeltaAssertion(
   kind: 'Data',
   data: GetDataEnumKindContent<AccountDeltaAssertionArgs, 'Data'>
 ): GetDataEnumKind<AccountDeltaAssertionArgs, 'Data'>;
+export function accountDeltaAssertion(
+  kind: 'DataHash',
+  data: GetDataEnumKindContent<AccountDeltaAssertionArgs, 'DataHash'>
+): GetDataEnumKind<AccountDeltaAssertionArgs, 'DataHash'>;
 export function accountDeltaAssertion<
   K extends AccountDeltaAssertionArgs['__kind']
 >(kind: K, data?: any): Extract<AccountDeltaAssertionArgs, { __kind: K }> {
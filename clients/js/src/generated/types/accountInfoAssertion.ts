@@ -20,6 +20,8 @@ import {
   Option,
   OptionOrNullable,
   combineCodec,
+  getArrayDecoder,
+  getArrayEncoder,
   getBooleanDecoder,
   getBooleanEncoder,
   getBytesDecoder,
@@ -36,18 +38,26 @@ import {
   getU64Encoder,
 } from '@solana/codecs';
 import {
+  AnchorDiscriminatorValue,
+  AnchorDiscriminatorValueArgs,
   EquatableOperator,
   EquatableOperatorArgs,
   IntegerOperator,
   IntegerOperatorArgs,
   KnownProgram,
   KnownProgramArgs,
+  SetOperator,
+  SetOperatorArgs,
+  getAnchorDiscriminatorValueDecoder,
+  getAnchorDiscriminatorValueEncoder,
   getEquatableOperatorDecoder,
   getEquatableOperatorEncoder,
   getIntegerOperatorDecoder,
   getIntegerOperatorEncoder,
   getKnownProgramDecoder,
   getKnownProgramEncoder,
+  getSetOperatorDecoder,
+  getSetOperatorEncoder,
 } from '.';
 
 export type AccountInfoAssertion =
@@ -64,7 +74,13 @@ export type AccountInfoAssertion =
       expectedHash: Uint8Array;
       start: Option<number>;
       length: Option<number>;
-    };
+    }
+  | {
+      __kind: 'AnchorDiscriminator';
+      value: AnchorDiscriminatorValue;
+      operator: EquatableOperator;
+    }
+  | { __kind: 'OwnerIn'; values: Array<Address>; operator: SetOperator };
 
 export type AccountInfoAssertionArgs =
   | {
@@ -96,7 +112,13 @@ export type AccountInfoAssertionArgs =
       expectedHash: Uint8Array;
       start: OptionOrNullable<number>;
       length: OptionOrNullable<number>;
-    };
+    }
+  | {
+      __kind: 'AnchorDiscriminator';
+      value: AnchorDiscriminatorValueArgs;
+      operator: EquatableOperatorArgs;
+    }
+  | { __kind: 'OwnerIn'; values: Array<Address>; operator: SetOperatorArgs };
 
 export function getAccountInfoAssertionEncoder(): Encoder<AccountInfoAssertionArgs> {
   return getDataEnumEncoder([
@@ -164,6 +186,20 @@ export function getAccountInfoAssertionEncoder(): Encoder<AccountInfoAssertionAr
         ['length', getOptionEncoder(getU16Encoder())],
       ]),
     ],
+    [
+      'AnchorDiscriminator',
+      getStructEncoder([
+        ['value', getAnchorDiscriminatorValueEncoder()],
+        ['operator', getEquatableOperatorEncoder()],
+      ]),
+    ],
+    [
+      'OwnerIn',
+      getStructEncoder([
+        ['values', getArrayEncoder(getAddressEncoder())],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -233,6 +269,20 @@ export function getAccountInfoAssertionDecoder(): Decoder<AccountInfoAssertion>
         ['length', getOptionDecoder(getU16Decoder())],
       ]),
     ],
+    [
+      'AnchorDiscriminator',
+      getStructDecoder([
+        ['value', getAnchorDiscriminatorValueDecoder()],
+        ['operator', getEquatableOperatorDecoder()],
+      ]),
+    ],
+    [
+      'OwnerIn',
+      getStructDecoder([
+        ['values', getArrayDecoder(getAddressDecoder())],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -283,6 +333,14 @@ export function accountInfoAssertion(
   kind: 'VerifyDatahash',
   data: GetDataEnumKindContent<AccountInfoAssertionArgs, 'VerifyDatahash'>
 ): GetDataEnumKind<AccountInfoAssertionArgs, 'VerifyDatahash'>;
+export function accountInfoAssertion(
+  kind: 'AnchorDiscriminator',
+  data: GetDataEnumKindContent<AccountInfoAssertionArgs, 'AnchorDiscriminator'>
+): GetDataEnumKind<AccountInfoAssertionArgs, 'AnchorDiscriminator'>;
+export function accountInfoAssertion(
+  kind: 'OwnerIn',
+  data: GetDataEnumKindContent<AccountInfoAssertionArgs, 'OwnerIn'>
+): GetDataEnumKind<AccountInfoAssertionArgs, 'OwnerIn'>;
 export function accountInfoAssertion<
   K extends AccountInfoAssertionArgs['__kind']
 >(kind: K, data?: any): Extract<AccountInfoAssertionArgs, { __kind: K }> {
@@ -0,0 +1,162 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  combineCodec,
+  getDataEnumDecoder,
+  getDataEnumEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+} from '@solana/codecs';
+import {
+  MintAccountSnapshotAssertion,
+  MintAccountSnapshotAssertionArgs,
+  StakeAccountSnapshotAssertion,
+  StakeAccountSnapshotAssertionArgs,
+  TokenAccountSnapshotAssertion,
+  TokenAccountSnapshotAssertionArgs,
+  getMintAccountSnapshotAssertionDecoder,
+  getMintAccountSnapshotAssertionEncoder,
+  getStakeAccountSnapshotAssertionDecoder,
+  getStakeAccountSnapshotAssertionEncoder,
+  getTokenAccountSnapshotAssertionDecoder,
+  getTokenAccountSnapshotAssertionEncoder,
+} from '.';
+
+export type AccountSnapshotAssertion =
+  | {
+      __kind: 'TokenAccount';
+      memoryOffset: number;
+      assertion: TokenAccountSnapshotAssertion;
+    }
+  | {
+      __kind: 'MintAccount';
+      memoryOffset: number;
+      assertion: MintAccountSnapshotAssertion;
+    }
+  | {
+      __kind: 'StakeAccount';
+      memoryOffset: number;
+      assertion: StakeAccountSnapshotAssertion;
+    };
+
+export type AccountSnapshotAssertionArgs =
+  | {
+      __kind: 'TokenAccount';
+      memoryOffset: number;
+      assertion: TokenAccountSnapshotAssertionArgs;
+    }
+  | {
+      __kind: 'MintAccount';
+      memoryOffset: number;
+      assertion: MintAccountSnapshotAssertionArgs;
+    }
+  | {
+      __kind: 'StakeAccount';
+      memoryOffset: number;
+      assertion: StakeAccountSnapshotAssertionArgs;
+    };
+
+export function getAccountSnapshotAssertionEncoder(): Encoder<AccountSnapshotAssertionArgs> {
+  return getDataEnumEncoder([
+    [
+      'TokenAccount',
+      getStructEncoder([
+        ['memoryOffset', getU16Encoder()],
+        ['assertion', getTokenAccountSnapshotAssertionEncoder()],
+      ]),
+    ],
+    [
+      'MintAccount',
+      getStructEncoder([
+        ['memoryOffset', getU16Encoder()],
+        ['assertion', getMintAccountSnapshotAssertionEncoder()],
+      ]),
+    ],
+    [
+      'StakeAccount',
+      getStructEncoder([
+        ['memoryOffset', getU16Encoder()],
+        ['assertion', getStakeAccountSnapshotAssertionEncoder()],
+      ]),
+    ],
+  ]);
+}
+
+export function getAccountSnapshotAssertionDecoder(): Decoder<AccountSnapshotAssertion> {
+  return getDataEnumDecoder([
+    [
+      'TokenAccount',
+      getStructDecoder([
+        ['memoryOffset', getU16Decoder()],
+        ['assertion', getTokenAccountSnapshotAssertionDecoder()],
+      ]),
+    ],
+    [
+      'MintAccount',
+      getStructDecoder([
+        ['memoryOffset', getU16Decoder()],
+        ['assertion', getMintAccountSnapshotAssertionDecoder()],
+      ]),
+    ],
+    [
+      'StakeAccount',
+      getStructDecoder([
+        ['memoryOffset', getU16Decoder()],
+        ['assertion', getStakeAccountSnapshotAssertionDecoder()],
+      ]),
+    ],
+  ]);
+}
+
+export function getAccountSnapshotAssertionCodec(): Codec<
+  AccountSnapshotAssertionArgs,
+  AccountSnapshotAssertion
+> {
+  return combineCodec(
+    getAccountSnapshotAssertionEncoder(),
+    getAccountSnapshotAssertionDecoder()
+  );
+}
+
+// Data Enum Helpers.
+export function accountSnapshotAssertion(
+  kind: 'TokenAccount',
+  data: GetDataEnumKindContent<AccountSnapshotAssertionArgs, 'TokenAccount'>
+): GetDataEnumKind<AccountSnapshotAssertionArgs, 'TokenAccount'>;
+export function accountSnapshotAssertion(
+  kind: 'MintAccount',
+  data: GetDataEnumKindContent<AccountSnapshotAssertionArgs, 'MintAccount'>
+): GetDataEnumKind<AccountSnapshotAssertionArgs, 'MintAccount'>;
+export function accountSnapshotAssertion(
+  kind: 'StakeAccount',
+  data: GetDataEnumKindContent<AccountSnapshotAssertionArgs, 'StakeAccount'>
+): GetDataEnumKind<AccountSnapshotAssertionArgs, 'StakeAccount'>;
+export function accountSnapshotAssertion<
+  K extends AccountSnapshotAssertionArgs['__kind']
+>(kind: K, data?: any): Extract<AccountSnapshotAssertionArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+
+export function isAccountSnapshotAssertion<
+  K extends AccountSnapshotAssertion['__kind']
+>(
+  kind: K,
+  value: AccountSnapshotAssertion
+): value is AccountSnapshotAssertion & { __kind: K } {
+  return value.__kind === kind;
+}
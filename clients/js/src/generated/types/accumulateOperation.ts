@@ -0,0 +1,42 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  combineCodec,
+  getScalarEnumDecoder,
+  getScalarEnumEncoder,
+} from '@solana/codecs';
+
+export enum AccumulateOperation {
+  Add,
+  Min,
+  Max,
+}
+
+export type AccumulateOperationArgs = AccumulateOperation;
+
+export function getAccumulateOperationEncoder(): Encoder<AccumulateOperationArgs> {
+  return getScalarEnumEncoder(AccumulateOperation);
+}
+
+export function getAccumulateOperationDecoder(): Decoder<AccumulateOperation> {
+  return getScalarEnumDecoder(AccumulateOperation);
+}
+
+export function getAccumulateOperationCodec(): Codec<
+  AccumulateOperationArgs,
+  AccumulateOperation
+> {
+  return combineCodec(
+    getAccumulateOperationEncoder(),
+    getAccumulateOperationDecoder()
+  );
+}
@@ -0,0 +1,123 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  combineCodec,
+  getDataEnumDecoder,
+  getDataEnumEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getTupleDecoder,
+  getTupleEncoder,
+  getU128Decoder,
+  getU128Encoder,
+  getU16Decoder,
+  getU16Encoder,
+  getUnitDecoder,
+  getUnitEncoder,
+} from '@solana/codecs';
+import {
+  IntegerDataType,
+  IntegerDataTypeArgs,
+  getIntegerDataTypeDecoder,
+  getIntegerDataTypeEncoder,
+} from '.';
+
+export type AccumulateSource =
+  | { __kind: 'Lamports' }
+  | { __kind: 'TokenAmount' }
+  | { __kind: 'AccountData'; offset: number; dataType: IntegerDataType }
+  | { __kind: 'Value'; fields: [bigint] };
+
+export type AccumulateSourceArgs =
+  | { __kind: 'Lamports' }
+  | { __kind: 'TokenAmount' }
+  | { __kind: 'AccountData'; offset: number; dataType: IntegerDataTypeArgs }
+  | { __kind: 'Value'; fields: [number | bigint] };
+
+export function getAccumulateSourceEncoder(): Encoder<AccumulateSourceArgs> {
+  return getDataEnumEncoder([
+    ['Lamports', getUnitEncoder()],
+    ['TokenAmount', getUnitEncoder()],
+    [
+      'AccountData',
+      getStructEncoder([
+        ['offset', getU16Encoder()],
+        ['dataType', getIntegerDataTypeEncoder()],
+      ]),
+    ],
+    [
+      'Value',
+      getStructEncoder([['fields', getTupleEncoder([getU128Encoder()])]]),
+    ],
+  ]);
+}
+
+export function getAccumulateSourceDecoder(): Decoder<AccumulateSource> {
+  return getDataEnumDecoder([
+    ['Lamports', getUnitDecoder()],
+    ['TokenAmount', getUnitDecoder()],
+    [
+      'AccountData',
+      getStructDecoder([
+        ['offset', getU16Decoder()],
+        ['dataType', getIntegerDataTypeDecoder()],
+      ]),
+    ],
+    [
+      'Value',
+      getStructDecoder([['fields', getTupleDecoder([getU128Decoder()])]]),
+    ],
+  ]);
+}
+
+export function getAccumulateSourceCodec(): Codec<
+  AccumulateSourceArgs,
+  AccumulateSource
+> {
+  return combineCodec(
+    getAccumulateSourceEncoder(),
+    getAccumulateSourceDecoder()
+  );
+}
+
+// Data Enum Helpers.
+export function accumulateSource(
+  kind: 'Lamports'
+): GetDataEnumKind<AccumulateSourceArgs, 'Lamports'>;
+export function accumulateSource(
+  kind: 'TokenAmount'
+): GetDataEnumKind<AccumulateSourceArgs, 'TokenAmount'>;
+export function accumulateSource(
+  kind: 'AccountData',
+  data: GetDataEnumKindContent<AccumulateSourceArgs, 'AccountData'>
+): GetDataEnumKind<AccumulateSourceArgs, 'AccountData'>;
+export function accumulateSource(
+  kind: 'Value',
+  data: GetDataEnumKindContent<AccumulateSourceArgs, 'Value'>['fields']
+): GetDataEnumKind<AccumulateSourceArgs, 'Value'>;
+export function accumulateSource<K extends AccumulateSourceArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<AccumulateSourceArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+
+export function isAccumulateSource<K extends AccumulateSource['__kind']>(
+  kind: K,
+  value: AccumulateSource
+): value is AccumulateSource & { __kind: K } {
+  return value.__kind === kind;
+}
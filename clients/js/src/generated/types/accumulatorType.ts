@@ -0,0 +1,38 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  combineCodec,
+  getScalarEnumDecoder,
+  getScalarEnumEncoder,
+} from '@solana/codecs';
+
+export enum AccumulatorType {
+  U64,
+  U128,
+}
+
+export type AccumulatorTypeArgs = AccumulatorType;
+
+export function getAccumulatorTypeEncoder(): Encoder<AccumulatorTypeArgs> {
+  return getScalarEnumEncoder(AccumulatorType);
+}
+
+export function getAccumulatorTypeDecoder(): Decoder<AccumulatorType> {
+  return getScalarEnumDecoder(AccumulatorType);
+}
+
+export function getAccumulatorTypeCodec(): Codec<
+  AccumulatorTypeArgs,
+  AccumulatorType
+> {
+  return combineCodec(getAccumulatorTypeEncoder(), getAccumulatorTypeDecoder());
+}
@@ -0,0 +1,104 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  combineCodec,
+  getBytesDecoder,
+  getBytesEncoder,
+  getDataEnumDecoder,
+  getDataEnumEncoder,
+  getStringDecoder,
+  getStringEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getTupleDecoder,
+  getTupleEncoder,
+} from '@solana/codecs';
+
+export type AnchorDiscriminatorValue =
+  | { __kind: 'AccountName'; fields: [string] }
+  | { __kind: 'Discriminator'; fields: [Uint8Array] };
+
+export type AnchorDiscriminatorValueArgs = AnchorDiscriminatorValue;
+
+export function getAnchorDiscriminatorValueEncoder(): Encoder<AnchorDiscriminatorValueArgs> {
+  return getDataEnumEncoder([
+    [
+      'AccountName',
+      getStructEncoder([['fields', getTupleEncoder([getStringEncoder()])]]),
+    ],
+    [
+      'Discriminator',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getBytesEncoder({ size: 8 })])],
+      ]),
+    ],
+  ]);
+}
+
+export function getAnchorDiscriminatorValueDecoder(): Decoder<AnchorDiscriminatorValue> {
+  return getDataEnumDecoder([
+    [
+      'AccountName',
+      getStructDecoder([['fields', getTupleDecoder([getStringDecoder()])]]),
+    ],
+    [
+      'Discriminator',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getBytesDecoder({ size: 8 })])],
+      ]),
+    ],
+  ]);
+}
+
+export function getAnchorDiscriminatorValueCodec(): Codec<
+  AnchorDiscriminatorValueArgs,
+  AnchorDiscriminatorValue
+> {
+  return combineCodec(
+    getAnchorDiscriminatorValueEncoder(),
+    getAnchorDiscriminatorValueDecoder()
+  );
+}
+
+// Data Enum Helpers.
+export function anchorDiscriminatorValue(
+  kind: 'AccountName',
+  data: GetDataEnumKindContent<
+    AnchorDiscriminatorValueArgs,
+    'AccountName'
+  >['fields']
+): GetDataEnumKind<AnchorDiscriminatorValueArgs, 'AccountName'>;
+export function anchorDiscriminatorValue(
+  kind: 'Discriminator',
+  data: GetDataEnumKindContent<
+    AnchorDiscriminatorValueArgs,
+    'Discriminator'
+  >['fields']
+): GetDataEnumKind<AnchorDiscriminatorValueArgs, 'Discriminator'>;
+export function anchorDiscriminatorValue<
+  K extends AnchorDiscriminatorValueArgs['__kind']
+>(kind: K, data?: any): Extract<AnchorDiscriminatorValueArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+
+export function isAnchorDiscriminatorValue<
+  K extends AnchorDiscriminatorValue['__kind']
+>(
+  kind: K,
+  value: AnchorDiscriminatorValue
+): value is AnchorDiscriminatorValue & { __kind: K } {
+  return value.__kind === kind;
+}
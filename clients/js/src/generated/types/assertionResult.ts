@@ -26,6 +26,10 @@ import {
   getBytesEncoder,
   getDataEnumDecoder,
   getDataEnumEncoder,
+  getF32Decoder,
+  getF32Encoder,
+  getF64Decoder,
+  getF64Encoder,
   getI128Decoder,
   getI128Encoder,
   getI16Decoder,
@@ -79,7 +83,14 @@ export type AssertionResult =
   | {
       __kind: 'Bool';
       fields: [Option<boolean>, Option<boolean>, number, boolean];
-    };
+    }
+  | { __kind: 'F32'; fields: [Option<number>, Option<number>, number, boolean] }
+  | { __kind: 'F64'; fields: [Option<number>, Option<number>, number, boolean] }
+  | {
+      __kind: 'BytesContains';
+      fields: [Option<number>, Uint8Array, number, boolean];
+    }
+  | { __kind: 'Set'; fields: [Uint8Array, Uint8Array, number, boolean] };
 
 export type AssertionResultArgs =
   | {
@@ -190,7 +201,30 @@ export type AssertionResultArgs =
         number,
         boolean
       ];
-    };
+    }
+  | {
+      __kind: 'F32';
+      fields: [
+        OptionOrNullable<number>,
+        OptionOrNullable<number>,
+        number,
+        boolean
+      ];
+    }
+  | {
+      __kind: 'F64';
+      fields: [
+        OptionOrNullable<number>,
+        OptionOrNullable<number>,
+        number,
+        boolean
+      ];
+    }
+  | {
+      __kind: 'BytesContains';
+      fields: [OptionOrNullable<number>, Uint8Array, number, boolean];
+    }
+  | { __kind: 'Set'; fields: [Uint8Array, Uint8Array, number, boolean] };
 
 export function getAssertionResultEncoder(): Encoder<AssertionResultArgs> {
   return getDataEnumEncoder([
@@ -376,6 +410,62 @@ export function getAssertionResultEncoder(): Encoder<AssertionResultArgs> {
         ],
       ]),
     ],
+    [
+      'F32',
+      getStructEncoder([
+        [
+          'fields',
+          getTupleEncoder([
+            getOptionEncoder(getF32Encoder()),
+            getOptionEncoder(getF32Encoder()),
+            getU8Encoder(),
+            getBooleanEncoder(),
+          ]),
+        ],
+      ]),
+    ],
+    [
+      'F64',
+      getStructEncoder([
+        [
+          'fields',
+          getTupleEncoder([
+            getOptionEncoder(getF64Encoder()),
+            getOptionEncoder(getF64Encoder()),
+            getU8Encoder(),
+            getBooleanEncoder(),
+          ]),
+        ],
+      ]),
+    ],
+    [
+      'BytesContains',
+      getStructEncoder([
+        [
+          'fields',
+          getTupleEncoder([
+            getOptionEncoder(getU32Encoder()),
+            getBytesEncoder({ size: getU32Encoder() }),
+            getU8Encoder(),
+            getBooleanEncoder(),
+          ]),
+        ],
+      ]),
+    ],
+    [
+      'Set',
+      getStructEncoder([
+        [
+          'fields',
+          getTupleEncoder([
+            getBytesEncoder({ size: getU32Encoder() }),
+            getBytesEncoder({ size: getU32Encoder() }),
+            getU8Encoder(),
+            getBooleanEncoder(),
+          ]),
+        ],
+      ]),
+    ],
   ]);
 }
 
@@ -563,6 +653,62 @@ export function getAssertionResultDecoder(): Decoder<AssertionResult> {
         ],
       ]),
     ],
+    [
+      'F32',
+      getStructDecoder([
+        [
+          'fields',
+          getTupleDecoder([
+            getOptionDecoder(getF32Decoder()),
+            getOptionDecoder(getF32Decoder()),
+            getU8Decoder(),
+            getBooleanDecoder(),
+          ]),
+        ],
+      ]),
+    ],
+    [
+      'F64',
+      getStructDecoder([
+        [
+          'fields',
+          getTupleDecoder([
+            getOptionDecoder(getF64Decoder()),
+            getOptionDecoder(getF64Decoder()),
+            getU8Decoder(),
+            getBooleanDecoder(),
+          ]),
+        ],
+      ]),
+    ],
+    [
+      'BytesContains',
+      getStructDecoder([
+        [
+          'fields',
+          getTupleDecoder([
+            getOptionDecoder(getU32Decoder()),
+            getBytesDecoder({ size: getU32Decoder() }),
+            getU8Decoder(),
+            getBooleanDecoder(),
+          ]),
+        ],
+      ]),
+    ],
+    [
+      'Set',
+      getStructDecoder([
+        [
+          'fields',
+          getTupleDecoder([
+            getBytesDecoder({ size: getU32Decoder() }),
+            getBytesDecoder({ size: getU32Decoder() }),
+            getU8Decoder(),
+            getBooleanDecoder(),
+          ]),
+        ],
+      ]),
+    ],
   ]);
 }
 
@@ -626,6 +772,22 @@ export function assertionResult(
   kind: 'Bool',
   data: GetDataEnumKindContent<AssertionResultArgs, 'Bool'>['fields']
 ): GetDataEnumKind<AssertionResultArgs, 'Bool'>;
+export function assertionResult(
+  kind: 'F32',
+  data: GetDataEnumKindContent<AssertionResultArgs, 'F32'>['fields']
+): GetDataEnumKind<AssertionResultArgs, 'F32'>;
+export function assertionResult(
+  kind: 'F64',
+  data: GetDataEnumKindContent<AssertionResultArgs, 'F64'>['fields']
+): GetDataEnumKind<AssertionResultArgs, 'F64'>;
+export function assertionResult(
+  kind: 'BytesContains',
+  data: GetDataEnumKindContent<AssertionResultArgs, 'BytesContains'>['fields']
+): GetDataEnumKind<AssertionResultArgs, 'BytesContains'>;
+export function assertionResult(
+  kind: 'Set',
+  data: GetDataEnumKindContent<AssertionResultArgs, 'Set'>['fields']
+): GetDataEnumKind<AssertionResultArgs, 'Set'>;
 export function assertionResult<K extends AssertionResultArgs['__kind']>(
   kind: K,
   data?: any
@@ -0,0 +1,121 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  combineCodec,
+  getDataEnumDecoder,
+  getDataEnumEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getTupleDecoder,
+  getTupleEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getUnitDecoder,
+  getUnitEncoder,
+} from '@solana/codecs';
+
+export type BorshFieldType =
+  | { __kind: 'Fixed'; fields: [number] }
+  | { __kind: 'String' }
+  | { __kind: 'FixedVec'; fields: [number] }
+  | { __kind: 'StringVec' }
+  | { __kind: 'FixedOption'; fields: [number] }
+  | { __kind: 'StringOption' };
+
+export type BorshFieldTypeArgs = BorshFieldType;
+
+export function getBorshFieldTypeEncoder(): Encoder<BorshFieldTypeArgs> {
+  return getDataEnumEncoder([
+    [
+      'Fixed',
+      getStructEncoder([['fields', getTupleEncoder([getU16Encoder()])]]),
+    ],
+    ['String', getUnitEncoder()],
+    [
+      'FixedVec',
+      getStructEncoder([['fields', getTupleEncoder([getU16Encoder()])]]),
+    ],
+    ['StringVec', getUnitEncoder()],
+    [
+      'FixedOption',
+      getStructEncoder([['fields', getTupleEncoder([getU16Encoder()])]]),
+    ],
+    ['StringOption', getUnitEncoder()],
+  ]);
+}
+
+export function getBorshFieldTypeDecoder(): Decoder<BorshFieldType> {
+  return getDataEnumDecoder([
+    [
+      'Fixed',
+      getStructDecoder([['fields', getTupleDecoder([getU16Decoder()])]]),
+    ],
+    ['String', getUnitDecoder()],
+    [
+      'FixedVec',
+      getStructDecoder([['fields', getTupleDecoder([getU16Decoder()])]]),
+    ],
+    ['StringVec', getUnitDecoder()],
+    [
+      'FixedOption',
+      getStructDecoder([['fields', getTupleDecoder([getU16Decoder()])]]),
+    ],
+    ['StringOption', getUnitDecoder()],
+  ]);
+}
+
+export function getBorshFieldTypeCodec(): Codec<
+  BorshFieldTypeArgs,
+  BorshFieldType
+> {
+  return combineCodec(getBorshFieldTypeEncoder(), getBorshFieldTypeDecoder());
+}
+
+// Data Enum Helpers.
+export function borshFieldType(
+  kind: 'Fixed',
+  data: GetDataEnumKindContent<BorshFieldTypeArgs, 'Fixed'>['fields']
+): GetDataEnumKind<BorshFieldTypeArgs, 'Fixed'>;
+export function borshFieldType(
+  kind: 'String'
+): GetDataEnumKind<BorshFieldTypeArgs, 'String'>;
+export function borshFieldType(
+  kind: 'FixedVec',
+  data: GetDataEnumKindContent<BorshFieldTypeArgs, 'FixedVec'>['fields']
+): GetDataEnumKind<BorshFieldTypeArgs, 'FixedVec'>;
+export function borshFieldType(
+  kind: 'StringVec'
+): GetDataEnumKind<BorshFieldTypeArgs, 'StringVec'>;
+export function borshFieldType(
+  kind: 'FixedOption',
+  data: GetDataEnumKindContent<BorshFieldTypeArgs, 'FixedOption'>['fields']
+): GetDataEnumKind<BorshFieldTypeArgs, 'FixedOption'>;
+export function borshFieldType(
+  kind: 'StringOption'
+): GetDataEnumKind<BorshFieldTypeArgs, 'StringOption'>;
+export function borshFieldType<K extends BorshFieldTypeArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<BorshFieldTypeArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+
+export function isBorshFieldType<K extends BorshFieldType['__kind']>(
+  kind: K,
+  value: BorshFieldType
+): value is BorshFieldType & { __kind: K } {
+  return value.__kind === kind;
+}
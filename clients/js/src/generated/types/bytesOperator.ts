@@ -0,0 +1,101 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  combineCodec,
+  getBytesDecoder,
+  getBytesEncoder,
+  getDataEnumDecoder,
+  getDataEnumEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  getUnitDecoder,
+  getUnitEncoder,
+} from '@solana/codecs';
+
+export type BytesOperator =
+  | { __kind: 'Equal' }
+  | { __kind: 'NotEqual' }
+  | { __kind: 'Contains' }
+  | { __kind: 'MaskedEqual'; mask: Uint8Array }
+  | { __kind: 'StartsWith' };
+
+export type BytesOperatorArgs = BytesOperator;
+
+export function getBytesOperatorEncoder(): Encoder<BytesOperatorArgs> {
+  return getDataEnumEncoder([
+    ['Equal', getUnitEncoder()],
+    ['NotEqual', getUnitEncoder()],
+    ['Contains', getUnitEncoder()],
+    [
+      'MaskedEqual',
+      getStructEncoder([['mask', getBytesEncoder({ size: getU32Encoder() })]]),
+    ],
+    ['StartsWith', getUnitEncoder()],
+  ]);
+}
+
+export function getBytesOperatorDecoder(): Decoder<BytesOperator> {
+  return getDataEnumDecoder([
+    ['Equal', getUnitDecoder()],
+    ['NotEqual', getUnitDecoder()],
+    ['Contains', getUnitDecoder()],
+    [
+      'MaskedEqual',
+      getStructDecoder([['mask', getBytesDecoder({ size: getU32Decoder() })]]),
+    ],
+    ['StartsWith', getUnitDecoder()],
+  ]);
+}
+
+export function getBytesOperatorCodec(): Codec<
+  BytesOperatorArgs,
+  BytesOperator
+> {
+  return combineCodec(getBytesOperatorEncoder(), getBytesOperatorDecoder());
+}
+
+// Data Enum Helpers.
+export function bytesOperator(
+  kind: 'Equal'
+): GetDataEnumKind<BytesOperatorArgs, 'Equal'>;
+export function bytesOperator(
+  kind: 'NotEqual'
+): GetDataEnumKind<BytesOperatorArgs, 'NotEqual'>;
+export function bytesOperator(
+  kind: 'Contains'
+): GetDataEnumKind<BytesOperatorArgs, 'Contains'>;
+export function bytesOperator(
+  kind: 'MaskedEqual',
+  data: GetDataEnumKindContent<BytesOperatorArgs, 'MaskedEqual'>
+): GetDataEnumKind<BytesOperatorArgs, 'MaskedEqual'>;
+export function bytesOperator(
+  kind: 'StartsWith'
+): GetDataEnumKind<BytesOperatorArgs, 'StartsWith'>;
+export function bytesOperator<K extends BytesOperatorArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<BytesOperatorArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+
+export function isBytesOperator<K extends BytesOperator['__kind']>(
+  kind: K,
+  value: BytesOperator
+): value is BytesOperator & { __kind: K } {
+  return value.__kind === kind;
+}
@@ -0,0 +1,270 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Address,
+  getAddressDecoder,
+  getAddressEncoder,
+} from '@solana/addresses';
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  combineCodec,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getDataEnumDecoder,
+  getDataEnumEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
+} from '@solana/codecs';
+import {
+  EquatableOperator,
+  EquatableOperatorArgs,
+  IntegerOperator,
+  IntegerOperatorArgs,
+  getEquatableOperatorDecoder,
+  getEquatableOperatorEncoder,
+  getIntegerOperatorDecoder,
+  getIntegerOperatorEncoder,
+} from '.';
+
+export type ConcurrentMerkleTreeAssertion =
+  | { __kind: 'MaxDepth'; value: number; operator: IntegerOperator }
+  | { __kind: 'MaxBufferSize'; value: number; operator: IntegerOperator }
+  | { __kind: 'Authority'; value: Address; operator: EquatableOperator }
+  | { __kind: 'CreationSlot'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'SequenceNumber'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'ActiveIndex'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'RightmostIndex'; value: number; operator: IntegerOperator }
+  | { __kind: 'IsFull'; value: boolean; operator: EquatableOperator };
+
+export type ConcurrentMerkleTreeAssertionArgs =
+  | { __kind: 'MaxDepth'; value: number; operator: IntegerOperatorArgs }
+  | { __kind: 'MaxBufferSize'; value: number; operator: IntegerOperatorArgs }
+  | { __kind: 'Authority'; value: Address; operator: EquatableOperatorArgs }
+  | {
+      __kind: 'CreationSlot';
+      value: number | bigint;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'SequenceNumber';
+      value: number | bigint;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'ActiveIndex';
+      value: number | bigint;
+      operator: IntegerOperatorArgs;
+    }
+  | { __kind: 'RightmostIndex'; value: number; operator: IntegerOperatorArgs }
+  | { __kind: 'IsFull'; value: boolean; operator: EquatableOperatorArgs };
+
+export function getConcurrentMerkleTreeAssertionEncoder(): Encoder<ConcurrentMerkleTreeAssertionArgs> {
+  return getDataEnumEncoder([
+    [
+      'MaxDepth',
+      getStructEncoder([
+        ['value', getU32Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'MaxBufferSize',
+      getStructEncoder([
+        ['value', getU32Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'Authority',
+      getStructEncoder([
+        ['value', getAddressEncoder()],
+        ['operator', getEquatableOperatorEncoder()],
+      ]),
+    ],
+    [
+      'CreationSlot',
+      getStructEncoder([
+        ['value', getU64Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'SequenceNumber',
+      getStructEncoder([
+        ['value', getU64Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'ActiveIndex',
+      getStructEncoder([
+        ['value', getU64Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'RightmostIndex',
+      getStructEncoder([
+        ['value', getU32Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'IsFull',
+      getStructEncoder([
+        ['value', getBooleanEncoder()],
+        ['operator', getEquatableOperatorEncoder()],
+      ]),
+    ],
+  ]);
+}
+
+export function getConcurrentMerkleTreeAssertionDecoder(): Decoder<ConcurrentMerkleTreeAssertion> {
+  return getDataEnumDecoder([
+    [
+      'MaxDepth',
+      getStructDecoder([
+        ['value', getU32Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'MaxBufferSize',
+      getStructDecoder([
+        ['value', getU32Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'Authority',
+      getStructDecoder([
+        ['value', getAddressDecoder()],
+        ['operator', getEquatableOperatorDecoder()],
+      ]),
+    ],
+    [
+      'CreationSlot',
+      getStructDecoder([
+        ['value', getU64Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'SequenceNumber',
+      getStructDecoder([
+        ['value', getU64Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'ActiveIndex',
+      getStructDecoder([
+        ['value', getU64Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'RightmostIndex',
+      getStructDecoder([
+        ['value', getU32Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'IsFull',
+      getStructDecoder([
+        ['value', getBooleanDecoder()],
+        ['operator', getEquatableOperatorDecoder()],
+      ]),
+    ],
+  ]);
+}
+
+export function getConcurrentMerkleTreeAssertionCodec(): Codec<
+  ConcurrentMerkleTreeAssertionArgs,
+  ConcurrentMerkleTreeAssertion
+> {
+  return combineCodec(
+    getConcurrentMerkleTreeAssertionEncoder(),
+    getConcurrentMerkleTreeAssertionDecoder()
+  );
+}
+
+// Data Enum Helpers.
+export function concurrentMerkleTreeAssertion(
+  kind: 'MaxDepth',
+  data: GetDataEnumKindContent<ConcurrentMerkleTreeAssertionArgs, 'MaxDepth'>
+): GetDataEnumKind<ConcurrentMerkleTreeAssertionArgs, 'MaxDepth'>;
+export function concurrentMerkleTreeAssertion(
+  kind: 'MaxBufferSize',
+  data: GetDataEnumKindContent<
+    ConcurrentMerkleTreeAssertionArgs,
+    'MaxBufferSize'
+  >
+): GetDataEnumKind<ConcurrentMerkleTreeAssertionArgs, 'MaxBufferSize'>;
+export function concurrentMerkleTreeAssertion(
+  kind: 'Authority',
+  data: GetDataEnumKindContent<ConcurrentMerkleTreeAssertionArgs, 'Authority'>
+): GetDataEnumKind<ConcurrentMerkleTreeAssertionArgs, 'Authority'>;
+export function concurrentMerkleTreeAssertion(
+  kind: 'CreationSlot',
+  data: GetDataEnumKindContent<
+    ConcurrentMerkleTreeAssertionArgs,
+    'CreationSlot'
+  >
+): GetDataEnumKind<ConcurrentMerkleTreeAssertionArgs, 'CreationSlot'>;
+export function concurrentMerkleTreeAssertion(
+  kind: 'SequenceNumber',
+  data: GetDataEnumKindContent<
+    ConcurrentMerkleTreeAssertionArgs,
+    'SequenceNumber'
+  >
+): GetDataEnumKind<ConcurrentMerkleTreeAssertionArgs, 'SequenceNumber'>;
+export function concurrentMerkleTreeAssertion(
+  kind: 'ActiveIndex',
+  data: GetDataEnumKindContent<ConcurrentMerkleTreeAssertionArgs, 'ActiveIndex'>
+): GetDataEnumKind<ConcurrentMerkleTreeAssertionArgs, 'ActiveIndex'>;
+export function concurrentMerkleTreeAssertion(
+  kind: 'RightmostIndex',
+  data: GetDataEnumKindContent<
+    ConcurrentMerkleTreeAssertionArgs,
+    'RightmostIndex'
+  >
+): GetDataEnumKind<ConcurrentMerkleTreeAssertionArgs, 'RightmostIndex'>;
+export function concurrentMerkleTreeAssertion(
+  kind: 'IsFull',
+  data: GetDataEnumKindContent<ConcurrentMerkleTreeAssertionArgs, 'IsFull'>
+): GetDataEnumKind<ConcurrentMerkleTreeAssertionArgs, 'IsFull'>;
+export function concurrentMerkleTreeAssertion<
+  K extends ConcurrentMerkleTreeAssertionArgs['__kind']
+>(
+  kind: K,
+  data?: any
+): Extract<ConcurrentMerkleTreeAssertionArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+
+export function isConcurrentMerkleTreeAssertion<
+  K extends ConcurrentMerkleTreeAssertion['__kind']
+>(
+  kind: K,
+  value: ConcurrentMerkleTreeAssertion
+): value is ConcurrentMerkleTreeAssertion & { __kind: K } {
+  return value.__kind === kind;
+}
@@ -0,0 +1,35 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  combineCodec,
+  getScalarEnumDecoder,
+  getScalarEnumEncoder,
+} from '@solana/codecs';
+
+export enum DataHashType {
+  Keccak256,
+  Sha256,
+}
+
+export type DataHashTypeArgs = DataHashType;
+
+export function getDataHashTypeEncoder(): Encoder<DataHashTypeArgs> {
+  return getScalarEnumEncoder(DataHashType);
+}
+
+export function getDataHashTypeDecoder(): Decoder<DataHashType> {
+  return getScalarEnumDecoder(DataHashType);
+}
+
+export function getDataHashTypeCodec(): Codec<DataHashTypeArgs, DataHashType> {
+  return combineCodec(getDataHashTypeEncoder(), getDataHashTypeDecoder());
+}
@@ -0,0 +1,151 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  combineCodec,
+  getArrayDecoder,
+  getArrayEncoder,
+  getDataEnumDecoder,
+  getDataEnumEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getTupleDecoder,
+  getTupleEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
+  getUnitDecoder,
+  getUnitEncoder,
+} from '@solana/codecs';
+import {
+  BorshFieldType,
+  BorshFieldTypeArgs,
+  PointerSize,
+  PointerSizeArgs,
+  getBorshFieldTypeDecoder,
+  getBorshFieldTypeEncoder,
+  getPointerSizeDecoder,
+  getPointerSizeEncoder,
+} from '.';
+
+export type DataPathSegment =
+  | { __kind: 'Skip'; fields: [Array<BorshFieldType>] }
+  | { __kind: 'VecElement'; element: BorshFieldType; index: number }
+  | { __kind: 'OptionSome' }
+  | { __kind: 'Pointer'; at: number; size: PointerSize; stride: number };
+
+export type DataPathSegmentArgs =
+  | { __kind: 'Skip'; fields: [Array<BorshFieldTypeArgs>] }
+  | { __kind: 'VecElement'; element: BorshFieldTypeArgs; index: number }
+  | { __kind: 'OptionSome' }
+  | { __kind: 'Pointer'; at: number; size: PointerSizeArgs; stride: number };
+
+export function getDataPathSegmentEncoder(): Encoder<DataPathSegmentArgs> {
+  return getDataEnumEncoder([
+    [
+      'Skip',
+      getStructEncoder([
+        [
+          'fields',
+          getTupleEncoder([getArrayEncoder(getBorshFieldTypeEncoder())]),
+        ],
+      ]),
+    ],
+    [
+      'VecElement',
+      getStructEncoder([
+        ['element', getBorshFieldTypeEncoder()],
+        ['index', getU32Encoder()],
+      ]),
+    ],
+    ['OptionSome', getUnitEncoder()],
+    [
+      'Pointer',
+      getStructEncoder([
+        ['at', getU16Encoder()],
+        ['size', getPointerSizeEncoder()],
+        ['stride', getU32Encoder()],
+      ]),
+    ],
+  ]);
+}
+
+export function getDataPathSegmentDecoder(): Decoder<DataPathSegment> {
+  return getDataEnumDecoder([
+    [
+      'Skip',
+      getStructDecoder([
+        [
+          'fields',
+          getTupleDecoder([getArrayDecoder(getBorshFieldTypeDecoder())]),
+        ],
+      ]),
+    ],
+    [
+      'VecElement',
+      getStructDecoder([
+        ['element', getBorshFieldTypeDecoder()],
+        ['index', getU32Decoder()],
+      ]),
+    ],
+    ['OptionSome', getUnitDecoder()],
+    [
+      'Pointer',
+      getStructDecoder([
+        ['at', getU16Decoder()],
+        ['size', getPointerSizeDecoder()],
+        ['stride', getU32Decoder()],
+      ]),
+    ],
+  ]);
+}
+
+export function getDataPathSegmentCodec(): Codec<
+  DataPathSegmentArgs,
+  DataPathSegment
+> {
+  return combineCodec(getDataPathSegmentEncoder(), getDataPathSegmentDecoder());
+}
+
+// Data Enum Helpers.
+export function dataPathSegment(
+  kind: 'Skip',
+  data: GetDataEnumKindContent<DataPathSegmentArgs, 'Skip'>['fields']
+): GetDataEnumKind<DataPathSegmentArgs, 'Skip'>;
+export function dataPathSegment(
+  kind: 'VecElement',
+  data: GetDataEnumKindContent<DataPathSegmentArgs, 'VecElement'>
+): GetDataEnumKind<DataPathSegmentArgs, 'VecElement'>;
+export function dataPathSegment(
+  kind: 'OptionSome'
+): GetDataEnumKind<DataPathSegmentArgs, 'OptionSome'>;
+export function dataPathSegment(
+  kind: 'Pointer',
+  data: GetDataEnumKindContent<DataPathSegmentArgs, 'Pointer'>
+): GetDataEnumKind<DataPathSegmentArgs, 'Pointer'>;
+export function dataPathSegment<K extends DataPathSegmentArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<DataPathSegmentArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+
+export function isDataPathSegment<K extends DataPathSegment['__kind']>(
+  kind: K,
+  value: DataPathSegment
+): value is DataPathSegment & { __kind: K } {
+  return value.__kind === kind;
+}
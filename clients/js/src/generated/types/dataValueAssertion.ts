@@ -24,6 +24,10 @@ import {
   getBytesEncoder,
   getDataEnumDecoder,
   getDataEnumEncoder,
+  getF32Decoder,
+  getF32Encoder,
+  getF64Decoder,
+  getF64Encoder,
   getI128Decoder,
   getI128Encoder,
   getI16Decoder,
@@ -48,14 +52,34 @@ import {
   getU8Encoder,
 } from '@solana/codecs';
 import {
+  BytesOperator,
+  BytesOperatorArgs,
+  Endianness,
+  EndiannessArgs,
   EquatableOperator,
   EquatableOperatorArgs,
+  FixedPointScale,
+  FixedPointScaleArgs,
+  FloatOperator,
+  FloatOperatorArgs,
   IntegerOperator,
   IntegerOperatorArgs,
+  IntegerSize,
+  IntegerSizeArgs,
+  getBytesOperatorDecoder,
+  getBytesOperatorEncoder,
+  getEndiannessDecoder,
+  getEndiannessEncoder,
   getEquatableOperatorDecoder,
   getEquatableOperatorEncoder,
+  getFixedPointScaleDecoder,
+  getFixedPointScaleEncoder,
+  getFloatOperatorDecoder,
+  getFloatOperatorEncoder,
   getIntegerOperatorDecoder,
   getIntegerOperatorEncoder,
+  getIntegerSizeDecoder,
+  getIntegerSizeEncoder,
 } from '.';
 
 export type DataValueAssertion =
@@ -70,8 +94,33 @@ export type DataValueAssertion =
   | { __kind: 'I64'; value: bigint; operator: IntegerOperator }
   | { __kind: 'U128'; value: bigint; operator: IntegerOperator }
   | { __kind: 'I128'; value: bigint; operator: IntegerOperator }
-  | { __kind: 'Bytes'; value: Uint8Array; operator: EquatableOperator }
-  | { __kind: 'Pubkey'; value: Address; operator: EquatableOperator };
+  | { __kind: 'Bytes'; value: Uint8Array; operator: BytesOperator }
+  | { __kind: 'Pubkey'; value: Address; operator: EquatableOperator }
+  | {
+      __kind: 'UnsignedInteger';
+      value: bigint;
+      size: IntegerSize;
+      endianness: Endianness;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'SignedInteger';
+      value: bigint;
+      size: IntegerSize;
+      endianness: Endianness;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'FixedPoint';
+      value: bigint;
+      decimals: number;
+      size: IntegerSize;
+      endianness: Endianness;
+      scale: FixedPointScale;
+      operator: IntegerOperator;
+    }
+  | { __kind: 'F32'; value: number; operator: FloatOperator }
+  | { __kind: 'F64'; value: number; operator: FloatOperator };
 
 export type DataValueAssertionArgs =
   | { __kind: 'Bool'; value: boolean; operator: EquatableOperatorArgs }
@@ -85,8 +134,33 @@ export type DataValueAssertionArgs =
   | { __kind: 'I64'; value: number | bigint; operator: IntegerOperatorArgs }
   | { __kind: 'U128'; value: number | bigint; operator: IntegerOperatorArgs }
   | { __kind: 'I128'; value: number | bigint; operator: IntegerOperatorArgs }
-  | { __kind: 'Bytes'; value: Uint8Array; operator: EquatableOperatorArgs }
-  | { __kind: 'Pubkey'; value: Address; operator: EquatableOperatorArgs };
+  | { __kind: 'Bytes'; value: Uint8Array; operator: BytesOperatorArgs }
+  | { __kind: 'Pubkey'; value: Address; operator: EquatableOperatorArgs }
+  | {
+      __kind: 'UnsignedInteger';
+      value: number | bigint;
+      size: IntegerSizeArgs;
+      endianness: EndiannessArgs;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'SignedInteger';
+      value: number | bigint;
+      size: IntegerSizeArgs;
+      endianness: EndiannessArgs;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'FixedPoint';
+      value: number | bigint;
+      decimals: number;
+      size: IntegerSizeArgs;
+      endianness: EndiannessArgs;
+      scale: FixedPointScaleArgs;
+      operator: IntegerOperatorArgs;
+    }
+  | { __kind: 'F32'; value: number; operator: FloatOperatorArgs }
+  | { __kind: 'F64'; value: number; operator: FloatOperatorArgs };
 
 export function getDataValueAssertionEncoder(): Encoder<DataValueAssertionArgs> {
   return getDataEnumEncoder([
@@ -171,7 +245,7 @@ export function getDataValueAssertionEncoder(): Encoder<DataValueAssertionArgs>
       'Bytes',
       getStructEncoder([
         ['value', getBytesEncoder({ size: getU32Encoder() })],
-        ['operator', getEquatableOperatorEncoder()],
+        ['operator', getBytesOperatorEncoder()],
       ]),
     ],
     [
@@ -181,6 +255,49 @@ export function getDataValueAssertionEncoder(): Encoder<DataValueAssertionArgs>
         ['operator', getEquatableOperatorEncoder()],
       ]),
     ],
+    [
+      'UnsignedInteger',
+      getStructEncoder([
+        ['value', getU128Encoder()],
+        ['size', getIntegerSizeEncoder()],
+        ['endianness', getEndiannessEncoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'SignedInteger',
+      getStructEncoder([
+        ['value', getI128Encoder()],
+        ['size', getIntegerSizeEncoder()],
+        ['endianness', getEndiannessEncoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'FixedPoint',
+      getStructEncoder([
+        ['value', getU128Encoder()],
+        ['decimals', getU8Encoder()],
+        ['size', getIntegerSizeEncoder()],
+        ['endianness', getEndiannessEncoder()],
+        ['scale', getFixedPointScaleEncoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'F32',
+      getStructEncoder([
+        ['value', getF32Encoder()],
+        ['operator', getFloatOperatorEncoder()],
+      ]),
+    ],
+    [
+      'F64',
+      getStructEncoder([
+        ['value', getF64Encoder()],
+        ['operator', getFloatOperatorEncoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -267,7 +384,7 @@ export function getDataValueAssertionDecoder(): Decoder<DataValueAssertion> {
       'Bytes',
       getStructDecoder([
         ['value', getBytesDecoder({ size: getU32Decoder() })],
-        ['operator', getEquatableOperatorDecoder()],
+        ['operator', getBytesOperatorDecoder()],
       ]),
     ],
     [
@@ -277,6 +394,49 @@ export function getDataValueAssertionDecoder(): Decoder<DataValueAssertion> {
         ['operator', getEquatableOperatorDecoder()],
       ]),
     ],
+    [
+      'UnsignedInteger',
+      getStructDecoder([
+        ['value', getU128Decoder()],
+        ['size', getIntegerSizeDecoder()],
+        ['endianness', getEndiannessDecoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'SignedInteger',
+      getStructDecoder([
+        ['value', getI128Decoder()],
+        ['size', getIntegerSizeDecoder()],
+        ['endianness', getEndiannessDecoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'FixedPoint',
+      getStructDecoder([
+        ['value', getU128Decoder()],
+        ['decimals', getU8Decoder()],
+        ['size', getIntegerSizeDecoder()],
+        ['endianness', getEndiannessDecoder()],
+        ['scale', getFixedPointScaleDecoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'F32',
+      getStructDecoder([
+        ['value', getF32Decoder()],
+        ['operator', getFloatOperatorDecoder()],
+      ]),
+    ],
+    [
+      'F64',
+      getStructDecoder([
+        ['value', getF64Decoder()],
+        ['operator', getFloatOperatorDecoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -343,6 +503,26 @@ export function dataValueAssertion(
   kind: 'Pubkey',
   data: GetDataEnumKindContent<DataValueAssertionArgs, 'Pubkey'>
 ): GetDataEnumKind<DataValueAssertionArgs, 'Pubkey'>;
+export function dataValueAssertion(
+  kind: 'UnsignedInteger',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'UnsignedInteger'>
+): GetDataEnumKind<DataValueAssertionArgs, 'UnsignedInteger'>;
+export function dataValueAssertion(
+  kind: 'SignedInteger',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'SignedInteger'>
+): GetDataEnumKind<DataValueAssertionArgs, 'SignedInteger'>;
+export function dataValueAssertion(
+  kind: 'FixedPoint',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'FixedPoint'>
+): GetDataEnumKind<DataValueAssertionArgs, 'FixedPoint'>;
+export function dataValueAssertion(
+  kind: 'F32',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'F32'>
+): GetDataEnumKind<DataValueAssertionArgs, 'F32'>;
+export function dataValueAssertion(
+  kind: 'F64',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'F64'>
+): GetDataEnumKind<DataValueAssertionArgs, 'F64'>;
 export function dataValueAssertion<K extends DataValueAssertionArgs['__kind']>(
   kind: K,
   data?: any
@@ -0,0 +1,35 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  combineCodec,
+  getScalarEnumDecoder,
+  getScalarEnumEncoder,
+} from '@solana/codecs';
+
+export enum Endianness {
+  Little,
+  Big,
+}
+
+export type EndiannessArgs = Endianness;
+
+export function getEndiannessEncoder(): Encoder<EndiannessArgs> {
+  return getScalarEnumEncoder(Endianness);
+}
+
+export function getEndiannessDecoder(): Decoder<Endianness> {
+  return getScalarEnumDecoder(Endianness);
+}
+
+export function getEndiannessCodec(): Codec<EndiannessArgs, Endianness> {
+  return combineCodec(getEndiannessEncoder(), getEndiannessDecoder());
+}
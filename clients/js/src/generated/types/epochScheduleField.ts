@@ -0,0 +1,44 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  combineCodec,
+  getScalarEnumDecoder,
+  getScalarEnumEncoder,
+} from '@solana/codecs';
+
+export enum EpochScheduleField {
+  SlotsPerEpoch,
+  LeaderScheduleSlotOffset,
+  Warmup,
+  FirstNormalEpoch,
+  FirstNormalSlot,
+}
+
+export type EpochScheduleFieldArgs = EpochScheduleField;
+
+export function getEpochScheduleFieldEncoder(): Encoder<EpochScheduleFieldArgs> {
+  return getScalarEnumEncoder(EpochScheduleField);
+}
+
+export function getEpochScheduleFieldDecoder(): Decoder<EpochScheduleField> {
+  return getScalarEnumDecoder(EpochScheduleField);
+}
+
+export function getEpochScheduleFieldCodec(): Codec<
+  EpochScheduleFieldArgs,
+  EpochScheduleField
+> {
+  return combineCodec(
+    getEpochScheduleFieldEncoder(),
+    getEpochScheduleFieldDecoder()
+  );
+}
@@ -0,0 +1,87 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Option,
+  OptionOrNullable,
+  combineCodec,
+  getDataEnumDecoder,
+  getDataEnumEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getUnitDecoder,
+  getUnitEncoder,
+} from '@solana/codecs';
+
+export type EvaluationMode =
+  | { __kind: 'Strict' }
+  | { __kind: 'Soft'; maxFailures: Option<number> };
+
+export type EvaluationModeArgs =
+  | { __kind: 'Strict' }
+  | { __kind: 'Soft'; maxFailures: OptionOrNullable<number> };
+
+export function getEvaluationModeEncoder(): Encoder<EvaluationModeArgs> {
+  return getDataEnumEncoder([
+    ['Strict', getUnitEncoder()],
+    [
+      'Soft',
+      getStructEncoder([['maxFailures', getOptionEncoder(getU16Encoder())]]),
+    ],
+  ]);
+}
+
+export function getEvaluationModeDecoder(): Decoder<EvaluationMode> {
+  return getDataEnumDecoder([
+    ['Strict', getUnitDecoder()],
+    [
+      'Soft',
+      getStructDecoder([['maxFailures', getOptionDecoder(getU16Decoder())]]),
+    ],
+  ]);
+}
+
+export function getEvaluationModeCodec(): Codec<
+  EvaluationModeArgs,
+  EvaluationMode
+> {
+  return combineCodec(getEvaluationModeEncoder(), getEvaluationModeDecoder());
+}
+
+// Data Enum Helpers.
+export function evaluationMode(
+  kind: 'Strict'
+): GetDataEnumKind<EvaluationModeArgs, 'Strict'>;
+export function evaluationMode(
+  kind: 'Soft',
+  data: GetDataEnumKindContent<EvaluationModeArgs, 'Soft'>
+): GetDataEnumKind<EvaluationModeArgs, 'Soft'>;
+export function evaluationMode<K extends EvaluationModeArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<EvaluationModeArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+
+export function isEvaluationMode<K extends EvaluationMode['__kind']>(
+  kind: K,
+  value: EvaluationMode
+): value is EvaluationMode & { __kind: K } {
+  return value.__kind === kind;
+}
@@ -0,0 +1,88 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  combineCodec,
+  getDataEnumDecoder,
+  getDataEnumEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getTupleDecoder,
+  getTupleEncoder,
+  getU8Decoder,
+  getU8Encoder,
+} from '@solana/codecs';
+
+export type FixedPointScale =
+  | { __kind: 'Decimal'; fields: [number] }
+  | { __kind: 'Binary'; fields: [number] };
+
+export type FixedPointScaleArgs = FixedPointScale;
+
+export function getFixedPointScaleEncoder(): Encoder<FixedPointScaleArgs> {
+  return getDataEnumEncoder([
+    [
+      'Decimal',
+      getStructEncoder([['fields', getTupleEncoder([getU8Encoder()])]]),
+    ],
+    [
+      'Binary',
+      getStructEncoder([['fields', getTupleEncoder([getU8Encoder()])]]),
+    ],
+  ]);
+}
+
+export function getFixedPointScaleDecoder(): Decoder<FixedPointScale> {
+  return getDataEnumDecoder([
+    [
+      'Decimal',
+      getStructDecoder([['fields', getTupleDecoder([getU8Decoder()])]]),
+    ],
+    [
+      'Binary',
+      getStructDecoder([['fields', getTupleDecoder([getU8Decoder()])]]),
+    ],
+  ]);
+}
+
+export function getFixedPointScaleCodec(): Codec<
+  FixedPointScaleArgs,
+  FixedPointScale
+> {
+  return combineCodec(getFixedPointScaleEncoder(), getFixedPointScaleDecoder());
+}
+
+// Data Enum Helpers.
+export function fixedPointScale(
+  kind: 'Decimal',
+  data: GetDataEnumKindContent<FixedPointScaleArgs, 'Decimal'>['fields']
+): GetDataEnumKind<FixedPointScaleArgs, 'Decimal'>;
+export function fixedPointScale(
+  kind: 'Binary',
+  data: GetDataEnumKindContent<FixedPointScaleArgs, 'Binary'>['fields']
+): GetDataEnumKind<FixedPointScaleArgs, 'Binary'>;
+export function fixedPointScale<K extends FixedPointScaleArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<FixedPointScaleArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+
+export function isFixedPointScale<K extends FixedPointScale['__kind']>(
+  kind: K,
+  value: FixedPointScale
+): value is FixedPointScale & { __kind: K } {
+  return value.__kind === kind;
+}
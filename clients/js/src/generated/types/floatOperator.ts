@@ -0,0 +1,148 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Option,
+  OptionOrNullable,
+  combineCodec,
+  getDataEnumDecoder,
+  getDataEnumEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getUnitDecoder,
+  getUnitEncoder,
+} from '@solana/codecs';
+import {
+  FloatTolerance,
+  FloatToleranceArgs,
+  getFloatToleranceDecoder,
+  getFloatToleranceEncoder,
+} from '.';
+
+export type FloatOperator =
+  | { __kind: 'Equal'; tolerance: Option<FloatTolerance> }
+  | { __kind: 'NotEqual'; tolerance: Option<FloatTolerance> }
+  | { __kind: 'GreaterThan' }
+  | { __kind: 'LessThan' }
+  | { __kind: 'GreaterThanOrEqual' }
+  | { __kind: 'LessThanOrEqual' }
+  | { __kind: 'IsNaN' }
+  | { __kind: 'IsNotNaN' };
+
+export type FloatOperatorArgs =
+  | { __kind: 'Equal'; tolerance: OptionOrNullable<FloatToleranceArgs> }
+  | { __kind: 'NotEqual'; tolerance: OptionOrNullable<FloatToleranceArgs> }
+  | { __kind: 'GreaterThan' }
+  | { __kind: 'LessThan' }
+  | { __kind: 'GreaterThanOrEqual' }
+  | { __kind: 'LessThanOrEqual' }
+  | { __kind: 'IsNaN' }
+  | { __kind: 'IsNotNaN' };
+
+export function getFloatOperatorEncoder(): Encoder<FloatOperatorArgs> {
+  return getDataEnumEncoder([
+    [
+      'Equal',
+      getStructEncoder([
+        ['tolerance', getOptionEncoder(getFloatToleranceEncoder())],
+      ]),
+    ],
+    [
+      'NotEqual',
+      getStructEncoder([
+        ['tolerance', getOptionEncoder(getFloatToleranceEncoder())],
+      ]),
+    ],
+    ['GreaterThan', getUnitEncoder()],
+    ['LessThan', getUnitEncoder()],
+    ['GreaterThanOrEqual', getUnitEncoder()],
+    ['LessThanOrEqual', getUnitEncoder()],
+    ['IsNaN', getUnitEncoder()],
+    ['IsNotNaN', getUnitEncoder()],
+  ]);
+}
+
+export function getFloatOperatorDecoder(): Decoder<FloatOperator> {
+  return getDataEnumDecoder([
+    [
+      'Equal',
+      getStructDecoder([
+        ['tolerance', getOptionDecoder(getFloatToleranceDecoder())],
+      ]),
+    ],
+    [
+      'NotEqual',
+      getStructDecoder([
+        ['tolerance', getOptionDecoder(getFloatToleranceDecoder())],
+      ]),
+    ],
+    ['GreaterThan', getUnitDecoder()],
+    ['LessThan', getUnitDecoder()],
+    ['GreaterThanOrEqual', getUnitDecoder()],
+    ['LessThanOrEqual', getUnitDecoder()],
+    ['IsNaN', getUnitDecoder()],
+    ['IsNotNaN', getUnitDecoder()],
+  ]);
+}
+
+export function getFloatOperatorCodec(): Codec<
+  FloatOperatorArgs,
+  FloatOperator
+> {
+  return combineCodec(getFloatOperatorEncoder(), getFloatOperatorDecoder());
+}
+
+// Data Enum Helpers.
+export function floatOperator(
+  kind: 'Equal',
+  data: GetDataEnumKindContent<FloatOperatorArgs, 'Equal'>
+): GetDataEnumKind<FloatOperatorArgs, 'Equal'>;
+export function floatOperator(
+  kind: 'NotEqual',
+  data: GetDataEnumKindContent<FloatOperatorArgs, 'NotEqual'>
+): GetDataEnumKind<FloatOperatorArgs, 'NotEqual'>;
+export function floatOperator(
+  kind: 'GreaterThan'
+): GetDataEnumKind<FloatOperatorArgs, 'GreaterThan'>;
+export function floatOperator(
+  kind: 'LessThan'
+): GetDataEnumKind<FloatOperatorArgs, 'LessThan'>;
+export function floatOperator(
+  kind: 'GreaterThanOrEqual'
+): GetDataEnumKind<FloatOperatorArgs, 'GreaterThanOrEqual'>;
+export function floatOperator(
+  kind: 'LessThanOrEqual'
+): GetDataEnumKind<FloatOperatorArgs, 'LessThanOrEqual'>;
+export function floatOperator(
+  kind: 'IsNaN'
+): GetDataEnumKind<FloatOperatorArgs, 'IsNaN'>;
+export function floatOperator(
+  kind: 'IsNotNaN'
+): GetDataEnumKind<FloatOperatorArgs, 'IsNotNaN'>;
+export function floatOperator<K extends FloatOperatorArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<FloatOperatorArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+
+export function isFloatOperator<K extends FloatOperator['__kind']>(
+  kind: K,
+  value: FloatOperator
+): value is FloatOperator & { __kind: K } {
+  return value.__kind === kind;
+}
@@ -0,0 +1,88 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  combineCodec,
+  getDataEnumDecoder,
+  getDataEnumEncoder,
+  getF64Decoder,
+  getF64Encoder,
+  getStructDecoder,
+  getStructEncoder,
+  getTupleDecoder,
+  getTupleEncoder,
+} from '@solana/codecs';
+
+export type FloatTolerance =
+  | { __kind: 'Absolute'; fields: [number] }
+  | { __kind: 'Relative'; fields: [number] };
+
+export type FloatToleranceArgs = FloatTolerance;
+
+export function getFloatToleranceEncoder(): Encoder<FloatToleranceArgs> {
+  return getDataEnumEncoder([
+    [
+      'Absolute',
+      getStructEncoder([['fields', getTupleEncoder([getF64Encoder()])]]),
+    ],
+    [
+      'Relative',
+      getStructEncoder([['fields', getTupleEncoder([getF64Encoder()])]]),
+    ],
+  ]);
+}
+
+export function getFloatToleranceDecoder(): Decoder<FloatTolerance> {
+  return getDataEnumDecoder([
+    [
+      'Absolute',
+      getStructDecoder([['fields', getTupleDecoder([getF64Decoder()])]]),
+    ],
+    [
+      'Relative',
+      getStructDecoder([['fields', getTupleDecoder([getF64Decoder()])]]),
+    ],
+  ]);
+}
+
+export function getFloatToleranceCodec(): Codec<
+  FloatToleranceArgs,
+  FloatTolerance
+> {
+  return combineCodec(getFloatToleranceEncoder(), getFloatToleranceDecoder());
+}
+
+// Data Enum Helpers.
+export function floatTolerance(
+  kind: 'Absolute',
+  data: GetDataEnumKindContent<FloatToleranceArgs, 'Absolute'>['fields']
+): GetDataEnumKind<FloatToleranceArgs, 'Absolute'>;
+export function floatTolerance(
+  kind: 'Relative',
+  data: GetDataEnumKindContent<FloatToleranceArgs, 'Relative'>['fields']
+): GetDataEnumKind<FloatToleranceArgs, 'Relative'>;
+export function floatTolerance<K extends FloatToleranceArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<FloatToleranceArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+
+export function isFloatTolerance<K extends FloatTolerance['__kind']>(
+  kind: K,
+  value: FloatTolerance
+): value is FloatTolerance & { __kind: K } {
+  return value.__kind === kind;
+}
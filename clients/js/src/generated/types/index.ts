@@ -10,24 +10,56 @@ export * from './accountDeltaAssertion';
 export * from './accountInfoAssertion';
 export * from './accountInfoDeltaAssertion';
 export * from './accountInfoField';
+export * from './accountSnapshotAssertion';
+export * from './accumulateOperation';
+export * from './accumulateSource';
+export * from './accumulatorType';
+export * from './anchorDiscriminatorValue';
 export * from './assertionResult';
+export * from './borshFieldType';
 export * from './bubblegumTreeConfigAssertion';
+export * from './bytesOperator';
 export * from './clockField';
+export * from './concurrentMerkleTreeAssertion';
+export * from './dataHashType';
+export * from './dataPathSegment';
 export * from './dataValue';
 export * from './dataValueAssertion';
 export * from './dataValueDeltaAssertion';
+export * from './endianness';
+export * from './epochScheduleField';
 export * from './equatableOperator';
+export * from './evaluationMode';
+export * from './fixedPointScale';
+export * from './floatOperator';
+export * from './floatTolerance';
+export * from './integerDataType';
 export * from './integerOperator';
+export * from './integerSize';
 export * from './knownProgram';
+export * from './lockedRange';
 export * from './logLevel';
+export * from './memoryHeader';
+export * from './memoryHeaderAssertion';
+export * from './memoryWriteEntry';
 export * from './merkleTreeAssertion';
 export * from './metaAssertion';
 export * from './mintAccountAssertion';
+export * from './mintAccountField';
+export * from './mintAccountSnapshotAssertion';
+export * from './pointerSize';
+export * from './rentField';
+export * from './setOperator';
 export * from './stakeAccountAssertion';
+export * from './stakeAccountSnapshotAssertion';
 export * from './stakeAssertion';
+export * from './stakePoolAccountAssertion';
+export * from './stakePoolFeeAssertion';
 export * from './stakeStateType';
 export * from './sysvarClockAssertion';
 export * from './tokenAccountAssertion';
+export * from './tokenAccountField';
+export * from './tokenAccountSnapshotAssertion';
 export * from './upgradableBufferAssertion';
 export * from './upgradeableLoaderStateAssertion';
 export * from './upgradeableLoaderStateType';
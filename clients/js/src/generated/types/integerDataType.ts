@@ -0,0 +1,41 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  combineCodec,
+  getScalarEnumDecoder,
+  getScalarEnumEncoder,
+} from '@solana/codecs';
+
+export enum IntegerDataType {
+  U8,
+  U16,
+  U32,
+  U64,
+  U128,
+}
+
+export type IntegerDataTypeArgs = IntegerDataType;
+
+export function getIntegerDataTypeEncoder(): Encoder<IntegerDataTypeArgs> {
+  return getScalarEnumEncoder(IntegerDataType);
+}
+
+export function getIntegerDataTypeDecoder(): Decoder<IntegerDataType> {
+  return getScalarEnumDecoder(IntegerDataType);
+}
+
+export function getIntegerDataTypeCodec(): Codec<
+  IntegerDataTypeArgs,
+  IntegerDataType
+> {
+  return combineCodec(getIntegerDataTypeEncoder(), getIntegerDataTypeDecoder());
+}
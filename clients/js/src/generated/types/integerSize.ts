@@ -0,0 +1,38 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  combineCodec,
+  getScalarEnumDecoder,
+  getScalarEnumEncoder,
+} from '@solana/codecs';
+
+export enum IntegerSize {
+  Bits8,
+  Bits16,
+  Bits32,
+  Bits64,
+  Bits128,
+}
+
+export type IntegerSizeArgs = IntegerSize;
+
+export function getIntegerSizeEncoder(): Encoder<IntegerSizeArgs> {
+  return getScalarEnumEncoder(IntegerSize);
+}
+
+export function getIntegerSizeDecoder(): Decoder<IntegerSize> {
+  return getScalarEnumDecoder(IntegerSize);
+}
+
+export function getIntegerSizeCodec(): Codec<IntegerSizeArgs, IntegerSize> {
+  return combineCodec(getIntegerSizeEncoder(), getIntegerSizeDecoder());
+}
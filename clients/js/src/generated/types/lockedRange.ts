@@ -0,0 +1,40 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+} from '@solana/codecs';
+
+export type LockedRange = { start: number; end: number };
+
+export type LockedRangeArgs = LockedRange;
+
+export function getLockedRangeEncoder(): Encoder<LockedRangeArgs> {
+  return getStructEncoder([
+    ['start', getU16Encoder()],
+    ['end', getU16Encoder()],
+  ]);
+}
+
+export function getLockedRangeDecoder(): Decoder<LockedRange> {
+  return getStructDecoder([
+    ['start', getU16Decoder()],
+    ['end', getU16Decoder()],
+  ]);
+}
+
+export function getLockedRangeCodec(): Codec<LockedRangeArgs, LockedRange> {
+  return combineCodec(getLockedRangeEncoder(), getLockedRangeDecoder());
+}
@@ -0,0 +1,86 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  combineCodec,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+} from '@solana/codecs';
+import {
+  LockedRange,
+  LockedRangeArgs,
+  getLockedRangeDecoder,
+  getLockedRangeEncoder,
+} from '.';
+
+export type MemoryHeader = {
+  discriminator: Uint8Array;
+  slot: bigint;
+  instructionIndex: number;
+  writeCount: number;
+  flags: number;
+  lockedRangeCount: number;
+  lockedRanges: Array<LockedRange>;
+  lockedSlot: bigint;
+};
+
+export type MemoryHeaderArgs = {
+  discriminator: Uint8Array;
+  slot: number | bigint;
+  instructionIndex: number;
+  writeCount: number;
+  flags: number;
+  lockedRangeCount: number;
+  lockedRanges: Array<LockedRangeArgs>;
+  lockedSlot: number | bigint;
+};
+
+export function getMemoryHeaderEncoder(): Encoder<MemoryHeaderArgs> {
+  return getStructEncoder([
+    ['discriminator', getBytesEncoder({ size: 8 })],
+    ['slot', getU64Encoder()],
+    ['instructionIndex', getU16Encoder()],
+    ['writeCount', getU32Encoder()],
+    ['flags', getU8Encoder()],
+    ['lockedRangeCount', getU8Encoder()],
+    ['lockedRanges', getArrayEncoder(getLockedRangeEncoder(), { size: 8 })],
+    ['lockedSlot', getU64Encoder()],
+  ]);
+}
+
+export function getMemoryHeaderDecoder(): Decoder<MemoryHeader> {
+  return getStructDecoder([
+    ['discriminator', getBytesDecoder({ size: 8 })],
+    ['slot', getU64Decoder()],
+    ['instructionIndex', getU16Decoder()],
+    ['writeCount', getU32Decoder()],
+    ['flags', getU8Decoder()],
+    ['lockedRangeCount', getU8Decoder()],
+    ['lockedRanges', getArrayDecoder(getLockedRangeDecoder(), { size: 8 })],
+    ['lockedSlot', getU64Decoder()],
+  ]);
+}
+
+export function getMemoryHeaderCodec(): Codec<MemoryHeaderArgs, MemoryHeader> {
+  return combineCodec(getMemoryHeaderEncoder(), getMemoryHeaderDecoder());
+}
@@ -0,0 +1,191 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  combineCodec,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getDataEnumDecoder,
+  getDataEnumEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
+} from '@solana/codecs';
+import {
+  EquatableOperator,
+  EquatableOperatorArgs,
+  IntegerOperator,
+  IntegerOperatorArgs,
+  getEquatableOperatorDecoder,
+  getEquatableOperatorEncoder,
+  getIntegerOperatorDecoder,
+  getIntegerOperatorEncoder,
+} from '.';
+
+export type MemoryHeaderAssertion =
+  | { __kind: 'Slot'; value: bigint; operator: IntegerOperator }
+  | {
+      __kind: 'WrittenInCurrentSlot';
+      value: boolean;
+      operator: EquatableOperator;
+    }
+  | { __kind: 'InstructionIndex'; value: number; operator: IntegerOperator }
+  | { __kind: 'WriteCount'; value: number; operator: IntegerOperator }
+  | { __kind: 'IsLocked'; value: boolean; operator: EquatableOperator };
+
+export type MemoryHeaderAssertionArgs =
+  | { __kind: 'Slot'; value: number | bigint; operator: IntegerOperatorArgs }
+  | {
+      __kind: 'WrittenInCurrentSlot';
+      value: boolean;
+      operator: EquatableOperatorArgs;
+    }
+  | { __kind: 'InstructionIndex'; value: number; operator: IntegerOperatorArgs }
+  | { __kind: 'WriteCount'; value: number; operator: IntegerOperatorArgs }
+  | { __kind: 'IsLocked'; value: boolean; operator: EquatableOperatorArgs };
+
+export function getMemoryHeaderAssertionEncoder(): Encoder<MemoryHeaderAssertionArgs> {
+  return getDataEnumEncoder([
+    [
+      'Slot',
+      getStructEncoder([
+        ['value', getU64Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'WrittenInCurrentSlot',
+      getStructEncoder([
+        ['value', getBooleanEncoder()],
+        ['operator', getEquatableOperatorEncoder()],
+      ]),
+    ],
+    [
+      'InstructionIndex',
+      getStructEncoder([
+        ['value', getU16Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'WriteCount',
+      getStructEncoder([
+        ['value', getU32Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'IsLocked',
+      getStructEncoder([
+        ['value', getBooleanEncoder()],
+        ['operator', getEquatableOperatorEncoder()],
+      ]),
+    ],
+  ]);
+}
+
+export function getMemoryHeaderAssertionDecoder(): Decoder<MemoryHeaderAssertion> {
+  return getDataEnumDecoder([
+    [
+      'Slot',
+      getStructDecoder([
+        ['value', getU64Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'WrittenInCurrentSlot',
+      getStructDecoder([
+        ['value', getBooleanDecoder()],
+        ['operator', getEquatableOperatorDecoder()],
+      ]),
+    ],
+    [
+      'InstructionIndex',
+      getStructDecoder([
+        ['value', getU16Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'WriteCount',
+      getStructDecoder([
+        ['value', getU32Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'IsLocked',
+      getStructDecoder([
+        ['value', getBooleanDecoder()],
+        ['operator', getEquatableOperatorDecoder()],
+      ]),
+    ],
+  ]);
+}
+
+export function getMemoryHeaderAssertionCodec(): Codec<
+  MemoryHeaderAssertionArgs,
+  MemoryHeaderAssertion
+> {
+  return combineCodec(
+    getMemoryHeaderAssertionEncoder(),
+    getMemoryHeaderAssertionDecoder()
+  );
+}
+
+// Data Enum Helpers.
+export function memoryHeaderAssertion(
+  kind: 'Slot',
+  data: GetDataEnumKindContent<MemoryHeaderAssertionArgs, 'Slot'>
+): GetDataEnumKind<MemoryHeaderAssertionArgs, 'Slot'>;
+export function memoryHeaderAssertion(
+  kind: 'WrittenInCurrentSlot',
+  data: GetDataEnumKindContent<
+    MemoryHeaderAssertionArgs,
+    'WrittenInCurrentSlot'
+  >
+): GetDataEnumKind<MemoryHeaderAssertionArgs, 'WrittenInCurrentSlot'>;
+export function memoryHeaderAssertion(
+  kind: 'InstructionIndex',
+  data: GetDataEnumKindContent<MemoryHeaderAssertionArgs, 'InstructionIndex'>
+): GetDataEnumKind<MemoryHeaderAssertionArgs, 'InstructionIndex'>;
+export function memoryHeaderAssertion(
+  kind: 'WriteCount',
+  data: GetDataEnumKindContent<MemoryHeaderAssertionArgs, 'WriteCount'>
+): GetDataEnumKind<MemoryHeaderAssertionArgs, 'WriteCount'>;
+export function memoryHeaderAssertion(
+  kind: 'IsLocked',
+  data: GetDataEnumKindContent<MemoryHeaderAssertionArgs, 'IsLocked'>
+): GetDataEnumKind<MemoryHeaderAssertionArgs, 'IsLocked'>;
+export function memoryHeaderAssertion<
+  K extends MemoryHeaderAssertionArgs['__kind']
+>(kind: K, data?: any): Extract<MemoryHeaderAssertionArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+
+export function isMemoryHeaderAssertion<
+  K extends MemoryHeaderAssertion['__kind']
+>(
+  kind: K,
+  value: MemoryHeaderAssertion
+): value is MemoryHeaderAssertion & { __kind: K } {
+  return value.__kind === kind;
+}
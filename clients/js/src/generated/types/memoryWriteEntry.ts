@@ -0,0 +1,64 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU8Decoder,
+  getU8Encoder,
+} from '@solana/codecs';
+import {
+  WriteType,
+  WriteTypeArgs,
+  getWriteTypeDecoder,
+  getWriteTypeEncoder,
+} from '.';
+
+export type MemoryWriteEntry = {
+  writeOffset: number;
+  sourceAccountIndex: number;
+  writeType: WriteType;
+};
+
+export type MemoryWriteEntryArgs = {
+  writeOffset: number;
+  sourceAccountIndex: number;
+  writeType: WriteTypeArgs;
+};
+
+export function getMemoryWriteEntryEncoder(): Encoder<MemoryWriteEntryArgs> {
+  return getStructEncoder([
+    ['writeOffset', getU16Encoder()],
+    ['sourceAccountIndex', getU8Encoder()],
+    ['writeType', getWriteTypeEncoder()],
+  ]);
+}
+
+export function getMemoryWriteEntryDecoder(): Decoder<MemoryWriteEntry> {
+  return getStructDecoder([
+    ['writeOffset', getU16Decoder()],
+    ['sourceAccountIndex', getU8Decoder()],
+    ['writeType', getWriteTypeDecoder()],
+  ]);
+}
+
+export function getMemoryWriteEntryCodec(): Codec<
+  MemoryWriteEntryArgs,
+  MemoryWriteEntry
+> {
+  return combineCodec(
+    getMemoryWriteEntryEncoder(),
+    getMemoryWriteEntryDecoder()
+  );
+}
@@ -6,6 +6,11 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+import {
+  Address,
+  getAddressDecoder,
+  getAddressEncoder,
+} from '@solana/addresses';
 import {
   Codec,
   Decoder,
@@ -21,15 +26,31 @@ import {
   getStructEncoder,
   getU32Decoder,
   getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
 } from '@solana/codecs';
 
-export type MerkleTreeAssertion = {
-  __kind: 'VerifyLeaf';
-  leafIndex: number;
-  leafHash: Uint8Array;
-};
+export type MerkleTreeAssertion =
+  | { __kind: 'VerifyLeaf'; leafIndex: number; leafHash: Uint8Array }
+  | {
+      __kind: 'VerifyBubblegumLeaf';
+      owner: Address;
+      delegate: Address;
+      nonce: bigint;
+      dataHash: Uint8Array;
+      creatorHash: Uint8Array;
+    };
 
-export type MerkleTreeAssertionArgs = MerkleTreeAssertion;
+export type MerkleTreeAssertionArgs =
+  | { __kind: 'VerifyLeaf'; leafIndex: number; leafHash: Uint8Array }
+  | {
+      __kind: 'VerifyBubblegumLeaf';
+      owner: Address;
+      delegate: Address;
+      nonce: number | bigint;
+      dataHash: Uint8Array;
+      creatorHash: Uint8Array;
+    };
 
 export function getMerkleTreeAssertionEncoder(): Encoder<MerkleTreeAssertionArgs> {
   return getDataEnumEncoder([
@@ -40,6 +61,16 @@ export function getMerkleTreeAssertionEncoder(): Encoder<MerkleTreeAssertionArgs
         ['leafHash', getBytesEncoder({ size: 32 })],
       ]),
     ],
+    [
+      'VerifyBubblegumLeaf',
+      getStructEncoder([
+        ['owner', getAddressEncoder()],
+        ['delegate', getAddressEncoder()],
+        ['nonce', getU64Encoder()],
+        ['dataHash', getBytesEncoder({ size: 32 })],
+        ['creatorHash', getBytesEncoder({ size: 32 })],
+      ]),
+    ],
   ]);
 }
 
@@ -52,6 +83,16 @@ export function getMerkleTreeAssertionDecoder(): Decoder<MerkleTreeAssertion> {
         ['leafHash', getBytesDecoder({ size: 32 })],
       ]),
     ],
+    [
+      'VerifyBubblegumLeaf',
+      getStructDecoder([
+        ['owner', getAddressDecoder()],
+        ['delegate', getAddressDecoder()],
+        ['nonce', getU64Decoder()],
+        ['dataHash', getBytesDecoder({ size: 32 })],
+        ['creatorHash', getBytesDecoder({ size: 32 })],
+      ]),
+    ],
   ]);
 }
 
@@ -70,6 +111,10 @@ export function merkleTreeAssertion(
   kind: 'VerifyLeaf',
   data: GetDataEnumKindContent<MerkleTreeAssertionArgs, 'VerifyLeaf'>
 ): GetDataEnumKind<MerkleTreeAssertionArgs, 'VerifyLeaf'>;
+export function merkleTreeAssertion(
+  kind: 'VerifyBubblegumLeaf',
+  data: GetDataEnumKindContent<MerkleTreeAssertionArgs, 'VerifyBubblegumLeaf'>
+): GetDataEnumKind<MerkleTreeAssertionArgs, 'VerifyBubblegumLeaf'>;
 export function merkleTreeAssertion<
   K extends MerkleTreeAssertionArgs['__kind']
 >(kind: K, data?: any): Extract<MerkleTreeAssertionArgs, { __kind: K }> {
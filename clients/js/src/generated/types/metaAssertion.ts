@@ -18,6 +18,8 @@ import {
   GetDataEnumKind,
   GetDataEnumKindContent,
   combineCodec,
+  getArrayDecoder,
+  getArrayEncoder,
   getDataEnumDecoder,
   getDataEnumEncoder,
   getI64Decoder,
@@ -32,10 +34,14 @@ import {
   EquatableOperatorArgs,
   IntegerOperator,
   IntegerOperatorArgs,
+  SetOperator,
+  SetOperatorArgs,
   getEquatableOperatorDecoder,
   getEquatableOperatorEncoder,
   getIntegerOperatorDecoder,
   getIntegerOperatorEncoder,
+  getSetOperatorDecoder,
+  getSetOperatorEncoder,
 } from '.';
 
 export type MetaAssertion =
@@ -48,7 +54,28 @@ export type MetaAssertion =
     }
   | { __kind: 'LockupUnixTimestamp'; value: bigint; operator: IntegerOperator }
   | { __kind: 'LockupEpoch'; value: bigint; operator: IntegerOperator }
-  | { __kind: 'LockupCustodian'; value: Address; operator: EquatableOperator };
+  | { __kind: 'LockupCustodian'; value: Address; operator: EquatableOperator }
+  | {
+      __kind: 'AuthorizedStakerIn';
+      values: Array<Address>;
+      operator: SetOperator;
+    }
+  | {
+      __kind: 'AuthorizedWithdrawerIn';
+      values: Array<Address>;
+      operator: SetOperator;
+    }
+  | {
+      __kind: 'RentExemptReserveIn';
+      values: Array<bigint>;
+      operator: SetOperator;
+    }
+  | {
+      __kind: 'LockupUnixTimestampIn';
+      values: Array<bigint>;
+      operator: SetOperator;
+    }
+  | { __kind: 'LockupEpochIn'; values: Array<bigint>; operator: SetOperator };
 
 export type MetaAssertionArgs =
   | {
@@ -80,6 +107,31 @@ export type MetaAssertionArgs =
       __kind: 'LockupCustodian';
       value: Address;
       operator: EquatableOperatorArgs;
+    }
+  | {
+      __kind: 'AuthorizedStakerIn';
+      values: Array<Address>;
+      operator: SetOperatorArgs;
+    }
+  | {
+      __kind: 'AuthorizedWithdrawerIn';
+      values: Array<Address>;
+      operator: SetOperatorArgs;
+    }
+  | {
+      __kind: 'RentExemptReserveIn';
+      values: Array<number | bigint>;
+      operator: SetOperatorArgs;
+    }
+  | {
+      __kind: 'LockupUnixTimestampIn';
+      values: Array<number | bigint>;
+      operator: SetOperatorArgs;
+    }
+  | {
+      __kind: 'LockupEpochIn';
+      values: Array<number | bigint>;
+      operator: SetOperatorArgs;
     };
 
 export function getMetaAssertionEncoder(): Encoder<MetaAssertionArgs> {
@@ -126,6 +178,41 @@ export function getMetaAssertionEncoder(): Encoder<MetaAssertionArgs> {
         ['operator', getEquatableOperatorEncoder()],
       ]),
     ],
+    [
+      'AuthorizedStakerIn',
+      getStructEncoder([
+        ['values', getArrayEncoder(getAddressEncoder())],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
+    [
+      'AuthorizedWithdrawerIn',
+      getStructEncoder([
+        ['values', getArrayEncoder(getAddressEncoder())],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
+    [
+      'RentExemptReserveIn',
+      getStructEncoder([
+        ['values', getArrayEncoder(getU64Encoder())],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
+    [
+      'LockupUnixTimestampIn',
+      getStructEncoder([
+        ['values', getArrayEncoder(getI64Encoder())],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
+    [
+      'LockupEpochIn',
+      getStructEncoder([
+        ['values', getArrayEncoder(getU64Encoder())],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -173,6 +260,41 @@ export function getMetaAssertionDecoder(): Decoder<MetaAssertion> {
         ['operator', getEquatableOperatorDecoder()],
       ]),
     ],
+    [
+      'AuthorizedStakerIn',
+      getStructDecoder([
+        ['values', getArrayDecoder(getAddressDecoder())],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
+    [
+      'AuthorizedWithdrawerIn',
+      getStructDecoder([
+        ['values', getArrayDecoder(getAddressDecoder())],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
+    [
+      'RentExemptReserveIn',
+      getStructDecoder([
+        ['values', getArrayDecoder(getU64Decoder())],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
+    [
+      'LockupUnixTimestampIn',
+      getStructDecoder([
+        ['values', getArrayDecoder(getI64Decoder())],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
+    [
+      'LockupEpochIn',
+      getStructDecoder([
+        ['values', getArrayDecoder(getU64Decoder())],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -208,6 +330,26 @@ export function metaAssertion(
   kind: 'LockupCustodian',
   data: GetDataEnumKindContent<MetaAssertionArgs, 'LockupCustodian'>
 ): GetDataEnumKind<MetaAssertionArgs, 'LockupCustodian'>;
+export function metaAssertion(
+  kind: 'AuthorizedStakerIn',
+  data: GetDataEnumKindContent<MetaAssertionArgs, 'AuthorizedStakerIn'>
+): GetDataEnumKind<MetaAssertionArgs, 'AuthorizedStakerIn'>;
+export function metaAssertion(
+  kind: 'AuthorizedWithdrawerIn',
+  data: GetDataEnumKindContent<MetaAssertionArgs, 'AuthorizedWithdrawerIn'>
+): GetDataEnumKind<MetaAssertionArgs, 'AuthorizedWithdrawerIn'>;
+export function metaAssertion(
+  kind: 'RentExemptReserveIn',
+  data: GetDataEnumKindContent<MetaAssertionArgs, 'RentExemptReserveIn'>
+): GetDataEnumKind<MetaAssertionArgs, 'RentExemptReserveIn'>;
+export function metaAssertion(
+  kind: 'LockupUnixTimestampIn',
+  data: GetDataEnumKindContent<MetaAssertionArgs, 'LockupUnixTimestampIn'>
+): GetDataEnumKind<MetaAssertionArgs, 'LockupUnixTimestampIn'>;
+export function metaAssertion(
+  kind: 'LockupEpochIn',
+  data: GetDataEnumKindContent<MetaAssertionArgs, 'LockupEpochIn'>
+): GetDataEnumKind<MetaAssertionArgs, 'LockupEpochIn'>;
 export function metaAssertion<K extends MetaAssertionArgs['__kind']>(
   kind: K,
   data?: any
@@ -20,14 +20,20 @@ import {
   Option,
   OptionOrNullable,
   combineCodec,
+  getArrayDecoder,
+  getArrayEncoder,
   getBooleanDecoder,
   getBooleanEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
   getDataEnumDecoder,
   getDataEnumEncoder,
   getOptionDecoder,
   getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -38,10 +44,14 @@ import {
   EquatableOperatorArgs,
   IntegerOperator,
   IntegerOperatorArgs,
+  SetOperator,
+  SetOperatorArgs,
   getEquatableOperatorDecoder,
   getEquatableOperatorEncoder,
   getIntegerOperatorDecoder,
   getIntegerOperatorEncoder,
+  getSetOperatorDecoder,
+  getSetOperatorEncoder,
 } from '.';
 
 export type MintAccountAssertion =
@@ -57,7 +67,19 @@ export type MintAccountAssertion =
       __kind: 'FreezeAuthority';
       value: Option<Address>;
       operator: EquatableOperator;
-    };
+    }
+  | {
+      __kind: 'MintAuthorityIn';
+      values: Array<Option<Address>>;
+      operator: SetOperator;
+    }
+  | { __kind: 'DecimalsIn'; values: Uint8Array; operator: SetOperator }
+  | {
+      __kind: 'FreezeAuthorityIn';
+      values: Array<Option<Address>>;
+      operator: SetOperator;
+    }
+  | { __kind: 'SupplyIn'; values: Array<bigint>; operator: SetOperator };
 
 export type MintAccountAssertionArgs =
   | {
@@ -72,6 +94,22 @@ export type MintAccountAssertionArgs =
       __kind: 'FreezeAuthority';
       value: OptionOrNullable<Address>;
       operator: EquatableOperatorArgs;
+    }
+  | {
+      __kind: 'MintAuthorityIn';
+      values: Array<OptionOrNullable<Address>>;
+      operator: SetOperatorArgs;
+    }
+  | { __kind: 'DecimalsIn'; values: Uint8Array; operator: SetOperatorArgs }
+  | {
+      __kind: 'FreezeAuthorityIn';
+      values: Array<OptionOrNullable<Address>>;
+      operator: SetOperatorArgs;
+    }
+  | {
+      __kind: 'SupplyIn';
+      values: Array<number | bigint>;
+      operator: SetOperatorArgs;
     };
 
 export function getMintAccountAssertionEncoder(): Encoder<MintAccountAssertionArgs> {
@@ -111,6 +149,34 @@ export function getMintAccountAssertionEncoder(): Encoder<MintAccountAssertionAr
         ['operator', getEquatableOperatorEncoder()],
       ]),
     ],
+    [
+      'MintAuthorityIn',
+      getStructEncoder([
+        ['values', getArrayEncoder(getOptionEncoder(getAddressEncoder()))],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
+    [
+      'DecimalsIn',
+      getStructEncoder([
+        ['values', getBytesEncoder({ size: getU32Encoder() })],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
+    [
+      'FreezeAuthorityIn',
+      getStructEncoder([
+        ['values', getArrayEncoder(getOptionEncoder(getAddressEncoder()))],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
+    [
+      'SupplyIn',
+      getStructEncoder([
+        ['values', getArrayEncoder(getU64Encoder())],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -151,6 +217,34 @@ export function getMintAccountAssertionDecoder(): Decoder<MintAccountAssertion>
         ['operator', getEquatableOperatorDecoder()],
       ]),
     ],
+    [
+      'MintAuthorityIn',
+      getStructDecoder([
+        ['values', getArrayDecoder(getOptionDecoder(getAddressDecoder()))],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
+    [
+      'DecimalsIn',
+      getStructDecoder([
+        ['values', getBytesDecoder({ size: getU32Decoder() })],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
+    [
+      'FreezeAuthorityIn',
+      getStructDecoder([
+        ['values', getArrayDecoder(getOptionDecoder(getAddressDecoder()))],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
+    [
+      'SupplyIn',
+      getStructDecoder([
+        ['values', getArrayDecoder(getU64Decoder())],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -185,6 +279,22 @@ export function mintAccountAssertion(
   kind: 'FreezeAuthority',
   data: GetDataEnumKindContent<MintAccountAssertionArgs, 'FreezeAuthority'>
 ): GetDataEnumKind<MintAccountAssertionArgs, 'FreezeAuthority'>;
+export function mintAccountAssertion(
+  kind: 'MintAuthorityIn',
+  data: GetDataEnumKindContent<MintAccountAssertionArgs, 'MintAuthorityIn'>
+): GetDataEnumKind<MintAccountAssertionArgs, 'MintAuthorityIn'>;
+export function mintAccountAssertion(
+  kind: 'DecimalsIn',
+  data: GetDataEnumKindContent<MintAccountAssertionArgs, 'DecimalsIn'>
+): GetDataEnumKind<MintAccountAssertionArgs, 'DecimalsIn'>;
+export function mintAccountAssertion(
+  kind: 'FreezeAuthorityIn',
+  data: GetDataEnumKindContent<MintAccountAssertionArgs, 'FreezeAuthorityIn'>
+): GetDataEnumKind<MintAccountAssertionArgs, 'FreezeAuthorityIn'>;
+export function mintAccountAssertion(
+  kind: 'SupplyIn',
+  data: GetDataEnumKindContent<MintAccountAssertionArgs, 'SupplyIn'>
+): GetDataEnumKind<MintAccountAssertionArgs, 'SupplyIn'>;
 export function mintAccountAssertion<
   K extends MintAccountAssertionArgs['__kind']
 >(kind: K, data?: any): Extract<MintAccountAssertionArgs, { __kind: K }> {
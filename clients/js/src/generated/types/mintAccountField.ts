@@ -0,0 +1,44 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  combineCodec,
+  getScalarEnumDecoder,
+  getScalarEnumEncoder,
+} from '@solana/codecs';
+
+export enum MintAccountField {
+  MintAuthority,
+  Supply,
+  Decimals,
+  IsInitialized,
+  FreezeAuthority,
+}
+
+export type MintAccountFieldArgs = MintAccountField;
+
+export function getMintAccountFieldEncoder(): Encoder<MintAccountFieldArgs> {
+  return getScalarEnumEncoder(MintAccountField);
+}
+
+export function getMintAccountFieldDecoder(): Decoder<MintAccountField> {
+  return getScalarEnumDecoder(MintAccountField);
+}
+
+export function getMintAccountFieldCodec(): Codec<
+  MintAccountFieldArgs,
+  MintAccountField
+> {
+  return combineCodec(
+    getMintAccountFieldEncoder(),
+    getMintAccountFieldDecoder()
+  );
+}
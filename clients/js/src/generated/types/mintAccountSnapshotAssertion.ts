@@ -0,0 +1,131 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  combineCodec,
+  getDataEnumDecoder,
+  getDataEnumEncoder,
+  getI128Decoder,
+  getI128Encoder,
+  getStructDecoder,
+  getStructEncoder,
+} from '@solana/codecs';
+import {
+  EquatableOperator,
+  EquatableOperatorArgs,
+  IntegerOperator,
+  IntegerOperatorArgs,
+  getEquatableOperatorDecoder,
+  getEquatableOperatorEncoder,
+  getIntegerOperatorDecoder,
+  getIntegerOperatorEncoder,
+} from '.';
+
+export type MintAccountSnapshotAssertion =
+  | { __kind: 'Supply'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'MintAuthority'; operator: EquatableOperator }
+  | { __kind: 'FreezeAuthority'; operator: EquatableOperator };
+
+export type MintAccountSnapshotAssertionArgs =
+  | { __kind: 'Supply'; value: number | bigint; operator: IntegerOperatorArgs }
+  | { __kind: 'MintAuthority'; operator: EquatableOperatorArgs }
+  | { __kind: 'FreezeAuthority'; operator: EquatableOperatorArgs };
+
+export function getMintAccountSnapshotAssertionEncoder(): Encoder<MintAccountSnapshotAssertionArgs> {
+  return getDataEnumEncoder([
+    [
+      'Supply',
+      getStructEncoder([
+        ['value', getI128Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'MintAuthority',
+      getStructEncoder([['operator', getEquatableOperatorEncoder()]]),
+    ],
+    [
+      'FreezeAuthority',
+      getStructEncoder([['operator', getEquatableOperatorEncoder()]]),
+    ],
+  ]);
+}
+
+export function getMintAccountSnapshotAssertionDecoder(): Decoder<MintAccountSnapshotAssertion> {
+  return getDataEnumDecoder([
+    [
+      'Supply',
+      getStructDecoder([
+        ['value', getI128Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'MintAuthority',
+      getStructDecoder([['operator', getEquatableOperatorDecoder()]]),
+    ],
+    [
+      'FreezeAuthority',
+      getStructDecoder([['operator', getEquatableOperatorDecoder()]]),
+    ],
+  ]);
+}
+
+export function getMintAccountSnapshotAssertionCodec(): Codec<
+  MintAccountSnapshotAssertionArgs,
+  MintAccountSnapshotAssertion
+> {
+  return combineCodec(
+    getMintAccountSnapshotAssertionEncoder(),
+    getMintAccountSnapshotAssertionDecoder()
+  );
+}
+
+// Data Enum Helpers.
+export function mintAccountSnapshotAssertion(
+  kind: 'Supply',
+  data: GetDataEnumKindContent<MintAccountSnapshotAssertionArgs, 'Supply'>
+): GetDataEnumKind<MintAccountSnapshotAssertionArgs, 'Supply'>;
+export function mintAccountSnapshotAssertion(
+  kind: 'MintAuthority',
+  data: GetDataEnumKindContent<
+    MintAccountSnapshotAssertionArgs,
+    'MintAuthority'
+  >
+): GetDataEnumKind<MintAccountSnapshotAssertionArgs, 'MintAuthority'>;
+export function mintAccountSnapshotAssertion(
+  kind: 'FreezeAuthority',
+  data: GetDataEnumKindContent<
+    MintAccountSnapshotAssertionArgs,
+    'FreezeAuthority'
+  >
+): GetDataEnumKind<MintAccountSnapshotAssertionArgs, 'FreezeAuthority'>;
+export function mintAccountSnapshotAssertion<
+  K extends MintAccountSnapshotAssertionArgs['__kind']
+>(
+  kind: K,
+  data?: any
+): Extract<MintAccountSnapshotAssertionArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+
+export function isMintAccountSnapshotAssertion<
+  K extends MintAccountSnapshotAssertion['__kind']
+>(
+  kind: K,
+  value: MintAccountSnapshotAssertion
+): value is MintAccountSnapshotAssertion & { __kind: K } {
+  return value.__kind === kind;
+}
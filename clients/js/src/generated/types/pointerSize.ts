@@ -0,0 +1,35 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  combineCodec,
+  getScalarEnumDecoder,
+  getScalarEnumEncoder,
+} from '@solana/codecs';
+
+export enum PointerSize {
+  U16,
+  U32,
+}
+
+export type PointerSizeArgs = PointerSize;
+
+export function getPointerSizeEncoder(): Encoder<PointerSizeArgs> {
+  return getScalarEnumEncoder(PointerSize);
+}
+
+export function getPointerSizeDecoder(): Decoder<PointerSize> {
+  return getScalarEnumDecoder(PointerSize);
+}
+
+export function getPointerSizeCodec(): Codec<PointerSizeArgs, PointerSize> {
+  return combineCodec(getPointerSizeEncoder(), getPointerSizeDecoder());
+}
@@ -0,0 +1,36 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  combineCodec,
+  getScalarEnumDecoder,
+  getScalarEnumEncoder,
+} from '@solana/codecs';
+
+export enum RentField {
+  LamportsPerByteYear,
+  ExemptionThreshold,
+  BurnPercent,
+}
+
+export type RentFieldArgs = RentField;
+
+export function getRentFieldEncoder(): Encoder<RentFieldArgs> {
+  return getScalarEnumEncoder(RentField);
+}
+
+export function getRentFieldDecoder(): Decoder<RentField> {
+  return getScalarEnumDecoder(RentField);
+}
+
+export function getRentFieldCodec(): Codec<RentFieldArgs, RentField> {
+  return combineCodec(getRentFieldEncoder(), getRentFieldDecoder());
+}
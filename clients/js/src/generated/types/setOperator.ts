@@ -0,0 +1,35 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  combineCodec,
+  getScalarEnumDecoder,
+  getScalarEnumEncoder,
+} from '@solana/codecs';
+
+export enum SetOperator {
+  In,
+  NotIn,
+}
+
+export type SetOperatorArgs = SetOperator;
+
+export function getSetOperatorEncoder(): Encoder<SetOperatorArgs> {
+  return getScalarEnumEncoder(SetOperator);
+}
+
+export function getSetOperatorDecoder(): Decoder<SetOperator> {
+  return getScalarEnumDecoder(SetOperator);
+}
+
+export function getSetOperatorCodec(): Codec<SetOperatorArgs, SetOperator> {
+  return combineCodec(getSetOperatorEncoder(), getSetOperatorDecoder());
+}
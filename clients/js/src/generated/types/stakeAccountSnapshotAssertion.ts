@@ -0,0 +1,163 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  combineCodec,
+  getDataEnumDecoder,
+  getDataEnumEncoder,
+  getI128Decoder,
+  getI128Encoder,
+  getStructDecoder,
+  getStructEncoder,
+} from '@solana/codecs';
+import {
+  EquatableOperator,
+  EquatableOperatorArgs,
+  IntegerOperator,
+  IntegerOperatorArgs,
+  getEquatableOperatorDecoder,
+  getEquatableOperatorEncoder,
+  getIntegerOperatorDecoder,
+  getIntegerOperatorEncoder,
+} from '.';
+
+export type StakeAccountSnapshotAssertion =
+  | { __kind: 'State'; operator: EquatableOperator }
+  | { __kind: 'AuthorizedStaker'; operator: EquatableOperator }
+  | { __kind: 'AuthorizedWithdrawer'; operator: EquatableOperator }
+  | { __kind: 'DelegationVoterPubkey'; operator: EquatableOperator }
+  | { __kind: 'DelegationStake'; value: bigint; operator: IntegerOperator };
+
+export type StakeAccountSnapshotAssertionArgs =
+  | { __kind: 'State'; operator: EquatableOperatorArgs }
+  | { __kind: 'AuthorizedStaker'; operator: EquatableOperatorArgs }
+  | { __kind: 'AuthorizedWithdrawer'; operator: EquatableOperatorArgs }
+  | { __kind: 'DelegationVoterPubkey'; operator: EquatableOperatorArgs }
+  | {
+      __kind: 'DelegationStake';
+      value: number | bigint;
+      operator: IntegerOperatorArgs;
+    };
+
+export function getStakeAccountSnapshotAssertionEncoder(): Encoder<StakeAccountSnapshotAssertionArgs> {
+  return getDataEnumEncoder([
+    ['State', getStructEncoder([['operator', getEquatableOperatorEncoder()]])],
+    [
+      'AuthorizedStaker',
+      getStructEncoder([['operator', getEquatableOperatorEncoder()]]),
+    ],
+    [
+      'AuthorizedWithdrawer',
+      getStructEncoder([['operator', getEquatableOperatorEncoder()]]),
+    ],
+    [
+      'DelegationVoterPubkey',
+      getStructEncoder([['operator', getEquatableOperatorEncoder()]]),
+    ],
+    [
+      'DelegationStake',
+      getStructEncoder([
+        ['value', getI128Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+  ]);
+}
+
+export function getStakeAccountSnapshotAssertionDecoder(): Decoder<StakeAccountSnapshotAssertion> {
+  return getDataEnumDecoder([
+    ['State', getStructDecoder([['operator', getEquatableOperatorDecoder()]])],
+    [
+      'AuthorizedStaker',
+      getStructDecoder([['operator', getEquatableOperatorDecoder()]]),
+    ],
+    [
+      'AuthorizedWithdrawer',
+      getStructDecoder([['operator', getEquatableOperatorDecoder()]]),
+    ],
+    [
+      'DelegationVoterPubkey',
+      getStructDecoder([['operator', getEquatableOperatorDecoder()]]),
+    ],
+    [
+      'DelegationStake',
+      getStructDecoder([
+        ['value', getI128Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+  ]);
+}
+
+export function getStakeAccountSnapshotAssertionCodec(): Codec<
+  StakeAccountSnapshotAssertionArgs,
+  StakeAccountSnapshotAssertion
+> {
+  return combineCodec(
+    getStakeAccountSnapshotAssertionEncoder(),
+    getStakeAccountSnapshotAssertionDecoder()
+  );
+}
+
+// Data Enum Helpers.
+export function stakeAccountSnapshotAssertion(
+  kind: 'State',
+  data: GetDataEnumKindContent<StakeAccountSnapshotAssertionArgs, 'State'>
+): GetDataEnumKind<StakeAccountSnapshotAssertionArgs, 'State'>;
+export function stakeAccountSnapshotAssertion(
+  kind: 'AuthorizedStaker',
+  data: GetDataEnumKindContent<
+    StakeAccountSnapshotAssertionArgs,
+    'AuthorizedStaker'
+  >
+): GetDataEnumKind<StakeAccountSnapshotAssertionArgs, 'AuthorizedStaker'>;
+export function stakeAccountSnapshotAssertion(
+  kind: 'AuthorizedWithdrawer',
+  data: GetDataEnumKindContent<
+    StakeAccountSnapshotAssertionArgs,
+    'AuthorizedWithdrawer'
+  >
+): GetDataEnumKind<StakeAccountSnapshotAssertionArgs, 'AuthorizedWithdrawer'>;
+export function stakeAccountSnapshotAssertion(
+  kind: 'DelegationVoterPubkey',
+  data: GetDataEnumKindContent<
+    StakeAccountSnapshotAssertionArgs,
+    'DelegationVoterPubkey'
+  >
+): GetDataEnumKind<StakeAccountSnapshotAssertionArgs, 'DelegationVoterPubkey'>;
+export function stakeAccountSnapshotAssertion(
+  kind: 'DelegationStake',
+  data: GetDataEnumKindContent<
+    StakeAccountSnapshotAssertionArgs,
+    'DelegationStake'
+  >
+): GetDataEnumKind<StakeAccountSnapshotAssertionArgs, 'DelegationStake'>;
+export function stakeAccountSnapshotAssertion<
+  K extends StakeAccountSnapshotAssertionArgs['__kind']
+>(
+  kind: K,
+  data?: any
+): Extract<StakeAccountSnapshotAssertionArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+
+export function isStakeAccountSnapshotAssertion<
+  K extends StakeAccountSnapshotAssertion['__kind']
+>(
+  kind: K,
+  value: StakeAccountSnapshotAssertion
+): value is StakeAccountSnapshotAssertion & { __kind: K } {
+  return value.__kind === kind;
+}
@@ -18,6 +18,8 @@ import {
   GetDataEnumKind,
   GetDataEnumKindContent,
   combineCodec,
+  getArrayDecoder,
+  getArrayEncoder,
   getDataEnumDecoder,
   getDataEnumEncoder,
   getStructDecoder,
@@ -30,10 +32,14 @@ import {
   EquatableOperatorArgs,
   IntegerOperator,
   IntegerOperatorArgs,
+  SetOperator,
+  SetOperatorArgs,
   getEquatableOperatorDecoder,
   getEquatableOperatorEncoder,
   getIntegerOperatorDecoder,
   getIntegerOperatorEncoder,
+  getSetOperatorDecoder,
+  getSetOperatorEncoder,
 } from '.';
 
 export type StakeAssertion =
@@ -53,7 +59,32 @@ export type StakeAssertion =
       value: bigint;
       operator: IntegerOperator;
     }
-  | { __kind: 'CreditsObserved'; value: bigint; operator: IntegerOperator };
+  | { __kind: 'CreditsObserved'; value: bigint; operator: IntegerOperator }
+  | {
+      __kind: 'DelegationVoterPubkeyIn';
+      values: Array<Address>;
+      operator: SetOperator;
+    }
+  | {
+      __kind: 'DelegationStakeIn';
+      values: Array<bigint>;
+      operator: SetOperator;
+    }
+  | {
+      __kind: 'DelegationActivationEpochIn';
+      values: Array<bigint>;
+      operator: SetOperator;
+    }
+  | {
+      __kind: 'DelegationDeactivationEpochIn';
+      values: Array<bigint>;
+      operator: SetOperator;
+    }
+  | {
+      __kind: 'CreditsObservedIn';
+      values: Array<bigint>;
+      operator: SetOperator;
+    };
 
 export type StakeAssertionArgs =
   | {
@@ -80,6 +111,31 @@ export type StakeAssertionArgs =
       __kind: 'CreditsObserved';
       value: number | bigint;
       operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'DelegationVoterPubkeyIn';
+      values: Array<Address>;
+      operator: SetOperatorArgs;
+    }
+  | {
+      __kind: 'DelegationStakeIn';
+      values: Array<number | bigint>;
+      operator: SetOperatorArgs;
+    }
+  | {
+      __kind: 'DelegationActivationEpochIn';
+      values: Array<number | bigint>;
+      operator: SetOperatorArgs;
+    }
+  | {
+      __kind: 'DelegationDeactivationEpochIn';
+      values: Array<number | bigint>;
+      operator: SetOperatorArgs;
+    }
+  | {
+      __kind: 'CreditsObservedIn';
+      values: Array<number | bigint>;
+      operator: SetOperatorArgs;
     };
 
 export function getStakeAssertionEncoder(): Encoder<StakeAssertionArgs> {
@@ -119,6 +175,41 @@ export function getStakeAssertionEncoder(): Encoder<StakeAssertionArgs> {
         ['operator', getIntegerOperatorEncoder()],
       ]),
     ],
+    [
+      'DelegationVoterPubkeyIn',
+      getStructEncoder([
+        ['values', getArrayEncoder(getAddressEncoder())],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
+    [
+      'DelegationStakeIn',
+      getStructEncoder([
+        ['values', getArrayEncoder(getU64Encoder())],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
+    [
+      'DelegationActivationEpochIn',
+      getStructEncoder([
+        ['values', getArrayEncoder(getU64Encoder())],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
+    [
+      'DelegationDeactivationEpochIn',
+      getStructEncoder([
+        ['values', getArrayEncoder(getU64Encoder())],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
+    [
+      'CreditsObservedIn',
+      getStructEncoder([
+        ['values', getArrayEncoder(getU64Encoder())],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -159,6 +250,41 @@ export function getStakeAssertionDecoder(): Decoder<StakeAssertion> {
         ['operator', getIntegerOperatorDecoder()],
       ]),
     ],
+    [
+      'DelegationVoterPubkeyIn',
+      getStructDecoder([
+        ['values', getArrayDecoder(getAddressDecoder())],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
+    [
+      'DelegationStakeIn',
+      getStructDecoder([
+        ['values', getArrayDecoder(getU64Decoder())],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
+    [
+      'DelegationActivationEpochIn',
+      getStructDecoder([
+        ['values', getArrayDecoder(getU64Decoder())],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
+    [
+      'DelegationDeactivationEpochIn',
+      getStructDecoder([
+        ['values', getArrayDecoder(getU64Decoder())],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
+    [
+      'CreditsObservedIn',
+      getStructDecoder([
+        ['values', getArrayDecoder(getU64Decoder())],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -193,6 +319,32 @@ export function stakeAssertion(
   kind: 'CreditsObserved',
   data: GetDataEnumKindContent<StakeAssertionArgs, 'CreditsObserved'>
 ): GetDataEnumKind<StakeAssertionArgs, 'CreditsObserved'>;
+export function stakeAssertion(
+  kind: 'DelegationVoterPubkeyIn',
+  data: GetDataEnumKindContent<StakeAssertionArgs, 'DelegationVoterPubkeyIn'>
+): GetDataEnumKind<StakeAssertionArgs, 'DelegationVoterPubkeyIn'>;
+export function stakeAssertion(
+  kind: 'DelegationStakeIn',
+  data: GetDataEnumKindContent<StakeAssertionArgs, 'DelegationStakeIn'>
+): GetDataEnumKind<StakeAssertionArgs, 'DelegationStakeIn'>;
+export function stakeAssertion(
+  kind: 'DelegationActivationEpochIn',
+  data: GetDataEnumKindContent<
+    StakeAssertionArgs,
+    'DelegationActivationEpochIn'
+  >
+): GetDataEnumKind<StakeAssertionArgs, 'DelegationActivationEpochIn'>;
+export function stakeAssertion(
+  kind: 'DelegationDeactivationEpochIn',
+  data: GetDataEnumKindContent<
+    StakeAssertionArgs,
+    'DelegationDeactivationEpochIn'
+  >
+): GetDataEnumKind<StakeAssertionArgs, 'DelegationDeactivationEpochIn'>;
+export function stakeAssertion(
+  kind: 'CreditsObservedIn',
+  data: GetDataEnumKindContent<StakeAssertionArgs, 'CreditsObservedIn'>
+): GetDataEnumKind<StakeAssertionArgs, 'CreditsObservedIn'>;
 export function stakeAssertion<K extends StakeAssertionArgs['__kind']>(
   kind: K,
   data?: any
@@ -0,0 +1,455 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Address,
+  getAddressDecoder,
+  getAddressEncoder,
+} from '@solana/addresses';
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  combineCodec,
+  getDataEnumDecoder,
+  getDataEnumEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getTupleDecoder,
+  getTupleEncoder,
+  getU128Decoder,
+  getU128Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+} from '@solana/codecs';
+import {
+  EquatableOperator,
+  EquatableOperatorArgs,
+  IntegerOperator,
+  IntegerOperatorArgs,
+  StakePoolFeeAssertion,
+  StakePoolFeeAssertionArgs,
+  getEquatableOperatorDecoder,
+  getEquatableOperatorEncoder,
+  getIntegerOperatorDecoder,
+  getIntegerOperatorEncoder,
+  getStakePoolFeeAssertionDecoder,
+  getStakePoolFeeAssertionEncoder,
+} from '.';
+
+export type StakePoolAccountAssertion =
+  | { __kind: 'Manager'; value: Address; operator: EquatableOperator }
+  | { __kind: 'Staker'; value: Address; operator: EquatableOperator }
+  | { __kind: 'PoolMint'; value: Address; operator: EquatableOperator }
+  | { __kind: 'TotalLamports'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'PoolTokenSupply'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'ExchangeRate'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'LastUpdateEpoch'; value: bigint; operator: IntegerOperator }
+  | {
+      __kind: 'LastEpochTotalLamports';
+      value: bigint;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'LastEpochPoolTokenSupply';
+      value: bigint;
+      operator: IntegerOperator;
+    }
+  | { __kind: 'EpochFee'; fields: [StakePoolFeeAssertion] }
+  | { __kind: 'StakeDepositFee'; fields: [StakePoolFeeAssertion] }
+  | { __kind: 'StakeWithdrawalFee'; fields: [StakePoolFeeAssertion] }
+  | { __kind: 'SolDepositFee'; fields: [StakePoolFeeAssertion] }
+  | { __kind: 'SolWithdrawalFee'; fields: [StakePoolFeeAssertion] }
+  | { __kind: 'StakeReferralFee'; value: number; operator: IntegerOperator }
+  | { __kind: 'SolReferralFee'; value: number; operator: IntegerOperator };
+
+export type StakePoolAccountAssertionArgs =
+  | { __kind: 'Manager'; value: Address; operator: EquatableOperatorArgs }
+  | { __kind: 'Staker'; value: Address; operator: EquatableOperatorArgs }
+  | { __kind: 'PoolMint'; value: Address; operator: EquatableOperatorArgs }
+  | {
+      __kind: 'TotalLamports';
+      value: number | bigint;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'PoolTokenSupply';
+      value: number | bigint;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'ExchangeRate';
+      value: number | bigint;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'LastUpdateEpoch';
+      value: number | bigint;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'LastEpochTotalLamports';
+      value: number | bigint;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'LastEpochPoolTokenSupply';
+      value: number | bigint;
+      operator: IntegerOperatorArgs;
+    }
+  | { __kind: 'EpochFee'; fields: [StakePoolFeeAssertionArgs] }
+  | { __kind: 'StakeDepositFee'; fields: [StakePoolFeeAssertionArgs] }
+  | { __kind: 'StakeWithdrawalFee'; fields: [StakePoolFeeAssertionArgs] }
+  | { __kind: 'SolDepositFee'; fields: [StakePoolFeeAssertionArgs] }
+  | { __kind: 'SolWithdrawalFee'; fields: [StakePoolFeeAssertionArgs] }
+  | { __kind: 'StakeReferralFee'; value: number; operator: IntegerOperatorArgs }
+  | { __kind: 'SolReferralFee'; value: number; operator: IntegerOperatorArgs };
+
+export function getStakePoolAccountAssertionEncoder(): Encoder<StakePoolAccountAssertionArgs> {
+  return getDataEnumEncoder([
+    [
+      'Manager',
+      getStructEncoder([
+        ['value', getAddressEncoder()],
+        ['operator', getEquatableOperatorEncoder()],
+      ]),
+    ],
+    [
+      'Staker',
+      getStructEncoder([
+        ['value', getAddressEncoder()],
+        ['operator', getEquatableOperatorEncoder()],
+      ]),
+    ],
+    [
+      'PoolMint',
+      getStructEncoder([
+        ['value', getAddressEncoder()],
+        ['operator', getEquatableOperatorEncoder()],
+      ]),
+    ],
+    [
+      'TotalLamports',
+      getStructEncoder([
+        ['value', getU64Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'PoolTokenSupply',
+      getStructEncoder([
+        ['value', getU64Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'ExchangeRate',
+      getStructEncoder([
+        ['value', getU128Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'LastUpdateEpoch',
+      getStructEncoder([
+        ['value', getU64Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'LastEpochTotalLamports',
+      getStructEncoder([
+        ['value', getU64Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'LastEpochPoolTokenSupply',
+      getStructEncoder([
+        ['value', getU64Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'EpochFee',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getStakePoolFeeAssertionEncoder()])],
+      ]),
+    ],
+    [
+      'StakeDepositFee',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getStakePoolFeeAssertionEncoder()])],
+      ]),
+    ],
+    [
+      'StakeWithdrawalFee',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getStakePoolFeeAssertionEncoder()])],
+      ]),
+    ],
+    [
+      'SolDepositFee',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getStakePoolFeeAssertionEncoder()])],
+      ]),
+    ],
+    [
+      'SolWithdrawalFee',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getStakePoolFeeAssertionEncoder()])],
+      ]),
+    ],
+    [
+      'StakeReferralFee',
+      getStructEncoder([
+        ['value', getU8Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'SolReferralFee',
+      getStructEncoder([
+        ['value', getU8Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+  ]);
+}
+
+export function getStakePoolAccountAssertionDecoder(): Decoder<StakePoolAccountAssertion> {
+  return getDataEnumDecoder([
+    [
+      'Manager',
+      getStructDecoder([
+        ['value', getAddressDecoder()],
+        ['operator', getEquatableOperatorDecoder()],
+      ]),
+    ],
+    [
+      'Staker',
+      getStructDecoder([
+        ['value', getAddressDecoder()],
+        ['operator', getEquatableOperatorDecoder()],
+      ]),
+    ],
+    [
+      'PoolMint',
+      getStructDecoder([
+        ['value', getAddressDecoder()],
+        ['operator', getEquatableOperatorDecoder()],
+      ]),
+    ],
+    [
+      'TotalLamports',
+      getStructDecoder([
+        ['value', getU64Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'PoolTokenSupply',
+      getStructDecoder([
+        ['value', getU64Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'ExchangeRate',
+      getStructDecoder([
+        ['value', getU128Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'LastUpdateEpoch',
+      getStructDecoder([
+        ['value', getU64Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'LastEpochTotalLamports',
+      getStructDecoder([
+        ['value', getU64Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'LastEpochPoolTokenSupply',
+      getStructDecoder([
+        ['value', getU64Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'EpochFee',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getStakePoolFeeAssertionDecoder()])],
+      ]),
+    ],
+    [
+      'StakeDepositFee',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getStakePoolFeeAssertionDecoder()])],
+      ]),
+    ],
+    [
+      'StakeWithdrawalFee',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getStakePoolFeeAssertionDecoder()])],
+      ]),
+    ],
+    [
+      'SolDepositFee',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getStakePoolFeeAssertionDecoder()])],
+      ]),
+    ],
+    [
+      'SolWithdrawalFee',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getStakePoolFeeAssertionDecoder()])],
+      ]),
+    ],
+    [
+      'StakeReferralFee',
+      getStructDecoder([
+        ['value', getU8Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'SolReferralFee',
+      getStructDecoder([
+        ['value', getU8Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+  ]);
+}
+
+export function getStakePoolAccountAssertionCodec(): Codec<
+  StakePoolAccountAssertionArgs,
+  StakePoolAccountAssertion
+> {
+  return combineCodec(
+    getStakePoolAccountAssertionEncoder(),
+    getStakePoolAccountAssertionDecoder()
+  );
+}
+
+// Data Enum Helpers.
+export function stakePoolAccountAssertion(
+  kind: 'Manager',
+  data: GetDataEnumKindContent<StakePoolAccountAssertionArgs, 'Manager'>
+): GetDataEnumKind<StakePoolAccountAssertionArgs, 'Manager'>;
+export function stakePoolAccountAssertion(
+  kind: 'Staker',
+  data: GetDataEnumKindContent<StakePoolAccountAssertionArgs, 'Staker'>
+): GetDataEnumKind<StakePoolAccountAssertionArgs, 'Staker'>;
+export function stakePoolAccountAssertion(
+  kind: 'PoolMint',
+  data: GetDataEnumKindContent<StakePoolAccountAssertionArgs, 'PoolMint'>
+): GetDataEnumKind<StakePoolAccountAssertionArgs, 'PoolMint'>;
+export function stakePoolAccountAssertion(
+  kind: 'TotalLamports',
+  data: GetDataEnumKindContent<StakePoolAccountAssertionArgs, 'TotalLamports'>
+): GetDataEnumKind<StakePoolAccountAssertionArgs, 'TotalLamports'>;
+export function stakePoolAccountAssertion(
+  kind: 'PoolTokenSupply',
+  data: GetDataEnumKindContent<StakePoolAccountAssertionArgs, 'PoolTokenSupply'>
+): GetDataEnumKind<StakePoolAccountAssertionArgs, 'PoolTokenSupply'>;
+export function stakePoolAccountAssertion(
+  kind: 'ExchangeRate',
+  data: GetDataEnumKindContent<StakePoolAccountAssertionArgs, 'ExchangeRate'>
+): GetDataEnumKind<StakePoolAccountAssertionArgs, 'ExchangeRate'>;
+export function stakePoolAccountAssertion(
+  kind: 'LastUpdateEpoch',
+  data: GetDataEnumKindContent<StakePoolAccountAssertionArgs, 'LastUpdateEpoch'>
+): GetDataEnumKind<StakePoolAccountAssertionArgs, 'LastUpdateEpoch'>;
+export function stakePoolAccountAssertion(
+  kind: 'LastEpochTotalLamports',
+  data: GetDataEnumKindContent<
+    StakePoolAccountAssertionArgs,
+    'LastEpochTotalLamports'
+  >
+): GetDataEnumKind<StakePoolAccountAssertionArgs, 'LastEpochTotalLamports'>;
+export function stakePoolAccountAssertion(
+  kind: 'LastEpochPoolTokenSupply',
+  data: GetDataEnumKindContent<
+    StakePoolAccountAssertionArgs,
+    'LastEpochPoolTokenSupply'
+  >
+): GetDataEnumKind<StakePoolAccountAssertionArgs, 'LastEpochPoolTokenSupply'>;
+export function stakePoolAccountAssertion(
+  kind: 'EpochFee',
+  data: GetDataEnumKindContent<
+    StakePoolAccountAssertionArgs,
+    'EpochFee'
+  >['fields']
+): GetDataEnumKind<StakePoolAccountAssertionArgs, 'EpochFee'>;
+export function stakePoolAccountAssertion(
+  kind: 'StakeDepositFee',
+  data: GetDataEnumKindContent<
+    StakePoolAccountAssertionArgs,
+    'StakeDepositFee'
+  >['fields']
+): GetDataEnumKind<StakePoolAccountAssertionArgs, 'StakeDepositFee'>;
+export function stakePoolAccountAssertion(
+  kind: 'StakeWithdrawalFee',
+  data: GetDataEnumKindContent<
+    StakePoolAccountAssertionArgs,
+    'StakeWithdrawalFee'
+  >['fields']
+): GetDataEnumKind<StakePoolAccountAssertionArgs, 'StakeWithdrawalFee'>;
+export function stakePoolAccountAssertion(
+  kind: 'SolDepositFee',
+  data: GetDataEnumKindContent<
+    StakePoolAccountAssertionArgs,
+    'SolDepositFee'
+  >['fields']
+): GetDataEnumKind<StakePoolAccountAssertionArgs, 'SolDepositFee'>;
+export function stakePoolAccountAssertion(
+  kind: 'SolWithdrawalFee',
+  data: GetDataEnumKindContent<
+    StakePoolAccountAssertionArgs,
+    'SolWithdrawalFee'
+  >['fields']
+): GetDataEnumKind<StakePoolAccountAssertionArgs, 'SolWithdrawalFee'>;
+export function stakePoolAccountAssertion(
+  kind: 'StakeReferralFee',
+  data: GetDataEnumKindContent<
+    StakePoolAccountAssertionArgs,
+    'StakeReferralFee'
+  >
+): GetDataEnumKind<StakePoolAccountAssertionArgs, 'StakeReferralFee'>;
+export function stakePoolAccountAssertion(
+  kind: 'SolReferralFee',
+  data: GetDataEnumKindContent<StakePoolAccountAssertionArgs, 'SolReferralFee'>
+): GetDataEnumKind<StakePoolAccountAssertionArgs, 'SolReferralFee'>;
+export function stakePoolAccountAssertion<
+  K extends StakePoolAccountAssertionArgs['__kind']
+>(kind: K, data?: any): Extract<StakePoolAccountAssertionArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+
+export function isStakePoolAccountAssertion<
+  K extends StakePoolAccountAssertion['__kind']
+>(
+  kind: K,
+  value: StakePoolAccountAssertion
+): value is StakePoolAccountAssertion & { __kind: K } {
+  return value.__kind === kind;
+}
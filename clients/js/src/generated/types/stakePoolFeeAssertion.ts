@@ -0,0 +1,118 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  combineCodec,
+  getDataEnumDecoder,
+  getDataEnumEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+} from '@solana/codecs';
+import {
+  IntegerOperator,
+  IntegerOperatorArgs,
+  getIntegerOperatorDecoder,
+  getIntegerOperatorEncoder,
+} from '.';
+
+export type StakePoolFeeAssertion =
+  | { __kind: 'Numerator'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'Denominator'; value: bigint; operator: IntegerOperator };
+
+export type StakePoolFeeAssertionArgs =
+  | {
+      __kind: 'Numerator';
+      value: number | bigint;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'Denominator';
+      value: number | bigint;
+      operator: IntegerOperatorArgs;
+    };
+
+export function getStakePoolFeeAssertionEncoder(): Encoder<StakePoolFeeAssertionArgs> {
+  return getDataEnumEncoder([
+    [
+      'Numerator',
+      getStructEncoder([
+        ['value', getU64Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'Denominator',
+      getStructEncoder([
+        ['value', getU64Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+  ]);
+}
+
+export function getStakePoolFeeAssertionDecoder(): Decoder<StakePoolFeeAssertion> {
+  return getDataEnumDecoder([
+    [
+      'Numerator',
+      getStructDecoder([
+        ['value', getU64Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'Denominator',
+      getStructDecoder([
+        ['value', getU64Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+  ]);
+}
+
+export function getStakePoolFeeAssertionCodec(): Codec<
+  StakePoolFeeAssertionArgs,
+  StakePoolFeeAssertion
+> {
+  return combineCodec(
+    getStakePoolFeeAssertionEncoder(),
+    getStakePoolFeeAssertionDecoder()
+  );
+}
+
+// Data Enum Helpers.
+export function stakePoolFeeAssertion(
+  kind: 'Numerator',
+  data: GetDataEnumKindContent<StakePoolFeeAssertionArgs, 'Numerator'>
+): GetDataEnumKind<StakePoolFeeAssertionArgs, 'Numerator'>;
+export function stakePoolFeeAssertion(
+  kind: 'Denominator',
+  data: GetDataEnumKindContent<StakePoolFeeAssertionArgs, 'Denominator'>
+): GetDataEnumKind<StakePoolFeeAssertionArgs, 'Denominator'>;
+export function stakePoolFeeAssertion<
+  K extends StakePoolFeeAssertionArgs['__kind']
+>(kind: K, data?: any): Extract<StakePoolFeeAssertionArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+
+export function isStakePoolFeeAssertion<
+  K extends StakePoolFeeAssertion['__kind']
+>(
+  kind: K,
+  value: StakePoolFeeAssertion
+): value is StakePoolFeeAssertion & { __kind: K } {
+  return value.__kind === kind;
+}
@@ -20,12 +20,18 @@ import {
   Option,
   OptionOrNullable,
   combineCodec,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
   getDataEnumDecoder,
   getDataEnumEncoder,
   getOptionDecoder,
   getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -38,10 +44,14 @@ import {
   EquatableOperatorArgs,
   IntegerOperator,
   IntegerOperatorArgs,
+  SetOperator,
+  SetOperatorArgs,
   getEquatableOperatorDecoder,
   getEquatableOperatorEncoder,
   getIntegerOperatorDecoder,
   getIntegerOperatorEncoder,
+  getSetOperatorDecoder,
+  getSetOperatorEncoder,
 } from '.';
 
 export type TokenAccountAssertion =
@@ -57,7 +67,26 @@ export type TokenAccountAssertion =
       value: Option<Address>;
       operator: EquatableOperator;
     }
-  | { __kind: 'TokenAccountOwnerIsDerived' };
+  | { __kind: 'TokenAccountOwnerIsDerived' }
+  | { __kind: 'MintIn'; values: Array<Address>; operator: SetOperator }
+  | { __kind: 'OwnerIn'; values: Array<Address>; operator: SetOperator }
+  | {
+      __kind: 'DelegateIn';
+      values: Array<Option<Address>>;
+      operator: SetOperator;
+    }
+  | { __kind: 'StateIn'; values: Uint8Array; operator: SetOperator }
+  | {
+      __kind: 'CloseAuthorityIn';
+      values: Array<Option<Address>>;
+      operator: SetOperator;
+    }
+  | { __kind: 'AmountIn'; values: Array<bigint>; operator: SetOperator }
+  | {
+      __kind: 'DelegatedAmountIn';
+      values: Array<bigint>;
+      operator: SetOperator;
+    };
 
 export type TokenAccountAssertionArgs =
   | { __kind: 'Mint'; value: Address; operator: EquatableOperatorArgs }
@@ -84,7 +113,30 @@ export type TokenAccountAssertionArgs =
       value: OptionOrNullable<Address>;
       operator: EquatableOperatorArgs;
     }
-  | { __kind: 'TokenAccountOwnerIsDerived' };
+  | { __kind: 'TokenAccountOwnerIsDerived' }
+  | { __kind: 'MintIn'; values: Array<Address>; operator: SetOperatorArgs }
+  | { __kind: 'OwnerIn'; values: Array<Address>; operator: SetOperatorArgs }
+  | {
+      __kind: 'DelegateIn';
+      values: Array<OptionOrNullable<Address>>;
+      operator: SetOperatorArgs;
+    }
+  | { __kind: 'StateIn'; values: Uint8Array; operator: SetOperatorArgs }
+  | {
+      __kind: 'CloseAuthorityIn';
+      values: Array<OptionOrNullable<Address>>;
+      operator: SetOperatorArgs;
+    }
+  | {
+      __kind: 'AmountIn';
+      values: Array<number | bigint>;
+      operator: SetOperatorArgs;
+    }
+  | {
+      __kind: 'DelegatedAmountIn';
+      values: Array<number | bigint>;
+      operator: SetOperatorArgs;
+    };
 
 export function getTokenAccountAssertionEncoder(): Encoder<TokenAccountAssertionArgs> {
   return getDataEnumEncoder([
@@ -145,6 +197,55 @@ export function getTokenAccountAssertionEncoder(): Encoder<TokenAccountAssertion
       ]),
     ],
     ['TokenAccountOwnerIsDerived', getUnitEncoder()],
+    [
+      'MintIn',
+      getStructEncoder([
+        ['values', getArrayEncoder(getAddressEncoder())],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
+    [
+      'OwnerIn',
+      getStructEncoder([
+        ['values', getArrayEncoder(getAddressEncoder())],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
+    [
+      'DelegateIn',
+      getStructEncoder([
+        ['values', getArrayEncoder(getOptionEncoder(getAddressEncoder()))],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
+    [
+      'StateIn',
+      getStructEncoder([
+        ['values', getBytesEncoder({ size: getU32Encoder() })],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
+    [
+      'CloseAuthorityIn',
+      getStructEncoder([
+        ['values', getArrayEncoder(getOptionEncoder(getAddressEncoder()))],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
+    [
+      'AmountIn',
+      getStructEncoder([
+        ['values', getArrayEncoder(getU64Encoder())],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
+    [
+      'DelegatedAmountIn',
+      getStructEncoder([
+        ['values', getArrayEncoder(getU64Encoder())],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -207,6 +308,55 @@ export function getTokenAccountAssertionDecoder(): Decoder<TokenAccountAssertion
       ]),
     ],
     ['TokenAccountOwnerIsDerived', getUnitDecoder()],
+    [
+      'MintIn',
+      getStructDecoder([
+        ['values', getArrayDecoder(getAddressDecoder())],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
+    [
+      'OwnerIn',
+      getStructDecoder([
+        ['values', getArrayDecoder(getAddressDecoder())],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
+    [
+      'DelegateIn',
+      getStructDecoder([
+        ['values', getArrayDecoder(getOptionDecoder(getAddressDecoder()))],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
+    [
+      'StateIn',
+      getStructDecoder([
+        ['values', getBytesDecoder({ size: getU32Decoder() })],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
+    [
+      'CloseAuthorityIn',
+      getStructDecoder([
+        ['values', getArrayDecoder(getOptionDecoder(getAddressDecoder()))],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
+    [
+      'AmountIn',
+      getStructDecoder([
+        ['values', getArrayDecoder(getU64Decoder())],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
+    [
+      'DelegatedAmountIn',
+      getStructDecoder([
+        ['values', getArrayDecoder(getU64Decoder())],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -256,6 +406,34 @@ export function tokenAccountAssertion(
 export function tokenAccountAssertion(
   kind: 'TokenAccountOwnerIsDerived'
 ): GetDataEnumKind<TokenAccountAssertionArgs, 'TokenAccountOwnerIsDerived'>;
+export function tokenAccountAssertion(
+  kind: 'MintIn',
+  data: GetDataEnumKindContent<TokenAccountAssertionArgs, 'MintIn'>
+): GetDataEnumKind<TokenAccountAssertionArgs, 'MintIn'>;
+export function tokenAccountAssertion(
+  kind: 'OwnerIn',
+  data: GetDataEnumKindContent<TokenAccountAssertionArgs, 'OwnerIn'>
+): GetDataEnumKind<TokenAccountAssertionArgs, 'OwnerIn'>;
+export function tokenAccountAssertion(
+  kind: 'DelegateIn',
+  data: GetDataEnumKindContent<TokenAccountAssertionArgs, 'DelegateIn'>
+): GetDataEnumKind<TokenAccountAssertionArgs, 'DelegateIn'>;
+export function tokenAccountAssertion(
+  kind: 'StateIn',
+  data: GetDataEnumKindContent<TokenAccountAssertionArgs, 'StateIn'>
+): GetDataEnumKind<TokenAccountAssertionArgs, 'StateIn'>;
+export function tokenAccountAssertion(
+  kind: 'CloseAuthorityIn',
+  data: GetDataEnumKindContent<TokenAccountAssertionArgs, 'CloseAuthorityIn'>
+): GetDataEnumKind<TokenAccountAssertionArgs, 'CloseAuthorityIn'>;
+export function tokenAccountAssertion(
+  kind: 'AmountIn',
+  data: GetDataEnumKindContent<TokenAccountAssertionArgs, 'AmountIn'>
+): GetDataEnumKind<TokenAccountAssertionArgs, 'AmountIn'>;
+export function tokenAccountAssertion(
+  kind: 'DelegatedAmountIn',
+  data: GetDataEnumKindContent<TokenAccountAssertionArgs, 'DelegatedAmountIn'>
+): GetDataEnumKind<TokenAccountAssertionArgs, 'DelegatedAmountIn'>;
 export function tokenAccountAssertion<
   K extends TokenAccountAssertionArgs['__kind']
 >(kind: K, data?: any): Extract<TokenAccountAssertionArgs, { __kind: K }> {
@@ -0,0 +1,47 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  combineCodec,
+  getScalarEnumDecoder,
+  getScalarEnumEncoder,
+} from '@solana/codecs';
+
+export enum TokenAccountField {
+  Mint,
+  Owner,
+  Amount,
+  Delegate,
+  State,
+  IsNative,
+  DelegatedAmount,
+  CloseAuthority,
+}
+
+export type TokenAccountFieldArgs = TokenAccountField;
+
+export function getTokenAccountFieldEncoder(): Encoder<TokenAccountFieldArgs> {
+  return getScalarEnumEncoder(TokenAccountField);
+}
+
+export function getTokenAccountFieldDecoder(): Decoder<TokenAccountField> {
+  return getScalarEnumDecoder(TokenAccountField);
+}
+
+export function getTokenAccountFieldCodec(): Codec<
+  TokenAccountFieldArgs,
+  TokenAccountField
+> {
+  return combineCodec(
+    getTokenAccountFieldEncoder(),
+    getTokenAccountFieldDecoder()
+  );
+}
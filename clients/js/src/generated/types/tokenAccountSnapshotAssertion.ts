@@ -0,0 +1,154 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Codec,
+  Decoder,
+  Encoder,
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  combineCodec,
+  getDataEnumDecoder,
+  getDataEnumEncoder,
+  getI128Decoder,
+  getI128Encoder,
+  getStructDecoder,
+  getStructEncoder,
+} from '@solana/codecs';
+import {
+  EquatableOperator,
+  EquatableOperatorArgs,
+  IntegerOperator,
+  IntegerOperatorArgs,
+  getEquatableOperatorDecoder,
+  getEquatableOperatorEncoder,
+  getIntegerOperatorDecoder,
+  getIntegerOperatorEncoder,
+} from '.';
+
+export type TokenAccountSnapshotAssertion =
+  | { __kind: 'Amount'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'Owner'; operator: EquatableOperator }
+  | { __kind: 'Delegate'; operator: EquatableOperator }
+  | { __kind: 'DelegatedAmount'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'State'; operator: EquatableOperator };
+
+export type TokenAccountSnapshotAssertionArgs =
+  | { __kind: 'Amount'; value: number | bigint; operator: IntegerOperatorArgs }
+  | { __kind: 'Owner'; operator: EquatableOperatorArgs }
+  | { __kind: 'Delegate'; operator: EquatableOperatorArgs }
+  | {
+      __kind: 'DelegatedAmount';
+      value: number | bigint;
+      operator: IntegerOperatorArgs;
+    }
+  | { __kind: 'State'; operator: EquatableOperatorArgs };
+
+export function getTokenAccountSnapshotAssertionEncoder(): Encoder<TokenAccountSnapshotAssertionArgs> {
+  return getDataEnumEncoder([
+    [
+      'Amount',
+      getStructEncoder([
+        ['value', getI128Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    ['Owner', getStructEncoder([['operator', getEquatableOperatorEncoder()]])],
+    [
+      'Delegate',
+      getStructEncoder([['operator', getEquatableOperatorEncoder()]]),
+    ],
+    [
+      'DelegatedAmount',
+      getStructEncoder([
+        ['value', getI128Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    ['State', getStructEncoder([['operator', getEquatableOperatorEncoder()]])],
+  ]);
+}
+
+export function getTokenAccountSnapshotAssertionDecoder(): Decoder<TokenAccountSnapshotAssertion> {
+  return getDataEnumDecoder([
+    [
+      'Amount',
+      getStructDecoder([
+        ['value', getI128Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    ['Owner', getStructDecoder([['operator', getEquatableOperatorDecoder()]])],
+    [
+      'Delegate',
+      getStructDecoder([['operator', getEquatableOperatorDecoder()]]),
+    ],
+    [
+      'DelegatedAmount',
+      getStructDecoder([
+        ['value', getI128Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    ['State', getStructDecoder([['operator', getEquatableOperatorDecoder()]])],
+  ]);
+}
+
+export function getTokenAccountSnapshotAssertionCodec(): Codec<
+  TokenAccountSnapshotAssertionArgs,
+  TokenAccountSnapshotAssertion
+> {
+  return combineCodec(
+    getTokenAccountSnapshotAssertionEncoder(),
+    getTokenAccountSnapshotAssertionDecoder()
+  );
+}
+
+// Data Enum Helpers.
+export function tokenAccountSnapshotAssertion(
+  kind: 'Amount',
+  data: GetDataEnumKindContent<TokenAccountSnapshotAssertionArgs, 'Amount'>
+): GetDataEnumKind<TokenAccountSnapshotAssertionArgs, 'Amount'>;
+export function tokenAccountSnapshotAssertion(
+  kind: 'Owner',
+  data: GetDataEnumKindContent<TokenAccountSnapshotAssertionArgs, 'Owner'>
+): GetDataEnumKind<TokenAccountSnapshotAssertionArgs, 'Owner'>;
+export function tokenAccountSnapshotAssertion(
+  kind: 'Delegate',
+  data: GetDataEnumKindContent<TokenAccountSnapshotAssertionArgs, 'Delegate'>
+): GetDataEnumKind<TokenAccountSnapshotAssertionArgs, 'Delegate'>;
+export function tokenAccountSnapshotAssertion(
+  kind: 'DelegatedAmount',
+  data: GetDataEnumKindContent<
+    TokenAccountSnapshotAssertionArgs,
+    'DelegatedAmount'
+  >
+): GetDataEnumKind<TokenAccountSnapshotAssertionArgs, 'DelegatedAmount'>;
+export function tokenAccountSnapshotAssertion(
+  kind: 'State',
+  data: GetDataEnumKindContent<TokenAccountSnapshotAssertionArgs, 'State'>
+): GetDataEnumKind<TokenAccountSnapshotAssertionArgs, 'State'>;
+export function tokenAccountSnapshotAssertion<
+  K extends TokenAccountSnapshotAssertionArgs['__kind']
+>(
+  kind: K,
+  data?: any
+): Extract<TokenAccountSnapshotAssertionArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+
+export function isTokenAccountSnapshotAssertion<
+  K extends TokenAccountSnapshotAssertion['__kind']
+>(
+  kind: K,
+  value: TokenAccountSnapshotAssertion
+): value is TokenAccountSnapshotAssertion & { __kind: K } {
+  return value.__kind === kind;
+}
@@ -12,42 +12,120 @@ import {
   Encoder,
   GetDataEnumKind,
   GetDataEnumKindContent,
+  Option,
+  OptionOrNullable,
   combineCodec,
   getDataEnumDecoder,
   getDataEnumEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getTupleDecoder,
   getTupleEncoder,
   getU16Decoder,
   getU16Encoder,
+  getUnitDecoder,
+  getUnitEncoder,
 } from '@solana/codecs';
 import {
   AccountInfoField,
   AccountInfoFieldArgs,
+  AccumulateOperation,
+  AccumulateOperationArgs,
+  AccumulateSource,
+  AccumulateSourceArgs,
+  AccumulatorType,
+  AccumulatorTypeArgs,
   ClockField,
   ClockFieldArgs,
+  DataHashType,
+  DataHashTypeArgs,
   DataValue,
   DataValueArgs,
+  EpochScheduleField,
+  EpochScheduleFieldArgs,
+  MintAccountField,
+  MintAccountFieldArgs,
+  RentField,
+  RentFieldArgs,
+  TokenAccountField,
+  TokenAccountFieldArgs,
   getAccountInfoFieldDecoder,
   getAccountInfoFieldEncoder,
+  getAccumulateOperationDecoder,
+  getAccumulateOperationEncoder,
+  getAccumulateSourceDecoder,
+  getAccumulateSourceEncoder,
+  getAccumulatorTypeDecoder,
+  getAccumulatorTypeEncoder,
   getClockFieldDecoder,
   getClockFieldEncoder,
+  getDataHashTypeDecoder,
+  getDataHashTypeEncoder,
   getDataValueDecoder,
   getDataValueEncoder,
+  getEpochScheduleFieldDecoder,
+  getEpochScheduleFieldEncoder,
+  getMintAccountFieldDecoder,
+  getMintAccountFieldEncoder,
+  getRentFieldDecoder,
+  getRentFieldEncoder,
+  getTokenAccountFieldDecoder,
+  getTokenAccountFieldEncoder,
 } from '.';
 
 export type WriteType =
   | { __kind: 'AccountData'; offset: number; dataLength: number }
   | { __kind: 'AccountInfoField'; fields: [AccountInfoField] }
   | { __kind: 'DataValue'; fields: [DataValue] }
-  | { __kind: 'Clock'; fields: [ClockField] };
+  | { __kind: 'Clock'; fields: [ClockField] }
+  | {
+      __kind: 'AccountDataHash';
+      hashType: DataHashType;
+      start: Option<number>;
+      length: Option<number>;
+    }
+  | { __kind: 'Rent'; fields: [RentField] }
+  | { __kind: 'EpochSchedule'; fields: [EpochScheduleField] }
+  | { __kind: 'RemainingComputeUnits' }
+  | { __kind: 'InstructionIndex' }
+  | { __kind: 'LatestSlotHash' }
+  | {
+      __kind: 'Accumulate';
+      operation: AccumulateOperation;
+      accumulator: AccumulatorType;
+      source: AccumulateSource;
+    }
+  | { __kind: 'TokenAccountField'; fields: [TokenAccountField] }
+  | { __kind: 'MintAccountField'; fields: [MintAccountField] }
+  | { __kind: 'AccountSnapshot'; dataLength: number };
 
 export type WriteTypeArgs =
   | { __kind: 'AccountData'; offset: number; dataLength: number }
   | { __kind: 'AccountInfoField'; fields: [AccountInfoFieldArgs] }
   | { __kind: 'DataValue'; fields: [DataValueArgs] }
-  | { __kind: 'Clock'; fields: [ClockFieldArgs] };
+  | { __kind: 'Clock'; fields: [ClockFieldArgs] }
+  | {
+      __kind: 'AccountDataHash';
+      hashType: DataHashTypeArgs;
+      start: OptionOrNullable<number>;
+      length: OptionOrNullable<number>;
+    }
+  | { __kind: 'Rent'; fields: [RentFieldArgs] }
+  | { __kind: 'EpochSchedule'; fields: [EpochScheduleFieldArgs] }
+  | { __kind: 'RemainingComputeUnits' }
+  | { __kind: 'InstructionIndex' }
+  | { __kind: 'LatestSlotHash' }
+  | {
+      __kind: 'Accumulate';
+      operation: AccumulateOperationArgs;
+      accumulator: AccumulatorTypeArgs;
+      source: AccumulateSourceArgs;
+    }
+  | { __kind: 'TokenAccountField'; fields: [TokenAccountFieldArgs] }
+  | { __kind: 'MintAccountField'; fields: [MintAccountFieldArgs] }
+  | { __kind: 'AccountSnapshot'; dataLength: number };
 
 export function getWriteTypeEncoder(): Encoder<WriteTypeArgs> {
   return getDataEnumEncoder([
@@ -72,6 +150,48 @@ export function getWriteTypeEncoder(): Encoder<WriteTypeArgs> {
       'Clock',
       getStructEncoder([['fields', getTupleEncoder([getClockFieldEncoder()])]]),
     ],
+    [
+      'AccountDataHash',
+      getStructEncoder([
+        ['hashType', getDataHashTypeEncoder()],
+        ['start', getOptionEncoder(getU16Encoder())],
+        ['length', getOptionEncoder(getU16Encoder())],
+      ]),
+    ],
+    [
+      'Rent',
+      getStructEncoder([['fields', getTupleEncoder([getRentFieldEncoder()])]]),
+    ],
+    [
+      'EpochSchedule',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getEpochScheduleFieldEncoder()])],
+      ]),
+    ],
+    ['RemainingComputeUnits', getUnitEncoder()],
+    ['InstructionIndex', getUnitEncoder()],
+    ['LatestSlotHash', getUnitEncoder()],
+    [
+      'Accumulate',
+      getStructEncoder([
+        ['operation', getAccumulateOperationEncoder()],
+        ['accumulator', getAccumulatorTypeEncoder()],
+        ['source', getAccumulateSourceEncoder()],
+      ]),
+    ],
+    [
+      'TokenAccountField',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getTokenAccountFieldEncoder()])],
+      ]),
+    ],
+    [
+      'MintAccountField',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getMintAccountFieldEncoder()])],
+      ]),
+    ],
+    ['AccountSnapshot', getStructEncoder([['dataLength', getU16Encoder()]])],
   ]);
 }
 
@@ -98,6 +218,48 @@ export function getWriteTypeDecoder(): Decoder<WriteType> {
       'Clock',
       getStructDecoder([['fields', getTupleDecoder([getClockFieldDecoder()])]]),
     ],
+    [
+      'AccountDataHash',
+      getStructDecoder([
+        ['hashType', getDataHashTypeDecoder()],
+        ['start', getOptionDecoder(getU16Decoder())],
+        ['length', getOptionDecoder(getU16Decoder())],
+      ]),
+    ],
+    [
+      'Rent',
+      getStructDecoder([['fields', getTupleDecoder([getRentFieldDecoder()])]]),
+    ],
+    [
+      'EpochSchedule',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getEpochScheduleFieldDecoder()])],
+      ]),
+    ],
+    ['RemainingComputeUnits', getUnitDecoder()],
+    ['InstructionIndex', getUnitDecoder()],
+    ['LatestSlotHash', getUnitDecoder()],
+    [
+      'Accumulate',
+      getStructDecoder([
+        ['operation', getAccumulateOperationDecoder()],
+        ['accumulator', getAccumulatorTypeDecoder()],
+        ['source', getAccumulateSourceDecoder()],
+      ]),
+    ],
+    [
+      'TokenAccountField',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getTokenAccountFieldDecoder()])],
+      ]),
+    ],
+    [
+      'MintAccountField',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getMintAccountFieldDecoder()])],
+      ]),
+    ],
+    ['AccountSnapshot', getStructDecoder([['dataLength', getU16Decoder()]])],
   ]);
 }
 
@@ -122,6 +284,43 @@ export function writeType(
   kind: 'Clock',
   data: GetDataEnumKindContent<WriteTypeArgs, 'Clock'>['fields']
 ): GetDataEnumKind<WriteTypeArgs, 'Clock'>;
+export function writeType(
+  kind: 'AccountDataHash',
+  data: GetDataEnumKindContent<WriteTypeArgs, 'AccountDataHash'>
+): GetDataEnumKind<WriteTypeArgs, 'AccountDataHash'>;
+export function writeType(
+  kind: 'Rent',
+  data: GetDataEnumKindContent<WriteTypeArgs, 'Rent'>['fields']
+): GetDataEnumKind<WriteTypeArgs, 'Rent'>;
+export function writeType(
+  kind: 'EpochSchedule',
+  data: GetDataEnumKindContent<WriteTypeArgs, 'EpochSchedule'>['fields']
+): GetDataEnumKind<WriteTypeArgs, 'EpochSchedule'>;
+export function writeType(
+  kind: 'RemainingComputeUnits'
+): GetDataEnumKind<WriteTypeArgs, 'RemainingComputeUnits'>;
+export function writeType(
+  kind: 'InstructionIndex'
+): GetDataEnumKind<WriteTypeArgs, 'InstructionIndex'>;
+export function writeType(
+  kind: 'LatestSlotHash'
+): GetDataEnumKind<WriteTypeArgs, 'LatestSlotHash'>;
+export function writeType(
+  kind: 'Accumulate',
+  data: GetDataEnumKindContent<WriteTypeArgs, 'Accumulate'>
+): GetDataEnumKind<WriteTypeArgs, 'Accumulate'>;
+export function writeType(
+  kind: 'TokenAccountField',
+  data: GetDataEnumKindContent<WriteTypeArgs, 'TokenAccountField'>['fields']
+): GetDataEnumKind<WriteTypeArgs, 'TokenAccountField'>;
+export function writeType(
+  kind: 'MintAccountField',
+  data: GetDataEnumKindContent<WriteTypeArgs, 'MintAccountField'>['fields']
+): GetDataEnumKind<WriteTypeArgs, 'MintAccountField'>;
+export function writeType(
+  kind: 'AccountSnapshot',
+  data: GetDataEnumKindContent<WriteTypeArgs, 'AccountSnapshot'>
+): GetDataEnumKind<WriteTypeArgs, 'AccountSnapshot'>;
 export function writeType<K extends WriteTypeArgs['__kind']>(
   kind: K,
   data?: any
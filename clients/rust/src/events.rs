@@ -0,0 +1,115 @@
+use crate::generated::types::AssertionResult;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+pub const ASSERTION_EVENT_DISCRIMINATOR: [u8; 8] = *b"LHASSERT";
+pub const ASSERTION_EVENT_VERSION: u8 = 1;
+
+/// Versioned envelope emitted by the program around each encoded assertion result.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct AssertionEvent {
+    pub discriminator: [u8; 8],
+    pub version: u8,
+    /// Lighthouse instruction discriminator.
+    pub instruction: u8,
+    /// Index of the assertion within Multi instructions, zero otherwise.
+    pub assertion_index: u16,
+    pub target_account: Pubkey,
+    /// Assertion variant index, or the data offset for account data assertions.
+    pub field: u16,
+    pub result: AssertionResult,
+}
+
+impl AssertionEvent {
+    /// Decodes an event, rejecting data without the event discriminator or from an unknown version.
+    pub fn decode(data: &[u8]) -> Result<Self, std::io::Error> {
+        if data.len() < 9 || data[..8] != ASSERTION_EVENT_DISCRIMINATOR {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "Not a lighthouse assertion event",
+            ));
+        }
+
+        if data[8] != ASSERTION_EVENT_VERSION {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("Unsupported assertion event version {}", data[8]),
+            ));
+        }
+
+        Self::try_from_slice(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AssertionEvent, ASSERTION_EVENT_DISCRIMINATOR, ASSERTION_EVENT_VERSION};
+    use crate::generated::types::{AssertionResult, LogLevel};
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use solana_program::pubkey::Pubkey;
+
+    fn event(result: AssertionResult) -> AssertionEvent {
+        AssertionEvent {
+            discriminator: ASSERTION_EVENT_DISCRIMINATOR,
+            version: ASSERTION_EVENT_VERSION,
+            instruction: 4,
+            assertion_index: 2,
+            target_account: Pubkey::new_unique(),
+            field: 64,
+            result,
+        }
+    }
+
+    #[test]
+    fn decode_round_trip() {
+        let results = vec![
+            AssertionResult::U8(Some(1), Some(2), 0, false),
+            AssertionResult::U64(Some(u64::MAX), None, 5, true),
+            AssertionResult::I128(Some(i128::MIN), Some(-1), 3, true),
+            AssertionResult::Pubkey(Some(Pubkey::new_unique()), None, 1, false),
+            AssertionResult::Bytes(vec![1, 2, 3], vec![1, 2, 3], 0, true),
+            AssertionResult::Bool(Some(true), Some(false), 1, true),
+            AssertionResult::F32(Some(1.5), None, 4, false),
+            AssertionResult::F64(Some(f64::MIN_POSITIVE), Some(-0.25), 2, true),
+            AssertionResult::BytesContains(Some(4), vec![0xde, 0xad], 0, true),
+            AssertionResult::BytesContains(None, vec![0xbe, 0xef], 0, false),
+            AssertionResult::Set(vec![3], vec![1, 2, 3], 0, true),
+        ];
+
+        for result in results {
+            let event = event(result);
+            let data = event.try_to_vec().unwrap();
+
+            assert_eq!(AssertionEvent::decode(&data).unwrap(), event);
+        }
+    }
+
+    #[test]
+    fn log_level_round_trip() {
+        let log_levels = [
+            (LogLevel::FailedPlaintextMessage, 4),
+            (LogLevel::FailedEncodedMessage, 5),
+            (LogLevel::Summary, 6),
+        ];
+
+        for (log_level, discriminant) in log_levels {
+            assert_eq!(log_level.try_to_vec().unwrap(), vec![discriminant]);
+            assert_eq!(LogLevel::try_from_slice(&[discriminant]).unwrap(), log_level);
+        }
+    }
+
+    #[test]
+    fn decode_rejects_foreign_data() {
+        let mut data = event(AssertionResult::U8(Some(1), Some(1), 0, true))
+            .try_to_vec()
+            .unwrap();
+
+        assert!(AssertionEvent::decode(&data[..8]).is_err());
+
+        data[8] = ASSERTION_EVENT_VERSION + 1;
+        assert!(AssertionEvent::decode(&data).is_err());
+
+        data[0] = 0;
+        assert!(AssertionEvent::decode(&data).is_err());
+    }
+}
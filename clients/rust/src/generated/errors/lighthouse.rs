@@ -61,6 +61,12 @@ pub enum LighthouseError {
     /// 6016 (0x1780) - CrossProgramInvokeViolation
     #[error("CrossProgramInvokeViolation")]
     CrossProgramInvokeViolation,
+    /// 6017 (0x1781) - ArithmeticOverflow
+    #[error("ArithmeticOverflow")]
+    ArithmeticOverflow,
+    /// 6018 (0x1782) - MemoryLocked
+    #[error("MemoryLocked")]
+    MemoryLocked,
 }
 
 impl solana_program::program_error::PrintProgramError for LighthouseError {
@@ -60,12 +60,13 @@ impl AssertAccountDataInstructionData {
     }
 }
 
-#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AssertAccountDataInstructionArgs {
     pub log_level: LogLevel,
     pub offset: u16,
     pub assertion: DataValueAssertion,
+    pub error_code: Option<u16>,
 }
 
 /// Instruction builder for `AssertAccountData`.
@@ -79,6 +80,7 @@ pub struct AssertAccountDataBuilder {
     log_level: Option<LogLevel>,
     offset: Option<u16>,
     assertion: Option<DataValueAssertion>,
+    error_code: Option<u16>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -108,6 +110,12 @@ impl AssertAccountDataBuilder {
         self.assertion = Some(assertion);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn error_code(&mut self, error_code: u16) -> &mut Self {
+        self.error_code = Some(error_code);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -135,6 +143,7 @@ impl AssertAccountDataBuilder {
             log_level: self.log_level.clone().unwrap_or(LogLevel::Silent),
             offset: self.offset.clone().expect("offset is not set"),
             assertion: self.assertion.clone().expect("assertion is not set"),
+            error_code: self.error_code.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -257,6 +266,7 @@ impl<'a, 'b> AssertAccountDataCpiBuilder<'a, 'b> {
             log_level: None,
             offset: None,
             assertion: None,
+            error_code: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -286,6 +296,12 @@ impl<'a, 'b> AssertAccountDataCpiBuilder<'a, 'b> {
         self.instruction.assertion = Some(assertion);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn error_code(&mut self, error_code: u16) -> &mut Self {
+        self.instruction.error_code = Some(error_code);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -339,6 +355,7 @@ impl<'a, 'b> AssertAccountDataCpiBuilder<'a, 'b> {
                 .assertion
                 .clone()
                 .expect("assertion is not set"),
+            error_code: self.instruction.error_code.clone(),
         };
         let instruction = AssertAccountDataCpi {
             __program: self.instruction.__program,
@@ -362,6 +379,7 @@ struct AssertAccountDataCpiBuilderInstruction<'a, 'b> {
     log_level: Option<LogLevel>,
     offset: Option<u16>,
     assertion: Option<DataValueAssertion>,
+    error_code: Option<u16>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
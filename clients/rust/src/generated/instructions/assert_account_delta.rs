@@ -71,6 +71,7 @@ impl AssertAccountDeltaInstructionData {
 pub struct AssertAccountDeltaInstructionArgs {
     pub log_level: LogLevel,
     pub assertion: AccountDeltaAssertion,
+    pub error_code: Option<u16>,
 }
 
 /// Instruction builder for `AssertAccountDelta`.
@@ -85,6 +86,7 @@ pub struct AssertAccountDeltaBuilder {
     account_b: Option<solana_program::pubkey::Pubkey>,
     log_level: Option<LogLevel>,
     assertion: Option<AccountDeltaAssertion>,
+    error_code: Option<u16>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -115,6 +117,12 @@ impl AssertAccountDeltaBuilder {
         self.assertion = Some(assertion);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn error_code(&mut self, error_code: u16) -> &mut Self {
+        self.error_code = Some(error_code);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -142,6 +150,7 @@ impl AssertAccountDeltaBuilder {
         let args = AssertAccountDeltaInstructionArgs {
             log_level: self.log_level.clone().unwrap_or(LogLevel::Silent),
             assertion: self.assertion.clone().expect("assertion is not set"),
+            error_code: self.error_code.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -275,6 +284,7 @@ impl<'a, 'b> AssertAccountDeltaCpiBuilder<'a, 'b> {
             account_b: None,
             log_level: None,
             assertion: None,
+            error_code: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -308,6 +318,12 @@ impl<'a, 'b> AssertAccountDeltaCpiBuilder<'a, 'b> {
         self.instruction.assertion = Some(assertion);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn error_code(&mut self, error_code: u16) -> &mut Self {
+        self.instruction.error_code = Some(error_code);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -360,6 +376,7 @@ impl<'a, 'b> AssertAccountDeltaCpiBuilder<'a, 'b> {
                 .assertion
                 .clone()
                 .expect("assertion is not set"),
+            error_code: self.instruction.error_code.clone(),
         };
         let instruction = AssertAccountDeltaCpi {
             __program: self.instruction.__program,
@@ -382,6 +399,7 @@ struct AssertAccountDeltaCpiBuilderInstruction<'a, 'b> {
     account_b: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     log_level: Option<LogLevel>,
     assertion: Option<AccountDeltaAssertion>,
+    error_code: Option<u16>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -6,6 +6,7 @@
 //!
 
 use crate::generated::types::AccountInfoAssertion;
+use crate::generated::types::EvaluationMode;
 use crate::generated::types::LogLevel;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
@@ -65,6 +66,8 @@ impl AssertAccountInfoMultiInstructionData {
 pub struct AssertAccountInfoMultiInstructionArgs {
     pub log_level: LogLevel,
     pub assertions: Vec<AccountInfoAssertion>,
+    pub evaluation_mode: EvaluationMode,
+    pub error_codes: Option<Vec<u16>>,
 }
 
 /// Instruction builder for `AssertAccountInfoMulti`.
@@ -77,6 +80,8 @@ pub struct AssertAccountInfoMultiBuilder {
     target_account: Option<solana_program::pubkey::Pubkey>,
     log_level: Option<LogLevel>,
     assertions: Option<Vec<AccountInfoAssertion>>,
+    evaluation_mode: Option<EvaluationMode>,
+    error_codes: Option<Vec<u16>>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -101,6 +106,18 @@ impl AssertAccountInfoMultiBuilder {
         self.assertions = Some(assertions);
         self
     }
+    /// `[optional argument, defaults to 'EvaluationMode::Strict']`
+    #[inline(always)]
+    pub fn evaluation_mode(&mut self, evaluation_mode: EvaluationMode) -> &mut Self {
+        self.evaluation_mode = Some(evaluation_mode);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn error_codes(&mut self, error_codes: Vec<u16>) -> &mut Self {
+        self.error_codes = Some(error_codes);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -127,6 +144,11 @@ impl AssertAccountInfoMultiBuilder {
         let args = AssertAccountInfoMultiInstructionArgs {
             log_level: self.log_level.clone().unwrap_or(LogLevel::Silent),
             assertions: self.assertions.clone().expect("assertions is not set"),
+            evaluation_mode: self
+                .evaluation_mode
+                .clone()
+                .unwrap_or(EvaluationMode::Strict),
+            error_codes: self.error_codes.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -248,6 +270,8 @@ impl<'a, 'b> AssertAccountInfoMultiCpiBuilder<'a, 'b> {
             target_account: None,
             log_level: None,
             assertions: None,
+            evaluation_mode: None,
+            error_codes: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -272,6 +296,18 @@ impl<'a, 'b> AssertAccountInfoMultiCpiBuilder<'a, 'b> {
         self.instruction.assertions = Some(assertions);
         self
     }
+    /// `[optional argument, defaults to 'EvaluationMode::Strict']`
+    #[inline(always)]
+    pub fn evaluation_mode(&mut self, evaluation_mode: EvaluationMode) -> &mut Self {
+        self.instruction.evaluation_mode = Some(evaluation_mode);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn error_codes(&mut self, error_codes: Vec<u16>) -> &mut Self {
+        self.instruction.error_codes = Some(error_codes);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -324,6 +360,12 @@ impl<'a, 'b> AssertAccountInfoMultiCpiBuilder<'a, 'b> {
                 .assertions
                 .clone()
                 .expect("assertions is not set"),
+            evaluation_mode: self
+                .instruction
+                .evaluation_mode
+                .clone()
+                .unwrap_or(EvaluationMode::Strict),
+            error_codes: self.instruction.error_codes.clone(),
         };
         let instruction = AssertAccountInfoMultiCpi {
             __program: self.instruction.__program,
@@ -346,6 +388,8 @@ struct AssertAccountInfoMultiCpiBuilderInstruction<'a, 'b> {
     target_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     log_level: Option<LogLevel>,
     assertions: Option<Vec<AccountInfoAssertion>>,
+    evaluation_mode: Option<EvaluationMode>,
+    error_codes: Option<Vec<u16>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
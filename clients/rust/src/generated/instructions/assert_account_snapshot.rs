@@ -0,0 +1,412 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::AccountSnapshotAssertion;
+use crate::generated::types::LogLevel;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct AssertAccountSnapshot {
+    /// Memory account holding the account snapshot
+    pub memory: solana_program::pubkey::Pubkey,
+    /// Live account compared against the snapshot
+    pub target_account: solana_program::pubkey::Pubkey,
+}
+
+impl AssertAccountSnapshot {
+    pub fn instruction(
+        &self,
+        args: AssertAccountSnapshotInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: AssertAccountSnapshotInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.memory,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.target_account,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = AssertAccountSnapshotInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::LIGHTHOUSE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+struct AssertAccountSnapshotInstructionData {
+    discriminator: u8,
+}
+
+impl AssertAccountSnapshotInstructionData {
+    fn new() -> Self {
+        Self { discriminator: 25 }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssertAccountSnapshotInstructionArgs {
+    pub log_level: LogLevel,
+    pub assertion: AccountSnapshotAssertion,
+    pub error_code: Option<u16>,
+}
+
+/// Instruction builder for `AssertAccountSnapshot`.
+///
+/// ### Accounts:
+///
+///   0. `[]` memory
+///   1. `[]` target_account
+#[derive(Default)]
+pub struct AssertAccountSnapshotBuilder {
+    memory: Option<solana_program::pubkey::Pubkey>,
+    target_account: Option<solana_program::pubkey::Pubkey>,
+    log_level: Option<LogLevel>,
+    assertion: Option<AccountSnapshotAssertion>,
+    error_code: Option<u16>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl AssertAccountSnapshotBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Memory account holding the account snapshot
+    #[inline(always)]
+    pub fn memory(&mut self, memory: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.memory = Some(memory);
+        self
+    }
+    /// Live account compared against the snapshot
+    #[inline(always)]
+    pub fn target_account(&mut self, target_account: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.target_account = Some(target_account);
+        self
+    }
+    /// `[optional argument, defaults to 'LogLevel::Silent']`
+    #[inline(always)]
+    pub fn log_level(&mut self, log_level: LogLevel) -> &mut Self {
+        self.log_level = Some(log_level);
+        self
+    }
+    #[inline(always)]
+    pub fn assertion(&mut self, assertion: AccountSnapshotAssertion) -> &mut Self {
+        self.assertion = Some(assertion);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn error_code(&mut self, error_code: u16) -> &mut Self {
+        self.error_code = Some(error_code);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = AssertAccountSnapshot {
+            memory: self.memory.expect("memory is not set"),
+            target_account: self.target_account.expect("target_account is not set"),
+        };
+        let args = AssertAccountSnapshotInstructionArgs {
+            log_level: self.log_level.clone().unwrap_or(LogLevel::Silent),
+            assertion: self.assertion.clone().expect("assertion is not set"),
+            error_code: self.error_code.clone(),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `assert_account_snapshot` CPI accounts.
+pub struct AssertAccountSnapshotCpiAccounts<'a, 'b> {
+    /// Memory account holding the account snapshot
+    pub memory: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Live account compared against the snapshot
+    pub target_account: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `assert_account_snapshot` CPI instruction.
+pub struct AssertAccountSnapshotCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Memory account holding the account snapshot
+    pub memory: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Live account compared against the snapshot
+    pub target_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: AssertAccountSnapshotInstructionArgs,
+}
+
+impl<'a, 'b> AssertAccountSnapshotCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: AssertAccountSnapshotCpiAccounts<'a, 'b>,
+        args: AssertAccountSnapshotInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            memory: accounts.memory,
+            target_account: accounts.target_account,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.memory.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.target_account.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = AssertAccountSnapshotInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::LIGHTHOUSE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(2 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.memory.clone());
+        account_infos.push(self.target_account.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `AssertAccountSnapshot` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` memory
+///   1. `[]` target_account
+pub struct AssertAccountSnapshotCpiBuilder<'a, 'b> {
+    instruction: Box<AssertAccountSnapshotCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> AssertAccountSnapshotCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(AssertAccountSnapshotCpiBuilderInstruction {
+            __program: program,
+            memory: None,
+            target_account: None,
+            log_level: None,
+            assertion: None,
+            error_code: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Memory account holding the account snapshot
+    #[inline(always)]
+    pub fn memory(
+        &mut self,
+        memory: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.memory = Some(memory);
+        self
+    }
+    /// Live account compared against the snapshot
+    #[inline(always)]
+    pub fn target_account(
+        &mut self,
+        target_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.target_account = Some(target_account);
+        self
+    }
+    /// `[optional argument, defaults to 'LogLevel::Silent']`
+    #[inline(always)]
+    pub fn log_level(&mut self, log_level: LogLevel) -> &mut Self {
+        self.instruction.log_level = Some(log_level);
+        self
+    }
+    #[inline(always)]
+    pub fn assertion(&mut self, assertion: AccountSnapshotAssertion) -> &mut Self {
+        self.instruction.assertion = Some(assertion);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn error_code(&mut self, error_code: u16) -> &mut Self {
+        self.instruction.error_code = Some(error_code);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = AssertAccountSnapshotInstructionArgs {
+            log_level: self
+                .instruction
+                .log_level
+                .clone()
+                .unwrap_or(LogLevel::Silent),
+            assertion: self
+                .instruction
+                .assertion
+                .clone()
+                .expect("assertion is not set"),
+            error_code: self.instruction.error_code.clone(),
+        };
+        let instruction = AssertAccountSnapshotCpi {
+            __program: self.instruction.__program,
+
+            memory: self.instruction.memory.expect("memory is not set"),
+
+            target_account: self
+                .instruction
+                .target_account
+                .expect("target_account is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct AssertAccountSnapshotCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    memory: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    target_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    log_level: Option<LogLevel>,
+    assertion: Option<AccountSnapshotAssertion>,
+    error_code: Option<u16>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
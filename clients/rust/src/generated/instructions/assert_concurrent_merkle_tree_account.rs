@@ -0,0 +1,378 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::ConcurrentMerkleTreeAssertion;
+use crate::generated::types::LogLevel;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct AssertConcurrentMerkleTreeAccount {
+    /// Target spl-account-compression merkle tree account to be asserted
+    pub target_merkle_tree: solana_program::pubkey::Pubkey,
+}
+
+impl AssertConcurrentMerkleTreeAccount {
+    pub fn instruction(
+        &self,
+        args: AssertConcurrentMerkleTreeAccountInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: AssertConcurrentMerkleTreeAccountInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(1 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.target_merkle_tree,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = AssertConcurrentMerkleTreeAccountInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::LIGHTHOUSE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+struct AssertConcurrentMerkleTreeAccountInstructionData {
+    discriminator: u8,
+}
+
+impl AssertConcurrentMerkleTreeAccountInstructionData {
+    fn new() -> Self {
+        Self { discriminator: 19 }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssertConcurrentMerkleTreeAccountInstructionArgs {
+    pub log_level: LogLevel,
+    pub assertion: ConcurrentMerkleTreeAssertion,
+    pub error_code: Option<u16>,
+}
+
+/// Instruction builder for `AssertConcurrentMerkleTreeAccount`.
+///
+/// ### Accounts:
+///
+///   0. `[]` target_merkle_tree
+#[derive(Default)]
+pub struct AssertConcurrentMerkleTreeAccountBuilder {
+    target_merkle_tree: Option<solana_program::pubkey::Pubkey>,
+    log_level: Option<LogLevel>,
+    assertion: Option<ConcurrentMerkleTreeAssertion>,
+    error_code: Option<u16>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl AssertConcurrentMerkleTreeAccountBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Target spl-account-compression merkle tree account to be asserted
+    #[inline(always)]
+    pub fn target_merkle_tree(
+        &mut self,
+        target_merkle_tree: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.target_merkle_tree = Some(target_merkle_tree);
+        self
+    }
+    /// `[optional argument, defaults to 'LogLevel::Silent']`
+    #[inline(always)]
+    pub fn log_level(&mut self, log_level: LogLevel) -> &mut Self {
+        self.log_level = Some(log_level);
+        self
+    }
+    #[inline(always)]
+    pub fn assertion(&mut self, assertion: ConcurrentMerkleTreeAssertion) -> &mut Self {
+        self.assertion = Some(assertion);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn error_code(&mut self, error_code: u16) -> &mut Self {
+        self.error_code = Some(error_code);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = AssertConcurrentMerkleTreeAccount {
+            target_merkle_tree: self
+                .target_merkle_tree
+                .expect("target_merkle_tree is not set"),
+        };
+        let args = AssertConcurrentMerkleTreeAccountInstructionArgs {
+            log_level: self.log_level.clone().unwrap_or(LogLevel::Silent),
+            assertion: self.assertion.clone().expect("assertion is not set"),
+            error_code: self.error_code.clone(),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `assert_concurrent_merkle_tree_account` CPI accounts.
+pub struct AssertConcurrentMerkleTreeAccountCpiAccounts<'a, 'b> {
+    /// Target spl-account-compression merkle tree account to be asserted
+    pub target_merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `assert_concurrent_merkle_tree_account` CPI instruction.
+pub struct AssertConcurrentMerkleTreeAccountCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Target spl-account-compression merkle tree account to be asserted
+    pub target_merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: AssertConcurrentMerkleTreeAccountInstructionArgs,
+}
+
+impl<'a, 'b> AssertConcurrentMerkleTreeAccountCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: AssertConcurrentMerkleTreeAccountCpiAccounts<'a, 'b>,
+        args: AssertConcurrentMerkleTreeAccountInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            target_merkle_tree: accounts.target_merkle_tree,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(1 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.target_merkle_tree.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = AssertConcurrentMerkleTreeAccountInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::LIGHTHOUSE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(1 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.target_merkle_tree.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `AssertConcurrentMerkleTreeAccount` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` target_merkle_tree
+pub struct AssertConcurrentMerkleTreeAccountCpiBuilder<'a, 'b> {
+    instruction: Box<AssertConcurrentMerkleTreeAccountCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> AssertConcurrentMerkleTreeAccountCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(AssertConcurrentMerkleTreeAccountCpiBuilderInstruction {
+            __program: program,
+            target_merkle_tree: None,
+            log_level: None,
+            assertion: None,
+            error_code: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Target spl-account-compression merkle tree account to be asserted
+    #[inline(always)]
+    pub fn target_merkle_tree(
+        &mut self,
+        target_merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.target_merkle_tree = Some(target_merkle_tree);
+        self
+    }
+    /// `[optional argument, defaults to 'LogLevel::Silent']`
+    #[inline(always)]
+    pub fn log_level(&mut self, log_level: LogLevel) -> &mut Self {
+        self.instruction.log_level = Some(log_level);
+        self
+    }
+    #[inline(always)]
+    pub fn assertion(&mut self, assertion: ConcurrentMerkleTreeAssertion) -> &mut Self {
+        self.instruction.assertion = Some(assertion);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn error_code(&mut self, error_code: u16) -> &mut Self {
+        self.instruction.error_code = Some(error_code);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = AssertConcurrentMerkleTreeAccountInstructionArgs {
+            log_level: self
+                .instruction
+                .log_level
+                .clone()
+                .unwrap_or(LogLevel::Silent),
+            assertion: self
+                .instruction
+                .assertion
+                .clone()
+                .expect("assertion is not set"),
+            error_code: self.instruction.error_code.clone(),
+        };
+        let instruction = AssertConcurrentMerkleTreeAccountCpi {
+            __program: self.instruction.__program,
+
+            target_merkle_tree: self
+                .instruction
+                .target_merkle_tree
+                .expect("target_merkle_tree is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct AssertConcurrentMerkleTreeAccountCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    target_merkle_tree: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    log_level: Option<LogLevel>,
+    assertion: Option<ConcurrentMerkleTreeAssertion>,
+    error_code: Option<u16>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
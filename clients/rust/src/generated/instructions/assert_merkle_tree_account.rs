@@ -76,6 +76,7 @@ impl AssertMerkleTreeAccountInstructionData {
 pub struct AssertMerkleTreeAccountInstructionArgs {
     pub log_level: LogLevel,
     pub assertion: MerkleTreeAssertion,
+    pub error_code: Option<u16>,
 }
 
 /// Instruction builder for `AssertMerkleTreeAccount`.
@@ -92,6 +93,7 @@ pub struct AssertMerkleTreeAccountBuilder {
     spl_account_compression: Option<solana_program::pubkey::Pubkey>,
     log_level: Option<LogLevel>,
     assertion: Option<MerkleTreeAssertion>,
+    error_code: Option<u16>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -134,6 +136,12 @@ impl AssertMerkleTreeAccountBuilder {
         self.assertion = Some(assertion);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn error_code(&mut self, error_code: u16) -> &mut Self {
+        self.error_code = Some(error_code);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -166,6 +174,7 @@ impl AssertMerkleTreeAccountBuilder {
         let args = AssertMerkleTreeAccountInstructionArgs {
             log_level: self.log_level.clone().unwrap_or(LogLevel::Silent),
             assertion: self.assertion.clone().expect("assertion is not set"),
+            error_code: self.error_code.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -311,6 +320,7 @@ impl<'a, 'b> AssertMerkleTreeAccountCpiBuilder<'a, 'b> {
             spl_account_compression: None,
             log_level: None,
             assertion: None,
+            error_code: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -350,6 +360,12 @@ impl<'a, 'b> AssertMerkleTreeAccountCpiBuilder<'a, 'b> {
         self.instruction.assertion = Some(assertion);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn error_code(&mut self, error_code: u16) -> &mut Self {
+        self.instruction.error_code = Some(error_code);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -402,6 +418,7 @@ impl<'a, 'b> AssertMerkleTreeAccountCpiBuilder<'a, 'b> {
                 .assertion
                 .clone()
                 .expect("assertion is not set"),
+            error_code: self.instruction.error_code.clone(),
         };
         let instruction = AssertMerkleTreeAccountCpi {
             __program: self.instruction.__program,
@@ -433,6 +450,7 @@ struct AssertMerkleTreeAccountCpiBuilderInstruction<'a, 'b> {
     spl_account_compression: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     log_level: Option<LogLevel>,
     assertion: Option<MerkleTreeAssertion>,
+    error_code: Option<u16>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
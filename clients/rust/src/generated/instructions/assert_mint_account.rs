@@ -65,6 +65,7 @@ impl AssertMintAccountInstructionData {
 pub struct AssertMintAccountInstructionArgs {
     pub log_level: LogLevel,
     pub assertion: MintAccountAssertion,
+    pub error_code: Option<u16>,
 }
 
 /// Instruction builder for `AssertMintAccount`.
@@ -77,6 +78,7 @@ pub struct AssertMintAccountBuilder {
     target_account: Option<solana_program::pubkey::Pubkey>,
     log_level: Option<LogLevel>,
     assertion: Option<MintAccountAssertion>,
+    error_code: Option<u16>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -101,6 +103,12 @@ impl AssertMintAccountBuilder {
         self.assertion = Some(assertion);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn error_code(&mut self, error_code: u16) -> &mut Self {
+        self.error_code = Some(error_code);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -127,6 +135,7 @@ impl AssertMintAccountBuilder {
         let args = AssertMintAccountInstructionArgs {
             log_level: self.log_level.clone().unwrap_or(LogLevel::Silent),
             assertion: self.assertion.clone().expect("assertion is not set"),
+            error_code: self.error_code.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -248,6 +257,7 @@ impl<'a, 'b> AssertMintAccountCpiBuilder<'a, 'b> {
             target_account: None,
             log_level: None,
             assertion: None,
+            error_code: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -272,6 +282,12 @@ impl<'a, 'b> AssertMintAccountCpiBuilder<'a, 'b> {
         self.instruction.assertion = Some(assertion);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn error_code(&mut self, error_code: u16) -> &mut Self {
+        self.instruction.error_code = Some(error_code);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -324,6 +340,7 @@ impl<'a, 'b> AssertMintAccountCpiBuilder<'a, 'b> {
                 .assertion
                 .clone()
                 .expect("assertion is not set"),
+            error_code: self.instruction.error_code.clone(),
         };
         let instruction = AssertMintAccountCpi {
             __program: self.instruction.__program,
@@ -346,6 +363,7 @@ struct AssertMintAccountCpiBuilderInstruction<'a, 'b> {
     target_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     log_level: Option<LogLevel>,
     assertion: Option<MintAccountAssertion>,
+    error_code: Option<u16>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
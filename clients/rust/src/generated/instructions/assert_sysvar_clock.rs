@@ -58,6 +58,7 @@ impl AssertSysvarClockInstructionData {
 pub struct AssertSysvarClockInstructionArgs {
     pub log_level: LogLevel,
     pub assertion: SysvarClockAssertion,
+    pub error_code: Option<u16>,
 }
 
 /// Instruction builder for `AssertSysvarClock`.
@@ -68,6 +69,7 @@ pub struct AssertSysvarClockInstructionArgs {
 pub struct AssertSysvarClockBuilder {
     log_level: Option<LogLevel>,
     assertion: Option<SysvarClockAssertion>,
+    error_code: Option<u16>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -86,6 +88,12 @@ impl AssertSysvarClockBuilder {
         self.assertion = Some(assertion);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn error_code(&mut self, error_code: u16) -> &mut Self {
+        self.error_code = Some(error_code);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -110,6 +118,7 @@ impl AssertSysvarClockBuilder {
         let args = AssertSysvarClockInstructionArgs {
             log_level: self.log_level.clone().unwrap_or(LogLevel::Silent),
             assertion: self.assertion.clone().expect("assertion is not set"),
+            error_code: self.error_code.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -214,6 +223,7 @@ impl<'a, 'b> AssertSysvarClockCpiBuilder<'a, 'b> {
             __program: program,
             log_level: None,
             assertion: None,
+            error_code: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -229,6 +239,12 @@ impl<'a, 'b> AssertSysvarClockCpiBuilder<'a, 'b> {
         self.instruction.assertion = Some(assertion);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn error_code(&mut self, error_code: u16) -> &mut Self {
+        self.instruction.error_code = Some(error_code);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -281,6 +297,7 @@ impl<'a, 'b> AssertSysvarClockCpiBuilder<'a, 'b> {
                 .assertion
                 .clone()
                 .expect("assertion is not set"),
+            error_code: self.instruction.error_code.clone(),
         };
         let instruction = AssertSysvarClockCpi {
             __program: self.instruction.__program,
@@ -297,6 +314,7 @@ struct AssertSysvarClockCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_program::account_info::AccountInfo<'a>,
     log_level: Option<LogLevel>,
     assertion: Option<SysvarClockAssertion>,
+    error_code: Option<u16>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
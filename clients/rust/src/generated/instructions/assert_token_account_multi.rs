@@ -5,6 +5,7 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::EvaluationMode;
 use crate::generated::types::LogLevel;
 use crate::generated::types::TokenAccountAssertion;
 use borsh::BorshDeserialize;
@@ -65,6 +66,8 @@ impl AssertTokenAccountMultiInstructionData {
 pub struct AssertTokenAccountMultiInstructionArgs {
     pub log_level: LogLevel,
     pub assertions: Vec<TokenAccountAssertion>,
+    pub evaluation_mode: EvaluationMode,
+    pub error_codes: Option<Vec<u16>>,
 }
 
 /// Instruction builder for `AssertTokenAccountMulti`.
@@ -77,6 +80,8 @@ pub struct AssertTokenAccountMultiBuilder {
     target_account: Option<solana_program::pubkey::Pubkey>,
     log_level: Option<LogLevel>,
     assertions: Option<Vec<TokenAccountAssertion>>,
+    evaluation_mode: Option<EvaluationMode>,
+    error_codes: Option<Vec<u16>>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -101,6 +106,18 @@ impl AssertTokenAccountMultiBuilder {
         self.assertions = Some(assertions);
         self
     }
+    /// `[optional argument, defaults to 'EvaluationMode::Strict']`
+    #[inline(always)]
+    pub fn evaluation_mode(&mut self, evaluation_mode: EvaluationMode) -> &mut Self {
+        self.evaluation_mode = Some(evaluation_mode);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn error_codes(&mut self, error_codes: Vec<u16>) -> &mut Self {
+        self.error_codes = Some(error_codes);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -127,6 +144,11 @@ impl AssertTokenAccountMultiBuilder {
         let args = AssertTokenAccountMultiInstructionArgs {
             log_level: self.log_level.clone().unwrap_or(LogLevel::Silent),
             assertions: self.assertions.clone().expect("assertions is not set"),
+            evaluation_mode: self
+                .evaluation_mode
+                .clone()
+                .unwrap_or(EvaluationMode::Strict),
+            error_codes: self.error_codes.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -248,6 +270,8 @@ impl<'a, 'b> AssertTokenAccountMultiCpiBuilder<'a, 'b> {
             target_account: None,
             log_level: None,
             assertions: None,
+            evaluation_mode: None,
+            error_codes: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -272,6 +296,18 @@ impl<'a, 'b> AssertTokenAccountMultiCpiBuilder<'a, 'b> {
         self.instruction.assertions = Some(assertions);
         self
     }
+    /// `[optional argument, defaults to 'EvaluationMode::Strict']`
+    #[inline(always)]
+    pub fn evaluation_mode(&mut self, evaluation_mode: EvaluationMode) -> &mut Self {
+        self.instruction.evaluation_mode = Some(evaluation_mode);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn error_codes(&mut self, error_codes: Vec<u16>) -> &mut Self {
+        self.instruction.error_codes = Some(error_codes);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -324,6 +360,12 @@ impl<'a, 'b> AssertTokenAccountMultiCpiBuilder<'a, 'b> {
                 .assertions
                 .clone()
                 .expect("assertions is not set"),
+            evaluation_mode: self
+                .instruction
+                .evaluation_mode
+                .clone()
+                .unwrap_or(EvaluationMode::Strict),
+            error_codes: self.instruction.error_codes.clone(),
         };
         let instruction = AssertTokenAccountMultiCpi {
             __program: self.instruction.__program,
@@ -346,6 +388,8 @@ struct AssertTokenAccountMultiCpiBuilderInstruction<'a, 'b> {
     target_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     log_level: Option<LogLevel>,
     assertions: Option<Vec<TokenAccountAssertion>>,
+    evaluation_mode: Option<EvaluationMode>,
+    error_codes: Option<Vec<u16>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -113,6 +113,7 @@ impl MemoryCloseBuilder {
         self.memory = Some(memory);
         self
     }
+    /// `[optional argument, defaults to '0']`
     #[inline(always)]
     pub fn memory_id(&mut self, memory_id: u8) -> &mut Self {
         self.memory_id = Some(memory_id);
@@ -149,7 +150,7 @@ impl MemoryCloseBuilder {
             memory: self.memory.expect("memory is not set"),
         };
         let args = MemoryCloseInstructionArgs {
-            memory_id: self.memory_id.clone().expect("memory_id is not set"),
+            memory_id: self.memory_id.clone().unwrap_or(0),
             memory_bump: self.memory_bump.clone().expect("memory_bump is not set"),
         };
 
@@ -322,6 +323,7 @@ impl<'a, 'b> MemoryCloseCpiBuilder<'a, 'b> {
         self.instruction.memory = Some(memory);
         self
     }
+    /// `[optional argument, defaults to '0']`
     #[inline(always)]
     pub fn memory_id(&mut self, memory_id: u8) -> &mut Self {
         self.instruction.memory_id = Some(memory_id);
@@ -374,11 +376,7 @@ impl<'a, 'b> MemoryCloseCpiBuilder<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
         let args = MemoryCloseInstructionArgs {
-            memory_id: self
-                .instruction
-                .memory_id
-                .clone()
-                .expect("memory_id is not set"),
+            memory_id: self.instruction.memory_id.clone().unwrap_or(0),
             memory_bump: self
                 .instruction
                 .memory_bump
@@ -6,37 +6,65 @@
 //!
 
 pub(crate) mod r#assert_account_data;
+pub(crate) mod r#assert_account_data_path;
 pub(crate) mod r#assert_account_delta;
 pub(crate) mod r#assert_account_info;
 pub(crate) mod r#assert_account_info_multi;
+pub(crate) mod r#assert_account_snapshot;
 pub(crate) mod r#assert_bubblegum_tree_config_account;
+pub(crate) mod r#assert_concurrent_merkle_tree_account;
+pub(crate) mod r#assert_concurrent_merkle_tree_account_multi;
+pub(crate) mod r#assert_memory_header;
 pub(crate) mod r#assert_merkle_tree_account;
 pub(crate) mod r#assert_mint_account;
 pub(crate) mod r#assert_mint_account_multi;
 pub(crate) mod r#assert_stake_account;
 pub(crate) mod r#assert_stake_account_multi;
+pub(crate) mod r#assert_stake_pool_account;
+pub(crate) mod r#assert_stake_pool_account_multi;
 pub(crate) mod r#assert_sysvar_clock;
 pub(crate) mod r#assert_token_account;
 pub(crate) mod r#assert_token_account_multi;
 pub(crate) mod r#assert_upgradeable_loader_account;
 pub(crate) mod r#assert_upgradeable_loader_account_multi;
 pub(crate) mod r#memory_close;
+pub(crate) mod r#memory_close_to_recipient;
+pub(crate) mod r#memory_close_with_authority;
+pub(crate) mod r#memory_init_header;
+pub(crate) mod r#memory_shrink;
 pub(crate) mod r#memory_write;
+pub(crate) mod r#memory_write_multi;
+pub(crate) mod r#memory_write_with_authority;
+pub(crate) mod r#memory_zero;
 
 pub use self::r#assert_account_data::*;
+pub use self::r#assert_account_data_path::*;
 pub use self::r#assert_account_delta::*;
 pub use self::r#assert_account_info::*;
 pub use self::r#assert_account_info_multi::*;
+pub use self::r#assert_account_snapshot::*;
 pub use self::r#assert_bubblegum_tree_config_account::*;
+pub use self::r#assert_concurrent_merkle_tree_account::*;
+pub use self::r#assert_concurrent_merkle_tree_account_multi::*;
+pub use self::r#assert_memory_header::*;
 pub use self::r#assert_merkle_tree_account::*;
 pub use self::r#assert_mint_account::*;
 pub use self::r#assert_mint_account_multi::*;
 pub use self::r#assert_stake_account::*;
 pub use self::r#assert_stake_account_multi::*;
+pub use self::r#assert_stake_pool_account::*;
+pub use self::r#assert_stake_pool_account_multi::*;
 pub use self::r#assert_sysvar_clock::*;
 pub use self::r#assert_token_account::*;
 pub use self::r#assert_token_account_multi::*;
 pub use self::r#assert_upgradeable_loader_account::*;
 pub use self::r#assert_upgradeable_loader_account_multi::*;
 pub use self::r#memory_close::*;
+pub use self::r#memory_close_to_recipient::*;
+pub use self::r#memory_close_with_authority::*;
+pub use self::r#memory_init_header::*;
+pub use self::r#memory_shrink::*;
 pub use self::r#memory_write::*;
+pub use self::r#memory_write_multi::*;
+pub use self::r#memory_write_with_authority::*;
+pub use self::r#memory_zero::*;
@@ -6,7 +6,9 @@
 //!
 
 use crate::generated::types::AccountInfoDeltaAssertion;
+use crate::generated::types::DataHashType;
 use crate::generated::types::DataValueDeltaAssertion;
+use crate::generated::types::EquatableOperator;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 
@@ -22,4 +24,11 @@ pub enum AccountDeltaAssertion {
         b_offset: u16,
         assertion: DataValueDeltaAssertion,
     },
+    DataHash {
+        a_offset: u16,
+        hash_type: DataHashType,
+        start: Option<u16>,
+        length: Option<u16>,
+        operator: EquatableOperator,
+    },
 }
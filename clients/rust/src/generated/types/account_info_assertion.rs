@@ -5,9 +5,11 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::AnchorDiscriminatorValue;
 use crate::generated::types::EquatableOperator;
 use crate::generated::types::IntegerOperator;
 use crate::generated::types::KnownProgram;
+use crate::generated::types::SetOperator;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
@@ -56,4 +58,12 @@ pub enum AccountInfoAssertion {
         start: Option<u16>,
         length: Option<u16>,
     },
+    AnchorDiscriminator {
+        value: AnchorDiscriminatorValue,
+        operator: EquatableOperator,
+    },
+    OwnerIn {
+        values: Vec<Pubkey>,
+        operator: SetOperator,
+    },
 }
@@ -0,0 +1,29 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::MintAccountSnapshotAssertion;
+use crate::generated::types::StakeAccountSnapshotAssertion;
+use crate::generated::types::TokenAccountSnapshotAssertion;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AccountSnapshotAssertion {
+    TokenAccount {
+        memory_offset: u16,
+        assertion: TokenAccountSnapshotAssertion,
+    },
+    MintAccount {
+        memory_offset: u16,
+        assertion: MintAccountSnapshotAssertion,
+    },
+    StakeAccount {
+        memory_offset: u16,
+        assertion: StakeAccountSnapshotAssertion,
+    },
+}
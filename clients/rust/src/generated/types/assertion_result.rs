@@ -9,7 +9,7 @@ use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
 
-#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AssertionResult {
     U8(Option<u8>, Option<u8>, u8, bool),
@@ -25,4 +25,8 @@ pub enum AssertionResult {
     Pubkey(Option<Pubkey>, Option<Pubkey>, u8, bool),
     Bytes(Vec<u8>, Vec<u8>, u8, bool),
     Bool(Option<bool>, Option<bool>, u8, bool),
+    F32(Option<f32>, Option<f32>, u8, bool),
+    F64(Option<f64>, Option<f64>, u8, bool),
+    BytesContains(Option<u32>, Vec<u8>, u8, bool),
+    Set(Vec<u8>, Vec<u8>, u8, bool),
 }
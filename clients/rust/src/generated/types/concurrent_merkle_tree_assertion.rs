@@ -0,0 +1,53 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::EquatableOperator;
+use crate::generated::types::IntegerOperator;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConcurrentMerkleTreeAssertion {
+    MaxDepth {
+        value: u32,
+        operator: IntegerOperator,
+    },
+    MaxBufferSize {
+        value: u32,
+        operator: IntegerOperator,
+    },
+    Authority {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        value: Pubkey,
+        operator: EquatableOperator,
+    },
+    CreationSlot {
+        value: u64,
+        operator: IntegerOperator,
+    },
+    SequenceNumber {
+        value: u64,
+        operator: IntegerOperator,
+    },
+    ActiveIndex {
+        value: u64,
+        operator: IntegerOperator,
+    },
+    RightmostIndex {
+        value: u32,
+        operator: IntegerOperator,
+    },
+    IsFull {
+        value: bool,
+        operator: EquatableOperator,
+    },
+}
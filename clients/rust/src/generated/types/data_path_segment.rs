@@ -0,0 +1,27 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::BorshFieldType;
+use crate::generated::types::PointerSize;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DataPathSegment {
+    Skip(Vec<BorshFieldType>),
+    VecElement {
+        element: BorshFieldType,
+        index: u32,
+    },
+    OptionSome,
+    Pointer {
+        at: u16,
+        size: PointerSize,
+        stride: u32,
+    },
+}
@@ -5,13 +5,18 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::BytesOperator;
+use crate::generated::types::Endianness;
 use crate::generated::types::EquatableOperator;
+use crate::generated::types::FixedPointScale;
+use crate::generated::types::FloatOperator;
 use crate::generated::types::IntegerOperator;
+use crate::generated::types::IntegerSize;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
 
-#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DataValueAssertion {
     Bool {
@@ -60,7 +65,7 @@ pub enum DataValueAssertion {
     },
     Bytes {
         value: Vec<u8>,
-        operator: EquatableOperator,
+        operator: BytesOperator,
     },
     Pubkey {
         #[cfg_attr(
@@ -70,4 +75,32 @@ pub enum DataValueAssertion {
         value: Pubkey,
         operator: EquatableOperator,
     },
+    UnsignedInteger {
+        value: u128,
+        size: IntegerSize,
+        endianness: Endianness,
+        operator: IntegerOperator,
+    },
+    SignedInteger {
+        value: i128,
+        size: IntegerSize,
+        endianness: Endianness,
+        operator: IntegerOperator,
+    },
+    FixedPoint {
+        value: u128,
+        decimals: u8,
+        size: IntegerSize,
+        endianness: Endianness,
+        scale: FixedPointScale,
+        operator: IntegerOperator,
+    },
+    F32 {
+        value: f32,
+        operator: FloatOperator,
+    },
+    F64 {
+        value: f64,
+        operator: FloatOperator,
+    },
 }
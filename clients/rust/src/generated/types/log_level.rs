@@ -15,4 +15,7 @@ pub enum LogLevel {
     PlaintextMessage,
     EncodedMessage,
     EncodedNoop,
+    FailedPlaintextMessage,
+    FailedEncodedMessage,
+    Summary,
 }
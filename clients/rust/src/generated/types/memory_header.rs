@@ -0,0 +1,23 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::LockedRange;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemoryHeader {
+    pub discriminator: [u8; 8],
+    pub slot: u64,
+    pub instruction_index: u16,
+    pub write_count: u32,
+    pub flags: u8,
+    pub locked_range_count: u8,
+    pub locked_ranges: [LockedRange; 8],
+    pub locked_slot: u64,
+}
@@ -0,0 +1,36 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::EquatableOperator;
+use crate::generated::types::IntegerOperator;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MemoryHeaderAssertion {
+    Slot {
+        value: u64,
+        operator: IntegerOperator,
+    },
+    WrittenInCurrentSlot {
+        value: bool,
+        operator: EquatableOperator,
+    },
+    InstructionIndex {
+        value: u16,
+        operator: IntegerOperator,
+    },
+    WriteCount {
+        value: u32,
+        operator: IntegerOperator,
+    },
+    IsLocked {
+        value: bool,
+        operator: EquatableOperator,
+    },
+}
@@ -7,6 +7,7 @@
 
 use crate::generated::types::EquatableOperator;
 use crate::generated::types::IntegerOperator;
+use crate::generated::types::SetOperator;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
@@ -50,4 +51,24 @@ pub enum MetaAssertion {
         value: Pubkey,
         operator: EquatableOperator,
     },
+    AuthorizedStakerIn {
+        values: Vec<Pubkey>,
+        operator: SetOperator,
+    },
+    AuthorizedWithdrawerIn {
+        values: Vec<Pubkey>,
+        operator: SetOperator,
+    },
+    RentExemptReserveIn {
+        values: Vec<u64>,
+        operator: SetOperator,
+    },
+    LockupUnixTimestampIn {
+        values: Vec<i64>,
+        operator: SetOperator,
+    },
+    LockupEpochIn {
+        values: Vec<u64>,
+        operator: SetOperator,
+    },
 }
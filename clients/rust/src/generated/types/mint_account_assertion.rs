@@ -7,6 +7,7 @@
 
 use crate::generated::types::EquatableOperator;
 use crate::generated::types::IntegerOperator;
+use crate::generated::types::SetOperator;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
@@ -34,4 +35,20 @@ pub enum MintAccountAssertion {
         value: Option<Pubkey>,
         operator: EquatableOperator,
     },
+    MintAuthorityIn {
+        values: Vec<Option<Pubkey>>,
+        operator: SetOperator,
+    },
+    DecimalsIn {
+        values: Vec<u8>,
+        operator: SetOperator,
+    },
+    FreezeAuthorityIn {
+        values: Vec<Option<Pubkey>>,
+        operator: SetOperator,
+    },
+    SupplyIn {
+        values: Vec<u64>,
+        operator: SetOperator,
+    },
 }
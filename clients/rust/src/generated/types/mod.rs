@@ -9,24 +9,56 @@ pub(crate) mod r#account_delta_assertion;
 pub(crate) mod r#account_info_assertion;
 pub(crate) mod r#account_info_delta_assertion;
 pub(crate) mod r#account_info_field;
+pub(crate) mod r#account_snapshot_assertion;
+pub(crate) mod r#accumulate_operation;
+pub(crate) mod r#accumulate_source;
+pub(crate) mod r#accumulator_type;
+pub(crate) mod r#anchor_discriminator_value;
 pub(crate) mod r#assertion_result;
+pub(crate) mod r#borsh_field_type;
 pub(crate) mod r#bubblegum_tree_config_assertion;
+pub(crate) mod r#bytes_operator;
 pub(crate) mod r#clock_field;
+pub(crate) mod r#concurrent_merkle_tree_assertion;
+pub(crate) mod r#data_hash_type;
+pub(crate) mod r#data_path_segment;
 pub(crate) mod r#data_value;
 pub(crate) mod r#data_value_assertion;
 pub(crate) mod r#data_value_delta_assertion;
+pub(crate) mod r#endianness;
+pub(crate) mod r#epoch_schedule_field;
 pub(crate) mod r#equatable_operator;
+pub(crate) mod r#evaluation_mode;
+pub(crate) mod r#fixed_point_scale;
+pub(crate) mod r#float_operator;
+pub(crate) mod r#float_tolerance;
+pub(crate) mod r#integer_data_type;
 pub(crate) mod r#integer_operator;
+pub(crate) mod r#integer_size;
 pub(crate) mod r#known_program;
+pub(crate) mod r#locked_range;
 pub(crate) mod r#log_level;
+pub(crate) mod r#memory_header;
+pub(crate) mod r#memory_header_assertion;
+pub(crate) mod r#memory_write_entry;
 pub(crate) mod r#merkle_tree_assertion;
 pub(crate) mod r#meta_assertion;
 pub(crate) mod r#mint_account_assertion;
+pub(crate) mod r#mint_account_field;
+pub(crate) mod r#mint_account_snapshot_assertion;
+pub(crate) mod r#pointer_size;
+pub(crate) mod r#rent_field;
+pub(crate) mod r#set_operator;
 pub(crate) mod r#stake_account_assertion;
+pub(crate) mod r#stake_account_snapshot_assertion;
 pub(crate) mod r#stake_assertion;
+pub(crate) mod r#stake_pool_account_assertion;
+pub(crate) mod r#stake_pool_fee_assertion;
 pub(crate) mod r#stake_state_type;
 pub(crate) mod r#sysvar_clock_assertion;
 pub(crate) mod r#token_account_assertion;
+pub(crate) mod r#token_account_field;
+pub(crate) mod r#token_account_snapshot_assertion;
 pub(crate) mod r#upgradable_buffer_assertion;
 pub(crate) mod r#upgradeable_loader_state_assertion;
 pub(crate) mod r#upgradeable_loader_state_type;
@@ -38,24 +70,56 @@ pub use self::r#account_delta_assertion::*;
 pub use self::r#account_info_assertion::*;
 pub use self::r#account_info_delta_assertion::*;
 pub use self::r#account_info_field::*;
+pub use self::r#account_snapshot_assertion::*;
+pub use self::r#accumulate_operation::*;
+pub use self::r#accumulate_source::*;
+pub use self::r#accumulator_type::*;
+pub use self::r#anchor_discriminator_value::*;
 pub use self::r#assertion_result::*;
+pub use self::r#borsh_field_type::*;
 pub use self::r#bubblegum_tree_config_assertion::*;
+pub use self::r#bytes_operator::*;
 pub use self::r#clock_field::*;
+pub use self::r#concurrent_merkle_tree_assertion::*;
+pub use self::r#data_hash_type::*;
+pub use self::r#data_path_segment::*;
 pub use self::r#data_value::*;
 pub use self::r#data_value_assertion::*;
 pub use self::r#data_value_delta_assertion::*;
+pub use self::r#endianness::*;
+pub use self::r#epoch_schedule_field::*;
 pub use self::r#equatable_operator::*;
+pub use self::r#evaluation_mode::*;
+pub use self::r#fixed_point_scale::*;
+pub use self::r#float_operator::*;
+pub use self::r#float_tolerance::*;
+pub use self::r#integer_data_type::*;
 pub use self::r#integer_operator::*;
+pub use self::r#integer_size::*;
 pub use self::r#known_program::*;
+pub use self::r#locked_range::*;
 pub use self::r#log_level::*;
+pub use self::r#memory_header::*;
+pub use self::r#memory_header_assertion::*;
+pub use self::r#memory_write_entry::*;
 pub use self::r#merkle_tree_assertion::*;
 pub use self::r#meta_assertion::*;
 pub use self::r#mint_account_assertion::*;
+pub use self::r#mint_account_field::*;
+pub use self::r#mint_account_snapshot_assertion::*;
+pub use self::r#pointer_size::*;
+pub use self::r#rent_field::*;
+pub use self::r#set_operator::*;
 pub use self::r#stake_account_assertion::*;
+pub use self::r#stake_account_snapshot_assertion::*;
 pub use self::r#stake_assertion::*;
+pub use self::r#stake_pool_account_assertion::*;
+pub use self::r#stake_pool_fee_assertion::*;
 pub use self::r#stake_state_type::*;
 pub use self::r#sysvar_clock_assertion::*;
 pub use self::r#token_account_assertion::*;
+pub use self::r#token_account_field::*;
+pub use self::r#token_account_snapshot_assertion::*;
 pub use self::r#upgradable_buffer_assertion::*;
 pub use self::r#upgradeable_loader_state_assertion::*;
 pub use self::r#upgradeable_loader_state_type::*;
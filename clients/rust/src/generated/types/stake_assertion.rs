@@ -7,6 +7,7 @@
 
 use crate::generated::types::EquatableOperator;
 use crate::generated::types::IntegerOperator;
+use crate::generated::types::SetOperator;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
@@ -38,4 +39,24 @@ pub enum StakeAssertion {
         value: u64,
         operator: IntegerOperator,
     },
+    DelegationVoterPubkeyIn {
+        values: Vec<Pubkey>,
+        operator: SetOperator,
+    },
+    DelegationStakeIn {
+        values: Vec<u64>,
+        operator: SetOperator,
+    },
+    DelegationActivationEpochIn {
+        values: Vec<u64>,
+        operator: SetOperator,
+    },
+    DelegationDeactivationEpochIn {
+        values: Vec<u64>,
+        operator: SetOperator,
+    },
+    CreditsObservedIn {
+        values: Vec<u64>,
+        operator: SetOperator,
+    },
 }
@@ -0,0 +1,79 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::EquatableOperator;
+use crate::generated::types::IntegerOperator;
+use crate::generated::types::StakePoolFeeAssertion;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StakePoolAccountAssertion {
+    Manager {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        value: Pubkey,
+        operator: EquatableOperator,
+    },
+    Staker {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        value: Pubkey,
+        operator: EquatableOperator,
+    },
+    PoolMint {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        value: Pubkey,
+        operator: EquatableOperator,
+    },
+    TotalLamports {
+        value: u64,
+        operator: IntegerOperator,
+    },
+    PoolTokenSupply {
+        value: u64,
+        operator: IntegerOperator,
+    },
+    ExchangeRate {
+        value: u128,
+        operator: IntegerOperator,
+    },
+    LastUpdateEpoch {
+        value: u64,
+        operator: IntegerOperator,
+    },
+    LastEpochTotalLamports {
+        value: u64,
+        operator: IntegerOperator,
+    },
+    LastEpochPoolTokenSupply {
+        value: u64,
+        operator: IntegerOperator,
+    },
+    EpochFee(StakePoolFeeAssertion),
+    StakeDepositFee(StakePoolFeeAssertion),
+    StakeWithdrawalFee(StakePoolFeeAssertion),
+    SolDepositFee(StakePoolFeeAssertion),
+    SolWithdrawalFee(StakePoolFeeAssertion),
+    StakeReferralFee {
+        value: u8,
+        operator: IntegerOperator,
+    },
+    SolReferralFee {
+        value: u8,
+        operator: IntegerOperator,
+    },
+}
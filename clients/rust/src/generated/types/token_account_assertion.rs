@@ -7,6 +7,7 @@
 
 use crate::generated::types::EquatableOperator;
 use crate::generated::types::IntegerOperator;
+use crate::generated::types::SetOperator;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
@@ -55,4 +56,32 @@ pub enum TokenAccountAssertion {
         operator: EquatableOperator,
     },
     TokenAccountOwnerIsDerived,
+    MintIn {
+        values: Vec<Pubkey>,
+        operator: SetOperator,
+    },
+    OwnerIn {
+        values: Vec<Pubkey>,
+        operator: SetOperator,
+    },
+    DelegateIn {
+        values: Vec<Option<Pubkey>>,
+        operator: SetOperator,
+    },
+    StateIn {
+        values: Vec<u8>,
+        operator: SetOperator,
+    },
+    CloseAuthorityIn {
+        values: Vec<Option<Pubkey>>,
+        operator: SetOperator,
+    },
+    AmountIn {
+        values: Vec<u64>,
+        operator: SetOperator,
+    },
+    DelegatedAmountIn {
+        values: Vec<u64>,
+        operator: SetOperator,
+    },
 }
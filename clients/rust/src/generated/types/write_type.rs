@@ -6,16 +6,47 @@
 //!
 
 use crate::generated::types::AccountInfoField;
+use crate::generated::types::AccumulateOperation;
+use crate::generated::types::AccumulateSource;
+use crate::generated::types::AccumulatorType;
 use crate::generated::types::ClockField;
+use crate::generated::types::DataHashType;
 use crate::generated::types::DataValue;
+use crate::generated::types::EpochScheduleField;
+use crate::generated::types::MintAccountField;
+use crate::generated::types::RentField;
+use crate::generated::types::TokenAccountField;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WriteType {
-    AccountData { offset: u16, data_length: u16 },
+    AccountData {
+        offset: u16,
+        data_length: u16,
+    },
     AccountInfoField(AccountInfoField),
     DataValue(DataValue),
     Clock(ClockField),
+    AccountDataHash {
+        hash_type: DataHashType,
+        start: Option<u16>,
+        length: Option<u16>,
+    },
+    Rent(RentField),
+    EpochSchedule(EpochScheduleField),
+    RemainingComputeUnits,
+    InstructionIndex,
+    LatestSlotHash,
+    Accumulate {
+        operation: AccumulateOperation,
+        accumulator: AccumulatorType,
+        source: AccumulateSource,
+    },
+    TokenAccountField(TokenAccountField),
+    MintAccountField(MintAccountField),
+    AccountSnapshot {
+        data_length: u16,
+    },
 }
@@ -10,27 +10,41 @@ pub use generated::types;
 
 pub mod instructions {
     pub use crate::generated::instructions::{
-        AssertAccountDataBuilder, AssertAccountDeltaBuilder, AssertAccountInfoBuilder,
-        AssertBubblegumTreeConfigAccountBuilder, AssertMerkleTreeAccountBuilder,
-        AssertMintAccountBuilder, AssertMintAccountMultiBuilder, AssertStakeAccountBuilder,
-        AssertStakeAccountMultiBuilder, AssertSysvarClockBuilder, AssertTokenAccountBuilder,
+        AssertAccountDataBuilder, AssertAccountDataPathBuilder, AssertAccountDeltaBuilder,
+        AssertAccountInfoBuilder, AssertAccountSnapshotBuilder,
+        AssertBubblegumTreeConfigAccountBuilder, AssertConcurrentMerkleTreeAccountBuilder,
+        AssertConcurrentMerkleTreeAccountMultiBuilder, AssertMemoryHeaderBuilder,
+        AssertMerkleTreeAccountBuilder, AssertMintAccountBuilder, AssertMintAccountMultiBuilder,
+        AssertStakeAccountBuilder, AssertStakeAccountMultiBuilder, AssertStakePoolAccountBuilder,
+        AssertStakePoolAccountMultiBuilder, AssertSysvarClockBuilder, AssertTokenAccountBuilder,
         AssertTokenAccountMultiBuilder, AssertUpgradeableLoaderAccountBuilder,
-        AssertUpgradeableLoaderAccountMultiBuilder, MemoryCloseBuilder, MemoryWriteBuilder,
+        AssertUpgradeableLoaderAccountMultiBuilder, MemoryCloseBuilder,
+        MemoryCloseToRecipientBuilder, MemoryCloseWithAuthorityBuilder, MemoryInitHeaderBuilder,
+        MemoryShrinkBuilder, MemoryWriteBuilder, MemoryWriteMultiBuilder,
+        MemoryWriteWithAuthorityBuilder, MemoryZeroBuilder,
     };
 }
 
 #[cfg(feature = "cpi")]
 pub mod cpi {
     pub use crate::generated::instructions::{
-        AssertAccountDataBuilder, AssertAccountDeltaBuilder, AssertAccountInfoBuilder,
-        AssertAccountInfoMultiBuilder, AssertBubblegumTreeConfigAccountCpiBuilder,
+        AssertAccountDataBuilder, AssertAccountDataPathCpiBuilder, AssertAccountDeltaBuilder,
+        AssertAccountInfoBuilder, AssertAccountInfoMultiBuilder, AssertAccountSnapshotCpiBuilder,
+        AssertBubblegumTreeConfigAccountCpiBuilder, AssertConcurrentMerkleTreeAccountCpiBuilder,
+        AssertConcurrentMerkleTreeAccountMultiCpiBuilder, AssertMemoryHeaderCpiBuilder,
         AssertMerkleTreeAccountBuilder, AssertMintAccountBuilder, AssertMintAccountMultiBuilder,
-        AssertStakeAccountBuilder, AssertStakeAccountMultiBuilder, AssertSysvarClockBuilder,
-        AssertTokenAccountBuilder, AssertTokenAccountMultiBuilder,
-        AssertUpgradeableLoaderAccountBuilder, MemoryCloseCpiBuilder, MemoryWriteCpiBuilder,
+        AssertStakeAccountBuilder, AssertStakeAccountMultiBuilder,
+        AssertStakePoolAccountCpiBuilder, AssertStakePoolAccountMultiCpiBuilder,
+        AssertSysvarClockBuilder, AssertTokenAccountBuilder, AssertTokenAccountMultiBuilder,
+        AssertUpgradeableLoaderAccountBuilder, MemoryCloseCpiBuilder,
+        MemoryCloseToRecipientCpiBuilder, MemoryCloseWithAuthorityCpiBuilder,
+        MemoryInitHeaderCpiBuilder, MemoryShrinkCpiBuilder, MemoryWriteCpiBuilder,
+        MemoryWriteMultiCpiBuilder, MemoryWriteWithAuthorityCpiBuilder, MemoryZeroCpiBuilder,
     };
 }
 
+pub mod events;
+
 pub mod errors {
     pub use crate::generated::errors::*;
 }
@@ -51,10 +65,54 @@ pub fn find_memory_pda_bump_iterate(
     let memory_ref = "memory".to_string();
     let seeds = [memory_ref.as_ref(), payer.as_ref(), &[memory_id]];
 
-    let mut bump_seed = [start_bump.unwrap_or(std::u8::MAX)];
+    find_pda_bump_iterate(&seeds, bump_skip, start_bump)
+}
+
+pub fn find_authority_memory_pda(
+    authority: Pubkey,
+    memory_id: u16,
+    top_level_only: bool,
+) -> (solana_program::pubkey::Pubkey, u8) {
+    solana_program::pubkey::Pubkey::find_program_address(
+        &[
+            "memory".to_string().as_ref(),
+            authority.as_ref(),
+            &memory_id.to_le_bytes(),
+            &[top_level_only as u8],
+        ],
+        &crate::ID,
+    )
+}
+
+pub fn find_authority_memory_pda_bump_iterate(
+    authority: Pubkey,
+    memory_id: u16,
+    top_level_only: bool,
+    bump_skip: u8,
+    start_bump: Option<u8>,
+) -> Option<(solana_program::pubkey::Pubkey, u8)> {
+    let memory_ref = "memory".to_string();
+    let memory_id_bytes = memory_id.to_le_bytes();
+    let top_level_only_bytes = [top_level_only as u8];
+    let seeds = [
+        memory_ref.as_ref(),
+        authority.as_ref(),
+        &memory_id_bytes,
+        &top_level_only_bytes,
+    ];
+
+    find_pda_bump_iterate(&seeds, bump_skip, start_bump)
+}
+
+fn find_pda_bump_iterate(
+    seeds: &[&[u8]],
+    bump_skip: u8,
+    start_bump: Option<u8>,
+) -> Option<(solana_program::pubkey::Pubkey, u8)> {
+    let mut bump_seed = [start_bump.unwrap_or(u8::MAX)];
     let mut bump_skip = bump_skip as usize;
 
-    for _ in 0..std::u8::MAX {
+    for _ in 0..u8::MAX {
         let mut seeds_with_bump = seeds.to_vec();
         seeds_with_bump.push(&bump_seed);
         match Pubkey::create_program_address(&seeds_with_bump, &crate::ID) {
@@ -69,8 +127,6 @@ pub fn find_memory_pda_bump_iterate(
             _ => break,
         }
         bump_seed[0] -= 1;
-
-        println!("bump_seed: {:?}", bump_seed[0])
     }
 
     None
@@ -78,8 +134,7 @@ pub fn find_memory_pda_bump_iterate(
 
 #[cfg(feature = "sdk")]
 pub mod utils {
-    use crate::generated::types::AssertionResult;
-    use borsh::BorshDeserialize;
+    use crate::events::{AssertionEvent, ASSERTION_EVENT_DISCRIMINATOR};
     use solana_sdk::{
         instruction::{AccountMeta, Instruction},
         message::{legacy, v0, CompileError, Message, VersionedMessage},
@@ -106,16 +161,24 @@ pub mod utils {
         IOError(std::io::Error),
     }
 
+    ///
+    /// Decodes the assertion events in the transaction logs, data logged by other programs is
+    /// skipped.
+    ///
     #[allow(deprecated)]
     pub fn parse_evaluation_payloads_from_logs(
         logs: Vec<&String>,
-    ) -> Result<Vec<AssertionResult>, ClientError> {
+    ) -> Result<Vec<AssertionEvent>, ClientError> {
         logs.iter()
-            .filter(|log| log.contains("Program data: "))
-            .map(|log| {
-                let encoded = log.split("Program data: ").collect::<Vec<&str>>()[1];
-                let decoded = base64::decode(encoded).map_err(ClientError::Base64DecodeError)?;
-                AssertionResult::try_from_slice(&decoded).map_err(ClientError::IOError)
+            .filter_map(|log| log.split("Program data: ").nth(1))
+            // Lighthouse logs each event as a single data field
+            .filter(|encoded| !encoded.contains(' '))
+            .filter_map(|encoded| match base64::decode(encoded) {
+                Ok(decoded) if decoded.starts_with(&ASSERTION_EVENT_DISCRIMINATOR) => {
+                    Some(AssertionEvent::decode(&decoded).map_err(ClientError::IOError))
+                }
+                Ok(_) => None,
+                Err(e) => Some(Err(ClientError::Base64DecodeError(e))),
             })
             .collect()
     }
@@ -254,4 +317,47 @@ pub mod utils {
 
         Ok(modified_ixs)
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::parse_evaluation_payloads_from_logs;
+        use crate::events::{
+            AssertionEvent, ASSERTION_EVENT_DISCRIMINATOR, ASSERTION_EVENT_VERSION,
+        };
+        use crate::generated::types::AssertionResult;
+        use base64::{engine::general_purpose::STANDARD, Engine};
+        use borsh::BorshSerialize;
+        use solana_program::pubkey::Pubkey;
+
+        #[test]
+        fn skips_foreign_program_data() {
+            let event = AssertionEvent {
+                discriminator: ASSERTION_EVENT_DISCRIMINATOR,
+                version: ASSERTION_EVENT_VERSION,
+                instruction: 3,
+                assertion_index: 0,
+                target_account: Pubkey::new_unique(),
+                field: 0,
+                result: AssertionResult::U64(Some(10), Some(10), 0, true),
+            };
+
+            let logs = [
+                "Program log: Instruction: Swap".to_string(),
+                format!("Program data: {}", STANDARD.encode([1, 2, 3, 4])),
+                format!(
+                    "Program data: {} {}",
+                    STANDARD.encode(b"first"),
+                    STANDARD.encode(b"second")
+                ),
+                format!(
+                    "Program data: {}",
+                    STANDARD.encode(event.try_to_vec().unwrap())
+                ),
+            ];
+
+            let events = parse_evaluation_payloads_from_logs(logs.iter().collect()).unwrap();
+
+            assert_eq!(events, vec![event]);
+        }
+    }
 }
@@ -14,7 +14,7 @@ const programDir = path.join(
 // Instanciate Kinobi.
 const kinobi = k.createFromIdls([path.join(programDir, 'lighthouse.json')]);
 
-// Memory account PDA
+// Memory account PDAs, namespaced by the payer or by a separate authority
 kinobi.update(
   k.addPdasVisitor({
     lighthouse: [
@@ -23,6 +23,12 @@ kinobi.update(
         k.variablePdaSeedNode('payer', k.publicKeyTypeNode()),
         k.variablePdaSeedNode('memory_id', k.numberTypeNode('u8')),
       ]),
+      k.pdaNode('authorityMemory', [
+        k.constantPdaSeedNodeFromString('memory'),
+        k.variablePdaSeedNode('authority', k.publicKeyTypeNode()),
+        k.variablePdaSeedNode('memory_id', k.numberTypeNode('u16')),
+        k.variablePdaSeedNode('top_level_only', k.booleanTypeNode()),
+      ]),
     ],
   })
 );
@@ -47,6 +53,62 @@ kinobi.update(
         },
       },
     },
+    memoryWriteMulti: {
+      arguments: {
+        memoryId: {
+          defaultValue: k.numberValueNode(0),
+        },
+      },
+    },
+    memoryInitHeader: {
+      arguments: {
+        memoryId: {
+          defaultValue: k.numberValueNode(0),
+        },
+      },
+    },
+    memoryClose: {
+      arguments: {
+        memoryId: {
+          defaultValue: k.numberValueNode(0),
+        },
+      },
+    },
+    memoryCloseToRecipient: {
+      arguments: {
+        memoryId: {
+          defaultValue: k.numberValueNode(0),
+        },
+      },
+    },
+    memoryZero: {
+      arguments: {
+        memoryId: {
+          defaultValue: k.numberValueNode(0),
+        },
+      },
+    },
+    memoryShrink: {
+      arguments: {
+        memoryId: {
+          defaultValue: k.numberValueNode(0),
+        },
+      },
+    },
+    memoryWriteWithAuthority: {
+      arguments: {
+        memoryId: {
+          defaultValue: k.numberValueNode(0),
+        },
+      },
+    },
+    memoryCloseWithAuthority: {
+      arguments: {
+        memoryId: {
+          defaultValue: k.numberValueNode(0),
+        },
+      },
+    },
   })
 );
 
@@ -61,10 +123,48 @@ kinobi.update(
         });
       },
     },
+    {
+      select: '[instructionArgumentNode]errorCode',
+      transform: (node) => {
+        return k.instructionArgumentNode({
+          ...node,
+          defaultValue: k.noneValueNode(),
+        });
+      },
+    },
+    {
+      select: '[instructionArgumentNode]evaluationMode',
+      transform: (node) => {
+        return k.instructionArgumentNode({
+          ...node,
+          defaultValue: k.enumValueNode('evaluationMode', 'Strict'),
+        });
+      },
+    },
+    {
+      select: '[instructionArgumentNode]errorCodes',
+      transform: (node) => {
+        return k.instructionArgumentNode({
+          ...node,
+          defaultValue: k.noneValueNode(),
+        });
+      },
+    },
   ])
 );
 
-kinobi.update(k.deleteNodesVisitor(['testAccountV1']));
+kinobi.update(
+  k.deleteNodesVisitor([
+    'testAccountV1',
+    // Program-side mirror of the stake pool account, not part of the interface.
+    'stakePool',
+    'stakePoolFee',
+    'stakePoolAccountType',
+    'futureEpochFee',
+    // Log envelope decoded by the hand-written events module.
+    'assertionEvent',
+  ])
+);
 
 //
 // How to long the kinobi tree
@@ -18,6 +18,9 @@ macro_rules! err_msg {
     };
 }
 
+// Reserved range for caller-chosen assertion error codes, 0x10000..=0x1FFFF
+pub const CUSTOM_ERROR_CODE_OFFSET: u32 = 0x10000;
+
 #[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
 #[repr(u32)]
 pub enum LighthouseError {
@@ -66,6 +69,12 @@ pub enum LighthouseError {
     // Guards
     #[error("CrossProgramInvokeViolation")]
     CrossProgramInvokeViolation = 6016,
+
+    // Memory errors
+    #[error("ArithmeticOverflow")]
+    ArithmeticOverflow = 6017,
+    #[error("MemoryLocked")]
+    MemoryLocked = 6018,
 }
 
 impl LighthouseError {
@@ -77,6 +86,25 @@ impl LighthouseError {
         }
     }
 
+    pub fn is_assertion_failure(e: &ProgramError) -> bool {
+        match e {
+            ProgramError::Custom(code) => {
+                *code == LighthouseError::AssertionFailed as u32
+                    || (CUSTOM_ERROR_CODE_OFFSET..=CUSTOM_ERROR_CODE_OFFSET + u16::MAX as u32)
+                        .contains(code)
+            }
+            _ => false,
+        }
+    }
+
+    pub fn map_custom_err(e: ProgramError, error_code: u16) -> ProgramError {
+        if e == LighthouseError::AssertionFailed.into() {
+            ProgramError::Custom(CUSTOM_ERROR_CODE_OFFSET + error_code as u32)
+        } else {
+            e
+        }
+    }
+
     pub fn failed_borrow_err(e: ProgramError) -> ProgramError {
         err_msg!("Failed to borrow data for target account", e);
         err!(LighthouseError::AccountBorrowFailed)
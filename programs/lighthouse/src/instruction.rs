@@ -1,16 +1,20 @@
 use crate::types::{
     assert::{
-        AccountDataAssertion, AccountDeltaAssertion, AccountInfoAssertion,
-        BubblegumTreeConfigAssertion, LogLevel, MerkleTreeAssertion, MintAccountAssertion,
-        StakeAccountAssertion, SysvarClockAssertion, TokenAccountAssertion,
+        AccountDataAssertion, AccountDataPathAssertion, AccountDeltaAssertion,
+        AccountInfoAssertion, AccountSnapshotAssertion, BubblegumTreeConfigAssertion,
+        ConcurrentMerkleTreeAssertion, EvaluationMode, LogLevel, MemoryHeaderAssertion,
+        MerkleTreeAssertion, MintAccountAssertion, StakeAccountAssertion,
+        StakePoolAccountAssertion, SysvarClockAssertion, TokenAccountAssertion,
         UpgradeableLoaderStateAssertion,
     },
-    write::WriteType,
+    write::{MemoryWriteEntry, WriteType},
 };
-use borsh::{BorshDeserialize, BorshSerialize};
+use borsh::BorshDeserialize;
 use shank::ShankInstruction;
 
-#[derive(BorshSerialize, BorshDeserialize, ShankInstruction)]
+// Evaluation modes and error codes are appended to the instruction layouts, they are skipped by
+// the derived deserializer and read by `unpack` only when bytes remain
+#[derive(BorshDeserialize, ShankInstruction)]
 #[rustfmt::skip]
 pub(crate) enum LighthouseInstruction {
     #[account(0, name = "program_id", desc = "Lighthouse program")]
@@ -31,56 +35,217 @@ pub(crate) enum LighthouseInstruction {
     MemoryClose { memory_id: u8, memory_bump: u8 },
 
     #[account(0, name = "target_account", desc = "Target account to be asserted")]
-    AssertAccountData { log_level: LogLevel, assertion: AccountDataAssertion },
+    AssertAccountData { log_level: LogLevel, assertion: AccountDataAssertion, #[borsh_skip] error_code: Option<u16> },
 
     #[account(0, name = "account_a", desc = "Account A where the delta is calculated from")]
     #[account(1, name = "account_b", desc = "Account B where the delta is calculated to")]
-    AssertAccountDelta { log_level: LogLevel, assertion: AccountDeltaAssertion },
+    AssertAccountDelta { log_level: LogLevel, assertion: AccountDeltaAssertion, #[borsh_skip] error_code: Option<u16> },
 
     #[account(0, name = "target_account", desc = "Target account to be asserted")]
-    AssertAccountInfo { log_level: LogLevel, assertion: AccountInfoAssertion },
+    AssertAccountInfo { log_level: LogLevel, assertion: AccountInfoAssertion, #[borsh_skip] error_code: Option<u16> },
 
     #[account(0, name = "target_account", desc = "Target account to be asserted")]
-    AssertAccountInfoMulti { log_level: LogLevel, assertions: Vec<AccountInfoAssertion> },
+    AssertAccountInfoMulti { log_level: LogLevel, assertions: Vec<AccountInfoAssertion>, #[borsh_skip] evaluation_mode: EvaluationMode, #[borsh_skip] error_codes: Option<Vec<u16>> },
 
     #[account(0, name = "target_account", desc = "Target account to be asserted")]
-    AssertMintAccount { log_level: LogLevel, assertion: MintAccountAssertion },
+    AssertMintAccount { log_level: LogLevel, assertion: MintAccountAssertion, #[borsh_skip] error_code: Option<u16> },
 
     #[account(0, name = "target_account", desc = "Target account to be asserted")]
-    AssertMintAccountMulti { log_level: LogLevel, assertions: Vec<MintAccountAssertion> },
+    AssertMintAccountMulti { log_level: LogLevel, assertions: Vec<MintAccountAssertion>, #[borsh_skip] evaluation_mode: EvaluationMode, #[borsh_skip] error_codes: Option<Vec<u16>> },
 
     #[account(0, name = "target_account", desc = "Target account to be asserted")]
-    AssertTokenAccount { log_level: LogLevel, assertion: TokenAccountAssertion },
+    AssertTokenAccount { log_level: LogLevel, assertion: TokenAccountAssertion, #[borsh_skip] error_code: Option<u16> },
 
     #[account(0, name = "target_account", desc = "Target account to be asserted")]
-    AssertTokenAccountMulti { log_level: LogLevel, assertions: Vec<TokenAccountAssertion> },
+    AssertTokenAccountMulti { log_level: LogLevel, assertions: Vec<TokenAccountAssertion>, #[borsh_skip] evaluation_mode: EvaluationMode, #[borsh_skip] error_codes: Option<Vec<u16>> },
 
     #[account(0, name = "target_account", desc = "Target account to be asserted")]
-    AssertStakeAccount { log_level: LogLevel, assertion: StakeAccountAssertion },
+    AssertStakeAccount { log_level: LogLevel, assertion: StakeAccountAssertion, #[borsh_skip] error_code: Option<u16> },
 
     #[account(0, name = "target_account", desc = "Target account to be asserted")]
-    AssertStakeAccountMulti { log_level: LogLevel, assertions: Vec<StakeAccountAssertion> },
+    AssertStakeAccountMulti { log_level: LogLevel, assertions: Vec<StakeAccountAssertion>, #[borsh_skip] evaluation_mode: EvaluationMode, #[borsh_skip] error_codes: Option<Vec<u16>> },
 
     #[account(0, name = "target_account", desc = "Target account to be asserted")]
-    AssertUpgradeableLoaderAccount { log_level: LogLevel, assertion: UpgradeableLoaderStateAssertion },
+    AssertUpgradeableLoaderAccount { log_level: LogLevel, assertion: UpgradeableLoaderStateAssertion, #[borsh_skip] error_code: Option<u16> },
 
     #[account(0, name = "target_account", desc = "Target account to be asserted")]
-    AssertUpgradeableLoaderAccountMulti { log_level: LogLevel, assertions: Vec<UpgradeableLoaderStateAssertion> },
+    AssertUpgradeableLoaderAccountMulti { log_level: LogLevel, assertions: Vec<UpgradeableLoaderStateAssertion>, #[borsh_skip] evaluation_mode: EvaluationMode, #[borsh_skip] error_codes: Option<Vec<u16>> },
 
     // No accounts
-    AssertSysvarClock { log_level : LogLevel, assertion: SysvarClockAssertion },
+    AssertSysvarClock { log_level : LogLevel, assertion: SysvarClockAssertion, #[borsh_skip] error_code: Option<u16> },
 
     #[account(0, name = "target_merkle_tree", desc = "Target merkle tree account to be asserted")]
     #[account(1, name = "root", desc = "The current root of the merkle tree")]
     #[account(2, name = "spl_account_compression", desc = "SPL account compression program")]
-    AssertMerkleTreeAccount { log_level: LogLevel, assertion: MerkleTreeAssertion },
+    AssertMerkleTreeAccount { log_level: LogLevel, assertion: MerkleTreeAssertion, #[borsh_skip] error_code: Option<u16> },
 
 
     #[account(0, name = "target_account", desc = "Target mpl-bubblegum tree config account to be asserted")]
-    AssertBubblegumTreeConfigAccount { log_level: LogLevel, assertion: BubblegumTreeConfigAssertion },
+    AssertBubblegumTreeConfigAccount { log_level: LogLevel, assertion: BubblegumTreeConfigAssertion, #[borsh_skip] error_code: Option<u16> },
+
+    #[account(0, name = "target_account", desc = "Target spl-stake-pool account to be asserted")]
+    AssertStakePoolAccount { log_level: LogLevel, assertion: StakePoolAccountAssertion, #[borsh_skip] error_code: Option<u16> },
+
+    #[account(0, name = "target_account", desc = "Target spl-stake-pool account to be asserted")]
+    AssertStakePoolAccountMulti { log_level: LogLevel, assertions: Vec<StakePoolAccountAssertion>, #[borsh_skip] evaluation_mode: EvaluationMode, #[borsh_skip] error_codes: Option<Vec<u16>> },
+
+    #[account(0, name = "target_merkle_tree", desc = "Target spl-account-compression merkle tree account to be asserted")]
+    AssertConcurrentMerkleTreeAccount { log_level: LogLevel, assertion: ConcurrentMerkleTreeAssertion, #[borsh_skip] error_code: Option<u16> },
+
+    #[account(0, name = "target_merkle_tree", desc = "Target spl-account-compression merkle tree account to be asserted")]
+    AssertConcurrentMerkleTreeAccountMulti { log_level: LogLevel, assertions: Vec<ConcurrentMerkleTreeAssertion>, #[borsh_skip] evaluation_mode: EvaluationMode, #[borsh_skip] error_codes: Option<Vec<u16>> },
+
+    #[account(0, name = "target_account", desc = "Target account to be asserted")]
+    AssertAccountDataPath { log_level: LogLevel, assertion: AccountDataPathAssertion, #[borsh_skip] error_code: Option<u16> },
+
+    #[account(0, name = "program_id", desc = "Lighthouse program")]
+    #[account(1, name = "system_program", desc = "System program")]
+    #[account(2, name = "payer", desc = "Payer account", signer, writable)]
+    #[account(3, name = "authority", desc = "Memory authority, namespaces the memory account", signer)]
+    #[account(4, name = "memory", desc = "Memory account", writable)]
+    #[account(5, name = "source_account", desc = "First account to be written to memory, further source accounts follow in order")]
+    MemoryWriteMulti {
+        memory_id: u16,
+        memory_bump: u8,
+        top_level_only: bool,
+        entries: Vec<MemoryWriteEntry>,
+    },
+
+    #[account(0, name = "program_id", desc = "Lighthouse program")]
+    #[account(1, name = "system_program", desc = "System program")]
+    #[account(2, name = "payer", desc = "Payer account", signer, writable)]
+    #[account(3, name = "authority", desc = "Memory authority, namespaces the memory account", signer)]
+    #[account(4, name = "memory", desc = "Memory account", writable)]
+    MemoryInitHeader { memory_id: u16, memory_bump: u8, top_level_only: bool, locked: bool },
+
+    #[account(0, name = "target_account", desc = "Target memory account to be asserted")]
+    AssertMemoryHeader { log_level: LogLevel, assertion: MemoryHeaderAssertion, #[borsh_skip] error_code: Option<u16> },
+
+    #[account(0, name = "memory", desc = "Memory account holding the account snapshot")]
+    #[account(1, name = "target_account", desc = "Live account compared against the snapshot")]
+    AssertAccountSnapshot { log_level: LogLevel, assertion: AccountSnapshotAssertion, #[borsh_skip] error_code: Option<u16> },
+
+    #[account(0, name = "program_id", desc = "Lighthouse program")]
+    #[account(1, name = "payer", desc = "Payer account", signer, writable)]
+    #[account(2, name = "authority", desc = "Memory authority, namespaces the memory account", signer)]
+    #[account(3, name = "memory", desc = "Memory account", writable)]
+    #[account(4, name = "recipient", desc = "Account receiving the memory account lamports", writable)]
+    MemoryCloseToRecipient { memory_id: u16, memory_bump: u8, top_level_only: bool },
+
+    #[account(0, name = "program_id", desc = "Lighthouse program")]
+    #[account(1, name = "payer", desc = "Payer account", signer, writable)]
+    #[account(2, name = "authority", desc = "Memory authority, namespaces the memory account", signer)]
+    #[account(3, name = "memory", desc = "Memory account", writable)]
+    MemoryZero {
+        memory_id: u16,
+        memory_bump: u8,
+        top_level_only: bool,
+        offset: u16,
+        length: Option<u16>,
+    },
+
+    #[account(0, name = "program_id", desc = "Lighthouse program")]
+    #[account(1, name = "payer", desc = "Payer account, receives refunded lamports", signer, writable)]
+    #[account(2, name = "authority", desc = "Memory authority, namespaces the memory account", signer)]
+    #[account(3, name = "memory", desc = "Memory account", writable)]
+    MemoryShrink { memory_id: u16, memory_bump: u8, top_level_only: bool, new_size: u16 },
+
+    #[account(0, name = "program_id", desc = "Lighthouse program")]
+    #[account(1, name = "system_program", desc = "System program")]
+    #[account(2, name = "payer", desc = "Payer account", signer, writable)]
+    #[account(3, name = "authority", desc = "Memory authority, namespaces the memory account", signer)]
+    #[account(4, name = "memory", desc = "Memory account", writable)]
+    #[account(5, name = "source_account", desc = "Account to be written to memory")]
+    MemoryWriteWithAuthority {
+        memory_id: u16,
+        memory_bump: u8,
+        top_level_only: bool,
+        write_offset: u16,
+        write_type: WriteType,
+    },
+
+    #[account(0, name = "program_id", desc = "Lighthouse program")]
+    #[account(1, name = "payer", desc = "Payer account, receives the memory account lamports", signer, writable)]
+    #[account(2, name = "authority", desc = "Memory authority, namespaces the memory account", signer)]
+    #[account(3, name = "memory", desc = "Memory account", writable)]
+    MemoryCloseWithAuthority { memory_id: u16, memory_bump: u8, top_level_only: bool },
 }
 
 impl LighthouseInstruction {
+    pub fn unpack(instruction_data: &[u8]) -> std::io::Result<Self> {
+        let data = &mut &instruction_data[..];
+        let mut instruction = Self::deserialize(data)?;
+
+        match &mut instruction {
+            LighthouseInstruction::AssertAccountData { error_code, .. }
+            | LighthouseInstruction::AssertAccountDelta { error_code, .. }
+            | LighthouseInstruction::AssertAccountInfo { error_code, .. }
+            | LighthouseInstruction::AssertMintAccount { error_code, .. }
+            | LighthouseInstruction::AssertTokenAccount { error_code, .. }
+            | LighthouseInstruction::AssertStakeAccount { error_code, .. }
+            | LighthouseInstruction::AssertUpgradeableLoaderAccount { error_code, .. }
+            | LighthouseInstruction::AssertSysvarClock { error_code, .. }
+            | LighthouseInstruction::AssertMerkleTreeAccount { error_code, .. }
+            | LighthouseInstruction::AssertBubblegumTreeConfigAccount { error_code, .. }
+            | LighthouseInstruction::AssertStakePoolAccount { error_code, .. }
+            | LighthouseInstruction::AssertConcurrentMerkleTreeAccount { error_code, .. }
+            | LighthouseInstruction::AssertAccountDataPath { error_code, .. }
+            | LighthouseInstruction::AssertMemoryHeader { error_code, .. }
+            | LighthouseInstruction::AssertAccountSnapshot { error_code, .. } => {
+                *error_code = deserialize_trailing(data)?;
+            }
+            LighthouseInstruction::AssertAccountInfoMulti {
+                evaluation_mode,
+                error_codes,
+                ..
+            }
+            | LighthouseInstruction::AssertMintAccountMulti {
+                evaluation_mode,
+                error_codes,
+                ..
+            }
+            | LighthouseInstruction::AssertTokenAccountMulti {
+                evaluation_mode,
+                error_codes,
+                ..
+            }
+            | LighthouseInstruction::AssertStakeAccountMulti {
+                evaluation_mode,
+                error_codes,
+                ..
+            }
+            | LighthouseInstruction::AssertUpgradeableLoaderAccountMulti {
+                evaluation_mode,
+                error_codes,
+                ..
+            }
+            | LighthouseInstruction::AssertStakePoolAccountMulti {
+                evaluation_mode,
+                error_codes,
+                ..
+            }
+            | LighthouseInstruction::AssertConcurrentMerkleTreeAccountMulti {
+                evaluation_mode,
+                error_codes,
+                ..
+            } => {
+                *evaluation_mode = deserialize_trailing(data)?;
+                *error_codes = deserialize_trailing(data)?;
+            }
+            LighthouseInstruction::MemoryWrite { .. }
+            | LighthouseInstruction::MemoryClose { .. }
+            | LighthouseInstruction::MemoryWriteMulti { .. }
+            | LighthouseInstruction::MemoryInitHeader { .. }
+            | LighthouseInstruction::MemoryCloseToRecipient { .. }
+            | LighthouseInstruction::MemoryZero { .. }
+            | LighthouseInstruction::MemoryShrink { .. }
+            | LighthouseInstruction::MemoryWriteWithAuthority { .. }
+            | LighthouseInstruction::MemoryCloseWithAuthority { .. } => {}
+        }
+
+        Ok(instruction)
+    }
+
     pub const fn get_name(&self) -> &'static str {
         match self {
             LighthouseInstruction::MemoryWrite { .. } => "MemoryWrite",
@@ -106,6 +271,26 @@ impl LighthouseInstruction {
                 "AssertBubblegumTreeConfigAccount"
             }
             LighthouseInstruction::AssertMerkleTreeAccount { .. } => "AssertMerkleTreeAccount",
+            LighthouseInstruction::AssertStakePoolAccount { .. } => "AssertStakePoolAccount",
+            LighthouseInstruction::AssertStakePoolAccountMulti { .. } => {
+                "AssertStakePoolAccountMulti"
+            }
+            LighthouseInstruction::AssertConcurrentMerkleTreeAccount { .. } => {
+                "AssertConcurrentMerkleTreeAccount"
+            }
+            LighthouseInstruction::AssertConcurrentMerkleTreeAccountMulti { .. } => {
+                "AssertConcurrentMerkleTreeAccountMulti"
+            }
+            LighthouseInstruction::AssertAccountDataPath { .. } => "AssertAccountDataPath",
+            LighthouseInstruction::MemoryWriteMulti { .. } => "MemoryWriteMulti",
+            LighthouseInstruction::MemoryInitHeader { .. } => "MemoryInitHeader",
+            LighthouseInstruction::AssertMemoryHeader { .. } => "AssertMemoryHeader",
+            LighthouseInstruction::AssertAccountSnapshot { .. } => "AssertAccountSnapshot",
+            LighthouseInstruction::MemoryCloseToRecipient { .. } => "MemoryCloseToRecipient",
+            LighthouseInstruction::MemoryZero { .. } => "MemoryZero",
+            LighthouseInstruction::MemoryShrink { .. } => "MemoryShrink",
+            LighthouseInstruction::MemoryWriteWithAuthority { .. } => "MemoryWriteWithAuthority",
+            LighthouseInstruction::MemoryCloseWithAuthority { .. } => "MemoryCloseWithAuthority",
         }
     }
 
@@ -130,6 +315,200 @@ impl LighthouseInstruction {
             LighthouseInstruction::AssertBubblegumTreeConfigAccount { log_level, .. } => *log_level,
             LighthouseInstruction::AssertSysvarClock { log_level, .. } => *log_level,
             LighthouseInstruction::AssertMerkleTreeAccount { log_level, .. } => *log_level,
+            LighthouseInstruction::AssertStakePoolAccount { log_level, .. } => *log_level,
+            LighthouseInstruction::AssertStakePoolAccountMulti { log_level, .. } => *log_level,
+            LighthouseInstruction::AssertConcurrentMerkleTreeAccount { log_level, .. } => {
+                *log_level
+            }
+            LighthouseInstruction::AssertConcurrentMerkleTreeAccountMulti { log_level, .. } => {
+                *log_level
+            }
+            LighthouseInstruction::AssertAccountDataPath { log_level, .. } => *log_level,
+            LighthouseInstruction::MemoryWriteMulti { .. } => LogLevel::Silent,
+            LighthouseInstruction::MemoryInitHeader { .. } => LogLevel::Silent,
+            LighthouseInstruction::AssertMemoryHeader { log_level, .. } => *log_level,
+            LighthouseInstruction::AssertAccountSnapshot { log_level, .. } => *log_level,
+            LighthouseInstruction::MemoryCloseToRecipient { .. } => LogLevel::Silent,
+            LighthouseInstruction::MemoryZero { .. } => LogLevel::Silent,
+            LighthouseInstruction::MemoryShrink { .. } => LogLevel::Silent,
+            LighthouseInstruction::MemoryWriteWithAuthority { .. } => LogLevel::Silent,
+            LighthouseInstruction::MemoryCloseWithAuthority { .. } => LogLevel::Silent,
         }
     }
 }
+
+fn deserialize_trailing<T: BorshDeserialize + Default>(data: &mut &[u8]) -> std::io::Result<T> {
+    if data.is_empty() {
+        Ok(T::default())
+    } else {
+        T::deserialize(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LighthouseInstruction;
+    use crate::types::assert::{
+        AccountDataAssertion, AccountInfoAssertion, DataValueAssertion, EvaluationMode,
+        IntegerOperator, LogLevel,
+    };
+    use crate::types::write::WriteType;
+    use borsh::BorshSerialize;
+
+    fn account_data_payload() -> Vec<u8> {
+        let assertion = AccountDataAssertion {
+            offset: 8,
+            assertion: DataValueAssertion::U8 {
+                value: 1,
+                operator: IntegerOperator::Equal,
+            },
+        };
+
+        (2u8, LogLevel::Silent, assertion).try_to_vec().unwrap()
+    }
+
+    fn account_info_multi_payload() -> Vec<u8> {
+        let assertions = vec![AccountInfoAssertion::Lamports {
+            value: 1,
+            operator: IntegerOperator::Equal,
+        }];
+
+        (5u8, LogLevel::Silent, assertions, EvaluationMode::Strict)
+            .try_to_vec()
+            .unwrap()
+    }
+
+    #[test]
+    fn unpack_without_error_code() {
+        let instruction = LighthouseInstruction::unpack(&account_data_payload()).unwrap();
+        assert!(matches!(
+            instruction,
+            LighthouseInstruction::AssertAccountData {
+                error_code: None,
+                ..
+            }
+        ));
+
+        let instruction = LighthouseInstruction::unpack(&account_info_multi_payload()).unwrap();
+        assert!(matches!(
+            instruction,
+            LighthouseInstruction::AssertAccountInfoMulti {
+                error_codes: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn unpack_with_error_code() {
+        let mut data = account_data_payload();
+        data.extend(Some(7u16).try_to_vec().unwrap());
+
+        let instruction = LighthouseInstruction::unpack(&data).unwrap();
+        assert!(matches!(
+            instruction,
+            LighthouseInstruction::AssertAccountData {
+                error_code: Some(7),
+                ..
+            }
+        ));
+
+        let mut data = account_info_multi_payload();
+        data.extend(Some(vec![7u16]).try_to_vec().unwrap());
+
+        let instruction = LighthouseInstruction::unpack(&data).unwrap();
+        match instruction {
+            LighthouseInstruction::AssertAccountInfoMulti { error_codes, .. } => {
+                assert_eq!(error_codes, Some(vec![7]));
+            }
+            _ => panic!("Unexpected instruction"),
+        }
+
+        // A truncated error code is rejected rather than defaulted
+        let mut data = account_data_payload();
+        data.push(1);
+        assert!(LighthouseInstruction::unpack(&data).is_err());
+    }
+
+    #[test]
+    fn unpack_evaluation_mode() {
+        let assertions = vec![AccountInfoAssertion::Lamports {
+            value: 1,
+            operator: IntegerOperator::Equal,
+        }];
+        let data = (5u8, LogLevel::Silent, assertions).try_to_vec().unwrap();
+
+        let instruction = LighthouseInstruction::unpack(&data).unwrap();
+        assert!(matches!(
+            instruction,
+            LighthouseInstruction::AssertAccountInfoMulti {
+                evaluation_mode: EvaluationMode::Strict,
+                error_codes: None,
+                ..
+            }
+        ));
+
+        let mut data = data;
+        data.extend(
+            EvaluationMode::Soft {
+                max_failures: Some(1),
+            }
+            .try_to_vec()
+            .unwrap(),
+        );
+
+        let instruction = LighthouseInstruction::unpack(&data).unwrap();
+        assert!(matches!(
+            instruction,
+            LighthouseInstruction::AssertAccountInfoMulti {
+                evaluation_mode: EvaluationMode::Soft {
+                    max_failures: Some(1)
+                },
+                error_codes: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn unpack_memory_write_original_layout() {
+        // discriminator, memory_id: u8, memory_bump, write_offset: u16, WriteType::AccountData
+        let data = (
+            0u8,
+            3u8,
+            254u8,
+            8u16,
+            WriteType::AccountData {
+                offset: 0,
+                data_length: 32,
+            },
+        )
+            .try_to_vec()
+            .unwrap();
+
+        let instruction = LighthouseInstruction::unpack(&data).unwrap();
+        assert!(matches!(
+            instruction,
+            LighthouseInstruction::MemoryWrite {
+                memory_id: 3,
+                memory_bump: 254,
+                write_offset: 8,
+                write_type: WriteType::AccountData {
+                    offset: 0,
+                    data_length: 32
+                },
+            }
+        ));
+
+        let data = (1u8, 3u8, 254u8).try_to_vec().unwrap();
+
+        let instruction = LighthouseInstruction::unpack(&data).unwrap();
+        assert!(matches!(
+            instruction,
+            LighthouseInstruction::MemoryClose {
+                memory_id: 3,
+                memory_bump: 254,
+            }
+        ));
+    }
+}
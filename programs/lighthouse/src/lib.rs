@@ -27,11 +27,17 @@ declare_id!("L1TEVtgA75k273wWz1s6XMmDhQY5i3MwcvKb4VbZzfK");
 pub mod lighthouse {
     use crate::processor;
     use crate::processor::*;
-    use crate::types::assert::LogLevel;
+    use crate::types::{
+        assert::{AssertionWithErrorCode, LogContext, LogLevel},
+        write::MemoryHeader,
+    };
+    use crate::validation::MemoryNamespace;
     use crate::{error::LighthouseError, instruction::LighthouseInstruction};
-    use borsh::BorshDeserialize;
     use solana_program::{
-        account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey,
+        account_info::{next_account_info, AccountInfo},
+        entrypoint::ProgramResult,
+        msg,
+        pubkey::Pubkey,
     };
 
     #[cfg(not(feature = "no-entrypoint"))]
@@ -42,14 +48,15 @@ pub mod lighthouse {
         accounts: &[AccountInfo],
         instruction_data: &[u8],
     ) -> ProgramResult {
-        let mut remaining_instruction_data = instruction_data;
-        let instruction = LighthouseInstruction::deserialize(&mut remaining_instruction_data)
+        let instruction = LighthouseInstruction::unpack(instruction_data)
             .or(Err(LighthouseError::InvalidInstructionData))?;
 
         if instruction.get_log_level() == LogLevel::PlaintextMessage {
             msg!("Instruction: {}", instruction.get_name());
         }
 
+        let log_context = LogContext::new(instruction.get_log_level(), instruction_data[0]);
+
         match instruction {
             LighthouseInstruction::MemoryWrite {
                 memory_id,
@@ -59,10 +66,9 @@ pub mod lighthouse {
             } => {
                 let ctx = MemoryWriteContext::load(
                     &mut accounts.iter(),
-                    memory_id,
-                    write_offset,
+                    MemoryNamespace::Payer { memory_id },
                     memory_bump,
-                    &write_type,
+                    (write_offset as u64) + write_type.data_length(),
                 )?;
                 processor::memory_write(&ctx, write_offset, &write_type)?;
             }
@@ -70,112 +76,406 @@ pub mod lighthouse {
                 memory_id,
                 memory_bump,
             } => {
-                let ctx = MemoryCloseContext::load(&mut accounts.iter(), memory_id, memory_bump)?;
+                let ctx = MemoryCloseContext::load(
+                    &mut accounts.iter(),
+                    MemoryNamespace::Payer { memory_id },
+                    memory_bump,
+                )?;
                 processor::memory_close(&ctx)?;
             }
             LighthouseInstruction::AssertAccountData {
-                log_level,
                 assertion,
+                error_code,
+                ..
             } => {
                 let ctx = AssertTargetAccountContext::load(&mut accounts.iter())?;
-                processor::assert_target_account(ctx, &assertion, log_level)?;
+                processor::assert_target_account(
+                    ctx,
+                    &AssertionWithErrorCode::new(&assertion, error_code),
+                    log_context,
+                )?;
             }
             LighthouseInstruction::AssertAccountDelta {
-                log_level,
                 assertion,
+                error_code,
+                ..
             } => {
                 let ctx = AssertAccountDeltaContext::load(&mut accounts.iter())?;
-                processor::assert_account_delta(&ctx, &assertion, log_level)?;
+                processor::assert_account_delta(
+                    &ctx,
+                    &AssertionWithErrorCode::new(&assertion, error_code),
+                    log_context,
+                )?;
             }
             LighthouseInstruction::AssertAccountInfo {
                 assertion,
-                log_level,
+                error_code,
+                ..
             } => {
                 let ctx = AssertTargetAccountContext::load(&mut accounts.iter())?;
-                processor::assert_target_account(ctx, &assertion, log_level)?;
+                processor::assert_target_account(
+                    ctx,
+                    &AssertionWithErrorCode::new(&assertion, error_code),
+                    log_context,
+                )?;
             }
             LighthouseInstruction::AssertAccountInfoMulti {
-                log_level,
                 assertions,
+                evaluation_mode,
+                error_codes,
+                ..
             } => {
                 let ctx = AssertTargetAccountContext::load(&mut accounts.iter())?;
-                processor::assert_target_account_multi(ctx, &assertions, log_level)?;
+                processor::assert_target_account_multi(
+                    ctx,
+                    &AssertionWithErrorCode::from_multi(&assertions, error_codes.as_deref())?,
+                    log_context,
+                    &evaluation_mode,
+                )?;
             }
             LighthouseInstruction::AssertMintAccount {
-                log_level,
                 assertion,
+                error_code,
+                ..
             } => {
                 let ctx = AssertMintAccountContext::load(&mut accounts.iter())?;
-                processor::assert_mint_account(ctx, &assertion, log_level)?;
+                processor::assert_mint_account(
+                    ctx,
+                    &AssertionWithErrorCode::new(&assertion, error_code),
+                    log_context,
+                )?;
             }
             LighthouseInstruction::AssertMintAccountMulti {
-                log_level,
                 assertions,
+                evaluation_mode,
+                error_codes,
+                ..
             } => {
                 let ctx = AssertMintAccountContext::load(&mut accounts.iter())?;
-                processor::assert_mint_account_multi(ctx, &assertions, log_level)?;
+                processor::assert_mint_account_multi(
+                    ctx,
+                    &AssertionWithErrorCode::from_multi(&assertions, error_codes.as_deref())?,
+                    log_context,
+                    &evaluation_mode,
+                )?;
             }
             LighthouseInstruction::AssertTokenAccount {
-                log_level,
                 assertion,
+                error_code,
+                ..
             } => {
                 let ctx = AssertTokenAccountContext::load(&mut accounts.iter())?;
-                processor::assert_token_account(ctx, &assertion, log_level)?;
+                processor::assert_token_account(
+                    ctx,
+                    &AssertionWithErrorCode::new(&assertion, error_code),
+                    log_context,
+                )?;
             }
             LighthouseInstruction::AssertTokenAccountMulti {
-                log_level,
                 assertions,
+                evaluation_mode,
+                error_codes,
+                ..
             } => {
                 let ctx = AssertTokenAccountContext::load(&mut accounts.iter())?;
-                processor::assert_token_account_multi(ctx, &assertions, log_level)?;
+                processor::assert_token_account_multi(
+                    ctx,
+                    &AssertionWithErrorCode::from_multi(&assertions, error_codes.as_deref())?,
+                    log_context,
+                    &evaluation_mode,
+                )?;
             }
             LighthouseInstruction::AssertStakeAccount {
-                log_level,
                 assertion,
+                error_code,
+                ..
             } => {
                 let ctx = AssertStakeAccountContext::load(&mut accounts.iter())?;
-                processor::assert_stake_account(ctx, assertion, log_level)?;
+                processor::assert_stake_account(
+                    ctx,
+                    AssertionWithErrorCode::new(&assertion, error_code),
+                    log_context,
+                )?;
             }
             LighthouseInstruction::AssertStakeAccountMulti {
-                log_level,
                 assertions,
+                evaluation_mode,
+                error_codes,
+                ..
             } => {
                 let ctx = AssertStakeAccountContext::load(&mut accounts.iter())?;
-                processor::assert_stake_account_multi(ctx, &assertions, log_level)?
+                processor::assert_stake_account_multi(
+                    ctx,
+                    &AssertionWithErrorCode::from_multi(&assertions, error_codes.as_deref())?,
+                    log_context,
+                    &evaluation_mode,
+                )?
             }
             LighthouseInstruction::AssertUpgradeableLoaderAccount {
-                log_level,
                 assertion,
+                error_code,
+                ..
             } => {
                 let ctx = AssertUpgradeableLoaderStateContext::load(&mut accounts.iter())?;
-                processor::assert_upgradeable_loader_state(ctx, &assertion, log_level)?;
+                processor::assert_upgradeable_loader_state(
+                    ctx,
+                    &AssertionWithErrorCode::new(&assertion, error_code),
+                    log_context,
+                )?;
             }
             LighthouseInstruction::AssertUpgradeableLoaderAccountMulti {
-                log_level,
                 assertions,
+                evaluation_mode,
+                error_codes,
+                ..
             } => {
                 let ctx = AssertUpgradeableLoaderStateContext::load(&mut accounts.iter())?;
-                processor::assert_upgradeable_loader_state_multi(ctx, &assertions, log_level)?;
+                processor::assert_upgradeable_loader_state_multi(
+                    ctx,
+                    &AssertionWithErrorCode::from_multi(&assertions, error_codes.as_deref())?,
+                    log_context,
+                    &evaluation_mode,
+                )?;
             }
             LighthouseInstruction::AssertSysvarClock {
-                log_level,
                 assertion,
+                error_code,
+                ..
             } => {
-                processor::assert_clock(&assertion, log_level)?;
+                processor::assert_clock(
+                    &AssertionWithErrorCode::new(&assertion, error_code),
+                    log_context,
+                )?;
             }
             LighthouseInstruction::AssertMerkleTreeAccount {
-                log_level,
                 assertion,
+                error_code,
+                ..
             } => {
                 let ctx = AssertMerkleTreeAccountContext::load(&mut accounts.iter())?;
-                processor::assert_merkle_tree_account(&ctx, &assertion, log_level)?;
+                processor::assert_merkle_tree_account(
+                    &ctx,
+                    &AssertionWithErrorCode::new(&assertion, error_code),
+                    log_context,
+                )?;
             }
             LighthouseInstruction::AssertBubblegumTreeConfigAccount {
-                log_level,
                 assertion,
+                error_code,
+                ..
             } => {
                 let ctx = AssertBubblegumTreeConfigAccountContext::load(&mut accounts.iter())?;
-                processor::assert_bubblegum_tree_config_account(&ctx, &assertion, log_level)?;
+                processor::assert_bubblegum_tree_config_account(
+                    &ctx,
+                    &AssertionWithErrorCode::new(&assertion, error_code),
+                    log_context,
+                )?;
+            }
+            LighthouseInstruction::AssertStakePoolAccount {
+                assertion,
+                error_code,
+                ..
+            } => {
+                let ctx = AssertStakePoolAccountContext::load(&mut accounts.iter())?;
+                processor::assert_stake_pool_account(
+                    ctx,
+                    &AssertionWithErrorCode::new(&assertion, error_code),
+                    log_context,
+                )?;
+            }
+            LighthouseInstruction::AssertStakePoolAccountMulti {
+                assertions,
+                evaluation_mode,
+                error_codes,
+                ..
+            } => {
+                let ctx = AssertStakePoolAccountContext::load(&mut accounts.iter())?;
+                processor::assert_stake_pool_account_multi(
+                    ctx,
+                    &AssertionWithErrorCode::from_multi(&assertions, error_codes.as_deref())?,
+                    log_context,
+                    &evaluation_mode,
+                )?;
+            }
+            LighthouseInstruction::AssertConcurrentMerkleTreeAccount {
+                assertion,
+                error_code,
+                ..
+            } => {
+                let ctx = AssertConcurrentMerkleTreeAccountContext::load(&mut accounts.iter())?;
+                processor::assert_concurrent_merkle_tree_account(
+                    ctx,
+                    &AssertionWithErrorCode::new(&assertion, error_code),
+                    log_context,
+                )?;
+            }
+            LighthouseInstruction::AssertConcurrentMerkleTreeAccountMulti {
+                assertions,
+                evaluation_mode,
+                error_codes,
+                ..
+            } => {
+                let ctx = AssertConcurrentMerkleTreeAccountContext::load(&mut accounts.iter())?;
+                processor::assert_concurrent_merkle_tree_account_multi(
+                    ctx,
+                    &AssertionWithErrorCode::from_multi(&assertions, error_codes.as_deref())?,
+                    log_context,
+                    &evaluation_mode,
+                )?;
+            }
+            LighthouseInstruction::AssertAccountDataPath {
+                assertion,
+                error_code,
+                ..
+            } => {
+                let ctx = AssertTargetAccountContext::load(&mut accounts.iter())?;
+                processor::assert_target_account(
+                    ctx,
+                    &AssertionWithErrorCode::new(&assertion, error_code),
+                    log_context,
+                )?;
+            }
+            LighthouseInstruction::MemoryWriteMulti {
+                memory_id,
+                memory_bump,
+                top_level_only,
+                entries,
+            } => {
+                let ctx = MemoryWriteContext::load(
+                    &mut accounts.iter(),
+                    MemoryNamespace::Authority {
+                        memory_id,
+                        top_level_only,
+                    },
+                    memory_bump,
+                    processor::memory_write_required_space(&entries),
+                )?;
+                processor::memory_write_multi(&ctx, &entries)?;
+            }
+            LighthouseInstruction::MemoryInitHeader {
+                memory_id,
+                memory_bump,
+                top_level_only,
+                locked,
+            } => {
+                let ctx = MemoryWriteContext::load(
+                    &mut accounts.iter(),
+                    MemoryNamespace::Authority {
+                        memory_id,
+                        top_level_only,
+                    },
+                    memory_bump,
+                    MemoryHeader::SIZE as u64,
+                )?;
+                processor::memory_init_header(&ctx, locked)?;
+            }
+            LighthouseInstruction::AssertMemoryHeader {
+                assertion,
+                error_code,
+                ..
+            } => {
+                let ctx = AssertTargetAccountContext::load(&mut accounts.iter())?;
+                processor::assert_target_account(
+                    ctx,
+                    &AssertionWithErrorCode::new(&assertion, error_code),
+                    log_context,
+                )?;
+            }
+            LighthouseInstruction::AssertAccountSnapshot {
+                assertion,
+                error_code,
+                ..
+            } => {
+                let ctx = AssertAccountDeltaContext::load(&mut accounts.iter())?;
+                processor::assert_account_delta(
+                    &ctx,
+                    &AssertionWithErrorCode::new(&assertion, error_code),
+                    log_context,
+                )?;
+            }
+            LighthouseInstruction::MemoryCloseToRecipient {
+                memory_id,
+                memory_bump,
+                top_level_only,
+            } => {
+                let account_iter = &mut accounts.iter();
+                let ctx = MemoryCloseContext::load(
+                    account_iter,
+                    MemoryNamespace::Authority {
+                        memory_id,
+                        top_level_only,
+                    },
+                    memory_bump,
+                )?;
+                processor::memory_close_to_recipient(&ctx, next_account_info(account_iter)?)?;
+            }
+            LighthouseInstruction::MemoryZero {
+                memory_id,
+                memory_bump,
+                top_level_only,
+                offset,
+                length,
+            } => {
+                let account_iter = &mut accounts.iter();
+                let ctx = MemoryCloseContext::load(
+                    account_iter,
+                    MemoryNamespace::Authority {
+                        memory_id,
+                        top_level_only,
+                    },
+                    memory_bump,
+                )?;
+                processor::memory_zero(&ctx, offset, length)?;
+            }
+            LighthouseInstruction::MemoryShrink {
+                memory_id,
+                memory_bump,
+                top_level_only,
+                new_size,
+            } => {
+                let account_iter = &mut accounts.iter();
+                let ctx = MemoryCloseContext::load(
+                    account_iter,
+                    MemoryNamespace::Authority {
+                        memory_id,
+                        top_level_only,
+                    },
+                    memory_bump,
+                )?;
+                processor::memory_shrink(&ctx, new_size)?;
+            }
+            LighthouseInstruction::MemoryWriteWithAuthority {
+                memory_id,
+                memory_bump,
+                top_level_only,
+                write_offset,
+                write_type,
+            } => {
+                let ctx = MemoryWriteContext::load(
+                    &mut accounts.iter(),
+                    MemoryNamespace::Authority {
+                        memory_id,
+                        top_level_only,
+                    },
+                    memory_bump,
+                    (write_offset as u64) + write_type.data_length(),
+                )?;
+                processor::memory_write(&ctx, write_offset, &write_type)?;
+            }
+            LighthouseInstruction::MemoryCloseWithAuthority {
+                memory_id,
+                memory_bump,
+                top_level_only,
+            } => {
+                let ctx = MemoryCloseContext::load(
+                    &mut accounts.iter(),
+                    MemoryNamespace::Authority {
+                        memory_id,
+                        top_level_only,
+                    },
+                    memory_bump,
+                )?;
+                processor::memory_close(&ctx)?;
             }
         }
 
@@ -1,5 +1,5 @@
 use crate::{
-    types::assert::{Assert, LogLevel},
+    types::assert::{Assert, LogContext},
     Result,
 };
 use solana_program::account_info::{next_account_info, AccountInfo};
@@ -26,7 +26,10 @@ pub(crate) fn assert_account_delta<
 >(
     ctx: &AssertAccountDeltaContext<'a, 'info>,
     assertion: &T,
-    log_level: LogLevel,
+    log_context: LogContext,
 ) -> Result<()> {
-    assertion.evaluate((ctx.left_account, ctx.right_account), log_level)
+    assertion.evaluate(
+        (ctx.left_account, ctx.right_account),
+        log_context.with_target_account(ctx.right_account.key),
+    )
 }
@@ -1,6 +1,6 @@
 use crate::{
     error::LighthouseError,
-    types::assert::{Assert, LogLevel},
+    types::assert::{Assert, LogContext},
     utils::{keys_equal, Result},
     validation::MPL_BUBBLEGUM_ID,
 };
@@ -33,7 +33,10 @@ pub(crate) fn assert_bubblegum_tree_config_account<
 >(
     ctx: &'a AssertBubblegumTreeConfigAccountContext<'a, 'info>,
     assertion: &T,
-    log_level: LogLevel,
+    log_context: LogContext,
 ) -> Result<()> {
-    assertion.evaluate(ctx.tree_config_account, log_level)
+    assertion.evaluate(
+        ctx.tree_config_account,
+        log_context.with_target_account(ctx.tree_config_account.key),
+    )
 }
@@ -1,12 +1,13 @@
 use crate::{
-    types::assert::{Assert, LogLevel},
+    types::assert::{Assert, LogContext},
     utils::Result,
 };
+use solana_program::sysvar::clock;
 use std::fmt::Debug;
 
 pub(crate) fn assert_clock<T: Assert<()> + Debug>(
     assertion: &T,
-    log_level: LogLevel,
+    log_context: LogContext,
 ) -> Result<()> {
-    assertion.evaluate((), log_level)
+    assertion.evaluate((), log_context.with_target_account(&clock::ID))
 }
@@ -0,0 +1,59 @@
+use crate::{
+    error::LighthouseError,
+    processor::assert_multi,
+    types::assert::{Assert, EvaluationMode, LogContext},
+    utils::{keys_equal, Result},
+    validation::SPL_ACCOUNT_COMPRESSION_ID,
+};
+use solana_program::account_info::{next_account_info, AccountInfo};
+use std::{fmt::Debug, slice::Iter};
+
+#[derive(Clone)]
+pub(crate) struct AssertConcurrentMerkleTreeAccountContext<'a, 'info> {
+    pub(crate) merkle_tree: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> AssertConcurrentMerkleTreeAccountContext<'a, 'info> {
+    pub(crate) fn load(account_iter: &mut Iter<'a, AccountInfo<'info>>) -> Result<Self> {
+        let merkle_tree = next_account_info(account_iter)?;
+
+        if !keys_equal(merkle_tree.owner, &SPL_ACCOUNT_COMPRESSION_ID) {
+            return Err(LighthouseError::AccountOwnerMismatch.into());
+        }
+
+        Ok(Self { merkle_tree })
+    }
+}
+
+pub(crate) fn assert_concurrent_merkle_tree_account<
+    'a,
+    'info,
+    T: Assert<&'a AccountInfo<'info>> + Debug,
+>(
+    ctx: AssertConcurrentMerkleTreeAccountContext<'a, 'info>,
+    assertion: &T,
+    log_context: LogContext,
+) -> Result<()> {
+    assertion.evaluate(
+        ctx.merkle_tree,
+        log_context.with_target_account(ctx.merkle_tree.key),
+    )
+}
+
+pub(crate) fn assert_concurrent_merkle_tree_account_multi<
+    'a,
+    'info,
+    T: Assert<&'a AccountInfo<'info>> + Debug,
+>(
+    ctx: AssertConcurrentMerkleTreeAccountContext<'a, 'info>,
+    assertions: &[T],
+    log_context: LogContext,
+    evaluation_mode: &EvaluationMode,
+) -> Result<()> {
+    assert_multi(
+        assertions,
+        ctx.merkle_tree,
+        log_context.with_target_account(ctx.merkle_tree.key),
+        evaluation_mode,
+    )
+}
@@ -1,5 +1,5 @@
 use crate::{
-    types::assert::{Assert, LogLevel},
+    types::assert::{Assert, LogContext},
     utils::Result,
     validation::{CheckedAccount, Program, SplAccountCompressionProgram},
 };
@@ -31,7 +31,7 @@ pub(crate) fn assert_merkle_tree_account<
 >(
     ctx: &'a AssertMerkleTreeAccountContext<'a, 'info>,
     assertion: &T,
-    log_level: LogLevel,
+    log_context: LogContext,
 ) -> Result<()> {
-    assertion.evaluate(ctx, log_level)
+    assertion.evaluate(ctx, log_context.with_target_account(ctx.merkle_tree.key))
 }
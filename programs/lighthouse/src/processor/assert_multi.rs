@@ -0,0 +1,166 @@
+use crate::{
+    error::LighthouseError,
+    types::assert::{Assert, EvaluationMode, LogContext},
+    utils::Result,
+};
+use solana_program::{msg, program_error::ProgramError};
+
+///
+/// Evaluates assertions in order against the same parameters. Strict mode stops at the first failure,
+/// soft mode evaluates every assertion and only fails once the failure count exceeds the configured maximum.
+///
+pub(crate) fn assert_multi<T: Assert<U>, U: Copy>(
+    assertions: &[T],
+    parameters: U,
+    log_context: LogContext,
+    evaluation_mode: &EvaluationMode,
+) -> Result<()> {
+    let mut failures = 0;
+    let mut first_failure: Option<(usize, ProgramError)> = None;
+
+    for (i, assertion) in assertions.iter().enumerate() {
+        if let Err(e) = assertion.evaluate(parameters, log_context.with_assertion_index(i)?) {
+            // Errors other than a failed assertion always abort the instruction.
+            if !LighthouseError::is_assertion_failure(&e) {
+                return Err(LighthouseError::map_multi_err(e, i as u32));
+            }
+
+            failures += 1;
+            first_failure.get_or_insert((i, e));
+
+            if *evaluation_mode == EvaluationMode::Strict {
+                break;
+            }
+        }
+    }
+
+    if log_context.is_summary() {
+        let evaluated = match (evaluation_mode, &first_failure) {
+            (EvaluationMode::Strict, Some((i, _))) => i + 1,
+            _ => assertions.len(),
+        };
+
+        match &first_failure {
+            Some((i, _)) => msg!(
+                "Summary: {} of {} assertions passed, first failure at index {}",
+                evaluated - failures,
+                assertions.len(),
+                i
+            ),
+            None => msg!(
+                "Summary: {} of {} assertions passed",
+                assertions.len(),
+                assertions.len()
+            ),
+        }
+    }
+
+    match first_failure {
+        Some((i, e)) if evaluation_mode.exceeds_max_failures(failures) => {
+            Err(LighthouseError::map_multi_err(e, i as u32))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::LighthouseError,
+        processor::assert_multi,
+        test_utils::{assert_is_program_error, assert_passed},
+        types::assert::{
+            AccountInfoAssertion, EvaluationMode, IntegerOperator, LogContext, LogLevel,
+        },
+    };
+    use solana_sdk::{
+        account_info::AccountInfo, program_error::ProgramError, signature::Keypair,
+        signer::EncodableKeypair, system_program,
+    };
+
+    #[test]
+    fn assert_multi_evaluation_mode() {
+        let key = Keypair::new().encodable_pubkey();
+        let lamports = &mut 100;
+        let data: &mut [u8] = &mut [];
+        let account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            lamports,
+            data,
+            &system_program::ID,
+            false,
+            0,
+        );
+
+        let lamports_assertion = |value: u64| AccountInfoAssertion::Lamports {
+            value,
+            operator: IntegerOperator::Equal,
+        };
+        let assertions = [
+            lamports_assertion(100),
+            lamports_assertion(101),
+            lamports_assertion(100),
+            lamports_assertion(102),
+        ];
+        let log_context = LogContext::from(LogLevel::Silent);
+
+        assert_is_program_error(
+            assert_multi(
+                &assertions,
+                &account_info,
+                log_context,
+                &EvaluationMode::Strict,
+            )
+            .unwrap_err(),
+            ProgramError::Custom(0x1900 + 1),
+        );
+
+        assert_passed(assert_multi(
+            &assertions,
+            &account_info,
+            log_context,
+            &EvaluationMode::Soft { max_failures: None },
+        ));
+
+        assert_passed(assert_multi(
+            &assertions,
+            &account_info,
+            log_context,
+            &EvaluationMode::Soft {
+                max_failures: Some(2),
+            },
+        ));
+
+        // Fails with the first failing assertion once past the maximum
+        assert_is_program_error(
+            assert_multi(
+                &assertions,
+                &account_info,
+                log_context,
+                &EvaluationMode::Soft {
+                    max_failures: Some(1),
+                },
+            )
+            .unwrap_err(),
+            ProgramError::Custom(0x1900 + 1),
+        );
+
+        // Errors other than failed assertions are not collected
+        assert_is_program_error(
+            assert_multi(
+                &[AccountInfoAssertion::VerifyDatahash {
+                    expected_hash: [0; 32],
+                    start: Some(1),
+                    length: None,
+                }],
+                &account_info,
+                log_context,
+                &EvaluationMode::Soft { max_failures: None },
+            )
+            .unwrap_err(),
+            LighthouseError::RangeOutOfBounds.into(),
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     error::LighthouseError,
-    types::assert::{Assert, LogLevel},
+    processor::assert_multi,
+    types::assert::{Assert, EvaluationMode, LogContext},
     utils::{keys_equal, Result},
 };
 use borsh::BorshDeserialize;
@@ -30,7 +31,7 @@ impl<'a, 'info> AssertStakeAccountContext<'a, 'info> {
 pub(crate) fn assert_stake_account<'a, 'info, T: for<'b> Assert<&'b StakeStateV2> + Debug>(
     ctx: AssertStakeAccountContext<'a, 'info>,
     assertion: T,
-    log_level: LogLevel,
+    log_context: LogContext,
 ) -> Result<()> {
     let data = ctx
         .stake_account
@@ -40,13 +41,17 @@ pub(crate) fn assert_stake_account<'a, 'info, T: for<'b> Assert<&'b StakeStateV2
     let stake_account =
         StakeStateV2::deserialize(&mut data.as_ref()).map_err(LighthouseError::stake_deser_err)?;
 
-    assertion.evaluate(&stake_account, log_level)
+    assertion.evaluate(
+        &stake_account,
+        log_context.with_target_account(ctx.stake_account.key),
+    )
 }
 
 pub(crate) fn assert_stake_account_multi<'a, 'info, T: for<'b> Assert<&'b StakeStateV2> + Debug>(
     ctx: AssertStakeAccountContext<'a, 'info>,
     assertions: &[T],
-    log_level: LogLevel,
+    log_context: LogContext,
+    evaluation_mode: &EvaluationMode,
 ) -> Result<()> {
     let data = ctx
         .stake_account
@@ -56,11 +61,10 @@ pub(crate) fn assert_stake_account_multi<'a, 'info, T: for<'b> Assert<&'b StakeS
     let stake_account =
         StakeStateV2::deserialize(&mut data.as_ref()).map_err(LighthouseError::stake_deser_err)?;
 
-    for (i, assertion) in assertions.iter().enumerate() {
-        assertion
-            .evaluate(&stake_account, log_level)
-            .map_err(|e| LighthouseError::map_multi_err(e, i as u32))?;
-    }
-
-    Ok(())
+    assert_multi(
+        assertions,
+        &stake_account,
+        log_context.with_target_account(ctx.stake_account.key),
+        evaluation_mode,
+    )
 }
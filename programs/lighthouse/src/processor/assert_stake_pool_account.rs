@@ -0,0 +1,83 @@
+use crate::{
+    err, err_msg,
+    error::LighthouseError,
+    processor::assert_multi,
+    types::assert::{Assert, EvaluationMode, LogContext, StakePool, StakePoolAccountType},
+    utils::{keys_equal, Result},
+    validation::SPL_STAKE_POOL_ID,
+};
+use borsh::BorshDeserialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    msg,
+};
+use std::{fmt::Debug, slice::Iter};
+
+#[derive(Clone)]
+pub(crate) struct AssertStakePoolAccountContext<'a, 'info> {
+    pub(crate) stake_pool_account: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> AssertStakePoolAccountContext<'a, 'info> {
+    pub(crate) fn load(account_iter: &mut Iter<'a, AccountInfo<'info>>) -> Result<Self> {
+        let stake_pool_account = next_account_info(account_iter)?;
+
+        if !keys_equal(stake_pool_account.owner, &SPL_STAKE_POOL_ID) {
+            return Err(LighthouseError::AccountOwnerMismatch.into());
+        }
+
+        Ok(Self { stake_pool_account })
+    }
+
+    fn load_stake_pool(&self) -> Result<StakePool> {
+        let data = self
+            .stake_pool_account
+            .try_borrow_data()
+            .map_err(LighthouseError::failed_borrow_err)?;
+
+        let stake_pool = StakePool::deserialize(&mut data.as_ref()).map_err(|e| {
+            err_msg!("Failed to deserialize stake pool account", e);
+            err!(LighthouseError::FailedToDeserialize)
+        })?;
+
+        if stake_pool.account_type != StakePoolAccountType::StakePool {
+            msg!("Account is not a stake pool: {:?}", stake_pool.account_type);
+            return Err(LighthouseError::AccountDiscriminatorValidationFailed.into());
+        }
+
+        Ok(stake_pool)
+    }
+}
+
+pub(crate) fn assert_stake_pool_account<'a, 'info, T: for<'b> Assert<&'b StakePool> + Debug>(
+    ctx: AssertStakePoolAccountContext<'a, 'info>,
+    assertion: &T,
+    log_context: LogContext,
+) -> Result<()> {
+    let stake_pool = ctx.load_stake_pool()?;
+
+    assertion.evaluate(
+        &stake_pool,
+        log_context.with_target_account(ctx.stake_pool_account.key),
+    )
+}
+
+pub(crate) fn assert_stake_pool_account_multi<
+    'a,
+    'info,
+    T: for<'b> Assert<&'b StakePool> + Debug,
+>(
+    ctx: AssertStakePoolAccountContext<'a, 'info>,
+    assertions: &[T],
+    log_context: LogContext,
+    evaluation_mode: &EvaluationMode,
+) -> Result<()> {
+    let stake_pool = ctx.load_stake_pool()?;
+
+    assert_multi(
+        assertions,
+        &stake_pool,
+        log_context.with_target_account(ctx.stake_pool_account.key),
+        evaluation_mode,
+    )
+}
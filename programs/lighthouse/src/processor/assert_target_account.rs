@@ -1,6 +1,6 @@
 use crate::{
-    error::LighthouseError,
-    types::assert::{Assert, LogLevel},
+    processor::assert_multi,
+    types::assert::{Assert, EvaluationMode, LogContext},
     utils::Result,
 };
 use solana_program::account_info::{next_account_info, AccountInfo};
@@ -22,21 +22,24 @@ impl<'a, 'info> AssertTargetAccountContext<'a, 'info> {
 pub(crate) fn assert_target_account<'a, 'info, T: Assert<&'a AccountInfo<'info>> + Debug>(
     ctx: AssertTargetAccountContext<'a, 'info>,
     assertion: &T,
-    log_level: LogLevel,
+    log_context: LogContext,
 ) -> Result<()> {
-    assertion.evaluate(ctx.target_account, log_level)
+    assertion.evaluate(
+        ctx.target_account,
+        log_context.with_target_account(ctx.target_account.key),
+    )
 }
 
 pub(crate) fn assert_target_account_multi<'a, 'info, T: Assert<&'a AccountInfo<'info>> + Debug>(
     ctx: AssertTargetAccountContext<'a, 'info>,
     assertions: &[T],
-    log_level: LogLevel,
+    log_context: LogContext,
+    evaluation_mode: &EvaluationMode,
 ) -> Result<()> {
-    for (i, assertion) in assertions.iter().enumerate() {
-        assertion
-            .evaluate(ctx.target_account, log_level)
-            .map_err(|e| LighthouseError::map_multi_err(e, i as u32))?;
-    }
-
-    Ok(())
+    assert_multi(
+        assertions,
+        ctx.target_account,
+        log_context.with_target_account(ctx.target_account.key),
+        evaluation_mode,
+    )
 }
@@ -1,7 +1,8 @@
 use crate::{
     err, err_msg,
     error::LighthouseError,
-    types::assert::{Assert, LogLevel},
+    processor::assert_multi,
+    types::assert::{Assert, EvaluationMode, LogContext},
     utils::{keys_equal, Result},
 };
 use solana_program::{
@@ -39,7 +40,7 @@ pub(crate) fn assert_upgradeable_loader_state<
 >(
     ctx: AssertUpgradeableLoaderStateContext<'a, 'info>,
     assertion: &T,
-    log_level: LogLevel,
+    log_context: LogContext,
 ) -> Result<()> {
     let data = ctx
         .upgradeable_loader_account
@@ -51,7 +52,10 @@ pub(crate) fn assert_upgradeable_loader_state<
         err!(LighthouseError::AccountBorrowFailed)
     })?;
 
-    assertion.evaluate(&state, log_level)
+    assertion.evaluate(
+        &state,
+        log_context.with_target_account(ctx.upgradeable_loader_account.key),
+    )
 }
 
 pub(crate) fn assert_upgradeable_loader_state_multi<
@@ -61,7 +65,8 @@ pub(crate) fn assert_upgradeable_loader_state_multi<
 >(
     ctx: AssertUpgradeableLoaderStateContext<'a, 'info>,
     assertions: &[T],
-    log_level: LogLevel,
+    log_context: LogContext,
+    evaluation_mode: &EvaluationMode,
 ) -> Result<()> {
     let data = ctx
         .upgradeable_loader_account
@@ -73,11 +78,10 @@ pub(crate) fn assert_upgradeable_loader_state_multi<
         err!(LighthouseError::AccountBorrowFailed)
     })?;
 
-    for (i, assertion) in assertions.iter().enumerate() {
-        assertion
-            .evaluate(&state, log_level)
-            .map_err(|e| LighthouseError::map_multi_err(e, i as u32))?;
-    }
-
-    Ok(())
+    assert_multi(
+        assertions,
+        &state,
+        log_context.with_target_account(ctx.upgradeable_loader_account.key),
+        evaluation_mode,
+    )
 }
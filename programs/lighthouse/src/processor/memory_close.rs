@@ -1,22 +1,32 @@
+use crate::error::LighthouseError;
+use crate::processor::check_top_level_only;
+use crate::types::write::MemoryHeader;
 use crate::utils::{close, Result};
 use crate::validation::{
-    AccountValidation, CheckedAccount, DerivedAddress, LighthouseProgram, Memory, MemorySeeds,
-    Program, Signer,
+    AccountValidation, CheckedAccount, LighthouseProgram, Memory, MemoryNamespace, Program, Signer,
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    msg,
+    rent::Rent,
+    sysvar::Sysvar,
 };
-use solana_program::account_info::{next_account_info, AccountInfo};
 use std::slice::Iter;
 
 #[allow(dead_code)]
 pub(crate) struct MemoryCloseContext<'a, 'info> {
     pub lighthouse_program: Program<'a, 'info, LighthouseProgram>,
     pub payer: Signer<'a, 'info>,
+    pub authority: Signer<'a, 'info>,
     pub memory: Memory<'a, 'info>,
+    pub top_level_only: bool,
 }
 
 impl<'a, 'info> MemoryCloseContext<'a, 'info> {
     pub(crate) fn load(
         account_iter: &mut Iter<'a, AccountInfo<'info>>,
-        memory_id: u8,
+        namespace: MemoryNamespace,
         memory_bump: u8,
     ) -> Result<Self> {
         let lighthouse_program = Program::new_checked(next_account_info(account_iter)?, None)?;
@@ -24,12 +34,13 @@ impl<'a, 'info> MemoryCloseContext<'a, 'info> {
             next_account_info(account_iter)?,
             Some(&vec![AccountValidation::IsWritable]),
         )?;
+        let authority = if namespace.has_authority_account() {
+            Signer::new_checked(next_account_info(account_iter)?, None)?
+        } else {
+            payer.clone()
+        };
 
-        let seeds = &Memory::get_seeds(MemorySeeds {
-            payer: payer.key,
-            memory_id,
-            bump: Some(memory_bump),
-        });
+        let seeds = &namespace.get_seeds(authority.key, memory_bump);
 
         let memory = Memory::new_checked(
             next_account_info(account_iter)?,
@@ -47,11 +58,328 @@ impl<'a, 'info> MemoryCloseContext<'a, 'info> {
         Ok(Self {
             lighthouse_program,
             payer,
+            authority,
             memory,
+            top_level_only: namespace.top_level_only(),
         })
     }
 }
 
 pub(crate) fn memory_close(ctx: &MemoryCloseContext) -> Result<()> {
-    close(ctx.memory.info, ctx.payer.info)
+    memory_close_to_recipient(ctx, ctx.payer.info)
+}
+
+pub(crate) fn memory_close_to_recipient<'info>(
+    ctx: &MemoryCloseContext<'_, 'info>,
+    recipient: &AccountInfo<'info>,
+) -> Result<()> {
+    check_top_level_only(ctx.top_level_only)?;
+
+    if !recipient.is_writable {
+        msg!("Rent recipient must be writable");
+        return Err(LighthouseError::AccountValidationFailed.into());
+    }
+
+    if let Some(header) = MemoryHeader::load(&ctx.memory.info.try_borrow_data()?)? {
+        if header.is_locked() && header.locked_slot == Clock::get()?.slot {
+            msg!("Locked memory cannot be closed in the slot it was locked or written");
+            return Err(LighthouseError::MemoryLocked.into());
+        }
+    }
+
+    close(ctx.memory.info, recipient)
+}
+
+pub(crate) fn memory_zero(
+    ctx: &MemoryCloseContext,
+    offset: u16,
+    length: Option<u16>,
+) -> Result<()> {
+    check_top_level_only(ctx.top_level_only)?;
+
+    let memory_ref = &mut ctx.memory.info.try_borrow_mut_data()?;
+
+    let start = offset as usize;
+    let end = match length {
+        Some(length) => start + length as usize,
+        None => memory_ref.len(),
+    };
+
+    if let Some(header) = MemoryHeader::load(memory_ref)? {
+        if start < MemoryHeader::SIZE {
+            msg!("Memory zero range overlaps the memory header");
+            return Err(LighthouseError::RangeOutOfBounds.into());
+        }
+
+        if header.is_locked() && header.find_locked_range(start, end).is_some() {
+            msg!("Memory zero range overlaps locked memory");
+            return Err(LighthouseError::MemoryLocked.into());
+        }
+    }
+
+    memory_ref
+        .get_mut(start..end)
+        .ok_or_else(|| LighthouseError::oob_err(start..end))?
+        .fill(0);
+
+    Ok(())
+}
+
+pub(crate) fn memory_shrink(ctx: &MemoryCloseContext, new_size: u16) -> Result<()> {
+    check_top_level_only(ctx.top_level_only)?;
+
+    let memory_info = ctx.memory.info;
+    let new_size = new_size as usize;
+
+    if new_size > memory_info.data_len() {
+        msg!(
+            "Memory shrink size {} exceeds current size {}",
+            new_size,
+            memory_info.data_len()
+        );
+        return Err(LighthouseError::RangeOutOfBounds.into());
+    }
+
+    let header = MemoryHeader::load(&memory_info.try_borrow_data()?)?;
+    if let Some(header) = header {
+        if new_size < MemoryHeader::SIZE {
+            msg!("Memory shrink would truncate the memory header");
+            return Err(LighthouseError::RangeOutOfBounds.into());
+        }
+
+        if header.is_locked() && header.find_locked_range(new_size, usize::MAX).is_some() {
+            msg!("Memory shrink would truncate locked memory");
+            return Err(LighthouseError::MemoryLocked.into());
+        }
+    }
+
+    memory_info.realloc(new_size, false)?;
+
+    // Refund rent no longer required by the smaller account
+    let excess_lamports = memory_info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(new_size));
+
+    **memory_info.try_borrow_mut_lamports()? -= excess_lamports;
+    **ctx.payer.info.try_borrow_mut_lamports()? += excess_lamports;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        memory_close, memory_close_to_recipient, memory_shrink, memory_zero, MemoryCloseContext,
+    };
+    use crate::{
+        error::LighthouseError,
+        test_utils::{assert_is_program_error, setup_syscall_stubs, ReallocAccount, TEST_CLOCK},
+        types::write::{MemoryHeader, MEMORY_HEADER_FLAG_LOCKED},
+        validation::{CheckedAccount, Memory, Program, Signer},
+    };
+    use solana_program::{account_info::AccountInfo, rent::Rent, system_program};
+    use solana_sdk::{signature::Keypair, signer::EncodableKeypair};
+
+    fn memory_close_context<'a, 'info>(
+        lighthouse_program: &'a AccountInfo<'info>,
+        payer: &'a AccountInfo<'info>,
+        memory: &'a AccountInfo<'info>,
+    ) -> MemoryCloseContext<'a, 'info> {
+        MemoryCloseContext {
+            lighthouse_program: Program::new(lighthouse_program),
+            payer: Signer::new(payer),
+            authority: Signer::new(payer),
+            memory: Memory::new(memory),
+            top_level_only: false,
+        }
+    }
+
+    fn accounts(memory_data: &[u8]) -> [ReallocAccount; 3] {
+        [
+            ReallocAccount::new(crate::ID, 1, system_program::ID, &[]),
+            ReallocAccount::new(
+                Keypair::new().encodable_pubkey(),
+                0,
+                system_program::ID,
+                &[],
+            ),
+            ReallocAccount::new(
+                Keypair::new().encodable_pubkey(),
+                Rent::default().minimum_balance(memory_data.len()),
+                crate::ID,
+                memory_data,
+            ),
+        ]
+    }
+
+    #[test]
+    fn close_refunds_payer() {
+        let [mut program, mut payer, mut memory] = accounts(&[1; 64]);
+        let (program, payer, memory) = (
+            program.info(false, false),
+            payer.info(true, true),
+            memory.info(false, true),
+        );
+
+        memory_close(&memory_close_context(&program, &payer, &memory)).unwrap();
+
+        assert_eq!(payer.lamports(), Rent::default().minimum_balance(64));
+        assert_eq!(memory.lamports(), 0);
+        assert_eq!(memory.data_len(), 0);
+        assert_eq!(memory.owner, &system_program::ID);
+    }
+
+    #[test]
+    fn close_to_recipient() {
+        let [mut program, mut payer, mut memory] = accounts(&[1; 64]);
+        let mut recipient = ReallocAccount::new(
+            Keypair::new().encodable_pubkey(),
+            5,
+            system_program::ID,
+            &[],
+        );
+        let (program, payer, memory, recipient) = (
+            program.info(false, false),
+            payer.info(true, true),
+            memory.info(false, true),
+            recipient.info(false, true),
+        );
+        let ctx = memory_close_context(&program, &payer, &memory);
+
+        let readonly_recipient = AccountInfo {
+            is_writable: false,
+            ..recipient.clone()
+        };
+        let result = memory_close_to_recipient(&ctx, &readonly_recipient);
+        assert_is_program_error(
+            result.unwrap_err(),
+            LighthouseError::AccountValidationFailed.into(),
+        );
+        assert_eq!(memory.lamports(), Rent::default().minimum_balance(64));
+
+        memory_close_to_recipient(&ctx, &recipient).unwrap();
+
+        assert_eq!(
+            recipient.lamports(),
+            5 + Rent::default().minimum_balance(64)
+        );
+        assert_eq!(payer.lamports(), 0);
+        assert_eq!(memory.lamports(), 0);
+        assert_eq!(memory.data_len(), 0);
+    }
+
+    #[test]
+    fn close_locked_memory() {
+        setup_syscall_stubs();
+
+        let memory_data = &mut [0u8; 96];
+        MemoryHeader {
+            flags: MEMORY_HEADER_FLAG_LOCKED,
+            locked_slot: TEST_CLOCK.slot,
+            ..MemoryHeader::default()
+        }
+        .store(memory_data)
+        .unwrap();
+
+        let [mut program, mut payer, mut memory] = accounts(memory_data);
+        let (program, payer, memory) = (
+            program.info(false, false),
+            payer.info(true, true),
+            memory.info(false, true),
+        );
+        let ctx = memory_close_context(&program, &payer, &memory);
+
+        // Locked memory cannot be closed, recreated and rewritten in the slot it was locked
+        for result in [memory_close(&ctx), memory_close_to_recipient(&ctx, &payer)] {
+            assert_is_program_error(result.unwrap_err(), LighthouseError::MemoryLocked.into());
+        }
+        assert_eq!(memory.data_len(), 96);
+
+        MemoryHeader {
+            flags: MEMORY_HEADER_FLAG_LOCKED,
+            locked_slot: TEST_CLOCK.slot - 1,
+            ..MemoryHeader::default()
+        }
+        .store(&mut memory.try_borrow_mut_data().unwrap())
+        .unwrap();
+
+        memory_close(&ctx).unwrap();
+        assert_eq!(memory.data_len(), 0);
+    }
+
+    #[test]
+    fn zero_memory_range() {
+        let [mut program, mut payer, mut memory] = accounts(&[1; 64]);
+        let (program, payer, memory) = (
+            program.info(false, false),
+            payer.info(true, true),
+            memory.info(false, true),
+        );
+        let ctx = memory_close_context(&program, &payer, &memory);
+
+        memory_zero(&ctx, 8, Some(4)).unwrap();
+        {
+            let data = memory.try_borrow_data().unwrap();
+            assert_eq!(&data[..8], &[1; 8]);
+            assert_eq!(&data[8..12], &[0; 4]);
+            assert_eq!(&data[12..], &[1; 52]);
+        }
+
+        memory_zero(&ctx, 32, None).unwrap();
+        {
+            let data = memory.try_borrow_data().unwrap();
+            assert_eq!(&data[12..32], &[1; 20]);
+            assert_eq!(&data[32..], &[0; 32]);
+        }
+
+        let result = memory_zero(&ctx, 60, Some(8));
+        assert_is_program_error(
+            result.unwrap_err(),
+            LighthouseError::RangeOutOfBounds.into(),
+        );
+
+        // Zeroing never moves lamports
+        assert_eq!(memory.lamports(), Rent::default().minimum_balance(64));
+        assert_eq!(payer.lamports(), 0);
+    }
+
+    #[test]
+    fn shrink_refunds_excess_rent() {
+        setup_syscall_stubs();
+
+        let rent = Rent::default();
+        let [mut program, mut payer, mut memory] = accounts(&[1; 200]);
+        let (program, payer, memory) = (
+            program.info(false, false),
+            payer.info(true, true),
+            memory.info(false, true),
+        );
+        let ctx = memory_close_context(&program, &payer, &memory);
+
+        let result = memory_shrink(&ctx, 201);
+        assert_is_program_error(
+            result.unwrap_err(),
+            LighthouseError::RangeOutOfBounds.into(),
+        );
+
+        memory_shrink(&ctx, 120).unwrap();
+
+        assert_eq!(memory.data_len(), 120);
+        assert_eq!(&memory.try_borrow_data().unwrap()[..], &[1; 120]);
+        assert_eq!(memory.lamports(), rent.minimum_balance(120));
+        assert_eq!(
+            payer.lamports(),
+            rent.minimum_balance(200) - rent.minimum_balance(120)
+        );
+
+        // An empty account still has to hold the rent for the account metadata
+        memory_shrink(&ctx, 0).unwrap();
+
+        assert_eq!(memory.data_len(), 0);
+        assert_eq!(memory.lamports(), rent.minimum_balance(0));
+        assert_eq!(
+            payer.lamports(),
+            rent.minimum_balance(200) - rent.minimum_balance(0)
+        );
+    }
 }
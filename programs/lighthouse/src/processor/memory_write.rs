@@ -1,19 +1,29 @@
 use crate::error::LighthouseError;
-use crate::types::write::{AccountInfoField, ClockField, DataValue, WriteType};
-use crate::utils::Result;
+use crate::types::assert::{ACCOUNT_SNAPSHOT_KEY_LENGTH, TOKEN_ACCOUNT_AMOUNT_RANGE};
+use crate::types::write::{
+    AccountInfoField, AccumulateSource, ClockField, DataValue, EpochScheduleField, MemoryHeader,
+    MemoryWriteEntry, RentField, WriteType, MEMORY_HEADER_FLAG_LOCKED,
+};
+use crate::utils::{
+    borrow_token_program_data, data_hash_range, keys_equal, try_from_slice, Result,
+    TokenAccountType,
+};
 use crate::validation::{
-    AccountValidation, CheckedAccount, DerivedAddress, InitializeType, LighthouseProgram, Memory,
-    MemorySeeds, Program, Signer, SystemProgram,
+    AccountValidation, CheckedAccount, InitializeType, LighthouseProgram, Memory, MemoryNamespace,
+    Program, Signer, SystemProgram,
 };
 use crate::{err, err_msg};
 use borsh::BorshSerialize;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     clock::Clock,
+    compute_units::sol_remaining_compute_units,
+    epoch_schedule::EpochSchedule,
     instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT},
     msg,
     program_error::ProgramError,
-    sysvar::Sysvar,
+    rent::Rent,
+    sysvar::{instructions, slot_hashes, Sysvar},
 };
 
 use std::slice::Iter;
@@ -23,17 +33,18 @@ pub(crate) struct MemoryWriteContext<'a, 'info> {
     pub lighthouse_program: Program<'a, 'info, LighthouseProgram>,
     pub system_program: Program<'a, 'info, SystemProgram>,
     pub payer: Signer<'a, 'info>,
+    pub authority: Signer<'a, 'info>,
     pub memory: Memory<'a, 'info>,
-    pub source_account: &'a AccountInfo<'info>,
+    pub top_level_only: bool,
+    pub source_accounts: &'a [AccountInfo<'info>],
 }
 
 impl<'a, 'info> MemoryWriteContext<'a, 'info> {
     pub(crate) fn load(
         account_iter: &mut Iter<'a, AccountInfo<'info>>,
-        memory_id: u8,
-        write_offset: u16,
+        namespace: MemoryNamespace,
         memory_bump: u8,
-        write_type: &WriteType,
+        required_space: u64,
     ) -> Result<Self> {
         let lighthouse_program = Program::new_checked(next_account_info(account_iter)?, None)?;
         let system_program = Program::new_checked(next_account_info(account_iter).unwrap(), None)?;
@@ -41,14 +52,13 @@ impl<'a, 'info> MemoryWriteContext<'a, 'info> {
             next_account_info(account_iter)?,
             Some(&vec![AccountValidation::IsWritable]),
         )?;
+        let authority = if namespace.has_authority_account() {
+            Signer::new_checked(next_account_info(account_iter)?, None)?
+        } else {
+            payer.clone()
+        };
 
-        let seeds = &Memory::get_seeds(MemorySeeds {
-            payer: payer.key,
-            memory_id,
-            bump: Some(memory_bump),
-        });
-
-        let required_space = (write_offset as u64) + write_type.data_length();
+        let seeds = &namespace.get_seeds(authority.key, memory_bump);
 
         let memory_info = next_account_info(account_iter)?;
         let memory = if memory_info.try_data_len()? < required_space as usize {
@@ -89,27 +99,172 @@ impl<'a, 'info> MemoryWriteContext<'a, 'info> {
             system_program,
             lighthouse_program,
             payer,
+            authority,
             memory,
-            source_account: next_account_info(account_iter)?,
+            top_level_only: namespace.top_level_only(),
+            source_accounts: account_iter.as_slice(),
+        })
+    }
+
+    fn source_account(&self, index: u8) -> Result<&'a AccountInfo<'info>> {
+        self.source_accounts.get(index as usize).ok_or_else(|| {
+            msg!("Source account index {} not provided", index);
+            ProgramError::NotEnoughAccountKeys
         })
     }
 }
 
+pub(crate) fn memory_write_required_space(entries: &[MemoryWriteEntry]) -> u64 {
+    entries
+        .iter()
+        .map(|entry| (entry.write_offset as u64) + entry.write_type.data_length())
+        .max()
+        .unwrap_or(0)
+}
+
 pub(crate) fn memory_write(
     ctx: &MemoryWriteContext,
     offset: u16,
     write_type: &WriteType,
 ) -> Result<()> {
-    if get_stack_height() > TRANSACTION_LEVEL_STACK_HEIGHT {
+    let source_account = ctx.source_account(0)?;
+
+    write_memory(ctx, &[(source_account, offset, write_type)])
+}
+
+pub(crate) fn memory_write_multi(
+    ctx: &MemoryWriteContext,
+    entries: &[MemoryWriteEntry],
+) -> Result<()> {
+    let writes = entries
+        .iter()
+        .map(|entry| {
+            Ok((
+                ctx.source_account(entry.source_account_index)?,
+                entry.write_offset,
+                &entry.write_type,
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    write_memory(ctx, &writes)
+}
+
+pub(crate) fn memory_init_header(ctx: &MemoryWriteContext, locked: bool) -> Result<()> {
+    check_top_level_only(ctx.top_level_only)?;
+
+    let memory_ref = &mut ctx.memory.info.try_borrow_mut_data()?;
+
+    if let Some(header) = MemoryHeader::load(memory_ref)? {
+        if header.is_locked() {
+            msg!("Locked memory header cannot be reinitialized before close");
+            return Err(LighthouseError::MemoryLocked.into());
+        }
+    }
+
+    if locked {
+        MemoryHeader {
+            flags: MEMORY_HEADER_FLAG_LOCKED,
+            locked_slot: Clock::get()?.slot,
+            ..MemoryHeader::default()
+        }
+        .store(memory_ref)
+    } else {
+        MemoryHeader::default().store(memory_ref)
+    }
+}
+
+pub(crate) fn check_top_level_only(top_level_only: bool) -> Result<()> {
+    if top_level_only && get_stack_height() > TRANSACTION_LEVEL_STACK_HEIGHT {
         msg!("Cross-program invocation violation");
         return Err(LighthouseError::CrossProgramInvokeViolation.into());
     }
 
-    let memory = ctx.memory.clone();
-    let source_account = ctx.source_account;
+    Ok(())
+}
 
+fn write_memory(
+    ctx: &MemoryWriteContext,
+    writes: &[(&AccountInfo, u16, &WriteType)],
+) -> Result<()> {
+    check_top_level_only(ctx.top_level_only)?;
+
+    let memory_ref = &mut ctx.memory.info.try_borrow_mut_data()?;
+    let mut header = MemoryHeader::load(memory_ref)?;
+
+    for (i, (source_account, offset, write_type)) in writes.iter().enumerate() {
+        if let Some(header) = header.as_mut() {
+            if (*offset as usize) < MemoryHeader::SIZE {
+                msg!("Memory write entry {} overlaps the memory header", i);
+                return Err(LighthouseError::RangeOutOfBounds.into());
+            }
+
+            if header.is_locked() {
+                let write_start = *offset as u64;
+                let write_end = write_start + write_type.data_length();
+
+                if let Some(range) =
+                    header.find_locked_range(write_start as usize, write_end as usize)
+                {
+                    msg!(
+                        "Memory write entry {} at {}..{} overlaps locked memory {}..{}",
+                        i,
+                        write_start,
+                        write_end,
+                        range.start,
+                        range.end
+                    );
+                    return Err(LighthouseError::MemoryLocked.into());
+                }
+
+                header.lock_range(write_start, write_end)?;
+            }
+        }
+
+        if let Err(e) = write(memory_ref, source_account, *offset, write_type) {
+            msg!("Memory write entry {} failed", i);
+            return Err(e);
+        }
+    }
+
+    match header {
+        Some(mut header) => {
+            let instructions_sysvar = ctx
+                .source_accounts
+                .iter()
+                .find(|account| keys_equal(account.key, &instructions::ID))
+                .ok_or_else(|| {
+                    msg!("Instructions sysvar is required to write to memory with a header");
+                    ProgramError::NotEnoughAccountKeys
+                })?;
+
+            header.slot = Clock::get()?.slot;
+            if header.is_locked() {
+                header.locked_slot = header.slot;
+            }
+            header.instruction_index =
+                instructions::load_current_index_checked(instructions_sysvar)?;
+            header.write_count = header.write_count.wrapping_add(1);
+            header.store(memory_ref)
+        }
+        None => {
+            if MemoryHeader::is_initialized(memory_ref) {
+                msg!("Raw memory write cannot create a memory header");
+                return Err(LighthouseError::AccountValidationFailed.into());
+            }
+
+            Ok(())
+        }
+    }
+}
+
+fn write(
+    memory_ref: &mut [u8],
+    source_account: &AccountInfo,
+    offset: u16,
+    write_type: &WriteType,
+) -> Result<()> {
     let write_offset = offset as usize;
-    let memory_ref = &mut memory.info.try_borrow_mut_data()?;
 
     match write_type {
         WriteType::DataValue(data_value) => {
@@ -134,21 +289,7 @@ pub(crate) fn memory_write(
                 DataValue::Pubkey(value) => value.to_bytes().to_vec(),
             };
 
-            let data_length = write_type.data_length() as usize;
-            let memory_write_range = write_offset..(write_offset + data_length);
-            let memory_write_slice =
-                memory_ref
-                    .get_mut(memory_write_range.clone())
-                    .ok_or_else(|| {
-                        msg!(
-                            "DataValue write - range out of bounds {:?} write length {:?}",
-                            memory_write_range,
-                            memory.info.data_len()
-                        );
-                        LighthouseError::RangeOutOfBounds
-                    })?;
-
-            memory_write_slice.copy_from_slice(&bytes);
+            write_bytes(memory_ref, write_offset, &bytes, "DataValue")?;
         }
         WriteType::AccountData {
             offset: data_offset,
@@ -168,19 +309,7 @@ pub(crate) fn memory_write(
                 LighthouseError::RangeOutOfBounds
             })?;
 
-            let memory_write_range = write_offset..(write_offset + data_length);
-            let memory_write_slice =
-                memory_ref
-                    .get_mut(memory_write_range.clone())
-                    .ok_or_else(|| {
-                        msg!(
-                            "AccountData - write range out of bounds {:?}",
-                            memory_write_range
-                        );
-                        LighthouseError::RangeOutOfBounds
-                    })?;
-
-            memory_write_slice.copy_from_slice(data_slice);
+            write_bytes(memory_ref, write_offset, data_slice, "AccountData")?;
         }
         WriteType::AccountInfoField(field) => {
             let bytes = match field {
@@ -223,20 +352,7 @@ pub(crate) fn memory_write(
                 }
             };
 
-            let data_length = write_type.data_length() as usize;
-            let memory_write_range = write_offset..(write_offset + data_length);
-            let memory_write_slice =
-                memory_ref
-                    .get_mut(memory_write_range.clone())
-                    .ok_or_else(|| {
-                        msg!(
-                            "AccountInfo write - range out of bounds {:?}",
-                            memory_write_range
-                        );
-                        LighthouseError::RangeOutOfBounds
-                    })?;
-
-            memory_write_slice.copy_from_slice(&bytes);
+            write_bytes(memory_ref, write_offset, &bytes, "AccountInfo")?;
         }
         WriteType::Clock(clock_field) => {
             let clock = Clock::get()?;
@@ -268,19 +384,1006 @@ pub(crate) fn memory_write(
                 })?,
             };
 
-            let data_length = write_type.data_length() as usize;
-            let memory_write_range = write_offset..(write_offset + data_length);
-            let memory_write_slice =
-                memory_ref
-                    .get_mut(memory_write_range.clone())
-                    .ok_or_else(|| {
-                        msg!("Clock write - range out of bounds {:?}", memory_write_range);
-                        LighthouseError::RangeOutOfBounds
-                    })?;
-
-            memory_write_slice.copy_from_slice(&bytes);
+            write_bytes(memory_ref, write_offset, &bytes, "Clock")?;
+        }
+        WriteType::AccountDataHash {
+            hash_type,
+            start,
+            length,
+        } => {
+            let data = source_account.try_borrow_data().map_err(|err| {
+                msg!("Failed to borrow target account: {:?}", err);
+                LighthouseError::AccountBorrowFailed
+            })?;
+
+            let hash = hash_type.hash(data_hash_range(&data, *start, *length)?);
+
+            write_bytes(memory_ref, write_offset, &hash, "AccountDataHash")?;
+        }
+        WriteType::Rent(rent_field) => {
+            let rent = Rent::get()?;
+
+            let bytes = match rent_field {
+                RentField::LamportsPerByteYear => {
+                    rent.lamports_per_byte_year.to_le_bytes().to_vec()
+                }
+                RentField::ExemptionThreshold => rent.exemption_threshold.to_le_bytes().to_vec(),
+                RentField::BurnPercent => vec![rent.burn_percent],
+            };
+
+            write_bytes(memory_ref, write_offset, &bytes, "Rent")?;
+        }
+        WriteType::EpochSchedule(epoch_schedule_field) => {
+            let epoch_schedule = EpochSchedule::get()?;
+
+            let bytes = match epoch_schedule_field {
+                EpochScheduleField::SlotsPerEpoch => {
+                    epoch_schedule.slots_per_epoch.to_le_bytes().to_vec()
+                }
+                EpochScheduleField::LeaderScheduleSlotOffset => epoch_schedule
+                    .leader_schedule_slot_offset
+                    .to_le_bytes()
+                    .to_vec(),
+                EpochScheduleField::Warmup => vec![epoch_schedule.warmup as u8],
+                EpochScheduleField::FirstNormalEpoch => {
+                    epoch_schedule.first_normal_epoch.to_le_bytes().to_vec()
+                }
+                EpochScheduleField::FirstNormalSlot => {
+                    epoch_schedule.first_normal_slot.to_le_bytes().to_vec()
+                }
+            };
+
+            write_bytes(memory_ref, write_offset, &bytes, "EpochSchedule")?;
+        }
+        WriteType::RemainingComputeUnits => {
+            let bytes = sol_remaining_compute_units().to_le_bytes();
+
+            write_bytes(memory_ref, write_offset, &bytes, "RemainingComputeUnits")?;
+        }
+        WriteType::InstructionIndex => {
+            if !keys_equal(source_account.key, &instructions::ID) {
+                msg!("InstructionIndex source account must be the instructions sysvar");
+                return Err(LighthouseError::AccountKeyMismatch.into());
+            }
+
+            let bytes = instructions::load_current_index_checked(source_account)?.to_le_bytes();
+
+            write_bytes(memory_ref, write_offset, &bytes, "InstructionIndex")?;
+        }
+        WriteType::LatestSlotHash => {
+            if !keys_equal(source_account.key, &slot_hashes::ID) {
+                msg!("LatestSlotHash source account must be the slot hashes sysvar");
+                return Err(LighthouseError::AccountKeyMismatch.into());
+            }
+
+            let data = source_account
+                .try_borrow_data()
+                .map_err(LighthouseError::failed_borrow_err)?;
+
+            // SlotHashes is a length prefixed vec of (slot, hash) ordered from most recent
+            let entry_count = try_from_slice::<u64>(&data, 0, None)?;
+            if entry_count == 0 {
+                msg!("SlotHashes sysvar has no entries");
+                return Err(LighthouseError::IndexOutOfBounds.into());
+            }
+
+            let bytes = data
+                .get(8..48)
+                .ok_or_else(|| LighthouseError::oob_err(8..48))?;
+
+            write_bytes(memory_ref, write_offset, bytes, "LatestSlotHash")?;
+        }
+        WriteType::Accumulate {
+            operation,
+            accumulator,
+            source,
+        } => {
+            let value = read_accumulate_source(source_account, source)?;
+
+            let accumulator_size = accumulator.size();
+            let accumulator_range = write_offset..(write_offset + accumulator.data_length());
+            let accumulator_slice = memory_ref
+                .get(accumulator_range.clone())
+                .ok_or_else(|| LighthouseError::oob_err(accumulator_range))?;
+
+            let current_value = if accumulator_slice[accumulator_size] != 0 {
+                let mut accumulator_bytes = [0u8; 16];
+                accumulator_bytes[..accumulator_size]
+                    .copy_from_slice(&accumulator_slice[..accumulator_size]);
+
+                Some(u128::from_le_bytes(accumulator_bytes))
+            } else {
+                None
+            };
+
+            let new_value = operation
+                .apply(current_value, value)
+                .filter(|new_value| *new_value <= accumulator.max_value())
+                .ok_or_else(|| {
+                    msg!(
+                        "Accumulate overflow {:?} {:?} with {}",
+                        operation,
+                        current_value,
+                        value
+                    );
+                    LighthouseError::ArithmeticOverflow
+                })?;
+
+            write_bytes(
+                memory_ref,
+                write_offset,
+                &new_value.to_le_bytes()[..accumulator_size],
+                "Accumulate",
+            )?;
+            write_bytes(
+                memory_ref,
+                write_offset + accumulator_size,
+                &[1],
+                "Accumulate",
+            )?;
+        }
+        WriteType::TokenAccountField(field) => {
+            let data = borrow_token_program_data(source_account, TokenAccountType::Account)?;
+
+            let data_range = field.range();
+            let data_slice = data
+                .get(data_range.clone())
+                .ok_or_else(|| LighthouseError::oob_err(data_range))?;
+
+            write_bytes(memory_ref, write_offset, data_slice, "TokenAccountField")?;
+        }
+        WriteType::MintAccountField(field) => {
+            let data = borrow_token_program_data(source_account, TokenAccountType::Mint)?;
+
+            let data_range = field.range();
+            let data_slice = data
+                .get(data_range.clone())
+                .ok_or_else(|| LighthouseError::oob_err(data_range))?;
+
+            write_bytes(memory_ref, write_offset, data_slice, "MintAccountField")?;
+        }
+        WriteType::AccountSnapshot { data_length } => {
+            let data = source_account
+                .try_borrow_data()
+                .map_err(LighthouseError::failed_borrow_err)?;
+
+            let data_range = 0..(*data_length as usize);
+            let data_slice = data
+                .get(data_range.clone())
+                .ok_or_else(|| LighthouseError::oob_err(data_range))?;
+
+            write_bytes(
+                memory_ref,
+                write_offset,
+                source_account.key.as_ref(),
+                "AccountSnapshot",
+            )?;
+            write_bytes(
+                memory_ref,
+                write_offset + ACCOUNT_SNAPSHOT_KEY_LENGTH,
+                data_slice,
+                "AccountSnapshot",
+            )?;
         }
     };
 
     Ok(())
 }
+
+fn read_accumulate_source(source_account: &AccountInfo, source: &AccumulateSource) -> Result<u128> {
+    match source {
+        AccumulateSource::Lamports => Ok(source_account.try_lamports()? as u128),
+        AccumulateSource::TokenAmount => {
+            let data = borrow_token_program_data(source_account, TokenAccountType::Account)?;
+
+            Ok(try_from_slice::<u64>(&data, TOKEN_ACCOUNT_AMOUNT_RANGE.start, None)? as u128)
+        }
+        AccumulateSource::AccountData { offset, data_type } => {
+            let data = source_account
+                .try_borrow_data()
+                .map_err(LighthouseError::failed_borrow_err)?;
+
+            let offset = *offset as usize;
+            let data_range = offset..(offset + data_type.size());
+            let data_slice = data
+                .get(data_range.clone())
+                .ok_or_else(|| LighthouseError::oob_err(data_range))?;
+
+            let mut value_bytes = [0u8; 16];
+            value_bytes[..data_type.size()].copy_from_slice(data_slice);
+
+            Ok(u128::from_le_bytes(value_bytes))
+        }
+        AccumulateSource::Value(value) => Ok(*value),
+    }
+}
+
+fn write_bytes(
+    memory_ref: &mut [u8],
+    write_offset: usize,
+    bytes: &[u8],
+    source: &str,
+) -> Result<()> {
+    let memory_write_range = write_offset..(write_offset + bytes.len());
+    let memory_write_slice = memory_ref
+        .get_mut(memory_write_range.clone())
+        .ok_or_else(|| {
+            msg!(
+                "{} write - range out of bounds {:?}",
+                source,
+                memory_write_range
+            );
+            LighthouseError::RangeOutOfBounds
+        })?;
+
+    memory_write_slice.copy_from_slice(bytes);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{memory_init_header, write, write_memory, MemoryWriteContext};
+    use crate::{
+        error::LighthouseError,
+        test_utils::{
+            assert_is_program_error, setup_syscall_stubs, TEST_CLOCK, TEST_REMAINING_COMPUTE_UNITS,
+        },
+        types::write::{
+            AccumulateOperation, AccumulateSource, AccumulatorType, ClockField, DataValue,
+            EpochScheduleField, IntegerDataType, LockedRange, MemoryHeader, MintAccountField,
+            RentField, TokenAccountField, WriteType,
+        },
+        validation::{CheckedAccount, Memory, Program, Signer},
+    };
+    use solana_program::{
+        account_info::AccountInfo,
+        epoch_schedule::EpochSchedule,
+        program_option::COption,
+        program_pack::Pack,
+        pubkey::Pubkey,
+        rent::Rent,
+        sysvar::{instructions, slot_hashes},
+    };
+    use solana_sdk::{signature::Keypair, signer::EncodableKeypair};
+    use spl_token_2022::{
+        extension::AccountType,
+        state::{Account, AccountState, Mint, Multisig},
+    };
+
+    fn pack_token_account(data: &mut [u8]) -> Account {
+        let token_account = Account {
+            mint: Keypair::new().encodable_pubkey(),
+            owner: Keypair::new().encodable_pubkey(),
+            amount: 420,
+            delegate: COption::None,
+            state: AccountState::Initialized,
+            is_native: COption::None,
+            delegated_amount: 0,
+            close_authority: COption::None,
+        };
+        Account::pack(token_account, &mut data[..Account::LEN]).unwrap();
+
+        token_account
+    }
+
+    fn pack_mint(data: &mut [u8]) -> Mint {
+        let mint = Mint {
+            mint_authority: COption::Some(Keypair::new().encodable_pubkey()),
+            supply: 1_000,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        };
+        Mint::pack(mint, &mut data[..Mint::LEN]).unwrap();
+
+        mint
+    }
+
+    fn write_from(
+        data: &mut [u8],
+        owner: &Pubkey,
+        write_type: &WriteType,
+    ) -> Result<Vec<u8>, solana_program::program_error::ProgramError> {
+        let key = Keypair::new().encodable_pubkey();
+        let lamports = &mut 0;
+        let source_account = AccountInfo::new(&key, false, false, lamports, data, owner, false, 0);
+
+        let mut memory = vec![0u8; 64];
+        write(&mut memory, &source_account, 8, write_type)?;
+
+        Ok(memory)
+    }
+
+    #[test]
+    fn write_token_account_field() {
+        let data = &mut [0u8; Account::LEN];
+        let token_account = pack_token_account(data);
+
+        let memory = write_from(
+            data,
+            &spl_token::ID,
+            &WriteType::TokenAccountField(TokenAccountField::Owner),
+        )
+        .unwrap();
+        assert_eq!(&memory[8..40], token_account.owner.as_ref());
+
+        let memory = write_from(
+            data,
+            &spl_token::ID,
+            &WriteType::TokenAccountField(TokenAccountField::Amount),
+        )
+        .unwrap();
+        assert_eq!(&memory[8..16], &420u64.to_le_bytes());
+    }
+
+    #[test]
+    fn write_account_snapshot() {
+        let key = Keypair::new().encodable_pubkey();
+        let lamports = &mut 0;
+        let data = &mut [7u8; 24];
+        let source_account =
+            AccountInfo::new(&key, false, false, lamports, data, &spl_token::ID, false, 0);
+
+        let mut memory = vec![0u8; 64];
+        write(
+            &mut memory,
+            &source_account,
+            8,
+            &WriteType::AccountSnapshot { data_length: 16 },
+        )
+        .unwrap();
+
+        assert_eq!(&memory[8..40], key.as_ref());
+        assert_eq!(&memory[40..56], &[7u8; 16]);
+        assert_eq!(&memory[56..], &[0u8; 8]);
+
+        // Snapshot longer than the source account data
+        let result = write(
+            &mut memory,
+            &source_account,
+            8,
+            &WriteType::AccountSnapshot { data_length: 32 },
+        );
+
+        assert_is_program_error(
+            result.unwrap_err(),
+            LighthouseError::RangeOutOfBounds.into(),
+        );
+    }
+
+    #[test]
+    fn write_mint_account_field() {
+        let data = &mut [0u8; Mint::LEN];
+        pack_mint(data);
+
+        let memory = write_from(
+            data,
+            &spl_token::ID,
+            &WriteType::MintAccountField(MintAccountField::Supply),
+        )
+        .unwrap();
+        assert_eq!(&memory[8..16], &1_000u64.to_le_bytes());
+
+        let memory = write_from(
+            data,
+            &spl_token::ID,
+            &WriteType::MintAccountField(MintAccountField::Decimals),
+        )
+        .unwrap();
+        assert_eq!(memory[8], 6);
+    }
+
+    #[test]
+    fn write_token_2022_fields_with_extensions() {
+        // Token-2022 pads mints to the token account length before the account type byte
+        let data = &mut [0u8; Account::LEN + 8];
+        let token_account = pack_token_account(data);
+        data[Account::LEN] = AccountType::Account as u8;
+
+        let memory = write_from(
+            data,
+            &spl_token_2022::ID,
+            &WriteType::TokenAccountField(TokenAccountField::Mint),
+        )
+        .unwrap();
+        assert_eq!(&memory[8..40], token_account.mint.as_ref());
+
+        let result = write_from(
+            data,
+            &spl_token_2022::ID,
+            &WriteType::MintAccountField(MintAccountField::Supply),
+        );
+        assert_is_program_error(
+            result.unwrap_err(),
+            LighthouseError::AccountDiscriminatorValidationFailed.into(),
+        );
+
+        let data = &mut [0u8; Account::LEN + 8];
+        pack_mint(data);
+        data[Account::LEN] = AccountType::Mint as u8;
+
+        let memory = write_from(
+            data,
+            &spl_token_2022::ID,
+            &WriteType::MintAccountField(MintAccountField::Supply),
+        )
+        .unwrap();
+        assert_eq!(&memory[8..16], &1_000u64.to_le_bytes());
+    }
+
+    #[test]
+    fn write_token_fields_from_wrong_account_type() {
+        let data = &mut [0u8; Mint::LEN];
+        pack_mint(data);
+
+        let result = write_from(
+            data,
+            &spl_token::ID,
+            &WriteType::TokenAccountField(TokenAccountField::Amount),
+        );
+        assert_is_program_error(
+            result.unwrap_err(),
+            LighthouseError::AccountDiscriminatorValidationFailed.into(),
+        );
+
+        let data = &mut [0u8; Account::LEN];
+        pack_token_account(data);
+
+        let result = write_from(
+            data,
+            &spl_token::ID,
+            &WriteType::MintAccountField(MintAccountField::Supply),
+        );
+        assert_is_program_error(
+            result.unwrap_err(),
+            LighthouseError::AccountDiscriminatorValidationFailed.into(),
+        );
+
+        let result = write_from(
+            data,
+            &Pubkey::default(),
+            &WriteType::TokenAccountField(TokenAccountField::Amount),
+        );
+        assert_is_program_error(
+            result.unwrap_err(),
+            LighthouseError::AccountOwnerMismatch.into(),
+        );
+
+        // Multisig accounts are longer than a token account but carry no account type byte
+        for account_type in [AccountType::Mint, AccountType::Account] {
+            let data = &mut [0u8; Multisig::LEN];
+            data[Account::LEN] = account_type as u8;
+
+            for write_type in [
+                WriteType::TokenAccountField(TokenAccountField::Amount),
+                WriteType::MintAccountField(MintAccountField::Supply),
+            ] {
+                let result = write_from(data, &spl_token::ID, &write_type);
+                assert_is_program_error(
+                    result.unwrap_err(),
+                    LighthouseError::AccountDiscriminatorValidationFailed.into(),
+                );
+            }
+        }
+    }
+
+    fn write_from_sysvar(
+        key: &Pubkey,
+        data: &mut [u8],
+        write_type: &WriteType,
+    ) -> Result<Vec<u8>, solana_program::program_error::ProgramError> {
+        let lamports = &mut 0;
+        let source_account = AccountInfo::new(
+            key,
+            false,
+            false,
+            lamports,
+            data,
+            &solana_program::sysvar::ID,
+            false,
+            0,
+        );
+
+        let mut memory = vec![0u8; 64];
+        write(&mut memory, &source_account, 8, write_type)?;
+
+        Ok(memory)
+    }
+
+    #[test]
+    fn write_sysvar_fields() {
+        setup_syscall_stubs();
+
+        let rent = Rent::default();
+        let epoch_schedule = EpochSchedule::default();
+        let key = Keypair::new().encodable_pubkey();
+
+        let cases: Vec<(WriteType, Vec<u8>)> = vec![
+            (
+                WriteType::Clock(ClockField::Slot),
+                TEST_CLOCK.slot.to_le_bytes().to_vec(),
+            ),
+            (
+                WriteType::Clock(ClockField::UnixTimestamp),
+                TEST_CLOCK.unix_timestamp.to_le_bytes().to_vec(),
+            ),
+            (
+                WriteType::Rent(RentField::LamportsPerByteYear),
+                rent.lamports_per_byte_year.to_le_bytes().to_vec(),
+            ),
+            (
+                WriteType::Rent(RentField::ExemptionThreshold),
+                rent.exemption_threshold.to_le_bytes().to_vec(),
+            ),
+            (
+                WriteType::Rent(RentField::BurnPercent),
+                vec![rent.burn_percent],
+            ),
+            (
+                WriteType::EpochSchedule(EpochScheduleField::SlotsPerEpoch),
+                epoch_schedule.slots_per_epoch.to_le_bytes().to_vec(),
+            ),
+            (
+                WriteType::EpochSchedule(EpochScheduleField::Warmup),
+                vec![epoch_schedule.warmup as u8],
+            ),
+            (
+                WriteType::EpochSchedule(EpochScheduleField::FirstNormalSlot),
+                epoch_schedule.first_normal_slot.to_le_bytes().to_vec(),
+            ),
+            (
+                WriteType::RemainingComputeUnits,
+                TEST_REMAINING_COMPUTE_UNITS.to_le_bytes().to_vec(),
+            ),
+        ];
+
+        for (write_type, expected) in cases {
+            assert_eq!(write_type.data_length() as usize, expected.len());
+
+            let memory = write_from_sysvar(&key, &mut [], &write_type).unwrap();
+            assert_eq!(
+                &memory[8..8 + expected.len()],
+                &expected,
+                "{:?}",
+                write_type
+            );
+        }
+
+        // Writes past the end of memory are rejected
+        let lamports = &mut 0;
+        let source_account = AccountInfo::new(
+            &key,
+            false,
+            false,
+            lamports,
+            &mut [],
+            &solana_program::sysvar::ID,
+            false,
+            0,
+        );
+        let result = write(
+            &mut [0u8; 12],
+            &source_account,
+            8,
+            &WriteType::Clock(ClockField::Slot),
+        );
+        assert_is_program_error(
+            result.unwrap_err(),
+            LighthouseError::RangeOutOfBounds.into(),
+        );
+    }
+
+    #[test]
+    fn write_instruction_index() {
+        // Instructions sysvar data ends with the index of the executing instruction
+        let data = &mut [0u8; 16];
+        data[14..].copy_from_slice(&3u16.to_le_bytes());
+
+        let memory =
+            write_from_sysvar(&instructions::ID, data, &WriteType::InstructionIndex).unwrap();
+        assert_eq!(&memory[8..10], &3u16.to_le_bytes());
+
+        let result = write_from_sysvar(
+            &Keypair::new().encodable_pubkey(),
+            data,
+            &WriteType::InstructionIndex,
+        );
+        assert_is_program_error(
+            result.unwrap_err(),
+            LighthouseError::AccountKeyMismatch.into(),
+        );
+    }
+
+    #[test]
+    fn write_latest_slot_hash() {
+        let data = &mut [0u8; 8 + 2 * 40];
+        data[..8].copy_from_slice(&2u64.to_le_bytes());
+        data[8..16].copy_from_slice(&99u64.to_le_bytes());
+        data[16..48].copy_from_slice(&[7; 32]);
+        data[48..56].copy_from_slice(&98u64.to_le_bytes());
+        data[56..88].copy_from_slice(&[6; 32]);
+
+        let memory = write_from_sysvar(&slot_hashes::ID, data, &WriteType::LatestSlotHash).unwrap();
+        assert_eq!(&memory[8..16], &99u64.to_le_bytes());
+        assert_eq!(&memory[16..48], &[7; 32]);
+
+        let result = write_from_sysvar(
+            &Keypair::new().encodable_pubkey(),
+            data,
+            &WriteType::LatestSlotHash,
+        );
+        assert_is_program_error(
+            result.unwrap_err(),
+            LighthouseError::AccountKeyMismatch.into(),
+        );
+
+        let result = write_from_sysvar(&slot_hashes::ID, &mut [0u8; 8], &WriteType::LatestSlotHash);
+        assert_is_program_error(
+            result.unwrap_err(),
+            LighthouseError::IndexOutOfBounds.into(),
+        );
+    }
+
+    #[test]
+    fn write_accumulate() {
+        let data = &mut [0u8; Account::LEN];
+        pack_token_account(data);
+        let key = Keypair::new().encodable_pubkey();
+        let lamports = &mut 10;
+        let token_account =
+            AccountInfo::new(&key, false, false, lamports, data, &spl_token::ID, false, 0);
+
+        let accumulate = |operation, accumulator, source| WriteType::Accumulate {
+            operation,
+            accumulator,
+            source,
+        };
+
+        let mut memory = vec![0u8; 48];
+        for _ in 0..2 {
+            write(
+                &mut memory,
+                &token_account,
+                0,
+                &accumulate(
+                    AccumulateOperation::Add,
+                    AccumulatorType::U64,
+                    AccumulateSource::TokenAmount,
+                ),
+            )
+            .unwrap();
+        }
+        write(
+            &mut memory,
+            &token_account,
+            0,
+            &accumulate(
+                AccumulateOperation::Add,
+                AccumulatorType::U64,
+                AccumulateSource::Lamports,
+            ),
+        )
+        .unwrap();
+        assert_eq!(&memory[0..8], &850u64.to_le_bytes());
+        // The initialized flag follows the value
+        assert_eq!(memory[8], 1);
+
+        // An unset accumulator is seeded by its first write, so Min needs no seed
+        for value in [500, 200, 300] {
+            write(
+                &mut memory,
+                &token_account,
+                9,
+                &accumulate(
+                    AccumulateOperation::Min,
+                    AccumulatorType::U64,
+                    AccumulateSource::Value(value),
+                ),
+            )
+            .unwrap();
+        }
+        assert_eq!(&memory[9..17], &200u64.to_le_bytes());
+
+        // A tracked minimum of 0 is kept rather than treated as unset
+        for value in [0, 5] {
+            write(
+                &mut memory,
+                &token_account,
+                18,
+                &accumulate(
+                    AccumulateOperation::Min,
+                    AccumulatorType::U64,
+                    AccumulateSource::Value(value),
+                ),
+            )
+            .unwrap();
+        }
+        assert_eq!(&memory[18..27], &[0, 0, 0, 0, 0, 0, 0, 0, 1]);
+
+        write(
+            &mut memory,
+            &token_account,
+            27,
+            &accumulate(
+                AccumulateOperation::Max,
+                AccumulatorType::U128,
+                AccumulateSource::AccountData {
+                    offset: 64,
+                    data_type: IntegerDataType::U64,
+                },
+            ),
+        )
+        .unwrap();
+        assert_eq!(&memory[27..43], &420u128.to_le_bytes());
+
+        // Results that do not fit the accumulator overflow
+        write(
+            &mut memory,
+            &token_account,
+            0,
+            &WriteType::DataValue(DataValue::U64(u64::MAX - 419)),
+        )
+        .unwrap();
+        let result = write(
+            &mut memory,
+            &token_account,
+            0,
+            &accumulate(
+                AccumulateOperation::Add,
+                AccumulatorType::U64,
+                AccumulateSource::TokenAmount,
+            ),
+        );
+        assert_is_program_error(
+            result.unwrap_err(),
+            LighthouseError::ArithmeticOverflow.into(),
+        );
+
+        let result = write(
+            &mut memory,
+            &token_account,
+            0,
+            &accumulate(
+                AccumulateOperation::Max,
+                AccumulatorType::U64,
+                AccumulateSource::Value(u64::MAX as u128 + 1),
+            ),
+        );
+        assert_is_program_error(
+            result.unwrap_err(),
+            LighthouseError::ArithmeticOverflow.into(),
+        );
+
+        // Token amounts are only read from token program accounts
+        let system_lamports = &mut 0;
+        let system_data = &mut [0u8; Account::LEN];
+        let system_account = AccountInfo::new(
+            &key,
+            false,
+            false,
+            system_lamports,
+            system_data,
+            &solana_program::system_program::ID,
+            false,
+            0,
+        );
+        let result = write(
+            &mut memory,
+            &system_account,
+            0,
+            &accumulate(
+                AccumulateOperation::Add,
+                AccumulatorType::U64,
+                AccumulateSource::TokenAmount,
+            ),
+        );
+        assert_is_program_error(
+            result.unwrap_err(),
+            LighthouseError::AccountOwnerMismatch.into(),
+        );
+    }
+
+    fn memory_header_writes(
+        locked: bool,
+        writes: &[(u16, WriteType, bool)],
+    ) -> (MemoryHeader, Vec<u8>) {
+        setup_syscall_stubs();
+
+        let key = Keypair::new().encodable_pubkey();
+        let lamports = &mut 0;
+        let program = AccountInfo::new(&crate::ID, false, false, lamports, &mut [], &key, true, 0);
+
+        let memory_lamports = &mut 0;
+        let memory_data = &mut [0u8; 128];
+        let memory = AccountInfo::new(
+            &key,
+            false,
+            true,
+            memory_lamports,
+            memory_data,
+            &crate::ID,
+            false,
+            0,
+        );
+
+        let instructions_lamports = &mut 0;
+        let instructions_data = &mut [0u8; 4];
+        let source_accounts = [AccountInfo::new(
+            &instructions::ID,
+            false,
+            false,
+            instructions_lamports,
+            instructions_data,
+            &solana_program::sysvar::ID,
+            false,
+            0,
+        )];
+
+        let ctx = MemoryWriteContext {
+            lighthouse_program: Program::new(&program),
+            system_program: Program::new(&program),
+            payer: Signer::new(&program),
+            authority: Signer::new(&program),
+            memory: Memory::new(&memory),
+            top_level_only: false,
+            source_accounts: &source_accounts,
+        };
+
+        memory_init_header(&ctx, locked).unwrap();
+
+        for (offset, write_type, should_pass) in writes {
+            let result = write_memory(&ctx, &[(&source_accounts[0], *offset, write_type)]);
+
+            if *should_pass {
+                assert!(result.is_ok(), "{} {:?} {:?}", offset, write_type, result);
+            } else {
+                assert_is_program_error(result.unwrap_err(), LighthouseError::MemoryLocked.into());
+            }
+        }
+
+        let memory_data = memory.try_borrow_data().unwrap().to_vec();
+        let header = MemoryHeader::load(&memory_data).unwrap().unwrap();
+
+        // Locked memory cannot be reinitialized to reset the lock before close
+        let result = memory_init_header(&ctx, false);
+        if locked {
+            assert_is_program_error(result.unwrap_err(), LighthouseError::MemoryLocked.into());
+        } else {
+            result.unwrap();
+        }
+
+        (header, memory_data)
+    }
+
+    #[test]
+    fn write_locked_memory() {
+        let value = WriteType::DataValue(DataValue::U64(1));
+        let accumulate = WriteType::Accumulate {
+            operation: AccumulateOperation::Add,
+            accumulator: AccumulatorType::U64,
+            source: AccumulateSource::Value(1),
+        };
+
+        let (header, _) = memory_header_writes(
+            true,
+            &[
+                (64, value.clone(), true),
+                // Overwriting a written region
+                (64, value.clone(), false),
+                (68, value.clone(), false),
+                // Skipped bytes stay writable until they are written
+                (80, value.clone(), true),
+                (72, value.clone(), true),
+                (76, value.clone(), false),
+                (88, accumulate.clone(), true),
+                // Accumulators are written once
+                (88, accumulate.clone(), false),
+                (104, value.clone(), true),
+            ],
+        );
+
+        assert_eq!(
+            header.locked_ranges(),
+            &[
+                LockedRange { start: 64, end: 97 },
+                LockedRange {
+                    start: 104,
+                    end: 112
+                }
+            ]
+        );
+        assert_eq!(header.write_count, 5);
+        assert_eq!(header.locked_slot, TEST_CLOCK.slot);
+    }
+
+    #[test]
+    fn write_locked_min_accumulator() {
+        let min = |value| WriteType::Accumulate {
+            operation: AccumulateOperation::Min,
+            accumulator: AccumulatorType::U64,
+            source: AccumulateSource::Value(value),
+        };
+
+        // A locked accumulator cannot be seeded by an earlier write, its first write seeds it
+        let (header, memory_data) = memory_header_writes(
+            true,
+            &[
+                (64, min(300), true),
+                (64, min(200), false),
+                (64, WriteType::DataValue(DataValue::U64(u64::MAX)), false),
+            ],
+        );
+
+        assert_eq!(&memory_data[64..72], &300u64.to_le_bytes());
+        assert_eq!(memory_data[72], 1);
+        assert_eq!(
+            header.locked_ranges(),
+            &[LockedRange { start: 64, end: 73 }]
+        );
+    }
+
+    #[test]
+    fn write_unlocked_memory() {
+        let value = WriteType::DataValue(DataValue::U64(1));
+
+        let (header, _) = memory_header_writes(
+            false,
+            &[
+                (80, value.clone(), true),
+                (64, value.clone(), true),
+                (64, value.clone(), true),
+            ],
+        );
+
+        assert!(header.locked_ranges().is_empty());
+        assert_eq!(header.write_count, 3);
+    }
+
+    #[test]
+    fn write_locked_memory_beyond_lockable_range() {
+        setup_syscall_stubs();
+
+        let key = Keypair::new().encodable_pubkey();
+        let lamports = &mut 0;
+        let program = AccountInfo::new(&crate::ID, false, false, lamports, &mut [], &key, true, 0);
+
+        let memory_lamports = &mut 0;
+        let memory_data = &mut [0u8; 64];
+        let memory = AccountInfo::new(
+            &key,
+            false,
+            true,
+            memory_lamports,
+            memory_data,
+            &crate::ID,
+            false,
+            0,
+        );
+
+        let ctx = MemoryWriteContext {
+            lighthouse_program: Program::new(&program),
+            system_program: Program::new(&program),
+            payer: Signer::new(&program),
+            authority: Signer::new(&program),
+            memory: Memory::new(&memory),
+            top_level_only: false,
+            source_accounts: &[],
+        };
+
+        memory_init_header(&ctx, true).unwrap();
+
+        let result = write_memory(
+            &ctx,
+            &[(
+                &program,
+                u16::MAX - 4,
+                &WriteType::DataValue(DataValue::U64(1)),
+            )],
+        );
+        assert_is_program_error(
+            result.unwrap_err(),
+            LighthouseError::RangeOutOfBounds.into(),
+        );
+    }
+}
@@ -1,9 +1,12 @@
 pub mod assert_account_delta;
 pub mod assert_bubblegum_tree_config_account;
 pub mod assert_clock;
+pub mod assert_concurrent_merkle_tree_account;
 pub mod assert_merkle_tree_account;
 pub mod assert_mint_account;
+pub mod assert_multi;
 pub mod assert_stake_account;
+pub mod assert_stake_pool_account;
 pub mod assert_target_account;
 pub mod assert_token_account;
 pub mod assert_upgradeable_loader_state;
@@ -13,9 +16,12 @@ pub mod memory_write;
 pub(crate) use assert_account_delta::*;
 pub(crate) use assert_bubblegum_tree_config_account::*;
 pub(crate) use assert_clock::*;
+pub(crate) use assert_concurrent_merkle_tree_account::*;
 pub(crate) use assert_merkle_tree_account::*;
 pub(crate) use assert_mint_account::*;
+pub(crate) use assert_multi::*;
 pub(crate) use assert_stake_account::*;
+pub(crate) use assert_stake_pool_account::*;
 pub(crate) use assert_target_account::*;
 pub(crate) use assert_token_account::*;
 pub(crate) use assert_upgradeable_loader_state::*;
@@ -1,7 +1,18 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    entrypoint::SUCCESS,
+    epoch_schedule::EpochSchedule,
+    instruction::Instruction,
+    program_stubs::{set_syscall_stubs, SyscallStubs},
+    rent::Rent,
+};
 use solana_sdk::{
     program_error::ProgramError, pubkey::Pubkey, signature::Keypair, signer::EncodableKeypair,
 };
+use std::{cell::RefCell, sync::Once};
 
 #[derive(Debug, BorshDeserialize, BorshSerialize)]
 pub struct TestAccountV1 {
@@ -61,3 +72,142 @@ pub fn assert_passed(result: Result<(), ProgramError>) {
 pub fn assert_failed(result: Result<(), ProgramError>) {
     assert!(result.is_err(), "{:?}", result)
 }
+
+pub const TEST_CLOCK: Clock = Clock {
+    slot: 4_200,
+    epoch_start_timestamp: 1_700_000_000,
+    epoch: 9,
+    leader_schedule_epoch: 10,
+    unix_timestamp: 1_700_001_680,
+};
+
+pub const TEST_REMAINING_COMPUTE_UNITS: u64 = 180_000;
+
+struct TestSyscallStubs;
+
+impl SyscallStubs for TestSyscallStubs {
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Clock) = TEST_CLOCK };
+        SUCCESS
+    }
+
+    fn sol_get_epoch_schedule_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut EpochSchedule) = EpochSchedule::default() };
+        SUCCESS
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        SUCCESS
+    }
+
+    fn sol_remaining_compute_units(&self) -> u64 {
+        TEST_REMAINING_COMPUTE_UNITS
+    }
+
+    fn sol_log_data(&self, fields: &[&[u8]]) {
+        EMITTED_EVENTS.with(|events| {
+            events
+                .borrow_mut()
+                .push(EmittedEvent::LogData(fields.concat()))
+        });
+    }
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        _account_infos: &[AccountInfo],
+        _signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        EMITTED_EVENTS.with(|events| {
+            events.borrow_mut().push(EmittedEvent::Invoke(
+                instruction.program_id,
+                instruction.data.clone(),
+            ))
+        });
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum EmittedEvent {
+    LogData(Vec<u8>),
+    // Program id and instruction data of a cross program invocation
+    Invoke(Pubkey, Vec<u8>),
+}
+
+thread_local! {
+    static EMITTED_EVENTS: RefCell<Vec<EmittedEvent>> = const { RefCell::new(Vec::new()) };
+}
+
+///
+/// Drains the log data and invocations recorded by the syscall stubs on the current thread.
+///
+pub fn take_emitted_events() -> Vec<EmittedEvent> {
+    EMITTED_EVENTS.with(|events| events.take())
+}
+
+///
+/// Installs syscall stubs serving default sysvars, the stubs are process wide so every test sees the same values.
+///
+pub fn setup_syscall_stubs() {
+    static ONCE: Once = Once::new();
+
+    ONCE.call_once(|| {
+        set_syscall_stubs(Box::new(TestSyscallStubs));
+    });
+}
+
+///
+/// Account storage laid out like the runtime input buffer, `AccountInfo::realloc` reads the original
+/// data length in front of the key and writes the new data length in front of the data.
+///
+#[repr(C, align(8))]
+pub struct ReallocAccount {
+    _padding: u32,
+    original_data_len: u32,
+    key: Pubkey,
+    lamports: u64,
+    owner: Pubkey,
+    data_len: u64,
+    data: [u8; 256],
+}
+
+impl ReallocAccount {
+    pub fn new(key: Pubkey, lamports: u64, owner: Pubkey, data: &[u8]) -> Self {
+        let mut account = ReallocAccount {
+            _padding: 0,
+            original_data_len: data.len() as u32,
+            key,
+            lamports,
+            owner,
+            data_len: data.len() as u64,
+            data: [0; 256],
+        };
+        account.data[..data.len()].copy_from_slice(data);
+
+        account
+    }
+
+    pub fn info(&mut self, is_signer: bool, is_writable: bool) -> AccountInfo<'_> {
+        let ReallocAccount {
+            key,
+            lamports,
+            owner,
+            data_len,
+            data,
+            ..
+        } = self;
+
+        AccountInfo::new(
+            key,
+            is_signer,
+            is_writable,
+            lamports,
+            &mut data[..*data_len as usize],
+            owner,
+            false,
+            0,
+        )
+    }
+}
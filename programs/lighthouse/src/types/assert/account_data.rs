@@ -1,8 +1,15 @@
-use super::{Assert, LogLevel};
+use super::{Assert, LogContext};
 use crate::{
     err, err_msg,
     error::LighthouseError,
-    types::assert::evaluate::{EquatableOperator, Evaluate, IntegerOperator},
+    types::assert::{
+        data_path::{resolve_data_path, DataPathSegment},
+        evaluate::{
+            evaluate_bytes, AssertionResult, BytesOperator, EquatableOperator, Evaluate,
+            FloatOperator, IntegerOperator, Operator,
+        },
+        numeric::{read_signed, read_unsigned, Endianness, FixedPointScale, IntegerSize, U256},
+    },
     utils::{try_from_slice, Result},
 };
 use borsh::{BorshDeserialize, BorshSerialize};
@@ -62,19 +69,71 @@ pub enum DataValueAssertion {
     },
     Bytes {
         value: Vec<u8>,
-        operator: EquatableOperator,
+        operator: BytesOperator,
     },
     Pubkey {
         value: Pubkey,
         operator: EquatableOperator,
     },
+    UnsignedInteger {
+        value: u128,
+        size: IntegerSize,
+        endianness: Endianness,
+        operator: IntegerOperator,
+    },
+    SignedInteger {
+        value: i128,
+        size: IntegerSize,
+        endianness: Endianness,
+        operator: IntegerOperator,
+    },
+    // Compares the unsigned `raw / scale` stored in the account against `value / 10^decimals`,
+    // bitwise operators are rejected
+    FixedPoint {
+        value: u128,
+        decimals: u8,
+        size: IntegerSize,
+        endianness: Endianness,
+        scale: FixedPointScale,
+        operator: IntegerOperator,
+    },
+    F32 {
+        value: f32,
+        operator: FloatOperator,
+    },
+    F64 {
+        value: f64,
+        operator: FloatOperator,
+    },
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub struct AccountDataPathAssertion {
+    pub offset: u16,
+    pub path: Vec<DataPathSegment>,
+    pub assertion: DataValueAssertion,
 }
 
 impl Assert<&AccountInfo<'_>> for AccountDataAssertion {
-    fn evaluate(&self, account: &AccountInfo<'_>, log_level: LogLevel) -> Result<()> {
-        let offset = self.offset as usize;
-        let assertion = &self.assertion;
+    fn evaluate(&self, account: &AccountInfo<'_>, log_context: LogContext) -> Result<()> {
+        let data = account.try_borrow_data().map_err(|e| {
+            err_msg!("Cannot borrow data for target account", e);
+            err!(LighthouseError::AccountBorrowFailed)
+        })?;
+
+        if data.is_empty() {
+            return Err(LighthouseError::AccountNotInitialized.into());
+        }
 
+        self.assertion.evaluate(
+            (&data, self.offset as usize),
+            log_context.with_field(self.offset),
+        )
+    }
+}
+
+impl Assert<&AccountInfo<'_>> for AccountDataPathAssertion {
+    fn evaluate(&self, account: &AccountInfo<'_>, log_context: LogContext) -> Result<()> {
         let data = account.try_borrow_data().map_err(|e| {
             err_msg!("Cannot borrow data for target account", e);
             err!(LighthouseError::AccountBorrowFailed)
@@ -84,97 +143,97 @@ impl Assert<&AccountInfo<'_>> for AccountDataAssertion {
             return Err(LighthouseError::AccountNotInitialized.into());
         }
 
-        match assertion {
+        let offset = resolve_data_path(&data, self.offset as usize, &self.path)?;
+
+        self.assertion
+            .evaluate((&data, offset), log_context.with_field(self.offset))
+    }
+}
+
+impl Assert<(&[u8], usize)> for DataValueAssertion {
+    fn evaluate(&self, (data, offset): (&[u8], usize), log_context: LogContext) -> Result<()> {
+        match self {
             DataValueAssertion::Bool {
                 value: assertion_value,
                 operator,
             } => {
-                let actual_value = try_from_slice::<bool>(&data, offset, None)?;
-                bool::evaluate(&actual_value, assertion_value, operator, log_level)
+                let actual_value = try_from_slice::<bool>(data, offset, None)?;
+                bool::evaluate(&actual_value, assertion_value, operator, log_context)
             }
             DataValueAssertion::U8 {
                 value: assertion_value,
                 operator,
             } => {
-                let actual_value = try_from_slice::<u8>(&data, offset, None)?;
-                u8::evaluate(&actual_value, assertion_value, operator, log_level)
+                let actual_value = try_from_slice::<u8>(data, offset, None)?;
+                u8::evaluate(&actual_value, assertion_value, operator, log_context)
             }
             DataValueAssertion::I8 {
                 value: assertion_value,
                 operator,
             } => {
-                let actual_value = try_from_slice::<i8>(&data, offset, None)?;
-                i8::evaluate(&actual_value, assertion_value, operator, log_level)
+                let actual_value = try_from_slice::<i8>(data, offset, None)?;
+                i8::evaluate(&actual_value, assertion_value, operator, log_context)
             }
             DataValueAssertion::U16 {
                 value: assertion_value,
                 operator,
             } => {
-                let actual_value = try_from_slice::<u16>(&data, offset, None)?;
-                u16::evaluate(&actual_value, assertion_value, operator, log_level)
+                let actual_value = try_from_slice::<u16>(data, offset, None)?;
+                u16::evaluate(&actual_value, assertion_value, operator, log_context)
             }
             DataValueAssertion::I16 {
                 value: assertion_value,
                 operator,
             } => {
-                let actual_value = try_from_slice::<i16>(&data, offset, None)?;
-                i16::evaluate(&actual_value, assertion_value, operator, log_level)
+                let actual_value = try_from_slice::<i16>(data, offset, None)?;
+                i16::evaluate(&actual_value, assertion_value, operator, log_context)
             }
             DataValueAssertion::U32 {
                 value: assertion_value,
                 operator,
             } => {
-                let actual_value = try_from_slice::<u32>(&data, offset, None)?;
-                u32::evaluate(&actual_value, assertion_value, operator, log_level)
+                let actual_value = try_from_slice::<u32>(data, offset, None)?;
+                u32::evaluate(&actual_value, assertion_value, operator, log_context)
             }
             DataValueAssertion::I32 {
                 value: assertion_value,
                 operator,
             } => {
-                let actual_value = try_from_slice::<i32>(&data, offset, None)?;
-                i32::evaluate(&actual_value, assertion_value, operator, log_level)
+                let actual_value = try_from_slice::<i32>(data, offset, None)?;
+                i32::evaluate(&actual_value, assertion_value, operator, log_context)
             }
             DataValueAssertion::U64 {
                 value: assertion_value,
                 operator,
             } => {
-                let actual_value = try_from_slice::<u64>(&data, offset, None)?;
-                u64::evaluate(&actual_value, assertion_value, operator, log_level)
+                let actual_value = try_from_slice::<u64>(data, offset, None)?;
+                u64::evaluate(&actual_value, assertion_value, operator, log_context)
             }
             DataValueAssertion::I64 {
                 value: assertion_value,
                 operator,
             } => {
-                let actual_value = try_from_slice::<i64>(&data, offset, None)?;
-                i64::evaluate(&actual_value, assertion_value, operator, log_level)
+                let actual_value = try_from_slice::<i64>(data, offset, None)?;
+                i64::evaluate(&actual_value, assertion_value, operator, log_context)
             }
             DataValueAssertion::U128 {
                 value: assertion_value,
                 operator,
             } => {
-                let actual_value = try_from_slice::<u128>(&data, offset, None)?;
-                u128::evaluate(&actual_value, assertion_value, operator, log_level)
+                let actual_value = try_from_slice::<u128>(data, offset, None)?;
+                u128::evaluate(&actual_value, assertion_value, operator, log_context)
             }
             DataValueAssertion::I128 {
                 value: assertion_value,
                 operator,
             } => {
-                let actual_value = try_from_slice::<i128>(&data, offset, None)?;
-                i128::evaluate(&actual_value, assertion_value, operator, log_level)
+                let actual_value = try_from_slice::<i128>(data, offset, None)?;
+                i128::evaluate(&actual_value, assertion_value, operator, log_context)
             }
             DataValueAssertion::Bytes {
                 value: assertion_value,
                 operator,
-            } => {
-                let actual_value = data
-                    .get(offset..offset + assertion_value.len())
-                    .ok_or_else(|| {
-                        msg!("Data range out of bounds");
-                        err!(LighthouseError::RangeOutOfBounds)
-                    })?;
-
-                <[u8]>::evaluate(actual_value, assertion_value, operator, log_level)
-            }
+            } => evaluate_bytes(data, offset, assertion_value, operator, log_context),
             DataValueAssertion::Pubkey {
                 value: assertion_value,
                 operator,
@@ -189,12 +248,110 @@ impl Assert<&AccountInfo<'_>> for AccountDataAssertion {
                 })?;
                 let actual_value = bytemuck::from_bytes::<Pubkey>(data_slice);
 
-                Pubkey::evaluate(actual_value, assertion_value, operator, log_level)
+                Pubkey::evaluate(actual_value, assertion_value, operator, log_context)
+            }
+            DataValueAssertion::UnsignedInteger {
+                value: assertion_value,
+                size,
+                endianness,
+                operator,
+            } => {
+                let actual_value = read_unsigned(data, offset, *size, *endianness)?;
+                u128::evaluate(&actual_value, assertion_value, operator, log_context)
+            }
+            DataValueAssertion::SignedInteger {
+                value: assertion_value,
+                size,
+                endianness,
+                operator,
+            } => {
+                let actual_value = read_signed(data, offset, *size, *endianness)?;
+                i128::evaluate(&actual_value, assertion_value, operator, log_context)
+            }
+            DataValueAssertion::FixedPoint {
+                value: assertion_value,
+                decimals,
+                size,
+                endianness,
+                scale,
+                operator,
+            } => {
+                let raw_value = read_unsigned(data, offset, *size, *endianness)?;
+
+                // Cross multiply onto a common denominator so the comparison is exact,
+                // the products are 256-bit so any u128 value and factor can be compared
+                let actual_product =
+                    U256::widening_mul(raw_value, FixedPointScale::Decimal(*decimals).factor()?);
+                let assertion_product = U256::widening_mul(*assertion_value, scale.factor()?);
+
+                let passed = match operator {
+                    IntegerOperator::Equal => actual_product == assertion_product,
+                    IntegerOperator::NotEqual => actual_product != assertion_product,
+                    IntegerOperator::GreaterThan => actual_product > assertion_product,
+                    IntegerOperator::LessThan => actual_product < assertion_product,
+                    IntegerOperator::GreaterThanOrEqual => actual_product >= assertion_product,
+                    IntegerOperator::LessThanOrEqual => actual_product <= assertion_product,
+                    IntegerOperator::Contains | IntegerOperator::DoesNotContain => {
+                        msg!("Bitwise operators are not supported for fixed point values");
+                        return Err(LighthouseError::InvalidInstructionData.into());
+                    }
+                };
+
+                log_context.log_result(
+                    passed,
+                    || {
+                        msg!(
+                            "Result: {} / {:?} {} {} / 10^{}",
+                            raw_value,
+                            scale,
+                            operator.format(),
+                            assertion_value,
+                            decimals
+                        )
+                    },
+                    || {
+                        AssertionResult::U128(
+                            Some(raw_value),
+                            Some(*assertion_value),
+                            operator.code(),
+                            passed,
+                        )
+                    },
+                )?;
+
+                if passed {
+                    Ok(())
+                } else {
+                    Err(LighthouseError::AssertionFailed.into())
+                }
+            }
+            DataValueAssertion::F32 {
+                value: assertion_value,
+                operator,
+            } => {
+                // Borsh rejects NaN, so floats are decoded from the raw bytes
+                let actual_value = f32::from_le_bytes(read_array(data, offset)?);
+                f32::evaluate(&actual_value, assertion_value, operator, log_context)
+            }
+            DataValueAssertion::F64 {
+                value: assertion_value,
+                operator,
+            } => {
+                let actual_value = f64::from_le_bytes(read_array(data, offset)?);
+                f64::evaluate(&actual_value, assertion_value, operator, log_context)
             }
         }
     }
 }
 
+fn read_array<const N: usize>(data: &[u8], offset: usize) -> Result<[u8; N]> {
+    let data_slice = data
+        .get(offset..offset + N)
+        .ok_or_else(|| LighthouseError::oob_err(offset..offset + N))?;
+
+    Ok(data_slice.try_into().unwrap())
+}
+
 #[cfg(test)]
 mod tests {
     use super::DataValueAssertion;
@@ -202,8 +359,11 @@ mod tests {
         error::LighthouseError,
         test_utils::{assert_failed, assert_passed, create_test_account},
         types::assert::{
-            evaluate::{EquatableOperator, IntegerOperator},
-            AccountDataAssertion, Assert, LogLevel,
+            evaluate::{
+                BytesOperator, EquatableOperator, FloatOperator, FloatTolerance, IntegerOperator,
+            },
+            AccountDataAssertion, AccountDataPathAssertion, Assert, BorshFieldType,
+            DataPathSegment, Endianness, FixedPointScale, IntegerSize, LogLevel, PointerSize,
         },
     };
     use borsh::BorshSerialize;
@@ -272,7 +432,7 @@ mod tests {
                 assertion,
             };
 
-            let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage);
+            let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             if should_pass {
                 assert_passed(result);
@@ -332,7 +492,7 @@ mod tests {
                 assertion,
             };
 
-            let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage);
+            let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             if should_pass {
                 assert_passed(result);
@@ -378,7 +538,7 @@ mod tests {
                 assertion,
             };
 
-            let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage);
+            let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             if should_pass {
                 assert_passed(result);
@@ -405,7 +565,7 @@ mod tests {
                 operator: IntegerOperator::Equal,
             },
         };
-        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage);
+        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
         if let Err(e) = result {
             assert_eq!(e, LighthouseError::AccountNotInitialized.into());
         } else {
@@ -424,7 +584,7 @@ mod tests {
                 operator: IntegerOperator::Equal,
             },
         };
-        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage);
+        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
         if let Err(e) = result {
             assert_eq!(e, LighthouseError::AccountNotInitialized.into());
         } else {
@@ -444,7 +604,7 @@ mod tests {
             },
         };
 
-        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage);
+        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
         if let Err(e) = result {
             assert_eq!(e, LighthouseError::RangeOutOfBounds.into());
         } else {
@@ -464,7 +624,7 @@ mod tests {
             },
         };
 
-        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage);
+        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
         if let Err(e) = result {
             assert_eq!(e, LighthouseError::RangeOutOfBounds.into());
@@ -486,7 +646,7 @@ mod tests {
             },
         };
 
-        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage);
+        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
         if let Err(e) = result {
             assert_eq!(e, LighthouseError::RangeOutOfBounds.into());
@@ -508,7 +668,7 @@ mod tests {
             },
         };
 
-        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage);
+        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
         if let Err(e) = result {
             assert_eq!(e, LighthouseError::RangeOutOfBounds.into());
@@ -530,7 +690,7 @@ mod tests {
             },
         };
 
-        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage);
+        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
         if let Err(e) = result {
             assert_eq!(e, LighthouseError::RangeOutOfBounds.into());
@@ -552,7 +712,7 @@ mod tests {
             },
         };
 
-        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage);
+        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
         if let Err(e) = result {
             assert_eq!(e, LighthouseError::RangeOutOfBounds.into());
@@ -574,7 +734,7 @@ mod tests {
             },
         };
 
-        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage);
+        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
         if let Err(e) = result {
             assert_eq!(e, LighthouseError::RangeOutOfBounds.into());
@@ -596,7 +756,7 @@ mod tests {
             },
         };
 
-        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage);
+        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
         if let Err(e) = result {
             assert_eq!(e, LighthouseError::RangeOutOfBounds.into());
@@ -618,7 +778,7 @@ mod tests {
             },
         };
 
-        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage);
+        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
         if let Err(e) = result {
             assert_eq!(e, LighthouseError::RangeOutOfBounds.into());
@@ -640,7 +800,7 @@ mod tests {
             },
         };
 
-        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage);
+        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
         if let Err(e) = result {
             assert_eq!(e, LighthouseError::AccountNotInitialized.into());
@@ -658,11 +818,11 @@ mod tests {
             offset: 0,
             assertion: DataValueAssertion::Bytes {
                 value: vec![u8::MAX; 33],
-                operator: EquatableOperator::Equal,
+                operator: BytesOperator::Equal,
             },
         };
 
-        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage);
+        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
         if let Err(e) = result {
             assert_eq!(e, LighthouseError::RangeOutOfBounds.into());
@@ -686,7 +846,7 @@ mod tests {
                 operator: IntegerOperator::Equal,
             },
         };
-        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage);
+        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
         if let Err(e) = result {
             assert_eq!(e, LighthouseError::RangeOutOfBounds.into());
         } else {
@@ -704,7 +864,7 @@ mod tests {
             },
         };
 
-        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage);
+        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
         if let Err(e) = result {
             assert_eq!(e, LighthouseError::RangeOutOfBounds.into());
@@ -719,11 +879,11 @@ mod tests {
             offset: 17,
             assertion: DataValueAssertion::Bytes {
                 value: vec![u8::MAX; 16],
-                operator: EquatableOperator::Equal,
+                operator: BytesOperator::Equal,
             },
         };
 
-        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage);
+        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
         if let Err(e) = result {
             assert_eq!(e, LighthouseError::RangeOutOfBounds.into());
@@ -749,7 +909,7 @@ mod tests {
 
         let data = account_info.try_borrow_mut_data().unwrap();
 
-        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage);
+        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
         if let Err(e) = result {
             assert_eq!(e, LighthouseError::AccountBorrowFailed.into());
@@ -759,4 +919,569 @@ mod tests {
 
         drop(data);
     }
+
+    #[test]
+    fn evaluate_bytes_operators() {
+        let key = system_program::id();
+        let lamports = &mut 0;
+        let data: &mut [u8] = &mut [0xAA, 0x01, 0x02, 0x03, 0x0F, 0xFF];
+        let account_info = AccountInfo::new(&key, false, false, lamports, data, &key, false, 0);
+
+        let assertions = vec![
+            (vec![0x03, 0x0F], BytesOperator::Contains, true),
+            (vec![0x03, 0xFF], BytesOperator::Contains, false),
+            // Data before the offset is not searched
+            (vec![0xAA, 0x01], BytesOperator::Contains, false),
+            (vec![], BytesOperator::Contains, true),
+            (vec![0x01, 0x02], BytesOperator::StartsWith, true),
+            (
+                vec![0x01, 0x02, 0x03, 0x0F, 0xFF],
+                BytesOperator::StartsWith,
+                true,
+            ),
+            (vec![0x02, 0x03], BytesOperator::StartsWith, false),
+            // Prefixes longer than the data following the offset fail instead of erroring
+            (
+                vec![0x01, 0x02, 0x03, 0x0F, 0xFF, 0x00],
+                BytesOperator::StartsWith,
+                false,
+            ),
+            (
+                vec![0x01, 0x02, 0x03, 0x00],
+                BytesOperator::MaskedEqual {
+                    mask: vec![0xFF, 0xFF, 0xFF, 0xF0],
+                },
+                true,
+            ),
+            (
+                vec![0x01, 0x02, 0x03, 0x00],
+                BytesOperator::MaskedEqual {
+                    mask: vec![0xFF, 0xFF, 0xFF, 0x0F],
+                },
+                false,
+            ),
+            // Bytes without a mask byte are compared exactly
+            (
+                vec![0x00, 0x02, 0x03],
+                BytesOperator::MaskedEqual { mask: vec![0x00] },
+                true,
+            ),
+        ];
+
+        for (value, operator, should_pass) in assertions {
+            let assertion = AccountDataAssertion {
+                offset: 1,
+                assertion: DataValueAssertion::Bytes { value, operator },
+            };
+
+            let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
+
+            if should_pass {
+                assert_passed(result);
+            } else {
+                assert_failed(result);
+            }
+        }
+    }
+
+    #[test]
+    fn evaluate_endian_and_fixed_point() {
+        let key = system_program::id();
+        let lamports = &mut 0;
+
+        // Big-endian i32 at 0, big-endian u64 amount with 6 implied decimals at 4,
+        // and a little-endian Q64.64 price of 1.5 at 12
+        let mut data = Vec::new();
+        data.extend_from_slice(&(-42i32).to_be_bytes());
+        data.extend_from_slice(&1_500_000u64.to_be_bytes());
+        data.extend_from_slice(&(3u128 << 63).to_le_bytes());
+        let account_info =
+            AccountInfo::new(&key, false, false, lamports, &mut data, &key, false, 0);
+
+        let assertions = vec![
+            (
+                0,
+                DataValueAssertion::SignedInteger {
+                    value: -42,
+                    size: IntegerSize::Bits32,
+                    endianness: Endianness::Big,
+                    operator: IntegerOperator::Equal,
+                },
+                true,
+            ),
+            (
+                0,
+                DataValueAssertion::SignedInteger {
+                    value: -42,
+                    size: IntegerSize::Bits32,
+                    endianness: Endianness::Little,
+                    operator: IntegerOperator::Equal,
+                },
+                false,
+            ),
+            (
+                4,
+                DataValueAssertion::UnsignedInteger {
+                    value: 1_500_000,
+                    size: IntegerSize::Bits64,
+                    endianness: Endianness::Big,
+                    operator: IntegerOperator::Equal,
+                },
+                true,
+            ),
+            // 1.5 tokens == 1.50
+            (
+                4,
+                DataValueAssertion::FixedPoint {
+                    value: 150,
+                    decimals: 2,
+                    size: IntegerSize::Bits64,
+                    endianness: Endianness::Big,
+                    scale: FixedPointScale::Decimal(6),
+                    operator: IntegerOperator::Equal,
+                },
+                true,
+            ),
+            (
+                4,
+                DataValueAssertion::FixedPoint {
+                    value: 2,
+                    decimals: 0,
+                    size: IntegerSize::Bits64,
+                    endianness: Endianness::Big,
+                    scale: FixedPointScale::Decimal(6),
+                    operator: IntegerOperator::GreaterThanOrEqual,
+                },
+                false,
+            ),
+            (
+                12,
+                DataValueAssertion::FixedPoint {
+                    value: 14,
+                    decimals: 1,
+                    size: IntegerSize::Bits128,
+                    endianness: Endianness::Little,
+                    scale: FixedPointScale::Binary(64),
+                    operator: IntegerOperator::GreaterThan,
+                },
+                true,
+            ),
+            (
+                12,
+                DataValueAssertion::FixedPoint {
+                    value: 15,
+                    decimals: 1,
+                    size: IntegerSize::Bits128,
+                    endianness: Endianness::Little,
+                    scale: FixedPointScale::Binary(64),
+                    operator: IntegerOperator::LessThan,
+                },
+                false,
+            ),
+        ];
+
+        for (offset, assertion, should_pass) in assertions {
+            let assertion = AccountDataAssertion { offset, assertion };
+            let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
+
+            if should_pass {
+                assert_passed(result);
+            } else {
+                assert_failed(result);
+            }
+        }
+
+        // Both products of a Q64.64 value and a 20 decimal value exceed u128
+        let result = AccountDataAssertion {
+            offset: 12,
+            assertion: DataValueAssertion::FixedPoint {
+                value: 150 * 10u128.pow(18),
+                decimals: 20,
+                size: IntegerSize::Bits128,
+                endianness: Endianness::Little,
+                scale: FixedPointScale::Binary(64),
+                operator: IntegerOperator::Equal,
+            },
+        }
+        .evaluate(&account_info, LogLevel::PlaintextMessage.into());
+
+        assert_passed(result);
+
+        // A factor that does not fit in u128 is still rejected
+        let result = AccountDataAssertion {
+            offset: 12,
+            assertion: DataValueAssertion::FixedPoint {
+                value: 1,
+                decimals: 39,
+                size: IntegerSize::Bits128,
+                endianness: Endianness::Little,
+                scale: FixedPointScale::Binary(64),
+                operator: IntegerOperator::Equal,
+            },
+        }
+        .evaluate(&account_info, LogLevel::PlaintextMessage.into());
+
+        assert_eq!(
+            result.unwrap_err(),
+            LighthouseError::ArithmeticOverflow.into()
+        );
+
+        let result = AccountDataAssertion {
+            offset: 4,
+            assertion: DataValueAssertion::FixedPoint {
+                value: 1,
+                decimals: 0,
+                size: IntegerSize::Bits64,
+                endianness: Endianness::Big,
+                scale: FixedPointScale::Decimal(6),
+                operator: IntegerOperator::Contains,
+            },
+        }
+        .evaluate(&account_info, LogLevel::PlaintextMessage.into());
+
+        assert_eq!(
+            result.unwrap_err(),
+            LighthouseError::InvalidInstructionData.into()
+        );
+    }
+
+    #[test]
+    fn evaluate_floats() {
+        let key = system_program::id();
+        let lamports = &mut 0;
+
+        let mut data = Vec::new();
+        data.extend_from_slice(&0.1f32.to_le_bytes());
+        data.extend_from_slice(&(0.1f64 + 0.2f64).to_le_bytes());
+        data.extend_from_slice(&f64::NAN.to_le_bytes());
+        let account_info =
+            AccountInfo::new(&key, false, false, lamports, &mut data, &key, false, 0);
+
+        let assertions = vec![
+            (
+                0,
+                DataValueAssertion::F32 {
+                    value: 0.1,
+                    operator: FloatOperator::Equal { tolerance: None },
+                },
+                true,
+            ),
+            (
+                0,
+                DataValueAssertion::F32 {
+                    value: 0.0,
+                    operator: FloatOperator::GreaterThan,
+                },
+                true,
+            ),
+            (
+                4,
+                DataValueAssertion::F64 {
+                    value: 0.3,
+                    operator: FloatOperator::Equal { tolerance: None },
+                },
+                false,
+            ),
+            (
+                4,
+                DataValueAssertion::F64 {
+                    value: 0.3,
+                    operator: FloatOperator::Equal {
+                        tolerance: Some(FloatTolerance::Absolute(1e-12)),
+                    },
+                },
+                true,
+            ),
+            (
+                4,
+                DataValueAssertion::F64 {
+                    value: 0.31,
+                    operator: FloatOperator::NotEqual {
+                        tolerance: Some(FloatTolerance::Relative(0.01)),
+                    },
+                },
+                true,
+            ),
+            (
+                4,
+                DataValueAssertion::F64 {
+                    value: 0.301,
+                    operator: FloatOperator::Equal {
+                        tolerance: Some(FloatTolerance::Relative(0.01)),
+                    },
+                },
+                true,
+            ),
+            // NaN fails every comparison but IsNaN
+            (
+                12,
+                DataValueAssertion::F64 {
+                    value: 0.0,
+                    operator: FloatOperator::NotEqual { tolerance: None },
+                },
+                false,
+            ),
+            (
+                12,
+                DataValueAssertion::F64 {
+                    value: 0.0,
+                    operator: FloatOperator::LessThanOrEqual,
+                },
+                false,
+            ),
+            (
+                12,
+                DataValueAssertion::F64 {
+                    value: 0.0,
+                    operator: FloatOperator::IsNaN,
+                },
+                true,
+            ),
+            (
+                12,
+                DataValueAssertion::F64 {
+                    value: 0.0,
+                    operator: FloatOperator::IsNotNaN,
+                },
+                false,
+            ),
+        ];
+
+        for (offset, assertion, should_pass) in assertions {
+            let assertion = AccountDataAssertion { offset, assertion };
+
+            // Encoded results must serialize NaN without failing
+            for log_level in [LogLevel::PlaintextMessage, LogLevel::EncodedMessage] {
+                let result = assertion.evaluate(&account_info, log_level.into());
+
+                if should_pass {
+                    assert_passed(result);
+                } else {
+                    assert_failed(result);
+                }
+            }
+        }
+
+        let result = AccountDataAssertion {
+            offset: 16,
+            assertion: DataValueAssertion::F64 {
+                value: 0.0,
+                operator: FloatOperator::IsNaN,
+            },
+        }
+        .evaluate(&account_info, LogLevel::PlaintextMessage.into());
+
+        assert_eq!(
+            result.unwrap_err(),
+            LighthouseError::RangeOutOfBounds.into()
+        );
+    }
+
+    #[test]
+    fn evaluate_data_path() {
+        let key = system_program::id();
+        let lamports = &mut 0;
+        let test_account = create_test_account();
+        let data: &mut [u8] = &mut [0u8; 171];
+        data.copy_from_slice(test_account.try_to_vec().unwrap().as_ref());
+        let account_info = AccountInfo::new(&key, false, false, lamports, data, &key, false, 0);
+
+        let skip_options = DataPathSegment::Skip(vec![
+            BorshFieldType::FixedOption(1),
+            BorshFieldType::FixedOption(1),
+            BorshFieldType::FixedOption(2),
+            BorshFieldType::FixedOption(2),
+        ]);
+
+        let assertions = vec![
+            (
+                AccountDataPathAssertion {
+                    offset: 96,
+                    path: vec![DataPathSegment::OptionSome],
+                    assertion: DataValueAssertion::U8 {
+                        value: u8::MAX,
+                        operator: IntegerOperator::Equal,
+                    },
+                },
+                true,
+            ),
+            (
+                AccountDataPathAssertion {
+                    offset: 96,
+                    path: vec![
+                        DataPathSegment::Skip(vec![BorshFieldType::FixedOption(1)]),
+                        DataPathSegment::Skip(vec![BorshFieldType::FixedOption(1)]),
+                        DataPathSegment::OptionSome,
+                    ],
+                    assertion: DataValueAssertion::U16 {
+                        value: u16::MAX,
+                        operator: IntegerOperator::Equal,
+                    },
+                },
+                true,
+            ),
+            (
+                AccountDataPathAssertion {
+                    offset: 96,
+                    path: vec![
+                        skip_options.clone(),
+                        DataPathSegment::Skip(vec![BorshFieldType::Fixed(32)]),
+                    ],
+                    assertion: DataValueAssertion::U32 {
+                        value: 32,
+                        operator: IntegerOperator::Equal,
+                    },
+                },
+                true,
+            ),
+            (
+                AccountDataPathAssertion {
+                    offset: 96,
+                    path: vec![
+                        skip_options.clone(),
+                        DataPathSegment::Skip(vec![BorshFieldType::Fixed(32)]),
+                        DataPathSegment::VecElement {
+                            element: BorshFieldType::Fixed(1),
+                            index: 31,
+                        },
+                    ],
+                    assertion: DataValueAssertion::U8 {
+                        value: u8::MAX,
+                        operator: IntegerOperator::Equal,
+                    },
+                },
+                true,
+            ),
+            (
+                AccountDataPathAssertion {
+                    offset: 96,
+                    path: vec![
+                        skip_options.clone(),
+                        DataPathSegment::Skip(vec![BorshFieldType::Fixed(32)]),
+                        DataPathSegment::VecElement {
+                            element: BorshFieldType::Fixed(1),
+                            index: 0,
+                        },
+                    ],
+                    assertion: DataValueAssertion::U8 {
+                        value: 0,
+                        operator: IntegerOperator::Equal,
+                    },
+                },
+                false,
+            ),
+        ];
+
+        for (assertion, should_pass) in assertions {
+            let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
+
+            if should_pass {
+                assert_passed(result);
+            } else {
+                assert_failed(result);
+            }
+        }
+
+        // Fail on None option
+        let assertion = AccountDataPathAssertion {
+            offset: 98,
+            path: vec![DataPathSegment::OptionSome],
+            assertion: DataValueAssertion::U8 {
+                value: u8::MAX,
+                operator: IntegerOperator::Equal,
+            },
+        };
+
+        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
+        assert_eq!(result.unwrap_err(), LighthouseError::AssertionFailed.into());
+
+        // Fail on vec index past length
+        let assertion = AccountDataPathAssertion {
+            offset: 135,
+            path: vec![DataPathSegment::VecElement {
+                element: BorshFieldType::Fixed(1),
+                index: 32,
+            }],
+            assertion: DataValueAssertion::U8 {
+                value: u8::MAX,
+                operator: IntegerOperator::Equal,
+            },
+        };
+
+        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
+        assert_eq!(
+            result.unwrap_err(),
+            LighthouseError::IndexOutOfBounds.into()
+        );
+
+        // Fail on string length past end of data
+        let assertion = AccountDataPathAssertion {
+            offset: 135,
+            path: vec![DataPathSegment::Skip(vec![
+                BorshFieldType::Fixed(4),
+                BorshFieldType::String,
+            ])],
+            assertion: DataValueAssertion::U8 {
+                value: u8::MAX,
+                operator: IntegerOperator::Equal,
+            },
+        };
+
+        let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
+        assert_eq!(
+            result.unwrap_err(),
+            LighthouseError::RangeOutOfBounds.into()
+        );
+    }
+
+    #[test]
+    fn evaluate_pointer_path() {
+        let key = system_program::id();
+        let lamports = &mut 0;
+
+        // u16 head index 2, u32 index 3, padding, then a ring buffer of four u64 entries at offset 8
+        let mut data = Vec::new();
+        data.extend_from_slice(&2u16.to_le_bytes());
+        data.extend_from_slice(&3u32.to_le_bytes());
+        data.extend_from_slice(&[0u8; 2]);
+        for entry in 0..4u64 {
+            data.extend_from_slice(&(entry * 100).to_le_bytes());
+        }
+        let account_info =
+            AccountInfo::new(&key, false, false, lamports, &mut data, &key, false, 0);
+
+        let pointer = |at, size, stride| AccountDataPathAssertion {
+            offset: 8,
+            path: vec![DataPathSegment::Pointer { at, size, stride }],
+            assertion: DataValueAssertion::U64 {
+                value: 200,
+                operator: IntegerOperator::Equal,
+            },
+        };
+
+        assert_passed(
+            pointer(0, PointerSize::U16, 8)
+                .evaluate(&account_info, LogLevel::PlaintextMessage.into()),
+        );
+        assert_failed(
+            pointer(2, PointerSize::U32, 8)
+                .evaluate(&account_info, LogLevel::PlaintextMessage.into()),
+        );
+
+        // Index 3 with a stride of 10 ends past the data
+        let result = pointer(2, PointerSize::U32, 10)
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
+        assert_eq!(
+            result.unwrap_err(),
+            LighthouseError::RangeOutOfBounds.into()
+        );
+
+        // Index read past the data
+        let result = pointer(38, PointerSize::U32, 8)
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
+        assert_eq!(
+            result.unwrap_err(),
+            LighthouseError::RangeOutOfBounds.into()
+        );
+    }
 }
@@ -1,9 +1,12 @@
-use super::{Assert, EquatableOperator, IntegerOperator, LogLevel};
+use super::{Assert, BytesOperator, EquatableOperator, IntegerOperator, LogContext};
 use crate::{
     err,
     error::LighthouseError,
-    types::assert::evaluate::Evaluate,
-    utils::{try_from_slice, Result},
+    types::{
+        assert::evaluate::{evaluate_bytes, Evaluate},
+        write::DataHashType,
+    },
+    utils::{data_hash_range, try_from_slice, Result},
 };
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{account_info::AccountInfo, msg, pubkey::Pubkey};
@@ -19,6 +22,14 @@ pub enum AccountDeltaAssertion {
         b_offset: u16,
         assertion: DataValueDeltaAssertion,
     },
+    // Compares the hash stored at a_offset against the hash of account_b's data range
+    DataHash {
+        a_offset: u16,
+        hash_type: DataHashType,
+        start: Option<u16>,
+        length: Option<u16>,
+        operator: EquatableOperator,
+    },
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Debug)]
@@ -55,9 +66,11 @@ pub enum DataValueDeltaAssertion {
         value: i128,
         operator: IntegerOperator,
     },
+    // Compares `length` bytes of account_b at b_offset with the same window of account_a at a_offset,
+    // Contains is rejected as it would search the rest of account_a instead of the window
     Bytes {
         length: u16,
-        operator: EquatableOperator,
+        operator: BytesOperator,
     },
 }
 
@@ -65,7 +78,7 @@ impl<'a, 'info> Assert<(&'a AccountInfo<'info>, &'a AccountInfo<'info>)> for Acc
     fn evaluate(
         &self,
         accounts: (&'a AccountInfo<'info>, &'a AccountInfo<'info>),
-        log_level: LogLevel,
+        log_context: LogContext,
     ) -> Result<()> {
         match self {
             AccountDeltaAssertion::Data {
@@ -99,7 +112,7 @@ impl<'a, 'info> Assert<(&'a AccountInfo<'info>, &'a AccountInfo<'info>)> for Acc
 
                         let diff_value = b_value as i16 - a_value as i16;
 
-                        i16::evaluate(&diff_value, assertion_value, operator, log_level)
+                        i16::evaluate(&diff_value, assertion_value, operator, log_context)
                     }
                     DataValueDeltaAssertion::I8 {
                         value: assertion_value,
@@ -110,7 +123,7 @@ impl<'a, 'info> Assert<(&'a AccountInfo<'info>, &'a AccountInfo<'info>)> for Acc
 
                         let diff_value = b_value as i16 - a_value as i16;
 
-                        i16::evaluate(&diff_value, assertion_value, operator, log_level)
+                        i16::evaluate(&diff_value, assertion_value, operator, log_context)
                     }
                     DataValueDeltaAssertion::U16 {
                         value: assertion_value,
@@ -121,7 +134,7 @@ impl<'a, 'info> Assert<(&'a AccountInfo<'info>, &'a AccountInfo<'info>)> for Acc
 
                         let diff_value = b_value as i32 - a_value as i32;
 
-                        i32::evaluate(&diff_value, assertion_value, operator, log_level)
+                        i32::evaluate(&diff_value, assertion_value, operator, log_context)
                     }
                     DataValueDeltaAssertion::I16 {
                         value: assertion_value,
@@ -132,7 +145,7 @@ impl<'a, 'info> Assert<(&'a AccountInfo<'info>, &'a AccountInfo<'info>)> for Acc
 
                         let diff_value = b_value as i32 - a_value as i32;
 
-                        i32::evaluate(&diff_value, assertion_value, operator, log_level)
+                        i32::evaluate(&diff_value, assertion_value, operator, log_context)
                     }
                     DataValueDeltaAssertion::U32 {
                         value: assertion_value,
@@ -143,7 +156,7 @@ impl<'a, 'info> Assert<(&'a AccountInfo<'info>, &'a AccountInfo<'info>)> for Acc
 
                         let diff_value = b_value as i64 - a_value as i64;
 
-                        i64::evaluate(&diff_value, assertion_value, operator, log_level)
+                        i64::evaluate(&diff_value, assertion_value, operator, log_context)
                     }
                     DataValueDeltaAssertion::I32 {
                         value: assertion_value,
@@ -154,7 +167,7 @@ impl<'a, 'info> Assert<(&'a AccountInfo<'info>, &'a AccountInfo<'info>)> for Acc
 
                         let diff_value = b_value as i64 - a_value as i64;
 
-                        i64::evaluate(&diff_value, assertion_value, operator, log_level)
+                        i64::evaluate(&diff_value, assertion_value, operator, log_context)
                     }
                     DataValueDeltaAssertion::U64 {
                         value: assertion_value,
@@ -165,7 +178,7 @@ impl<'a, 'info> Assert<(&'a AccountInfo<'info>, &'a AccountInfo<'info>)> for Acc
 
                         let diff_value = b_value as i128 - a_value as i128;
 
-                        i128::evaluate(&diff_value, assertion_value, operator, log_level)
+                        i128::evaluate(&diff_value, assertion_value, operator, log_context)
                     }
                     DataValueDeltaAssertion::I64 {
                         value: assertion_value,
@@ -176,15 +189,14 @@ impl<'a, 'info> Assert<(&'a AccountInfo<'info>, &'a AccountInfo<'info>)> for Acc
 
                         let diff_value = b_value as i128 - a_value as i128;
 
-                        i128::evaluate(&diff_value, assertion_value, operator, log_level)
+                        i128::evaluate(&diff_value, assertion_value, operator, log_context)
                     }
                     DataValueDeltaAssertion::Bytes { operator, length } => {
-                        let a_value = a_account_data
-                            .get(a_offset..(a_offset + *length as usize))
-                            .ok_or_else(|| {
-                                msg!("Failed to read bytes from account_a");
-                                err!(LighthouseError::RangeOutOfBounds)
-                            })?;
+                        if let BytesOperator::Contains = operator {
+                            msg!("Contains is not supported for account data deltas");
+                            return Err(LighthouseError::InvalidInstructionData.into());
+                        }
+
                         let b_value = b_account_data
                             .get(b_offset..(b_offset + *length as usize))
                             .ok_or_else(|| {
@@ -192,7 +204,7 @@ impl<'a, 'info> Assert<(&'a AccountInfo<'info>, &'a AccountInfo<'info>)> for Acc
                                 err!(LighthouseError::RangeOutOfBounds)
                             })?;
 
-                        <[u8]>::evaluate(a_value, b_value, operator, log_level)
+                        evaluate_bytes(&a_account_data, a_offset, b_value, operator, log_context)
                     }
                 }
             }
@@ -219,7 +231,7 @@ impl<'a, 'info> Assert<(&'a AccountInfo<'info>, &'a AccountInfo<'info>)> for Acc
 
                         let diff_value = b_lamports as i128 - a_lamports as i128;
 
-                        i128::evaluate(&diff_value, value, operator, log_level)
+                        i128::evaluate(&diff_value, value, operator, log_context)
                     }
                     AccountInfoDeltaAssertion::DataLength { value, operator } => {
                         let a_data_len = try_from_slice::<u64>(&a_account_data, a_offset, None)?;
@@ -227,12 +239,12 @@ impl<'a, 'info> Assert<(&'a AccountInfo<'info>, &'a AccountInfo<'info>)> for Acc
 
                         let diff_value = b_data_len - a_data_len as i128;
 
-                        i128::evaluate(&diff_value, value, operator, log_level)
+                        i128::evaluate(&diff_value, value, operator, log_context)
                     }
                     AccountInfoDeltaAssertion::Owner { operator } => {
                         let a_owner = try_from_slice::<Pubkey>(&a_account_data, a_offset, None)?;
 
-                        Pubkey::evaluate(&a_owner, b_account.owner, operator, log_level)
+                        Pubkey::evaluate(&a_owner, b_account.owner, operator, log_context)
                     }
                     AccountInfoDeltaAssertion::RentEpoch { value, operator } => {
                         let a_rent_epoch = try_from_slice::<u64>(&a_account_data, a_offset, None)?;
@@ -240,10 +252,34 @@ impl<'a, 'info> Assert<(&'a AccountInfo<'info>, &'a AccountInfo<'info>)> for Acc
 
                         let diff_value = b_rent_epoch as i128 - a_rent_epoch as i128;
 
-                        i128::evaluate(&diff_value, value, operator, log_level)
+                        i128::evaluate(&diff_value, value, operator, log_context)
                     }
                 }
             }
+            AccountDeltaAssertion::DataHash {
+                a_offset,
+                hash_type,
+                start,
+                length,
+                operator,
+            } => {
+                let (a_account, b_account) = accounts;
+                let a_offset = *a_offset as usize;
+
+                let a_account_data = a_account
+                    .try_borrow_data()
+                    .map_err(LighthouseError::failed_borrow_err)?;
+                let b_account_data = b_account
+                    .try_borrow_data()
+                    .map_err(LighthouseError::failed_borrow_err)?;
+
+                let a_hash = a_account_data
+                    .get(a_offset..(a_offset + 32))
+                    .ok_or_else(|| LighthouseError::oob_err(a_offset..(a_offset + 32)))?;
+                let b_hash = hash_type.hash(data_hash_range(&b_account_data, *start, *length)?);
+
+                <[u8]>::evaluate(&b_hash, a_hash, operator, log_context)
+            }
         }
     }
 }
@@ -276,10 +312,14 @@ pub enum AccountInfoDeltaAssertion {
 #[cfg(test)]
 mod tests {
     use crate::{
-        test_utils::{assert_passed, create_test_account},
-        types::assert::{
-            AccountDeltaAssertion, AccountInfoDeltaAssertion, Assert, DataValueDeltaAssertion,
-            EquatableOperator, IntegerOperator, LogLevel,
+        error::LighthouseError,
+        test_utils::{assert_failed, assert_passed, create_test_account},
+        types::{
+            assert::{
+                AccountDeltaAssertion, AccountInfoDeltaAssertion, Assert, DataValueDeltaAssertion,
+                EquatableOperator, IntegerOperator, LogLevel,
+            },
+            write::DataHashType,
         },
     };
     use borsh::BorshSerialize;
@@ -313,7 +353,7 @@ mod tests {
 
         let result = assertion.evaluate(
             (&a_account_info, &b_account_info),
-            LogLevel::PlaintextMessage,
+            LogLevel::PlaintextMessage.into(),
         );
 
         assert_passed(result);
@@ -329,7 +369,7 @@ mod tests {
 
         let result = reverse_assertion.evaluate(
             (&b_account_info, &a_account_info),
-            LogLevel::PlaintextMessage,
+            LogLevel::PlaintextMessage.into(),
         );
 
         assert_passed(result);
@@ -362,7 +402,7 @@ mod tests {
 
         let result = assertion.evaluate(
             (&a_account_info.clone(), &b_account_info.clone()),
-            LogLevel::PlaintextMessage,
+            LogLevel::PlaintextMessage.into(),
         );
 
         assert_passed(result);
@@ -378,7 +418,7 @@ mod tests {
 
         let result = reverse_assertion.evaluate(
             (&b_account_info, &a_account_info),
-            LogLevel::PlaintextMessage,
+            LogLevel::PlaintextMessage.into(),
         );
 
         assert_passed(result);
@@ -411,7 +451,7 @@ mod tests {
 
         let result = assertion.evaluate(
             (&a_account_info.clone(), &b_account_info.clone()),
-            LogLevel::PlaintextMessage,
+            LogLevel::PlaintextMessage.into(),
         );
 
         assert_passed(result);
@@ -427,12 +467,45 @@ mod tests {
 
         let result = reverse_assertion.evaluate(
             (&b_account_info, &a_account_info),
-            LogLevel::PlaintextMessage,
+            LogLevel::PlaintextMessage.into(),
         );
 
         assert_passed(result);
     }
 
+    #[test]
+    fn evaluate_diff_bytes_rejects_contains() {
+        let key = system_program::id();
+        let (lamports_b, lamports_a) = (&mut 0, &mut 0);
+        let a_data: &mut [u8] = &mut [0u8; 64];
+        a_data[40..44].copy_from_slice(&[1, 2, 3, 4]);
+        let a_account_info =
+            AccountInfo::new(&key, false, false, lamports_b, a_data, &key, false, 0);
+
+        let b_data: &mut [u8] = &mut [1, 2, 3, 4];
+        let b_account_info =
+            AccountInfo::new(&key, false, false, lamports_a, b_data, &key, false, 0);
+
+        let assertion = AccountDeltaAssertion::Data {
+            a_offset: 0,
+            b_offset: 0,
+            assertion: DataValueDeltaAssertion::Bytes {
+                operator: crate::types::assert::evaluate::BytesOperator::Contains,
+                length: 4,
+            },
+        };
+
+        let result = assertion.evaluate(
+            (&a_account_info, &b_account_info),
+            LogLevel::PlaintextMessage.into(),
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            LighthouseError::InvalidInstructionData.into()
+        );
+    }
+
     #[test]
     fn evaluate_diff_i16() {
         let key = system_program::id();
@@ -460,7 +533,7 @@ mod tests {
 
         let result = assertion.evaluate(
             (&a_account_info.clone(), &b_account_info.clone()),
-            LogLevel::PlaintextMessage,
+            LogLevel::PlaintextMessage.into(),
         );
 
         assert_passed(result);
@@ -476,7 +549,7 @@ mod tests {
 
         let result = reverse_assertion.evaluate(
             (&b_account_info, &a_account_info),
-            LogLevel::PlaintextMessage,
+            LogLevel::PlaintextMessage.into(),
         );
 
         assert_passed(result);
@@ -502,14 +575,14 @@ mod tests {
             a_offset: 0,
             b_offset: 4,
             assertion: DataValueDeltaAssertion::Bytes {
-                operator: crate::types::assert::evaluate::EquatableOperator::Equal,
+                operator: crate::types::assert::evaluate::BytesOperator::Equal,
                 length: 32,
             },
         };
 
         let result = assertion.evaluate(
             (&a_account_info.clone(), &b_account_info.clone()),
-            LogLevel::PlaintextMessage,
+            LogLevel::PlaintextMessage.into(),
         );
 
         assert_passed(result);
@@ -518,14 +591,14 @@ mod tests {
             a_offset: 4,
             b_offset: 0,
             assertion: DataValueDeltaAssertion::Bytes {
-                operator: crate::types::assert::evaluate::EquatableOperator::Equal,
+                operator: crate::types::assert::evaluate::BytesOperator::Equal,
                 length: 32,
             },
         };
 
         let result = reverse_assertion.evaluate(
             (&b_account_info, &a_account_info),
-            LogLevel::PlaintextMessage,
+            LogLevel::PlaintextMessage.into(),
         );
 
         assert_passed(result);
@@ -560,7 +633,7 @@ mod tests {
         assertion
             .evaluate(
                 (&a_account_info, &b_account_info),
-                LogLevel::PlaintextMessage,
+                LogLevel::PlaintextMessage.into(),
             )
             .unwrap();
 
@@ -585,7 +658,7 @@ mod tests {
         reverse_assertion
             .evaluate(
                 (&a_account_info, &b_account_info),
-                LogLevel::PlaintextMessage,
+                LogLevel::PlaintextMessage.into(),
             )
             .unwrap();
 
@@ -609,7 +682,7 @@ mod tests {
 
         let result = reverse_assertion.evaluate(
             (&a_account_info, &b_account_info),
-            LogLevel::PlaintextMessage,
+            LogLevel::PlaintextMessage.into(),
         );
 
         assert_passed(result);
@@ -641,9 +714,53 @@ mod tests {
 
         let result = assertion.evaluate(
             (&a_account_info, &b_account_info),
-            LogLevel::PlaintextMessage,
+            LogLevel::PlaintextMessage.into(),
         );
 
         assert_passed(result);
     }
+
+    #[test]
+    fn evaluate_data_hash_delta() {
+        let key = system_program::id();
+
+        let b_bytes = create_test_account().try_to_vec().unwrap();
+        let (lamports_b, lamports_a) = (&mut 0, &mut 0);
+        let a_data: &mut [u8] = &mut [0u8; 64];
+        a_data[8..40].copy_from_slice(&DataHashType::Sha256.hash(&b_bytes[16..]));
+        let a_account_info =
+            AccountInfo::new(&key, false, false, lamports_a, a_data, &key, false, 0);
+
+        let b_data: &mut [u8] = &mut [0u8; 171];
+        b_data.copy_from_slice(&b_bytes);
+        let b_account_info =
+            AccountInfo::new(&key, false, false, lamports_b, b_data, &key, false, 0);
+
+        let assertions = vec![
+            (DataHashType::Sha256, Some(16), None, true),
+            (DataHashType::Sha256, Some(16), Some(155), true),
+            (DataHashType::Sha256, None, None, false),
+            (DataHashType::Keccak256, Some(16), None, false),
+        ];
+
+        for (hash_type, start, length, should_pass) in assertions {
+            let result = AccountDeltaAssertion::DataHash {
+                a_offset: 8,
+                hash_type,
+                start,
+                length,
+                operator: EquatableOperator::Equal,
+            }
+            .evaluate(
+                (&a_account_info, &b_account_info),
+                LogLevel::PlaintextMessage.into(),
+            );
+
+            if should_pass {
+                assert_passed(result);
+            } else {
+                assert_failed(result);
+            }
+        }
+    }
 }
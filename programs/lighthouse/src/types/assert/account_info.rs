@@ -1,11 +1,16 @@
-use super::{Assert, KnownProgram, LogLevel};
+use super::{Assert, KnownProgram, LogContext};
 use crate::{
     error::LighthouseError,
-    types::assert::evaluate::{EquatableOperator, Evaluate, IntegerOperator},
-    utils::Result,
+    types::{
+        assert::evaluate::{
+            EquatableOperator, Evaluate, EvaluateSet, IntegerOperator, SetOperator,
+        },
+        write::DataHashType,
+    },
+    utils::{anchor_account_discriminator, data_hash_range, Result},
 };
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::{account_info::AccountInfo, keccak, msg, pubkey::Pubkey};
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 
 #[derive(BorshDeserialize, BorshSerialize, Debug)]
 pub enum AccountInfoAssertion {
@@ -46,34 +51,52 @@ pub enum AccountInfoAssertion {
         start: Option<u16>,
         length: Option<u16>,
     },
+    AnchorDiscriminator {
+        value: AnchorDiscriminatorValue,
+        operator: EquatableOperator,
+    },
+    OwnerIn {
+        values: Vec<Pubkey>,
+        operator: SetOperator,
+    },
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub enum AnchorDiscriminatorValue {
+    // Hashed on-chain as sha256("account:<AccountName>")[..8]
+    AccountName(String),
+    Discriminator([u8; 8]),
 }
 
 impl Assert<&AccountInfo<'_>> for AccountInfoAssertion {
-    fn evaluate(&self, account: &AccountInfo<'_>, log_level: LogLevel) -> Result<()> {
+    fn evaluate(&self, account: &AccountInfo<'_>, log_context: LogContext) -> Result<()> {
         match self {
             AccountInfoAssertion::Owner { value, operator } => {
-                Pubkey::evaluate(account.owner, value, operator, log_level)
+                Pubkey::evaluate(account.owner, value, operator, log_context)
+            }
+            AccountInfoAssertion::OwnerIn { values, operator } => {
+                Pubkey::evaluate_set(account.owner, values, operator, log_context)
             }
             AccountInfoAssertion::KnownOwner { value, operator } => {
-                Pubkey::evaluate(account.owner, &value.to_pubkey(), operator, log_level)
+                Pubkey::evaluate(account.owner, &value.to_pubkey(), operator, log_context)
             }
             AccountInfoAssertion::Lamports { value, operator } => {
-                u64::evaluate(&account.try_lamports()?, value, operator, log_level)
+                u64::evaluate(&account.try_lamports()?, value, operator, log_context)
             }
             AccountInfoAssertion::DataLength { value, operator } => {
-                u64::evaluate(&(account.data_len() as u64), value, operator, log_level)
+                u64::evaluate(&(account.data_len() as u64), value, operator, log_context)
             }
             AccountInfoAssertion::Executable { value, operator } => {
-                bool::evaluate(&account.executable, value, operator, log_level)
+                bool::evaluate(&account.executable, value, operator, log_context)
             }
             AccountInfoAssertion::IsSigner { value, operator } => {
-                bool::evaluate(&account.is_signer, value, operator, log_level)
+                bool::evaluate(&account.is_signer, value, operator, log_context)
             }
             AccountInfoAssertion::IsWritable { value, operator } => {
-                bool::evaluate(&account.is_writable, value, operator, log_level)
+                bool::evaluate(&account.is_writable, value, operator, log_context)
             }
             AccountInfoAssertion::RentEpoch { value, operator } => {
-                u64::evaluate(&account.rent_epoch as &u64, value, operator, log_level)
+                u64::evaluate(&account.rent_epoch as &u64, value, operator, log_context)
             }
             AccountInfoAssertion::VerifyDatahash {
                 expected_hash,
@@ -81,33 +104,110 @@ impl Assert<&AccountInfo<'_>> for AccountInfoAssertion {
                 length,
             } => {
                 let account_data = account.try_borrow_data()?;
-
-                let start = start.unwrap_or(0);
-                let length = length.unwrap_or(
-                    account_data
-                        .len()
-                        .checked_sub(start as usize)
-                        .ok_or(LighthouseError::RangeOutOfBounds)? as u16,
-                );
-
-                let hash_range = start as usize..(start + length) as usize;
-                let account_data = &account_data.get(hash_range.clone()).ok_or_else(|| {
-                    msg!(
-                        "Failed to verify hash data, range {:?} was out of bounds",
-                        hash_range
-                    );
-
-                    LighthouseError::RangeOutOfBounds
-                })?;
-                let actual_hash = keccak::hashv(&[&account_data]).0;
+                let account_data = data_hash_range(&account_data, *start, *length)?;
+                let actual_hash = DataHashType::Keccak256.hash(account_data);
 
                 <[u8]>::evaluate(
                     &actual_hash,
                     expected_hash,
                     &EquatableOperator::Equal,
-                    log_level,
+                    log_context,
+                )
+            }
+            AccountInfoAssertion::AnchorDiscriminator { value, operator } => {
+                let account_data = account
+                    .try_borrow_data()
+                    .map_err(LighthouseError::failed_borrow_err)?;
+
+                let actual_discriminator = account_data
+                    .get(0..8)
+                    .ok_or_else(|| LighthouseError::oob_err(0..8))?;
+
+                let expected_discriminator = match value {
+                    AnchorDiscriminatorValue::AccountName(account_name) => {
+                        anchor_account_discriminator(account_name)
+                    }
+                    AnchorDiscriminatorValue::Discriminator(discriminator) => *discriminator,
+                };
+
+                <[u8]>::evaluate(
+                    actual_discriminator,
+                    &expected_discriminator,
+                    operator,
+                    log_context,
                 )
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    mod evaluate {
+        use crate::{
+            error::LighthouseError,
+            test_utils::{assert_failed, assert_is_program_error, assert_passed},
+            types::assert::{
+                AccountInfoAssertion, AnchorDiscriminatorValue, Assert, EquatableOperator, LogLevel,
+            },
+            utils::anchor_discriminator,
+        };
+        use solana_sdk::{account_info::AccountInfo, signature::Keypair, signer::EncodableKeypair};
+
+        #[test]
+        fn evaluate_anchor_discriminator() {
+            let key = Keypair::new().encodable_pubkey();
+            let owner = Keypair::new().encodable_pubkey();
+            let lamports = &mut 0;
+            let data = &mut [0u8; 16];
+            data[0..8].copy_from_slice(&anchor_discriminator("account:TreeConfig").to_le_bytes());
+            let account_info =
+                AccountInfo::new(&key, false, false, lamports, data, &owner, false, 0);
+
+            let result = AccountInfoAssertion::AnchorDiscriminator {
+                value: AnchorDiscriminatorValue::AccountName("TreeConfig".to_string()),
+                operator: EquatableOperator::Equal,
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
+            assert_passed(result);
+
+            let result = AccountInfoAssertion::AnchorDiscriminator {
+                value: AnchorDiscriminatorValue::AccountName("Voucher".to_string()),
+                operator: EquatableOperator::Equal,
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
+            assert_failed(result);
+
+            let result = AccountInfoAssertion::AnchorDiscriminator {
+                value: AnchorDiscriminatorValue::Discriminator(
+                    anchor_discriminator("account:TreeConfig").to_le_bytes(),
+                ),
+                operator: EquatableOperator::Equal,
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
+            assert_passed(result);
+
+            let result = AccountInfoAssertion::AnchorDiscriminator {
+                value: AnchorDiscriminatorValue::Discriminator([0u8; 8]),
+                operator: EquatableOperator::NotEqual,
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
+            assert_passed(result);
+
+            let lamports = &mut 0;
+            let data = &mut [0u8; 4];
+            let account_info =
+                AccountInfo::new(&key, false, false, lamports, data, &owner, false, 0);
+
+            let result = AccountInfoAssertion::AnchorDiscriminator {
+                value: AnchorDiscriminatorValue::AccountName("TreeConfig".to_string()),
+                operator: EquatableOperator::Equal,
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
+            assert_is_program_error(
+                result.unwrap_err(),
+                LighthouseError::RangeOutOfBounds.into(),
+            );
+        }
+    }
+}
@@ -0,0 +1,753 @@
+use super::{Assert, EquatableOperator, Evaluate, IntegerOperator, LogContext, StakeStateType};
+use crate::{
+    err, err_msg,
+    error::LighthouseError,
+    utils::{borrow_token_program_data, keys_equal, Result, TokenAccountType},
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    msg,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    stake::state::{Meta as StakeMeta, Stake as StakeInfo, StakeStateV2},
+};
+use spl_token_2022::state::{Account as TokenAccount, Mint};
+
+// Snapshots written by WriteType::AccountSnapshot start with the key of the source account
+pub const ACCOUNT_SNAPSHOT_KEY_LENGTH: usize = 32;
+
+///
+/// Compares a live account against a snapshot of the same account held in memory at `memory_offset`,
+/// the key stored in the snapshot must match the live account.
+/// Integer assertions are evaluated on the delta (live - snapshot), equatable assertions compare live to snapshot.
+///
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub enum AccountSnapshotAssertion {
+    TokenAccount {
+        memory_offset: u16,
+        assertion: TokenAccountSnapshotAssertion,
+    },
+    MintAccount {
+        memory_offset: u16,
+        assertion: MintAccountSnapshotAssertion,
+    },
+    StakeAccount {
+        memory_offset: u16,
+        assertion: StakeAccountSnapshotAssertion,
+    },
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub enum TokenAccountSnapshotAssertion {
+    Amount {
+        value: i128,
+        operator: IntegerOperator,
+    },
+    Owner {
+        operator: EquatableOperator,
+    },
+    Delegate {
+        operator: EquatableOperator,
+    },
+    DelegatedAmount {
+        value: i128,
+        operator: IntegerOperator,
+    },
+    State {
+        operator: EquatableOperator,
+    },
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub enum MintAccountSnapshotAssertion {
+    Supply {
+        value: i128,
+        operator: IntegerOperator,
+    },
+    MintAuthority {
+        operator: EquatableOperator,
+    },
+    FreezeAuthority {
+        operator: EquatableOperator,
+    },
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub enum StakeAccountSnapshotAssertion {
+    State {
+        operator: EquatableOperator,
+    },
+    AuthorizedStaker {
+        operator: EquatableOperator,
+    },
+    AuthorizedWithdrawer {
+        operator: EquatableOperator,
+    },
+    DelegationVoterPubkey {
+        operator: EquatableOperator,
+    },
+    DelegationStake {
+        value: i128,
+        operator: IntegerOperator,
+    },
+}
+
+fn unpack_token_program_state<T: Pack>(data: &[u8]) -> Result<T> {
+    let data_slice = data
+        .get(..T::LEN)
+        .ok_or_else(|| LighthouseError::oob_err(0..T::LEN))?;
+
+    T::unpack_from_slice(data_slice).map_err(|e| {
+        err_msg!("Failed to deserialize token program account", e);
+        err!(LighthouseError::FailedToDeserialize)
+    })
+}
+
+fn delta(snapshot_value: u64, live_value: u64) -> i128 {
+    live_value as i128 - snapshot_value as i128
+}
+
+fn stake_state_type(stake_account: &StakeStateV2) -> u8 {
+    (match stake_account {
+        StakeStateV2::Uninitialized => StakeStateType::Uninitialized,
+        StakeStateV2::Initialized(_) => StakeStateType::Initialized,
+        StakeStateV2::Stake(_, _, _) => StakeStateType::Stake,
+        StakeStateV2::RewardsPool => StakeStateType::RewardsPool,
+    }) as u8
+}
+
+fn stake_meta(stake_account: &StakeStateV2) -> Result<&StakeMeta> {
+    match stake_account {
+        StakeStateV2::Initialized(meta) | StakeStateV2::Stake(meta, _, _) => Ok(meta),
+        _ => {
+            msg!("Stake account is not in a state that has meta field");
+            Err(LighthouseError::AssertionFailed.into())
+        }
+    }
+}
+
+fn stake_info(stake_account: &StakeStateV2) -> Result<&StakeInfo> {
+    match stake_account {
+        StakeStateV2::Stake(_, stake, _) => Ok(stake),
+        _ => {
+            msg!("Stake account is not in a state that has stake field");
+            Err(LighthouseError::AssertionFailed.into())
+        }
+    }
+}
+
+impl Assert<(&TokenAccount, &TokenAccount)> for TokenAccountSnapshotAssertion {
+    fn evaluate(
+        &self,
+        (snapshot, live): (&TokenAccount, &TokenAccount),
+        log_context: LogContext,
+    ) -> Result<()> {
+        match self {
+            TokenAccountSnapshotAssertion::Amount { value, operator } => i128::evaluate(
+                &delta(snapshot.amount, live.amount),
+                value,
+                operator,
+                log_context,
+            ),
+            TokenAccountSnapshotAssertion::Owner { operator } => {
+                Pubkey::evaluate(&live.owner, &snapshot.owner, operator, log_context)
+            }
+            TokenAccountSnapshotAssertion::Delegate { operator } => <Option<&Pubkey>>::evaluate(
+                &live.delegate.as_ref().into(),
+                &snapshot.delegate.as_ref().into(),
+                operator,
+                log_context,
+            ),
+            TokenAccountSnapshotAssertion::DelegatedAmount { value, operator } => i128::evaluate(
+                &delta(snapshot.delegated_amount, live.delegated_amount),
+                value,
+                operator,
+                log_context,
+            ),
+            TokenAccountSnapshotAssertion::State { operator } => u8::evaluate(
+                &(live.state as u8),
+                &(snapshot.state as u8),
+                operator,
+                log_context,
+            ),
+        }
+    }
+}
+
+impl Assert<(&Mint, &Mint)> for MintAccountSnapshotAssertion {
+    fn evaluate(&self, (snapshot, live): (&Mint, &Mint), log_context: LogContext) -> Result<()> {
+        match self {
+            MintAccountSnapshotAssertion::Supply { value, operator } => i128::evaluate(
+                &delta(snapshot.supply, live.supply),
+                value,
+                operator,
+                log_context,
+            ),
+            MintAccountSnapshotAssertion::MintAuthority { operator } => {
+                <Option<&Pubkey>>::evaluate(
+                    &live.mint_authority.as_ref().into(),
+                    &snapshot.mint_authority.as_ref().into(),
+                    operator,
+                    log_context,
+                )
+            }
+            MintAccountSnapshotAssertion::FreezeAuthority { operator } => {
+                <Option<&Pubkey>>::evaluate(
+                    &live.freeze_authority.as_ref().into(),
+                    &snapshot.freeze_authority.as_ref().into(),
+                    operator,
+                    log_context,
+                )
+            }
+        }
+    }
+}
+
+impl Assert<(&StakeStateV2, &StakeStateV2)> for StakeAccountSnapshotAssertion {
+    fn evaluate(
+        &self,
+        (snapshot, live): (&StakeStateV2, &StakeStateV2),
+        log_context: LogContext,
+    ) -> Result<()> {
+        match self {
+            StakeAccountSnapshotAssertion::State { operator } => u8::evaluate(
+                &stake_state_type(live),
+                &stake_state_type(snapshot),
+                operator,
+                log_context,
+            ),
+            StakeAccountSnapshotAssertion::AuthorizedStaker { operator } => Pubkey::evaluate(
+                &stake_meta(live)?.authorized.staker,
+                &stake_meta(snapshot)?.authorized.staker,
+                operator,
+                log_context,
+            ),
+            StakeAccountSnapshotAssertion::AuthorizedWithdrawer { operator } => Pubkey::evaluate(
+                &stake_meta(live)?.authorized.withdrawer,
+                &stake_meta(snapshot)?.authorized.withdrawer,
+                operator,
+                log_context,
+            ),
+            StakeAccountSnapshotAssertion::DelegationVoterPubkey { operator } => Pubkey::evaluate(
+                &stake_info(live)?.delegation.voter_pubkey,
+                &stake_info(snapshot)?.delegation.voter_pubkey,
+                operator,
+                log_context,
+            ),
+            StakeAccountSnapshotAssertion::DelegationStake { value, operator } => {
+                let actual_delta = delta(
+                    stake_info(snapshot)?.delegation.stake,
+                    stake_info(live)?.delegation.stake,
+                );
+
+                i128::evaluate(&actual_delta, value, operator, log_context)
+            }
+        }
+    }
+}
+
+impl<'a, 'info> Assert<(&'a AccountInfo<'info>, &'a AccountInfo<'info>)>
+    for AccountSnapshotAssertion
+{
+    fn evaluate(
+        &self,
+        (memory, account): (&'a AccountInfo<'info>, &'a AccountInfo<'info>),
+        log_context: LogContext,
+    ) -> Result<()> {
+        if !keys_equal(memory.owner, &crate::ID) {
+            msg!("Snapshot account is not a memory account");
+            return Err(LighthouseError::AccountOwnerMismatch.into());
+        }
+
+        let memory_offset = match self {
+            AccountSnapshotAssertion::TokenAccount { memory_offset, .. }
+            | AccountSnapshotAssertion::MintAccount { memory_offset, .. }
+            | AccountSnapshotAssertion::StakeAccount { memory_offset, .. } => {
+                *memory_offset as usize
+            }
+        };
+
+        let memory_data = memory
+            .try_borrow_data()
+            .map_err(LighthouseError::failed_borrow_err)?;
+
+        let snapshot_key_range = memory_offset..(memory_offset + ACCOUNT_SNAPSHOT_KEY_LENGTH);
+        let snapshot_key = memory_data
+            .get(snapshot_key_range.clone())
+            .ok_or_else(|| LighthouseError::oob_err(snapshot_key_range.clone()))?;
+
+        if !keys_equal(bytemuck::from_bytes::<Pubkey>(snapshot_key), account.key) {
+            msg!("Snapshot was taken of a different account");
+            return Err(LighthouseError::AccountKeyMismatch.into());
+        }
+
+        let snapshot = &memory_data[snapshot_key_range.end..];
+
+        match self {
+            AccountSnapshotAssertion::TokenAccount { assertion, .. } => {
+                let live = borrow_token_program_data(account, TokenAccountType::Account)?;
+
+                assertion.evaluate(
+                    (
+                        &unpack_token_program_state(snapshot)?,
+                        &unpack_token_program_state(&live)?,
+                    ),
+                    log_context,
+                )
+            }
+            AccountSnapshotAssertion::MintAccount { assertion, .. } => {
+                let live = borrow_token_program_data(account, TokenAccountType::Mint)?;
+
+                assertion.evaluate(
+                    (
+                        &unpack_token_program_state(snapshot)?,
+                        &unpack_token_program_state(&live)?,
+                    ),
+                    log_context,
+                )
+            }
+            AccountSnapshotAssertion::StakeAccount { assertion, .. } => {
+                if !keys_equal(account.owner, &solana_program::stake::program::ID) {
+                    return Err(LighthouseError::AccountOwnerMismatch.into());
+                }
+
+                let live = account
+                    .try_borrow_data()
+                    .map_err(LighthouseError::failed_borrow_err)?;
+
+                let snapshot_stake_account = StakeStateV2::deserialize(&mut &snapshot[..])
+                    .map_err(LighthouseError::stake_deser_err)?;
+                let live_stake_account = StakeStateV2::deserialize(&mut live.as_ref())
+                    .map_err(LighthouseError::stake_deser_err)?;
+
+                assertion.evaluate((&snapshot_stake_account, &live_stake_account), log_context)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod evaluate {
+        use crate::{
+            error::LighthouseError,
+            test_utils::{assert_failed, assert_is_program_error, assert_passed},
+            types::assert::{
+                AccountSnapshotAssertion, Assert, EquatableOperator, IntegerOperator, LogLevel,
+                MintAccountSnapshotAssertion, StakeAccountSnapshotAssertion,
+                TokenAccountSnapshotAssertion,
+            },
+        };
+        use borsh::BorshSerialize;
+        use solana_program::{
+            account_info::AccountInfo,
+            program_option::COption,
+            program_pack::Pack,
+            pubkey::Pubkey,
+            stake::{
+                stake_flags::StakeFlags,
+                state::{Authorized, Delegation, Meta, Stake, StakeStateV2},
+            },
+        };
+        use solana_sdk::{signature::Keypair, signer::EncodableKeypair};
+        use spl_token_2022::state::{Account, AccountState, Mint};
+
+        const MEMORY_OFFSET: usize = 8;
+
+        fn pack_token_account(amount: u64, delegate: COption<Pubkey>) -> [u8; Account::LEN] {
+            let mut data = [0u8; Account::LEN];
+            Account::pack(
+                Account {
+                    mint: Keypair::new().encodable_pubkey(),
+                    owner: Pubkey::default(),
+                    amount,
+                    delegate,
+                    state: AccountState::Initialized,
+                    is_native: COption::None,
+                    delegated_amount: 0,
+                    close_authority: COption::None,
+                },
+                &mut data,
+            )
+            .unwrap();
+
+            data
+        }
+
+        // Memory holding a snapshot of `key` at MEMORY_OFFSET
+        fn snapshot_memory<const N: usize>(key: &Pubkey, snapshot_data: &[u8]) -> [u8; N] {
+            let mut memory_data = [0u8; N];
+            memory_data[MEMORY_OFFSET..MEMORY_OFFSET + 32].copy_from_slice(key.as_ref());
+            memory_data[MEMORY_OFFSET + 32..].copy_from_slice(snapshot_data);
+
+            memory_data
+        }
+
+        #[test]
+        fn evaluate_token_account_snapshot() {
+            let key = Keypair::new().encodable_pubkey();
+
+            let memory_key = Keypair::new().encodable_pubkey();
+            let memory_lamports = &mut 0;
+            let memory_data = &mut snapshot_memory::<{ MEMORY_OFFSET + 32 + Account::LEN }>(
+                &key,
+                &pack_token_account(100, COption::None),
+            );
+            let memory = AccountInfo::new(
+                &memory_key,
+                false,
+                false,
+                memory_lamports,
+                memory_data,
+                &crate::ID,
+                false,
+                0,
+            );
+
+            let lamports = &mut 0;
+            let data = &mut pack_token_account(60, COption::Some(key));
+            let account = AccountInfo::new(
+                &key,
+                false,
+                false,
+                lamports,
+                data,
+                &spl_token_2022::ID,
+                false,
+                0,
+            );
+
+            let assertions = vec![
+                (
+                    TokenAccountSnapshotAssertion::Amount {
+                        value: -40,
+                        operator: IntegerOperator::Equal,
+                    },
+                    true,
+                ),
+                (
+                    TokenAccountSnapshotAssertion::Amount {
+                        value: 0,
+                        operator: IntegerOperator::GreaterThanOrEqual,
+                    },
+                    false,
+                ),
+                (
+                    TokenAccountSnapshotAssertion::Owner {
+                        operator: EquatableOperator::Equal,
+                    },
+                    true,
+                ),
+                (
+                    TokenAccountSnapshotAssertion::Delegate {
+                        operator: EquatableOperator::Equal,
+                    },
+                    false,
+                ),
+                (
+                    TokenAccountSnapshotAssertion::State {
+                        operator: EquatableOperator::Equal,
+                    },
+                    true,
+                ),
+            ];
+
+            for (assertion, should_pass) in assertions {
+                let result = AccountSnapshotAssertion::TokenAccount {
+                    memory_offset: MEMORY_OFFSET as u16,
+                    assertion,
+                }
+                .evaluate((&memory, &account), LogLevel::PlaintextMessage.into());
+
+                if should_pass {
+                    assert_passed(result);
+                } else {
+                    assert_failed(result);
+                }
+            }
+
+            // Snapshot of a different account is rejected
+            let other_key = Keypair::new().encodable_pubkey();
+            let other_lamports = &mut 0;
+            let other_data = &mut pack_token_account(100, COption::None);
+            let other_account = AccountInfo::new(
+                &other_key,
+                false,
+                false,
+                other_lamports,
+                other_data,
+                &spl_token_2022::ID,
+                false,
+                0,
+            );
+
+            let result = AccountSnapshotAssertion::TokenAccount {
+                memory_offset: MEMORY_OFFSET as u16,
+                assertion: TokenAccountSnapshotAssertion::Amount {
+                    value: 0,
+                    operator: IntegerOperator::Equal,
+                },
+            }
+            .evaluate((&memory, &other_account), LogLevel::PlaintextMessage.into());
+
+            assert_is_program_error(
+                result.unwrap_err(),
+                LighthouseError::AccountKeyMismatch.into(),
+            );
+
+            // Live mint checked against a token account snapshot is rejected by owner
+            let result = AccountSnapshotAssertion::MintAccount {
+                memory_offset: MEMORY_OFFSET as u16,
+                assertion: MintAccountSnapshotAssertion::Supply {
+                    value: 0,
+                    operator: IntegerOperator::Equal,
+                },
+            }
+            .evaluate((&account, &memory), LogLevel::PlaintextMessage.into());
+
+            assert_is_program_error(
+                result.unwrap_err(),
+                LighthouseError::AccountOwnerMismatch.into(),
+            );
+
+            // Live token program account of the wrong type is rejected
+            let result = AccountSnapshotAssertion::MintAccount {
+                memory_offset: MEMORY_OFFSET as u16,
+                assertion: MintAccountSnapshotAssertion::Supply {
+                    value: 0,
+                    operator: IntegerOperator::Equal,
+                },
+            }
+            .evaluate((&memory, &account), LogLevel::PlaintextMessage.into());
+
+            assert_is_program_error(
+                result.unwrap_err(),
+                LighthouseError::AccountDiscriminatorValidationFailed.into(),
+            );
+        }
+
+        #[test]
+        fn evaluate_mint_account_snapshot() {
+            let authority = Keypair::new().encodable_pubkey();
+            let pack_mint = |supply: u64| {
+                let mut data = [0u8; Mint::LEN];
+                Mint::pack(
+                    Mint {
+                        mint_authority: COption::Some(authority),
+                        supply,
+                        decimals: 9,
+                        is_initialized: true,
+                        freeze_authority: COption::None,
+                    },
+                    &mut data,
+                )
+                .unwrap();
+
+                data
+            };
+
+            let key = Keypair::new().encodable_pubkey();
+
+            let memory_key = Keypair::new().encodable_pubkey();
+            let memory_lamports = &mut 0;
+            let memory_data =
+                &mut snapshot_memory::<{ MEMORY_OFFSET + 32 + Mint::LEN }>(&key, &pack_mint(1_000));
+            let memory = AccountInfo::new(
+                &memory_key,
+                false,
+                false,
+                memory_lamports,
+                memory_data,
+                &crate::ID,
+                false,
+                0,
+            );
+
+            let lamports = &mut 0;
+            let data = &mut pack_mint(1_500);
+            let account =
+                AccountInfo::new(&key, false, false, lamports, data, &spl_token::ID, false, 0);
+
+            let assertions = vec![
+                (
+                    MintAccountSnapshotAssertion::Supply {
+                        value: 500,
+                        operator: IntegerOperator::Equal,
+                    },
+                    true,
+                ),
+                (
+                    MintAccountSnapshotAssertion::MintAuthority {
+                        operator: EquatableOperator::Equal,
+                    },
+                    true,
+                ),
+                (
+                    MintAccountSnapshotAssertion::FreezeAuthority {
+                        operator: EquatableOperator::NotEqual,
+                    },
+                    false,
+                ),
+            ];
+
+            for (assertion, should_pass) in assertions {
+                let result = AccountSnapshotAssertion::MintAccount {
+                    memory_offset: MEMORY_OFFSET as u16,
+                    assertion,
+                }
+                .evaluate((&memory, &account), LogLevel::PlaintextMessage.into());
+
+                if should_pass {
+                    assert_passed(result);
+                } else {
+                    assert_failed(result);
+                }
+            }
+        }
+
+        #[test]
+        fn evaluate_stake_account_snapshot() {
+            let staker = Keypair::new().encodable_pubkey();
+            let voter = Keypair::new().encodable_pubkey();
+            let pack_stake = |stake: u64, withdrawer: Pubkey| {
+                let mut data = [0u8; StakeStateV2::size_of()];
+                StakeStateV2::Stake(
+                    Meta {
+                        authorized: Authorized { staker, withdrawer },
+                        ..Meta::default()
+                    },
+                    Stake {
+                        delegation: Delegation {
+                            voter_pubkey: voter,
+                            stake,
+                            ..Delegation::default()
+                        },
+                        credits_observed: 0,
+                    },
+                    StakeFlags::empty(),
+                )
+                .serialize(&mut data.as_mut_slice())
+                .unwrap();
+
+                data
+            };
+
+            let key = Keypair::new().encodable_pubkey();
+
+            let memory_key = Keypair::new().encodable_pubkey();
+            let memory_lamports = &mut 0;
+            let memory_data = &mut snapshot_memory::<
+                { MEMORY_OFFSET + 32 + StakeStateV2::size_of() },
+            >(&key, &pack_stake(1_000, staker));
+            let memory = AccountInfo::new(
+                &memory_key,
+                false,
+                false,
+                memory_lamports,
+                memory_data,
+                &crate::ID,
+                false,
+                0,
+            );
+
+            let lamports = &mut 0;
+            let data = &mut pack_stake(750, Keypair::new().encodable_pubkey());
+            let account = AccountInfo::new(
+                &key,
+                false,
+                false,
+                lamports,
+                data,
+                &solana_program::stake::program::ID,
+                false,
+                0,
+            );
+
+            let assertions = vec![
+                (
+                    StakeAccountSnapshotAssertion::DelegationStake {
+                        value: -250,
+                        operator: IntegerOperator::Equal,
+                    },
+                    true,
+                ),
+                (
+                    StakeAccountSnapshotAssertion::DelegationStake {
+                        value: 0,
+                        operator: IntegerOperator::GreaterThanOrEqual,
+                    },
+                    false,
+                ),
+                (
+                    StakeAccountSnapshotAssertion::State {
+                        operator: EquatableOperator::Equal,
+                    },
+                    true,
+                ),
+                (
+                    StakeAccountSnapshotAssertion::AuthorizedStaker {
+                        operator: EquatableOperator::Equal,
+                    },
+                    true,
+                ),
+                (
+                    StakeAccountSnapshotAssertion::AuthorizedWithdrawer {
+                        operator: EquatableOperator::Equal,
+                    },
+                    false,
+                ),
+                (
+                    StakeAccountSnapshotAssertion::DelegationVoterPubkey {
+                        operator: EquatableOperator::Equal,
+                    },
+                    true,
+                ),
+            ];
+
+            for (assertion, should_pass) in assertions {
+                let result = AccountSnapshotAssertion::StakeAccount {
+                    memory_offset: MEMORY_OFFSET as u16,
+                    assertion,
+                }
+                .evaluate((&memory, &account), LogLevel::PlaintextMessage.into());
+
+                if should_pass {
+                    assert_passed(result);
+                } else {
+                    assert_failed(result);
+                }
+            }
+
+            // Live account must be owned by the stake program
+            let unowned_lamports = &mut 0;
+            let unowned_data = &mut pack_stake(750, staker);
+            let unowned_account = AccountInfo::new(
+                &key,
+                false,
+                false,
+                unowned_lamports,
+                unowned_data,
+                &crate::ID,
+                false,
+                0,
+            );
+
+            let result = AccountSnapshotAssertion::StakeAccount {
+                memory_offset: MEMORY_OFFSET as u16,
+                assertion: StakeAccountSnapshotAssertion::State {
+                    operator: EquatableOperator::Equal,
+                },
+            }
+            .evaluate(
+                (&memory, &unowned_account),
+                LogLevel::PlaintextMessage.into(),
+            );
+
+            assert_is_program_error(
+                result.unwrap_err(),
+                LighthouseError::AccountOwnerMismatch.into(),
+            );
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use super::{Assert, EquatableOperator, Evaluate, IntegerOperator, LogLevel};
+use super::{Assert, EquatableOperator, Evaluate, IntegerOperator, LogContext};
 use crate::{err, err_msg, error::LighthouseError, utils::Result};
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
@@ -32,7 +32,7 @@ pub enum BubblegumTreeConfigAssertion {
 }
 
 impl<'info> Assert<&AccountInfo<'info>> for BubblegumTreeConfigAssertion {
-    fn evaluate(&self, account: &AccountInfo<'info>, log_level: LogLevel) -> Result<()> {
+    fn evaluate(&self, account: &AccountInfo<'info>, log_context: LogContext) -> Result<()> {
         let data = account.try_borrow_data().map_err(|e| {
             err_msg!("Cannot borrow data for target account", e);
             err!(LighthouseError::AccountBorrowFailed)
@@ -45,7 +45,7 @@ impl<'info> Assert<&AccountInfo<'info>> for BubblegumTreeConfigAssertion {
                     .ok_or_else(|| LighthouseError::oob_err(8..40))?;
                 let actual_tree_creator = bytemuck::from_bytes::<Pubkey>(data_slice);
 
-                Pubkey::evaluate(actual_tree_creator, value, operator, log_level)
+                Pubkey::evaluate(actual_tree_creator, value, operator, log_context)
             }
             BubblegumTreeConfigAssertion::TreeDelegate { value, operator } => {
                 let data_slice = data
@@ -53,7 +53,7 @@ impl<'info> Assert<&AccountInfo<'info>> for BubblegumTreeConfigAssertion {
                     .ok_or_else(|| LighthouseError::oob_err(40..72))?;
                 let actual_tree_delegate = bytemuck::from_bytes::<Pubkey>(data_slice);
 
-                Pubkey::evaluate(actual_tree_delegate, value, operator, log_level)
+                Pubkey::evaluate(actual_tree_delegate, value, operator, log_context)
             }
             BubblegumTreeConfigAssertion::TotalMintCapacity { value, operator } => {
                 let data_slice = data
@@ -65,7 +65,7 @@ impl<'info> Assert<&AccountInfo<'info>> for BubblegumTreeConfigAssertion {
                     err!(LighthouseError::FailedToDeserialize)
                 })?;
 
-                u64::evaluate(&actual_total_mint_capacity, value, operator, log_level)
+                u64::evaluate(&actual_total_mint_capacity, value, operator, log_context)
             }
             BubblegumTreeConfigAssertion::NumMinted { value, operator } => {
                 let data_slice = data
@@ -77,7 +77,7 @@ impl<'info> Assert<&AccountInfo<'info>> for BubblegumTreeConfigAssertion {
                     err!(LighthouseError::FailedToDeserialize)
                 })?;
 
-                u64::evaluate(&actual_num_minted, value, operator, log_level)
+                u64::evaluate(&actual_num_minted, value, operator, log_context)
             }
             BubblegumTreeConfigAssertion::IsPublic { value, operator } => {
                 let data_slice = data
@@ -89,14 +89,14 @@ impl<'info> Assert<&AccountInfo<'info>> for BubblegumTreeConfigAssertion {
                     err!(LighthouseError::FailedToDeserialize)
                 })?;
 
-                bool::evaluate(&actual_is_public, value, operator, log_level)
+                bool::evaluate(&actual_is_public, value, operator, log_context)
             }
             BubblegumTreeConfigAssertion::IsDecompressible { value, operator } => {
                 let actual_is_decompressible = data
                     .get(89)
                     .ok_or_else(|| LighthouseError::oob_err(89..90))?;
 
-                u8::evaluate(actual_is_decompressible, value, operator, log_level)
+                u8::evaluate(actual_is_decompressible, value, operator, log_context)
             }
         }
     }
@@ -1,4 +1,4 @@
-use super::{Assert, LogLevel};
+use super::{Assert, LogContext};
 use crate::{
     types::assert::evaluate::{Evaluate, IntegerOperator},
     utils::Result,
@@ -31,7 +31,7 @@ pub enum SysvarClockAssertion {
 }
 
 impl Assert<()> for SysvarClockAssertion {
-    fn evaluate(&self, _: (), log_level: LogLevel) -> Result<()> {
+    fn evaluate(&self, _: (), log_context: LogContext) -> Result<()> {
         let clock = Clock::get()?;
 
         match self {
@@ -41,7 +41,7 @@ impl Assert<()> for SysvarClockAssertion {
             } => {
                 let actual_slot = clock.slot;
 
-                u64::evaluate(&actual_slot, assertion_value, operator, log_level)
+                u64::evaluate(&actual_slot, assertion_value, operator, log_context)
             }
             SysvarClockAssertion::EpochStartTimestamp {
                 value: assertion_value,
@@ -53,7 +53,7 @@ impl Assert<()> for SysvarClockAssertion {
                     &actual_epoch_start_timestamp,
                     assertion_value,
                     operator,
-                    log_level,
+                    log_context,
                 )
             }
             SysvarClockAssertion::Epoch {
@@ -62,7 +62,7 @@ impl Assert<()> for SysvarClockAssertion {
             } => {
                 let actual_epoch = clock.epoch;
 
-                u64::evaluate(&actual_epoch, assertion_value, operator, log_level)
+                u64::evaluate(&actual_epoch, assertion_value, operator, log_context)
             }
             SysvarClockAssertion::LeaderScheduleEpoch {
                 value: assertion_value,
@@ -74,7 +74,7 @@ impl Assert<()> for SysvarClockAssertion {
                     &actual_leader_schedule_epoch,
                     assertion_value,
                     operator,
-                    log_level,
+                    log_context,
                 )
             }
             SysvarClockAssertion::UnixTimestamp {
@@ -83,7 +83,12 @@ impl Assert<()> for SysvarClockAssertion {
             } => {
                 let actual_unix_timestamp = clock.unix_timestamp;
 
-                i64::evaluate(&actual_unix_timestamp, assertion_value, operator, log_level)
+                i64::evaluate(
+                    &actual_unix_timestamp,
+                    assertion_value,
+                    operator,
+                    log_context,
+                )
             }
         }
     }
@@ -93,41 +98,21 @@ impl Assert<()> for SysvarClockAssertion {
 mod tests {
     mod evaluate {
         use crate::{
-            test_utils::{assert_failed, assert_passed},
+            test_utils::{assert_failed, assert_passed, setup_syscall_stubs, TEST_CLOCK},
             types::assert::{Assert, IntegerOperator, LogLevel, SysvarClockAssertion},
         };
-        use solana_sdk::{
-            clock::Clock,
-            program_stubs::{set_syscall_stubs, SyscallStubs},
-        };
-
-        pub struct MockSyscallStubs;
-
-        impl SyscallStubs for MockSyscallStubs {
-            fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
-                unsafe {
-                    *(var_addr as *mut _ as *mut Clock) = Clock {
-                        slot: 69,
-                        epoch_start_timestamp: 420,
-                        epoch: 1337,
-                        leader_schedule_epoch: 9001,
-                        unix_timestamp: 123456789,
-                    };
-                }
-                solana_program::entrypoint::SUCCESS
-            }
-        }
 
         #[test]
         fn evaluate_clock() {
-            set_syscall_stubs(Box::new(MockSyscallStubs {}));
+            // Stubs are process wide, so the shared stubs are used rather than a test local clock
+            setup_syscall_stubs();
 
             // Evaluate slot
             let result = SysvarClockAssertion::Slot {
-                value: 69,
+                value: TEST_CLOCK.slot,
                 operator: IntegerOperator::Equal,
             }
-            .evaluate((), LogLevel::PlaintextMessage);
+            .evaluate((), LogLevel::PlaintextMessage.into());
 
             assert_passed(result);
 
@@ -135,17 +120,17 @@ mod tests {
                 value: 1600,
                 operator: IntegerOperator::Equal,
             }
-            .evaluate((), LogLevel::PlaintextMessage);
+            .evaluate((), LogLevel::PlaintextMessage.into());
 
             assert_failed(result);
 
             // Evaluate epoch_start_timestamp
 
             let result = SysvarClockAssertion::EpochStartTimestamp {
-                value: 420,
+                value: TEST_CLOCK.epoch_start_timestamp,
                 operator: IntegerOperator::Equal,
             }
-            .evaluate((), LogLevel::PlaintextMessage);
+            .evaluate((), LogLevel::PlaintextMessage.into());
 
             assert_passed(result);
 
@@ -153,17 +138,17 @@ mod tests {
                 value: 1600,
                 operator: IntegerOperator::Equal,
             }
-            .evaluate((), LogLevel::PlaintextMessage);
+            .evaluate((), LogLevel::PlaintextMessage.into());
 
             assert_failed(result);
 
             // Evaluate epoch
 
             let result = SysvarClockAssertion::Epoch {
-                value: 1337,
+                value: TEST_CLOCK.epoch,
                 operator: IntegerOperator::Equal,
             }
-            .evaluate((), LogLevel::PlaintextMessage);
+            .evaluate((), LogLevel::PlaintextMessage.into());
 
             assert_passed(result);
 
@@ -171,16 +156,16 @@ mod tests {
                 value: 1600,
                 operator: IntegerOperator::Equal,
             }
-            .evaluate((), LogLevel::PlaintextMessage);
+            .evaluate((), LogLevel::PlaintextMessage.into());
 
             assert_failed(result);
             // Evaluate leader_schedule_epoch
 
             let result = SysvarClockAssertion::LeaderScheduleEpoch {
-                value: 9001,
+                value: TEST_CLOCK.leader_schedule_epoch,
                 operator: IntegerOperator::Equal,
             }
-            .evaluate((), LogLevel::PlaintextMessage);
+            .evaluate((), LogLevel::PlaintextMessage.into());
 
             assert_passed(result);
 
@@ -188,16 +173,16 @@ mod tests {
                 value: 1600,
                 operator: IntegerOperator::Equal,
             }
-            .evaluate((), LogLevel::PlaintextMessage);
+            .evaluate((), LogLevel::PlaintextMessage.into());
 
             assert_failed(result);
             // Evaluate unix_timestamp
 
             let result = SysvarClockAssertion::UnixTimestamp {
-                value: 123456789,
+                value: TEST_CLOCK.unix_timestamp,
                 operator: IntegerOperator::Equal,
             }
-            .evaluate((), LogLevel::PlaintextMessage);
+            .evaluate((), LogLevel::PlaintextMessage.into());
 
             assert_passed(result);
 
@@ -205,7 +190,7 @@ mod tests {
                 value: 1600,
                 operator: IntegerOperator::Equal,
             }
-            .evaluate((), LogLevel::PlaintextMessage);
+            .evaluate((), LogLevel::PlaintextMessage.into());
 
             assert_failed(result);
         }
@@ -0,0 +1,350 @@
+use super::{Assert, EquatableOperator, Evaluate, IntegerOperator, LogContext};
+use crate::{err, err_msg, error::LighthouseError, utils::Result};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{account_info::AccountInfo, msg, pubkey::Pubkey};
+
+// spl-account-compression `CompressionAccountType::ConcurrentMerkleTree`
+const CONCURRENT_MERKLE_TREE_ACCOUNT_TYPE: u8 = 1;
+// spl-account-compression `ConcurrentMerkleTreeHeaderData::V1`
+const CONCURRENT_MERKLE_TREE_HEADER_VERSION_V1: u8 = 0;
+const CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1: usize = 56;
+
+const NODE_SIZE: usize = 32;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub enum ConcurrentMerkleTreeAssertion {
+    MaxDepth {
+        value: u32,
+        operator: IntegerOperator,
+    },
+    MaxBufferSize {
+        value: u32,
+        operator: IntegerOperator,
+    },
+    Authority {
+        value: Pubkey,
+        operator: EquatableOperator,
+    },
+    CreationSlot {
+        value: u64,
+        operator: IntegerOperator,
+    },
+    SequenceNumber {
+        value: u64,
+        operator: IntegerOperator,
+    },
+    ActiveIndex {
+        value: u64,
+        operator: IntegerOperator,
+    },
+    // Index of the next leaf to be appended, equal to the number of leaves appended so far.
+    RightmostIndex {
+        value: u32,
+        operator: IntegerOperator,
+    },
+    IsFull {
+        value: bool,
+        operator: EquatableOperator,
+    },
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32> {
+    let data_slice = data
+        .get(offset..offset + 4)
+        .ok_or_else(|| LighthouseError::oob_err(offset..offset + 4))?;
+
+    u32::try_from_slice(data_slice).map_err(|e| {
+        err_msg!("Failed to deserialize u32 from merkle tree account data", e);
+        err!(LighthouseError::FailedToDeserialize)
+    })
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Result<u64> {
+    let data_slice = data
+        .get(offset..offset + 8)
+        .ok_or_else(|| LighthouseError::oob_err(offset..offset + 8))?;
+
+    u64::try_from_slice(data_slice).map_err(|e| {
+        err_msg!("Failed to deserialize u64 from merkle tree account data", e);
+        err!(LighthouseError::FailedToDeserialize)
+    })
+}
+
+///
+/// The rightmost proof follows the change log buffer, its index sits after the proof nodes and the leaf.
+///
+fn read_rightmost_index(data: &[u8]) -> Result<u32> {
+    let max_buffer_size = read_u32(data, 2)? as usize;
+    let max_depth = read_u32(data, 6)? as usize;
+
+    let change_log_size = NODE_SIZE + NODE_SIZE * max_depth + 8;
+    let rightmost_index_offset = max_buffer_size
+        .checked_mul(change_log_size)
+        .and_then(|size| size.checked_add(CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1 + 24))
+        .and_then(|offset| offset.checked_add(NODE_SIZE * max_depth + NODE_SIZE))
+        .ok_or(LighthouseError::RangeOutOfBounds)?;
+
+    read_u32(data, rightmost_index_offset)
+}
+
+impl<'info> Assert<&AccountInfo<'info>> for ConcurrentMerkleTreeAssertion {
+    fn evaluate(&self, account: &AccountInfo<'info>, log_context: LogContext) -> Result<()> {
+        let data = account
+            .try_borrow_data()
+            .map_err(LighthouseError::failed_borrow_err)?;
+
+        let header = data
+            .get(0..2)
+            .ok_or_else(|| LighthouseError::oob_err(0..2))?;
+
+        if header[0] != CONCURRENT_MERKLE_TREE_ACCOUNT_TYPE
+            || header[1] != CONCURRENT_MERKLE_TREE_HEADER_VERSION_V1
+        {
+            msg!(
+                "Unsupported merkle tree account type {} or header version {}",
+                header[0],
+                header[1]
+            );
+            return Err(LighthouseError::AccountDiscriminatorValidationFailed.into());
+        }
+
+        match self {
+            ConcurrentMerkleTreeAssertion::MaxBufferSize { value, operator } => {
+                let actual_max_buffer_size = read_u32(&data, 2)?;
+
+                u32::evaluate(&actual_max_buffer_size, value, operator, log_context)
+            }
+            ConcurrentMerkleTreeAssertion::MaxDepth { value, operator } => {
+                let actual_max_depth = read_u32(&data, 6)?;
+
+                u32::evaluate(&actual_max_depth, value, operator, log_context)
+            }
+            ConcurrentMerkleTreeAssertion::Authority { value, operator } => {
+                let data_slice = data
+                    .get(10..42)
+                    .ok_or_else(|| LighthouseError::oob_err(10..42))?;
+                let actual_authority = bytemuck::from_bytes::<Pubkey>(data_slice);
+
+                Pubkey::evaluate(actual_authority, value, operator, log_context)
+            }
+            ConcurrentMerkleTreeAssertion::CreationSlot { value, operator } => {
+                let actual_creation_slot = read_u64(&data, 42)?;
+
+                u64::evaluate(&actual_creation_slot, value, operator, log_context)
+            }
+            ConcurrentMerkleTreeAssertion::SequenceNumber { value, operator } => {
+                let actual_sequence_number =
+                    read_u64(&data, CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1)?;
+
+                u64::evaluate(&actual_sequence_number, value, operator, log_context)
+            }
+            ConcurrentMerkleTreeAssertion::ActiveIndex { value, operator } => {
+                let actual_active_index =
+                    read_u64(&data, CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1 + 8)?;
+
+                u64::evaluate(&actual_active_index, value, operator, log_context)
+            }
+            ConcurrentMerkleTreeAssertion::RightmostIndex { value, operator } => {
+                let actual_rightmost_index = read_rightmost_index(&data)?;
+
+                u32::evaluate(&actual_rightmost_index, value, operator, log_context)
+            }
+            ConcurrentMerkleTreeAssertion::IsFull { value, operator } => {
+                let max_depth = read_u32(&data, 6)?;
+                let rightmost_index = read_rightmost_index(&data)? as u64;
+
+                let actual_is_full = 1u64
+                    .checked_shl(max_depth)
+                    .is_some_and(|capacity| rightmost_index >= capacity);
+
+                bool::evaluate(&actual_is_full, value, operator, log_context)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod evaluate {
+        use crate::{
+            error::LighthouseError,
+            test_utils::{assert_failed, assert_is_program_error, assert_passed},
+            types::assert::{
+                Assert, ConcurrentMerkleTreeAssertion, EquatableOperator, IntegerOperator, LogLevel,
+            },
+            validation::SPL_ACCOUNT_COMPRESSION_ID,
+        };
+        use solana_sdk::{
+            account_info::AccountInfo, pubkey::Pubkey, signature::Keypair, signer::EncodableKeypair,
+        };
+
+        const MAX_DEPTH: usize = 3;
+        const MAX_BUFFER_SIZE: usize = 8;
+
+        fn create_tree_data(authority: &Pubkey, rightmost_index: u32) -> Vec<u8> {
+            let change_log_size = 32 + 32 * MAX_DEPTH + 8;
+            let path_size = 32 * MAX_DEPTH + 32 + 8;
+            let mut data = vec![0u8; 56 + 24 + MAX_BUFFER_SIZE * change_log_size + path_size];
+
+            data[0] = 1;
+            data[1] = 0;
+            data[2..6].copy_from_slice(&(MAX_BUFFER_SIZE as u32).to_le_bytes());
+            data[6..10].copy_from_slice(&(MAX_DEPTH as u32).to_le_bytes());
+            data[10..42].copy_from_slice(authority.as_ref());
+            data[42..50].copy_from_slice(&420u64.to_le_bytes());
+            data[56..64].copy_from_slice(&7u64.to_le_bytes());
+            data[64..72].copy_from_slice(&6u64.to_le_bytes());
+            data[72..80].copy_from_slice(&7u64.to_le_bytes());
+
+            let rightmost_index_offset = data.len() - 8;
+            data[rightmost_index_offset..rightmost_index_offset + 4]
+                .copy_from_slice(&rightmost_index.to_le_bytes());
+
+            data
+        }
+
+        #[test]
+        fn evaluate_concurrent_merkle_tree() {
+            let authority = Keypair::new().encodable_pubkey();
+            let key = Keypair::new().encodable_pubkey();
+            let lamports = &mut 0;
+            let data = &mut create_tree_data(&authority, 7);
+            let account_info = AccountInfo::new(
+                &key,
+                false,
+                false,
+                lamports,
+                data,
+                &SPL_ACCOUNT_COMPRESSION_ID,
+                false,
+                0,
+            );
+
+            let assertions = vec![
+                (
+                    ConcurrentMerkleTreeAssertion::MaxDepth {
+                        value: MAX_DEPTH as u32,
+                        operator: IntegerOperator::Equal,
+                    },
+                    true,
+                ),
+                (
+                    ConcurrentMerkleTreeAssertion::MaxBufferSize {
+                        value: MAX_BUFFER_SIZE as u32,
+                        operator: IntegerOperator::Equal,
+                    },
+                    true,
+                ),
+                (
+                    ConcurrentMerkleTreeAssertion::Authority {
+                        value: authority,
+                        operator: EquatableOperator::Equal,
+                    },
+                    true,
+                ),
+                (
+                    ConcurrentMerkleTreeAssertion::Authority {
+                        value: key,
+                        operator: EquatableOperator::Equal,
+                    },
+                    false,
+                ),
+                (
+                    ConcurrentMerkleTreeAssertion::CreationSlot {
+                        value: 420,
+                        operator: IntegerOperator::Equal,
+                    },
+                    true,
+                ),
+                (
+                    ConcurrentMerkleTreeAssertion::SequenceNumber {
+                        value: 7,
+                        operator: IntegerOperator::Equal,
+                    },
+                    true,
+                ),
+                (
+                    ConcurrentMerkleTreeAssertion::ActiveIndex {
+                        value: 6,
+                        operator: IntegerOperator::Equal,
+                    },
+                    true,
+                ),
+                (
+                    ConcurrentMerkleTreeAssertion::RightmostIndex {
+                        value: 8,
+                        operator: IntegerOperator::LessThan,
+                    },
+                    true,
+                ),
+                (
+                    ConcurrentMerkleTreeAssertion::IsFull {
+                        value: false,
+                        operator: EquatableOperator::Equal,
+                    },
+                    true,
+                ),
+            ];
+
+            for (assertion, should_pass) in assertions {
+                let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
+
+                if should_pass {
+                    assert_passed(result);
+                } else {
+                    assert_failed(result);
+                }
+            }
+
+            let lamports = &mut 0;
+            let data = &mut create_tree_data(&authority, 8);
+            let account_info = AccountInfo::new(
+                &key,
+                false,
+                false,
+                lamports,
+                data,
+                &SPL_ACCOUNT_COMPRESSION_ID,
+                false,
+                0,
+            );
+
+            let result = ConcurrentMerkleTreeAssertion::IsFull {
+                value: true,
+                operator: EquatableOperator::Equal,
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
+            assert_passed(result);
+        }
+
+        #[test]
+        fn evaluate_uninitialized_tree() {
+            let authority = Keypair::new().encodable_pubkey();
+            let key = Keypair::new().encodable_pubkey();
+            let lamports = &mut 0;
+            let data = &mut create_tree_data(&authority, 0);
+            data[0] = 0;
+            let account_info = AccountInfo::new(
+                &key,
+                false,
+                false,
+                lamports,
+                data,
+                &SPL_ACCOUNT_COMPRESSION_ID,
+                false,
+                0,
+            );
+
+            let result = ConcurrentMerkleTreeAssertion::MaxDepth {
+                value: MAX_DEPTH as u32,
+                operator: IntegerOperator::Equal,
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
+
+            assert_is_program_error(
+                result.unwrap_err(),
+                LighthouseError::AccountDiscriminatorValidationFailed.into(),
+            );
+        }
+    }
+}
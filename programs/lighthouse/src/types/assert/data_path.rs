@@ -0,0 +1,193 @@
+use crate::{error::LighthouseError, utils::Result};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::msg;
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub enum BorshFieldType {
+    // Any fixed width field (integers, bools, pubkeys, arrays, fixed size structs)
+    Fixed(u16),
+    String,
+    FixedVec(u16),
+    StringVec,
+    FixedOption(u16),
+    StringOption,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy)]
+pub enum PointerSize {
+    U16,
+    U32,
+}
+
+///
+/// Steps used to walk Borsh encoded account data from a starting offset.
+/// Pointing the final assertion at a `Vec` or `String` (a `U32` assertion) reads its length prefix.
+///
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub enum DataPathSegment {
+    Skip(Vec<BorshFieldType>),
+    VecElement {
+        element: BorshFieldType,
+        index: u32,
+    },
+    OptionSome,
+    // Advances by `index * stride`, with the little-endian index read at the absolute offset `at`
+    // (e.g. the head of a ring buffer). The indexed element must lie within the account data.
+    Pointer {
+        at: u16,
+        size: PointerSize,
+        stride: u32,
+    },
+}
+
+fn read_u8(data: &[u8], offset: usize) -> Result<u8> {
+    data.get(offset)
+        .copied()
+        .ok_or_else(|| LighthouseError::oob_err(offset..offset + 1))
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16> {
+    let data_slice = data
+        .get(offset..offset + 2)
+        .ok_or_else(|| LighthouseError::oob_err(offset..offset + 2))?;
+
+    Ok(u16::from_le_bytes(data_slice.try_into().unwrap()))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32> {
+    let data_slice = data
+        .get(offset..offset + 4)
+        .ok_or_else(|| LighthouseError::oob_err(offset..offset + 4))?;
+
+    Ok(u32::from_le_bytes(data_slice.try_into().unwrap()))
+}
+
+fn read_option_tag(data: &[u8], offset: usize) -> Result<bool> {
+    match read_u8(data, offset)? {
+        0 => Ok(false),
+        1 => Ok(true),
+        tag => {
+            msg!("Invalid Option tag {} at offset {}", tag, offset);
+            Err(LighthouseError::FailedToDeserialize.into())
+        }
+    }
+}
+
+fn advance(offset: usize, length: usize, data: &[u8]) -> Result<usize> {
+    let end = offset
+        .checked_add(length)
+        .ok_or_else(|| LighthouseError::oob_err(offset..usize::MAX))?;
+
+    if end > data.len() {
+        return Err(LighthouseError::oob_err(offset..end));
+    }
+
+    Ok(end)
+}
+
+fn skip_elements(
+    data: &[u8],
+    offset: usize,
+    element: &BorshFieldType,
+    count: u32,
+) -> Result<usize> {
+    match element {
+        BorshFieldType::Fixed(size) => {
+            let length = (*size as usize)
+                .checked_mul(count as usize)
+                .ok_or_else(|| LighthouseError::oob_err(offset..usize::MAX))?;
+
+            advance(offset, length, data)
+        }
+        _ => {
+            let mut offset = offset;
+            for _ in 0..count {
+                offset = skip_field(data, offset, element)?;
+            }
+
+            Ok(offset)
+        }
+    }
+}
+
+fn skip_field(data: &[u8], offset: usize, field: &BorshFieldType) -> Result<usize> {
+    match field {
+        BorshFieldType::Fixed(size) => advance(offset, *size as usize, data),
+        BorshFieldType::String => {
+            let length = read_u32(data, offset)?;
+            advance(offset + 4, length as usize, data)
+        }
+        BorshFieldType::FixedVec(size) => {
+            let length = read_u32(data, offset)?;
+            skip_elements(data, offset + 4, &BorshFieldType::Fixed(*size), length)
+        }
+        BorshFieldType::StringVec => {
+            let length = read_u32(data, offset)?;
+            skip_elements(data, offset + 4, &BorshFieldType::String, length)
+        }
+        BorshFieldType::FixedOption(size) => {
+            if read_option_tag(data, offset)? {
+                advance(offset + 1, *size as usize, data)
+            } else {
+                Ok(offset + 1)
+            }
+        }
+        BorshFieldType::StringOption => {
+            if read_option_tag(data, offset)? {
+                skip_field(data, offset + 1, &BorshFieldType::String)
+            } else {
+                Ok(offset + 1)
+            }
+        }
+    }
+}
+
+pub fn resolve_data_path(data: &[u8], offset: usize, path: &[DataPathSegment]) -> Result<usize> {
+    let mut offset = offset;
+
+    for segment in path {
+        offset = match segment {
+            DataPathSegment::Skip(fields) => {
+                for field in fields {
+                    offset = skip_field(data, offset, field)?;
+                }
+
+                offset
+            }
+            DataPathSegment::VecElement { element, index } => {
+                let length = read_u32(data, offset)?;
+                if *index >= length {
+                    msg!("Vec index {} out of bounds for length {}", index, length);
+                    return Err(LighthouseError::IndexOutOfBounds.into());
+                }
+
+                skip_elements(data, offset + 4, element, *index)?
+            }
+            DataPathSegment::OptionSome => {
+                if !read_option_tag(data, offset)? {
+                    msg!("Option at offset {} is None", offset);
+                    return Err(LighthouseError::AssertionFailed.into());
+                }
+
+                offset + 1
+            }
+            DataPathSegment::Pointer { at, size, stride } => {
+                let index = match size {
+                    PointerSize::U16 => read_u16(data, *at as usize)? as usize,
+                    PointerSize::U32 => read_u32(data, *at as usize)? as usize,
+                };
+
+                let element_offset = index
+                    .checked_mul(*stride as usize)
+                    .and_then(|length| offset.checked_add(length))
+                    .ok_or_else(|| LighthouseError::oob_err(offset..usize::MAX))?;
+
+                advance(element_offset, *stride as usize, data)?;
+
+                element_offset
+            }
+        };
+    }
+
+    Ok(offset)
+}
@@ -0,0 +1,156 @@
+use super::{assertion_variant_index, Assert, LogContext};
+use crate::{error::LighthouseError, utils::Result};
+use borsh::BorshSerialize;
+use solana_program::msg;
+
+///
+/// Pairs an assertion with the optional caller-chosen error code from its instruction, returned in
+/// place of AssertionFailed so the failing rule can be identified from the transaction error alone.
+///
+#[derive(Debug)]
+pub struct AssertionWithErrorCode<'a, T> {
+    pub assertion: &'a T,
+    pub error_code: Option<u16>,
+}
+
+impl<'a, T> AssertionWithErrorCode<'a, T> {
+    pub fn new(assertion: &'a T, error_code: Option<u16>) -> Self {
+        Self {
+            assertion,
+            error_code,
+        }
+    }
+
+    ///
+    /// Pairs the assertions of a Multi instruction with their error codes, when error codes are
+    /// given there must be exactly one per assertion.
+    ///
+    pub fn from_multi(assertions: &'a [T], error_codes: Option<&[u16]>) -> Result<Vec<Self>> {
+        match error_codes {
+            Some(error_codes) => {
+                if error_codes.len() != assertions.len() {
+                    msg!(
+                        "Expected {} error codes, one per assertion, found {}",
+                        assertions.len(),
+                        error_codes.len()
+                    );
+                    return Err(LighthouseError::InvalidInstructionData.into());
+                }
+
+                Ok(assertions
+                    .iter()
+                    .zip(error_codes)
+                    .map(|(assertion, error_code)| Self::new(assertion, Some(*error_code)))
+                    .collect())
+            }
+            None => Ok(assertions
+                .iter()
+                .map(|assertion| Self::new(assertion, None))
+                .collect()),
+        }
+    }
+}
+
+impl<T: Assert<U> + BorshSerialize, U> Assert<U> for AssertionWithErrorCode<'_, T> {
+    fn evaluate(&self, parameters: U, log_context: LogContext) -> Result<()> {
+        let log_context = log_context.with_field(assertion_variant_index(self.assertion) as u16);
+        let result = self.assertion.evaluate(parameters, log_context);
+
+        match self.error_code {
+            Some(error_code) => result.map_err(|e| LighthouseError::map_custom_err(e, error_code)),
+            None => result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod evaluate {
+        use crate::{
+            error::{LighthouseError, CUSTOM_ERROR_CODE_OFFSET},
+            test_utils::{assert_is_program_error, assert_passed},
+            types::assert::{
+                AccountInfoAssertion, Assert, AssertionWithErrorCode, IntegerOperator, LogLevel,
+            },
+        };
+        use solana_sdk::{
+            account_info::AccountInfo, program_error::ProgramError, signature::Keypair,
+            signer::EncodableKeypair, system_program,
+        };
+
+        #[test]
+        fn evaluate_error_code() {
+            let key = Keypair::new().encodable_pubkey();
+            let lamports = &mut 100;
+            let data: &mut [u8] = &mut [];
+            let account_info = AccountInfo::new(
+                &key,
+                false,
+                false,
+                lamports,
+                data,
+                &system_program::ID,
+                false,
+                0,
+            );
+
+            let lamports_assertion = |value: u64| AccountInfoAssertion::Lamports {
+                value,
+                operator: IntegerOperator::Equal,
+            };
+
+            assert_passed(
+                AssertionWithErrorCode::new(&lamports_assertion(100), Some(7))
+                    .evaluate(&account_info, LogLevel::Silent.into()),
+            );
+
+            assert_is_program_error(
+                AssertionWithErrorCode::new(&lamports_assertion(101), Some(7))
+                    .evaluate(&account_info, LogLevel::Silent.into())
+                    .unwrap_err(),
+                ProgramError::Custom(CUSTOM_ERROR_CODE_OFFSET + 7),
+            );
+
+            assert_is_program_error(
+                AssertionWithErrorCode::new(&lamports_assertion(101), None)
+                    .evaluate(&account_info, LogLevel::Silent.into())
+                    .unwrap_err(),
+                LighthouseError::AssertionFailed.into(),
+            );
+
+            // Errors other than a failed assertion are not remapped
+            assert_is_program_error(
+                LighthouseError::map_custom_err(LighthouseError::RangeOutOfBounds.into(), 7),
+                LighthouseError::RangeOutOfBounds.into(),
+            );
+        }
+
+        #[test]
+        fn multi_error_codes() {
+            let assertions = [
+                AccountInfoAssertion::Lamports {
+                    value: 100,
+                    operator: IntegerOperator::Equal,
+                },
+                AccountInfoAssertion::Lamports {
+                    value: 101,
+                    operator: IntegerOperator::Equal,
+                },
+            ];
+
+            let paired = AssertionWithErrorCode::from_multi(&assertions, Some(&[3, 4])).unwrap();
+            assert_eq!(
+                paired.iter().map(|a| a.error_code).collect::<Vec<_>>(),
+                vec![Some(3), Some(4)]
+            );
+
+            let paired = AssertionWithErrorCode::from_multi(&assertions, None).unwrap();
+            assert!(paired.iter().all(|a| a.error_code.is_none()));
+
+            assert_is_program_error(
+                AssertionWithErrorCode::from_multi(&assertions, Some(&[3])).unwrap_err(),
+                LighthouseError::InvalidInstructionData.into(),
+            );
+        }
+    }
+}
@@ -1,10 +1,7 @@
-use super::LogLevel;
-use crate::{error::LighthouseError, validation::SPL_NOOP_ID, Result};
+use super::{AssertionEvent, LogContext};
+use crate::{error::LighthouseError, Result};
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::{
-    instruction::Instruction, log::sol_log_data, msg, program::invoke, program_memory::sol_memcmp,
-    pubkey::Pubkey,
-};
+use solana_program::{msg, program_memory::sol_memcmp, pubkey::Pubkey};
 use std::fmt::Debug;
 
 const EQUAL_SYMBOL: &str = "==";
@@ -15,6 +12,17 @@ const GREATER_THAN_OR_EQUAL_SYMBOL: &str = ">=";
 const LESS_THAN_OR_EQUAL_SYMBOL: &str = "<=";
 const CONTAINS_SYMBOL: &str = "&";
 const DOES_NOT_CONTAIN_SYMBOL: &str = "!&";
+const MASKED_EQUAL_SYMBOL: &str = "&==";
+const STARTS_WITH_SYMBOL: &str = "^=";
+const IN_SYMBOL: &str = "in";
+const NOT_IN_SYMBOL: &str = "!in";
+const APPROX_EQUAL_SYMBOL: &str = "~=";
+const NOT_APPROX_EQUAL_SYMBOL: &str = "!~=";
+const IS_NAN_SYMBOL: &str = "nan";
+const IS_NOT_NAN_SYMBOL: &str = "!nan";
+
+// Upper bound on the candidate values of a set assertion
+pub const MAX_SET_VALUES: usize = 16;
 
 #[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
 pub enum AssertionResult {
@@ -31,33 +39,51 @@ pub enum AssertionResult {
     Pubkey(Option<Pubkey>, Option<Pubkey>, u8, bool),
     Bytes(Vec<u8>, Vec<u8>, u8, bool),
     Bool(Option<bool>, Option<bool>, u8, bool),
+    // NaN values are encoded as None
+    F32(Option<f32>, Option<f32>, u8, bool),
+    F64(Option<f64>, Option<f64>, u8, bool),
+    // Offset the assertion bytes were found at in the account data, None when absent
+    BytesContains(Option<u32>, Vec<u8>, u8, bool),
+    // Borsh encoded actual value and candidate values of a set membership assertion
+    Set(Vec<u8>, Vec<u8>, u8, bool),
 }
 
 impl AssertionResult {
-    pub fn log_data(&self) -> Result<()> {
-        let data = self.try_to_vec().map_err(LighthouseError::serialize_err)?;
-
-        sol_log_data(&[&data]);
-
-        Ok(())
+    pub fn log_data(self, log_context: &LogContext) -> Result<()> {
+        AssertionEvent::new(log_context, self).log_data()
     }
 
-    pub fn log_noop(&self) -> Result<()> {
-        let data = self.try_to_vec().map_err(LighthouseError::serialize_err)?;
-
-        invoke(
-            &Instruction {
-                program_id: SPL_NOOP_ID,
-                accounts: vec![],
-                data,
-            },
-            &[],
-        )
+    pub fn log_noop(self, log_context: &LogContext) -> Result<()> {
+        AssertionEvent::new(log_context, self).log_noop()
     }
 }
 
 pub trait Operator {
     fn format(&self) -> &str;
+
+    // Operator discriminant included in encoded assertion results
+    fn code(&self) -> u8;
+}
+
+///
+/// Compares an actual value against an assertion value for a given operator.
+///
+pub trait Comparator<T: ?Sized>: Operator {
+    fn compare(&self, actual_value: &T, assertion_value: &T) -> bool;
+}
+
+///
+/// Converts a compared value into its plaintext and encoded log representations.
+///
+pub trait AssertionValue {
+    fn log_plaintext(actual_value: &Self, operator: &str, assertion_value: &Self);
+
+    fn to_assertion_result(
+        actual_value: &Self,
+        assertion_value: &Self,
+        operator: u8,
+        passed: bool,
+    ) -> AssertionResult;
 }
 
 pub trait Evaluate<T: Operator> {
@@ -65,10 +91,33 @@ pub trait Evaluate<T: Operator> {
         actual_value: &Self,
         assertion_value: &Self,
         operator: &T,
-        log_level: LogLevel,
+        log_context: LogContext,
     ) -> Result<()>;
 }
 
+impl<T: AssertionValue + ?Sized, O: Comparator<T>> Evaluate<O> for T {
+    fn evaluate(
+        actual_value: &Self,
+        assertion_value: &Self,
+        operator: &O,
+        log_context: LogContext,
+    ) -> Result<()> {
+        let passed = operator.compare(actual_value, assertion_value);
+
+        log_context.log_result(
+            passed,
+            || T::log_plaintext(actual_value, operator.format(), assertion_value),
+            || T::to_assertion_result(actual_value, assertion_value, operator.code(), passed),
+        )?;
+
+        if passed {
+            Ok(())
+        } else {
+            Err(LighthouseError::AssertionFailed.into())
+        }
+    }
+}
+
 #[derive(BorshDeserialize, BorshSerialize, Debug, Copy, Clone)]
 #[repr(u8)]
 pub enum IntegerOperator {
@@ -95,20 +144,231 @@ impl Operator for IntegerOperator {
             IntegerOperator::DoesNotContain => DOES_NOT_CONTAIN_SYMBOL,
         }
     }
+
+    fn code(&self) -> u8 {
+        *self as u8
+    }
 }
 
-#[macro_export]
-macro_rules! impl_integer_evaluate {
-    ($(($type:ty, $payload_variant:ident)),*) => {
+#[derive(BorshDeserialize, BorshSerialize, Debug, Copy, Clone)]
+#[repr(u8)]
+pub enum EquatableOperator {
+    Equal,
+    NotEqual,
+}
+
+impl Operator for EquatableOperator {
+    fn format(&self) -> &str {
+        match self {
+            EquatableOperator::Equal => EQUAL_SYMBOL,
+            EquatableOperator::NotEqual => NOT_EQUAL_SYMBOL,
+        }
+    }
+
+    fn code(&self) -> u8 {
+        *self as u8
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub enum BytesOperator {
+    Equal,
+    NotEqual,
+    // Assertion bytes appear as a contiguous subsequence of the data following the offset
+    Contains,
+    // Bytes are equal on the bits selected by the mask, bytes past the end of the mask are compared exactly
+    MaskedEqual { mask: Vec<u8> },
+    // Data following the offset begins with the assertion bytes, fails rather than erroring when shorter
+    StartsWith,
+}
+
+impl Operator for BytesOperator {
+    fn format(&self) -> &str {
+        match self {
+            BytesOperator::Equal => EQUAL_SYMBOL,
+            BytesOperator::NotEqual => NOT_EQUAL_SYMBOL,
+            BytesOperator::Contains => CONTAINS_SYMBOL,
+            BytesOperator::MaskedEqual { .. } => MASKED_EQUAL_SYMBOL,
+            BytesOperator::StartsWith => STARTS_WITH_SYMBOL,
+        }
+    }
+
+    fn code(&self) -> u8 {
+        match self {
+            BytesOperator::Equal => 0,
+            BytesOperator::NotEqual => 1,
+            BytesOperator::Contains => 2,
+            BytesOperator::MaskedEqual { .. } => 3,
+            BytesOperator::StartsWith => 4,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Copy, Clone)]
+#[repr(u8)]
+pub enum SetOperator {
+    In,
+    NotIn,
+}
+
+impl Operator for SetOperator {
+    fn format(&self) -> &str {
+        match self {
+            SetOperator::In => IN_SYMBOL,
+            SetOperator::NotIn => NOT_IN_SYMBOL,
+        }
+    }
+
+    fn code(&self) -> u8 {
+        *self as u8
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Copy, Clone)]
+pub enum FloatTolerance {
+    // |actual - expected| <= epsilon
+    Absolute(f64),
+    // |actual - expected| <= epsilon * max(|actual|, |expected|)
+    Relative(f64),
+}
+
+impl FloatTolerance {
+    pub fn is_within(&self, actual_value: f64, assertion_value: f64) -> bool {
+        // Equal infinities have a NaN difference, and any other infinite or NaN operand would
+        // compare against an infinite or NaN relative bound, so only exact equality holds
+        if actual_value == assertion_value {
+            return true;
+        }
+        if !actual_value.is_finite() || !assertion_value.is_finite() {
+            return false;
+        }
+
+        let difference = (actual_value - assertion_value).abs();
+
+        match self {
+            FloatTolerance::Absolute(epsilon) => difference <= *epsilon,
+            FloatTolerance::Relative(epsilon) => {
+                difference <= epsilon * actual_value.abs().max(assertion_value.abs())
+            }
+        }
+    }
+}
+
+///
+/// Comparisons on floating point values. A NaN actual value fails every operator except `IsNaN`,
+/// `NotEqual` included, and the assertion value is ignored by `IsNaN` and `IsNotNaN`.
+///
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub enum FloatOperator {
+    Equal { tolerance: Option<FloatTolerance> },
+    NotEqual { tolerance: Option<FloatTolerance> },
+    GreaterThan,
+    LessThan,
+    GreaterThanOrEqual,
+    LessThanOrEqual,
+    IsNaN,
+    IsNotNaN,
+}
+
+impl Operator for FloatOperator {
+    fn format(&self) -> &str {
+        match self {
+            FloatOperator::Equal { tolerance: None } => EQUAL_SYMBOL,
+            FloatOperator::Equal { tolerance: Some(_) } => APPROX_EQUAL_SYMBOL,
+            FloatOperator::NotEqual { tolerance: None } => NOT_EQUAL_SYMBOL,
+            FloatOperator::NotEqual { tolerance: Some(_) } => NOT_APPROX_EQUAL_SYMBOL,
+            FloatOperator::GreaterThan => GREATER_THAN_SYMBOL,
+            FloatOperator::LessThan => LESS_THAN_SYMBOL,
+            FloatOperator::GreaterThanOrEqual => GREATER_THAN_OR_EQUAL_SYMBOL,
+            FloatOperator::LessThanOrEqual => LESS_THAN_OR_EQUAL_SYMBOL,
+            FloatOperator::IsNaN => IS_NAN_SYMBOL,
+            FloatOperator::IsNotNaN => IS_NOT_NAN_SYMBOL,
+        }
+    }
+
+    fn code(&self) -> u8 {
+        match self {
+            FloatOperator::Equal { .. } => 0,
+            FloatOperator::NotEqual { .. } => 1,
+            FloatOperator::GreaterThan => 2,
+            FloatOperator::LessThan => 3,
+            FloatOperator::GreaterThanOrEqual => 4,
+            FloatOperator::LessThanOrEqual => 5,
+            FloatOperator::IsNaN => 6,
+            FloatOperator::IsNotNaN => 7,
+        }
+    }
+}
+
+///
+/// Set membership of a value within a bounded list of candidate values. Encoded results carry the
+/// Borsh encoded actual value and candidate list as bytes.
+///
+pub trait EvaluateSet: Sized {
+    fn evaluate_set(
+        actual_value: &Self,
+        assertion_values: &[Self],
+        operator: &SetOperator,
+        log_context: LogContext,
+    ) -> Result<()>;
+}
+
+impl<T: PartialEq + Debug + BorshSerialize> EvaluateSet for T {
+    fn evaluate_set(
+        actual_value: &Self,
+        assertion_values: &[Self],
+        operator: &SetOperator,
+        log_context: LogContext,
+    ) -> Result<()> {
+        if assertion_values.len() > MAX_SET_VALUES {
+            msg!(
+                "Set assertion has {} values, at most {} are allowed",
+                assertion_values.len(),
+                MAX_SET_VALUES
+            );
+            return Err(LighthouseError::InvalidInstructionData.into());
+        }
+
+        let passed = match operator {
+            SetOperator::In => assertion_values.contains(actual_value),
+            SetOperator::NotIn => !assertion_values.contains(actual_value),
+        };
+
+        log_context.log_result(
+            passed,
+            || {
+                msg!(
+                    "Result: {:?} {} {:?}",
+                    actual_value,
+                    operator.format(),
+                    assertion_values
+                )
+            },
+            || {
+                // Serializing into a vector does not fail
+                AssertionResult::Set(
+                    actual_value.try_to_vec().unwrap_or_default(),
+                    assertion_values.try_to_vec().unwrap_or_default(),
+                    operator.code(),
+                    passed,
+                )
+            },
+        )?;
+
+        if passed {
+            Ok(())
+        } else {
+            Err(LighthouseError::AssertionFailed.into())
+        }
+    }
+}
+
+macro_rules! impl_integer_comparator {
+    ($($type:ty),*) => {
         $(
-            impl Evaluate<IntegerOperator> for $type {
-                fn evaluate(
-                    actual_value: &Self,
-                    assertion_value: &Self,
-                    operator: &IntegerOperator,
-                    log_level: LogLevel,
-                ) -> Result<()> {
-                    let passed = match operator {
+            impl Comparator<$type> for IntegerOperator {
+                fn compare(&self, actual_value: &$type, assertion_value: &$type) -> bool {
+                    match self {
                         IntegerOperator::Equal => actual_value == assertion_value,
                         IntegerOperator::NotEqual => actual_value != assertion_value,
                         IntegerOperator::GreaterThan => actual_value > assertion_value,
@@ -117,40 +377,23 @@ macro_rules! impl_integer_evaluate {
                         IntegerOperator::LessThanOrEqual => actual_value <= assertion_value,
                         IntegerOperator::Contains => actual_value & assertion_value == *assertion_value,
                         IntegerOperator::DoesNotContain => actual_value & assertion_value == 0,
-                    };
-
-                    match log_level {
-                        LogLevel::PlaintextMessage => {
-                            msg!(
-                                "Result: {} {} {}",
-                                actual_value,
-                                operator.format(),
-                                assertion_value
-                            );
-                        }
-                        LogLevel::Silent => {}
-                        LogLevel::EncodedMessage => {
-                            AssertionResult::$payload_variant(
-                                Some(*actual_value),
-                                Some(*assertion_value),
-                                *operator as u8,
-                                passed,
-                            ).log_data()?;
-                        }
-                        LogLevel::EncodedNoop => {
-                            AssertionResult::$payload_variant(
-                                Some(*actual_value),
-                                Some(*assertion_value),
-                                *operator as u8,
-                                passed,
-                            ).log_noop()?;
-                        }
                     }
+                }
+            }
+        )*
+    };
+}
 
-                    if passed {
-                        Ok(())
-                    } else {
-                        Err(LighthouseError::AssertionFailed.into())
+impl_integer_comparator!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+macro_rules! impl_equatable_comparator {
+    ($($type:ty),*) => {
+        $(
+            impl Comparator<$type> for EquatableOperator {
+                fn compare(&self, actual_value: &$type, assertion_value: &$type) -> bool {
+                    match self {
+                        EquatableOperator::Equal => actual_value == assertion_value,
+                        EquatableOperator::NotEqual => actual_value != assertion_value,
                     }
                 }
             }
@@ -158,355 +401,467 @@ macro_rules! impl_integer_evaluate {
     };
 }
 
-impl_integer_evaluate!(
-    (u8, U8),
-    (u16, U16),
-    (u32, U32),
-    (u64, U64),
-    (u128, U128),
-    (i8, I8),
-    (i16, I16),
-    (i32, I32),
-    (i64, I64),
-    (i128, I128)
+impl_equatable_comparator!(
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    bool,
+    Option<u8>,
+    Option<u16>,
+    Option<u32>,
+    Option<u64>,
+    Option<u128>,
+    Option<i8>,
+    Option<i16>,
+    Option<i32>,
+    Option<i64>,
+    Option<i128>,
+    Option<bool>,
+    Pubkey,
+    Option<&Pubkey>
 );
 
-#[derive(BorshDeserialize, BorshSerialize, Debug, Copy, Clone)]
-#[repr(u8)]
-pub enum EquatableOperator {
-    Equal,
-    NotEqual,
+impl Comparator<[u8]> for EquatableOperator {
+    fn compare(&self, actual_value: &[u8], assertion_value: &[u8]) -> bool {
+        let equal = actual_value.len() == assertion_value.len()
+            && sol_memcmp(actual_value, assertion_value, assertion_value.len()) == 0;
+
+        match self {
+            EquatableOperator::Equal => equal,
+            EquatableOperator::NotEqual => !equal,
+        }
+    }
 }
 
-impl Operator for EquatableOperator {
-    fn format(&self) -> &str {
+impl Comparator<[u8]> for BytesOperator {
+    fn compare(&self, actual_value: &[u8], assertion_value: &[u8]) -> bool {
         match self {
-            EquatableOperator::Equal => EQUAL_SYMBOL,
-            EquatableOperator::NotEqual => NOT_EQUAL_SYMBOL,
+            BytesOperator::Equal => EquatableOperator::Equal.compare(actual_value, assertion_value),
+            BytesOperator::NotEqual => {
+                EquatableOperator::NotEqual.compare(actual_value, assertion_value)
+            }
+            BytesOperator::Contains => find_bytes(actual_value, assertion_value).is_some(),
+            BytesOperator::MaskedEqual { mask } => {
+                actual_value.len() == assertion_value.len()
+                    && actual_value.iter().zip(assertion_value).enumerate().all(
+                        |(i, (actual, expected))| {
+                            let mask = mask.get(i).copied().unwrap_or(u8::MAX);
+                            actual & mask == expected & mask
+                        },
+                    )
+            }
+            BytesOperator::StartsWith => actual_value.starts_with(assertion_value),
         }
     }
 }
 
-#[macro_export]
-macro_rules! impl_evaluate {
+fn find_bytes(data: &[u8], value: &[u8]) -> Option<usize> {
+    if value.is_empty() {
+        return Some(0);
+    }
+
+    data.windows(value.len()).position(|window| window == value)
+}
+
+///
+/// Evaluates a bytes assertion against account data at an offset.
+///
+/// `Contains` searches all data following the offset and reports where the assertion bytes
+/// were found, `StartsWith` compares the data following the offset up to the length of the
+/// assertion bytes, every other operator compares the range the length of the assertion bytes.
+///
+pub fn evaluate_bytes(
+    data: &[u8],
+    offset: usize,
+    assertion_value: &[u8],
+    operator: &BytesOperator,
+    log_context: LogContext,
+) -> Result<()> {
+    if let BytesOperator::Contains = operator {
+        let searched_data = data
+            .get(offset..)
+            .ok_or_else(|| LighthouseError::oob_err(offset..data.len()))?;
+
+        let position = find_bytes(searched_data, assertion_value).map(|index| offset + index);
+        let passed = position.is_some();
+
+        log_context.log_result(
+            passed,
+            || match position {
+                Some(position) => {
+                    msg!("Result: {:?} found at offset {}", assertion_value, position)
+                }
+                None => msg!(
+                    "Result: {:?} not found after offset {}",
+                    assertion_value,
+                    offset
+                ),
+            },
+            || {
+                AssertionResult::BytesContains(
+                    position.map(|position| position as u32),
+                    assertion_value.to_vec(),
+                    operator.code(),
+                    passed,
+                )
+            },
+        )?;
+
+        return if passed {
+            Ok(())
+        } else {
+            Err(LighthouseError::AssertionFailed.into())
+        };
+    }
+
+    if let BytesOperator::StartsWith = operator {
+        let searched_data = data
+            .get(offset..)
+            .ok_or_else(|| LighthouseError::oob_err(offset..data.len()))?;
+        let actual_value = &searched_data[..assertion_value.len().min(searched_data.len())];
+
+        return <[u8]>::evaluate(actual_value, assertion_value, operator, log_context);
+    }
+
+    let data_range = offset..offset + assertion_value.len();
+    let actual_value = data
+        .get(data_range.clone())
+        .ok_or_else(|| LighthouseError::oob_err(data_range))?;
+
+    <[u8]>::evaluate(actual_value, assertion_value, operator, log_context)
+}
+
+macro_rules! impl_float_comparator {
     ($(($type:ty, $payload_variant:ident)),*) => {
         $(
-            impl Evaluate<EquatableOperator> for $type {
-                fn evaluate(
-                    actual_value: &Self,
-                    assertion_value: &Self,
-                    operator: &EquatableOperator,
-                    log_level: LogLevel,
-                ) -> Result<()> {
-                    let passed = match operator {
-                        EquatableOperator::Equal => actual_value == assertion_value,
-                        EquatableOperator::NotEqual => actual_value != assertion_value,
-                    };
-
-                    match log_level {
-                        LogLevel::PlaintextMessage => {
-                            msg!(
-                                "Result: {} {} {}",
-                                actual_value,
-                                operator.format(),
-                                assertion_value
-                            );
-                        }
-                        LogLevel::EncodedMessage => {
-                            AssertionResult::$payload_variant(
-                                Some(*actual_value),
-                                Some(*assertion_value),
-                                *operator as u8,
-                                passed,
-                            ).log_data()?;
+            impl Comparator<$type> for FloatOperator {
+                fn compare(&self, actual_value: &$type, assertion_value: &$type) -> bool {
+                    if actual_value.is_nan() {
+                        return matches!(self, FloatOperator::IsNaN);
+                    }
+
+                    match self {
+                        FloatOperator::Equal { tolerance: None } => actual_value == assertion_value,
+                        FloatOperator::Equal { tolerance: Some(tolerance) } => {
+                            tolerance.is_within(*actual_value as f64, *assertion_value as f64)
                         }
-                        LogLevel::EncodedNoop => {
-                            AssertionResult::$payload_variant(
-                                Some(*actual_value),
-                                Some(*assertion_value),
-                                *operator as u8,
-                                passed,
-                            ).log_noop()?;
+                        FloatOperator::NotEqual { tolerance: None } => actual_value != assertion_value,
+                        FloatOperator::NotEqual { tolerance: Some(tolerance) } => {
+                            !tolerance.is_within(*actual_value as f64, *assertion_value as f64)
                         }
-                        LogLevel::Silent => {}
+                        FloatOperator::GreaterThan => actual_value > assertion_value,
+                        FloatOperator::LessThan => actual_value < assertion_value,
+                        FloatOperator::GreaterThanOrEqual => actual_value >= assertion_value,
+                        FloatOperator::LessThanOrEqual => actual_value <= assertion_value,
+                        FloatOperator::IsNaN => false,
+                        FloatOperator::IsNotNaN => true,
                     }
+                }
+            }
 
-                    if passed {
-                        Ok(())
-                    } else {
-                        Err(LighthouseError::AssertionFailed.into())
-                    }
+            impl AssertionValue for $type {
+                fn log_plaintext(actual_value: &Self, operator: &str, assertion_value: &Self) {
+                    msg!("Result: {} {} {}", actual_value, operator, assertion_value);
+                }
+
+                fn to_assertion_result(
+                    actual_value: &Self,
+                    assertion_value: &Self,
+                    operator: u8,
+                    passed: bool,
+                ) -> AssertionResult {
+                    // Borsh refuses to serialize NaN
+                    let encode = |value: &$type| (!value.is_nan()).then_some(*value);
+
+                    AssertionResult::$payload_variant(
+                        encode(actual_value),
+                        encode(assertion_value),
+                        operator,
+                        passed,
+                    )
                 }
             }
         )*
     };
 }
-impl_evaluate!(
-    (u8, U8),
-    (u16, U16),
-    (u32, U32),
-    (u64, U64),
-    (u128, U128),
-    (i8, I8),
-    (i16, I16),
-    (i32, I32),
-    (i64, I64),
-    (i128, I128),
-    (bool, Bool)
-);
 
-#[macro_export]
-macro_rules! impl_evaluate_for_option_type {
+impl_float_comparator!((f32, F32), (f64, F64));
+
+macro_rules! impl_assertion_value {
     ($(($type:ty, $payload_variant:ident)),*) => {
         $(
-            impl Evaluate<EquatableOperator> for Option<$type> {
-                fn evaluate(
+            impl AssertionValue for $type {
+                fn log_plaintext(actual_value: &Self, operator: &str, assertion_value: &Self) {
+                    msg!("Result: {} {} {}", actual_value, operator, assertion_value);
+                }
+
+                fn to_assertion_result(
                     actual_value: &Self,
                     assertion_value: &Self,
-                    operator: &EquatableOperator,
-                    log_level: LogLevel,
-                ) -> Result<()> {
-                    let passed = match operator {
-                        EquatableOperator::Equal => actual_value == assertion_value,
-                        EquatableOperator::NotEqual => actual_value != assertion_value,
-                    };
-
-                    match log_level {
-                        LogLevel::PlaintextMessage => {
-                            msg!(
-                                "Result: {:?} {} {:?}",
-                                actual_value,
-                                operator.format(),
-                                assertion_value
-                            );
-                        }
-                        LogLevel::EncodedMessage => {
-                            AssertionResult::$payload_variant(
-                                *actual_value,
-                                *assertion_value,
-                                *operator as u8,
-                                passed,
-                            ).log_data()?;
-                        }
-                        LogLevel::EncodedNoop => {
-                            AssertionResult::$payload_variant(
-                                *actual_value,
-                                *assertion_value,
-                                *operator as u8,
-                                passed,
-                            ).log_noop()?;
-                        }
-                        LogLevel::Silent => {}
-                    }
+                    operator: u8,
+                    passed: bool,
+                ) -> AssertionResult {
+                    AssertionResult::$payload_variant(
+                        Some(*actual_value),
+                        Some(*assertion_value),
+                        operator,
+                        passed,
+                    )
+                }
+            }
 
-                    if passed {
-                        Ok(())
-                    } else {
-                        Err(LighthouseError::AssertionFailed.into())
-                    }
+            impl AssertionValue for Option<$type> {
+                fn log_plaintext(actual_value: &Self, operator: &str, assertion_value: &Self) {
+                    msg!("Result: {:?} {} {:?}", actual_value, operator, assertion_value);
+                }
+
+                fn to_assertion_result(
+                    actual_value: &Self,
+                    assertion_value: &Self,
+                    operator: u8,
+                    passed: bool,
+                ) -> AssertionResult {
+                    AssertionResult::$payload_variant(
+                        *actual_value,
+                        *assertion_value,
+                        operator,
+                        passed,
+                    )
                 }
             }
         )*
     };
 }
 
-impl_evaluate_for_option_type!(
-    (i8, I8),
+impl_assertion_value!(
     (u8, U8),
-    (i16, I16),
     (u16, U16),
-    (i32, I32),
     (u32, U32),
-    (i64, I64),
     (u64, U64),
-    (i128, I128),
     (u128, U128),
+    (i8, I8),
+    (i16, I16),
+    (i32, I32),
+    (i64, I64),
+    (i128, I128),
     (bool, Bool)
 );
 
-impl Evaluate<EquatableOperator> for Pubkey {
-    fn evaluate(
+impl AssertionValue for Pubkey {
+    fn log_plaintext(actual_value: &Self, operator: &str, assertion_value: &Self) {
+        msg!("Result: ");
+        actual_value.log();
+        msg!(operator);
+        assertion_value.log();
+    }
+
+    fn to_assertion_result(
         actual_value: &Self,
         assertion_value: &Self,
-        operator: &EquatableOperator,
-        log_level: LogLevel,
-    ) -> Result<()> {
-        let passed = match operator {
-            EquatableOperator::Equal => actual_value == assertion_value,
-            EquatableOperator::NotEqual => actual_value != assertion_value,
-        };
-
-        match log_level {
-            LogLevel::PlaintextMessage => {
-                msg!("Result: ");
-                actual_value.log();
-                msg!(operator.format());
-                assertion_value.log();
-            }
-            LogLevel::EncodedMessage => {
-                let payload = AssertionResult::Pubkey(
-                    Some(*actual_value),
-                    Some(*assertion_value),
-                    *operator as u8,
-                    passed,
-                )
-                .try_to_vec()
-                .map_err(LighthouseError::serialize_err)?;
-
-                sol_log_data(&[payload.as_slice()]);
-            }
-            LogLevel::EncodedNoop => {
-                let payload = AssertionResult::Pubkey(
-                    Some(*actual_value),
-                    Some(*assertion_value),
-                    *operator as u8,
-                    passed,
-                )
-                .try_to_vec()
-                .map_err(LighthouseError::serialize_err)?;
+        operator: u8,
+        passed: bool,
+    ) -> AssertionResult {
+        AssertionResult::Pubkey(
+            Some(*actual_value),
+            Some(*assertion_value),
+            operator,
+            passed,
+        )
+    }
+}
 
-                sol_log_data(&[payload.as_slice()]);
-            }
-            LogLevel::Silent => {}
+impl AssertionValue for Option<&Pubkey> {
+    fn log_plaintext(actual_value: &Self, operator: &str, assertion_value: &Self) {
+        msg!("Result: ");
+        match actual_value {
+            Some(actual_value) => actual_value.log(),
+            None => msg!("None"),
         }
-
-        if passed {
-            Ok(())
-        } else {
-            Err(LighthouseError::AssertionFailed.into())
+        msg!(operator);
+        match assertion_value {
+            Some(assertion_value) => assertion_value.log(),
+            None => msg!("None"),
         }
     }
-}
 
-impl Evaluate<EquatableOperator> for Option<&Pubkey> {
-    fn evaluate(
+    fn to_assertion_result(
         actual_value: &Self,
         assertion_value: &Self,
-        operator: &EquatableOperator,
-        log_level: LogLevel,
-    ) -> Result<()> {
-        let passed = match operator {
-            EquatableOperator::Equal => actual_value == assertion_value,
-            EquatableOperator::NotEqual => actual_value != assertion_value,
-        };
-
-        match log_level {
-            LogLevel::PlaintextMessage => match (actual_value, assertion_value) {
-                (Some(actual_value), Some(assertion_value)) => {
-                    msg!("Result: ");
-                    actual_value.log();
-                    msg!(operator.format());
-                    assertion_value.log();
-                }
-                (None, Some(assertion_value)) => {
-                    msg!("Result: ");
-                    msg!("None");
-                    msg!(operator.format());
-                    assertion_value.log();
-                }
-                (Some(actual_value), None) => {
-                    msg!("Result: ");
-                    actual_value.log();
-                    msg!(operator.format());
-                    msg!("None");
-                }
-                (None, None) => {
-                    msg!("Result: None {} None", operator.format());
-                }
-            },
-            LogLevel::EncodedMessage => {
-                let payload = AssertionResult::Pubkey(
-                    actual_value.copied(),
-                    assertion_value.copied(),
-                    *operator as u8,
-                    passed,
-                )
-                .try_to_vec()
-                .map_err(LighthouseError::serialize_err)?;
-
-                sol_log_data(&[payload.as_slice()]);
-            }
-            LogLevel::EncodedNoop => {
-                let payload = AssertionResult::Pubkey(
-                    actual_value.copied(),
-                    assertion_value.copied(),
-                    *operator as u8,
-                    passed,
-                )
-                .try_to_vec()
-                .map_err(LighthouseError::serialize_err)?;
-
-                sol_log_data(&[payload.as_slice()]);
-            }
-            LogLevel::Silent => {}
-        }
-
-        if passed {
-            Ok(())
-        } else {
-            Err(LighthouseError::AssertionFailed.into())
-        }
+        operator: u8,
+        passed: bool,
+    ) -> AssertionResult {
+        AssertionResult::Pubkey(
+            actual_value.copied(),
+            assertion_value.copied(),
+            operator,
+            passed,
+        )
     }
 }
 
-impl Evaluate<EquatableOperator> for [u8] {
-    fn evaluate(
+impl AssertionValue for [u8] {
+    fn log_plaintext(actual_value: &Self, operator: &str, assertion_value: &Self) {
+        msg!(
+            "Result: {:?} {} {:?}",
+            actual_value,
+            operator,
+            assertion_value
+        );
+    }
+
+    fn to_assertion_result(
         actual_value: &Self,
         assertion_value: &Self,
-        operator: &EquatableOperator,
-        log_level: LogLevel,
-    ) -> Result<()> {
-        let passed = match operator {
-            EquatableOperator::Equal => {
-                if actual_value.len() == assertion_value.len() {
-                    sol_memcmp(actual_value, assertion_value, assertion_value.len()) == 0
-                } else {
-                    false
-                }
-            }
-            EquatableOperator::NotEqual => {
-                if actual_value.len() == assertion_value.len() {
-                    sol_memcmp(actual_value, assertion_value, assertion_value.len()) != 0
-                } else {
-                    true
-                }
-            }
+        operator: u8,
+        passed: bool,
+    ) -> AssertionResult {
+        AssertionResult::Bytes(
+            actual_value.to_vec(),
+            assertion_value.to_vec(),
+            operator,
+            passed,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod evaluate {
+        use crate::{
+            test_utils::{
+                assert_failed, assert_passed, setup_syscall_stubs, take_emitted_events,
+                EmittedEvent,
+            },
+            types::assert::{
+                evaluate::find_bytes, AssertionEvent, AssertionResult, EquatableOperator, Evaluate,
+                FloatTolerance, IntegerOperator, LogLevel,
+            },
+            validation::SPL_NOOP_ID,
         };
+        use borsh::BorshDeserialize;
+        use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::EncodableKeypair};
+
+        #[test]
+        fn evaluate_generic_comparator() {
+            let log_context = LogLevel::PlaintextMessage.into();
+
+            assert_passed(u64::evaluate(
+                &0b1010,
+                &0b0010,
+                &IntegerOperator::Contains,
+                log_context,
+            ));
+            assert_failed(i8::evaluate(
+                &-1,
+                &0,
+                &IntegerOperator::GreaterThan,
+                log_context,
+            ));
+
+            assert_passed(<[u8]>::evaluate(
+                &[1, 2, 3],
+                &[1, 2],
+                &EquatableOperator::NotEqual,
+                log_context,
+            ));
+            assert_failed(<[u8]>::evaluate(
+                &[1, 2, 3],
+                &[1, 2],
+                &EquatableOperator::Equal,
+                log_context,
+            ));
+
+            let key = Keypair::new().encodable_pubkey();
+            assert_passed(Option::<&Pubkey>::evaluate(
+                &Some(&key),
+                &None,
+                &EquatableOperator::NotEqual,
+                log_context,
+            ));
+            assert_passed(Option::<u16>::evaluate(
+                &None,
+                &None,
+                &EquatableOperator::Equal,
+                log_context,
+            ));
+        }
 
-        match log_level {
-            LogLevel::PlaintextMessage => {
-                msg!(
-                    "Result: {:?} {} {:?}",
-                    actual_value,
-                    operator.format(),
-                    assertion_value
-                );
+        #[test]
+        fn encoded_noop_pubkey_result_is_invoked() {
+            setup_syscall_stubs();
+            take_emitted_events();
+
+            let key = Keypair::new().encodable_pubkey();
+
+            assert_passed(Pubkey::evaluate(
+                &key,
+                &key,
+                &EquatableOperator::Equal,
+                LogLevel::EncodedNoop.into(),
+            ));
+            assert_passed(Option::<&Pubkey>::evaluate(
+                &Some(&key),
+                &None,
+                &EquatableOperator::NotEqual,
+                LogLevel::EncodedNoop.into(),
+            ));
+
+            let events = take_emitted_events();
+            assert_eq!(events.len(), 2);
+            for event in events {
+                let EmittedEvent::Invoke(program_id, data) = event else {
+                    panic!("Expected a noop invocation, found {:?}", event);
+                };
+                assert_eq!(program_id, SPL_NOOP_ID);
+
+                let event = AssertionEvent::try_from_slice(&data).unwrap();
+                assert!(matches!(
+                    event.result,
+                    AssertionResult::Pubkey(Some(_), _, _, true)
+                ));
             }
-            LogLevel::EncodedMessage => {
-                AssertionResult::Bytes(
-                    actual_value.to_vec(),
-                    assertion_value.to_vec(),
-                    *operator as u8,
-                    passed,
-                )
-                .log_data()?;
-            }
-            LogLevel::EncodedNoop => {
-                AssertionResult::Bytes(
-                    actual_value.to_vec(),
-                    assertion_value.to_vec(),
-                    *operator as u8,
-                    passed,
-                )
-                .log_noop()?;
+
+            // Encoded messages are still logged as data
+            assert_passed(Pubkey::evaluate(
+                &key,
+                &key,
+                &EquatableOperator::Equal,
+                LogLevel::EncodedMessage.into(),
+            ));
+            assert!(matches!(
+                take_emitted_events().as_slice(),
+                [EmittedEvent::LogData(_)]
+            ));
+        }
+
+        #[test]
+        fn float_tolerance_infinity() {
+            for tolerance in [FloatTolerance::Absolute(0.1), FloatTolerance::Relative(0.1)] {
+                assert!(tolerance.is_within(f64::INFINITY, f64::INFINITY));
+                assert!(tolerance.is_within(f64::NEG_INFINITY, f64::NEG_INFINITY));
+                assert!(!tolerance.is_within(f64::INFINITY, f64::NEG_INFINITY));
+                assert!(!tolerance.is_within(f64::INFINITY, f64::MAX));
+                assert!(!tolerance.is_within(f64::NAN, f64::NAN));
+                assert!(tolerance.is_within(1.0, 1.05));
             }
-            LogLevel::Silent => {}
         }
 
-        if passed {
-            Ok(())
-        } else {
-            Err(LighthouseError::AssertionFailed.into())
+        #[test]
+        fn find_bytes_position() {
+            let data = [0xAA, 0x01, 0x02, 0x01, 0x02];
+
+            assert_eq!(find_bytes(&data, &[0x01, 0x02]), Some(1));
+            assert_eq!(find_bytes(&data[2..], &[0x01, 0x02]), Some(1));
+            assert_eq!(find_bytes(&data, &[0x02, 0xAA]), None);
+            assert_eq!(find_bytes(&data, &[]), Some(0));
+            assert_eq!(find_bytes(&[], &[0x01]), None);
         }
     }
 }
@@ -0,0 +1,22 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Default, Copy, Clone, PartialEq)]
+pub enum EvaluationMode {
+    // Stop at the first failing assertion
+    #[default]
+    Strict,
+    // Evaluate every assertion and only fail once more than max_failures assertions failed, never fails if None
+    Soft { max_failures: Option<u16> },
+}
+
+impl EvaluationMode {
+    pub fn exceeds_max_failures(&self, failures: usize) -> bool {
+        match self {
+            EvaluationMode::Strict => failures > 0,
+            EvaluationMode::Soft { max_failures } => match max_failures {
+                Some(max_failures) => failures > *max_failures as usize,
+                None => false,
+            },
+        }
+    }
+}
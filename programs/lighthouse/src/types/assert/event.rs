@@ -0,0 +1,232 @@
+use super::{AssertionResult, LogContext};
+use crate::{error::LighthouseError, validation::SPL_NOOP_ID, Result};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    instruction::Instruction, log::sol_log_data, program::invoke, pubkey::Pubkey,
+};
+
+pub const ASSERTION_EVENT_DISCRIMINATOR: [u8; 8] = *b"LHASSERT";
+pub const ASSERTION_EVENT_VERSION: u8 = 1;
+
+///
+/// Versioned envelope around an assertion result, emitted when the log level is encoded.
+///
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub struct AssertionEvent {
+    pub discriminator: [u8; 8],
+    pub version: u8,
+    pub instruction: u8,
+    pub assertion_index: u16,
+    pub target_account: Pubkey,
+    pub field: u16,
+    pub result: AssertionResult,
+}
+
+impl AssertionEvent {
+    pub fn new(log_context: &LogContext, result: AssertionResult) -> Self {
+        Self {
+            discriminator: ASSERTION_EVENT_DISCRIMINATOR,
+            version: ASSERTION_EVENT_VERSION,
+            instruction: log_context.instruction,
+            assertion_index: log_context.assertion_index,
+            target_account: log_context.target_account,
+            field: log_context.field,
+            result,
+        }
+    }
+
+    pub fn log_data(&self) -> Result<()> {
+        let data = self.try_to_vec().map_err(LighthouseError::serialize_err)?;
+
+        sol_log_data(&[&data]);
+
+        Ok(())
+    }
+
+    pub fn log_noop(&self) -> Result<()> {
+        let data = self.try_to_vec().map_err(LighthouseError::serialize_err)?;
+
+        invoke(
+            &Instruction {
+                program_id: SPL_NOOP_ID,
+                accounts: vec![],
+                data,
+            },
+            &[],
+        )
+    }
+}
+
+///
+/// Borsh variant index of an assertion enum, only the first byte of the encoding is written.
+///
+pub fn assertion_variant_index<T: BorshSerialize>(assertion: &T) -> u8 {
+    let mut variant_index = [0u8; 1];
+    // Serialization stops with an error once the single byte buffer is full.
+    let _ = assertion.serialize(&mut variant_index.as_mut_slice());
+
+    variant_index[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{
+        assert::{
+            assertion_variant_index, AccountDeltaAssertion, AccountInfoAssertion,
+            AccountSnapshotAssertion, AssertionEvent, AssertionResult,
+            BubblegumTreeConfigAssertion, ConcurrentMerkleTreeAssertion, EquatableOperator,
+            IntegerOperator, LogContext, LogLevel, MemoryHeaderAssertion, MerkleTreeAssertion,
+            MintAccountAssertion, SetOperator, StakeAccountAssertion,
+            StakeAccountSnapshotAssertion, StakePoolAccountAssertion, SysvarClockAssertion,
+            TokenAccountAssertion, UpgradeableLoaderStateAssertion, UpgradeableProgramAssertion,
+            ASSERTION_EVENT_DISCRIMINATOR, ASSERTION_EVENT_VERSION,
+        },
+        write::DataHashType,
+    };
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::EncodableKeypair};
+
+    fn assert_variant_index<T: BorshSerialize>(assertion: T, expected_index: u8) {
+        let data = assertion.try_to_vec().unwrap();
+
+        assert_eq!(data[0], expected_index);
+        assert_eq!(assertion_variant_index(&assertion), expected_index);
+    }
+
+    #[test]
+    fn assertion_event_roundtrip() {
+        let target_account = Keypair::new().encodable_pubkey();
+        let log_context = LogContext::new(LogLevel::EncodedMessage, 4)
+            .with_target_account(&target_account)
+            .with_assertion_index(2)
+            .unwrap()
+            .with_field(assertion_variant_index(&AccountInfoAssertion::DataLength {
+                value: 0,
+                operator: IntegerOperator::Equal,
+            }) as u16);
+
+        let event = AssertionEvent::new(
+            &log_context,
+            AssertionResult::U64(Some(1), Some(2), IntegerOperator::Equal as u8, false),
+        );
+        let data = event.try_to_vec().unwrap();
+
+        assert_eq!(data[..8], ASSERTION_EVENT_DISCRIMINATOR);
+
+        let decoded = AssertionEvent::try_from_slice(&data).unwrap();
+        assert_eq!(decoded.version, ASSERTION_EVENT_VERSION);
+        assert_eq!(decoded.instruction, 4);
+        assert_eq!(decoded.assertion_index, 2);
+        assert_eq!(decoded.target_account, target_account);
+        assert_eq!(decoded.field, 1);
+        assert!(matches!(
+            decoded.result,
+            AssertionResult::U64(Some(1), Some(2), 0, false)
+        ));
+    }
+
+    #[test]
+    fn assertion_variant_index_matches_borsh_encoding() {
+        assert_variant_index(
+            AccountDeltaAssertion::DataHash {
+                a_offset: 0,
+                hash_type: DataHashType::Sha256,
+                start: None,
+                length: None,
+                operator: EquatableOperator::Equal,
+            },
+            2,
+        );
+        assert_variant_index(
+            AccountInfoAssertion::OwnerIn {
+                values: vec![Pubkey::new_unique()],
+                operator: SetOperator::In,
+            },
+            10,
+        );
+        assert_variant_index(
+            AccountSnapshotAssertion::StakeAccount {
+                memory_offset: 0,
+                assertion: StakeAccountSnapshotAssertion::State {
+                    operator: EquatableOperator::Equal,
+                },
+            },
+            2,
+        );
+        assert_variant_index(
+            BubblegumTreeConfigAssertion::IsDecompressible {
+                value: 1,
+                operator: EquatableOperator::Equal,
+            },
+            5,
+        );
+        assert_variant_index(
+            SysvarClockAssertion::UnixTimestamp {
+                value: 0,
+                operator: IntegerOperator::Equal,
+            },
+            4,
+        );
+        assert_variant_index(
+            ConcurrentMerkleTreeAssertion::IsFull {
+                value: false,
+                operator: EquatableOperator::Equal,
+            },
+            7,
+        );
+        assert_variant_index(
+            MemoryHeaderAssertion::IsLocked {
+                value: true,
+                operator: EquatableOperator::Equal,
+            },
+            4,
+        );
+        assert_variant_index(
+            MerkleTreeAssertion::VerifyBubblegumLeaf {
+                owner: Pubkey::new_unique(),
+                delegate: Pubkey::new_unique(),
+                nonce: 0,
+                data_hash: [0; 32],
+                creator_hash: [0; 32],
+            },
+            1,
+        );
+        assert_variant_index(
+            MintAccountAssertion::SupplyIn {
+                values: vec![1, 2],
+                operator: SetOperator::NotIn,
+            },
+            8,
+        );
+        assert_variant_index(
+            StakeAccountAssertion::StakeFlags {
+                value: 0,
+                operator: IntegerOperator::Equal,
+            },
+            3,
+        );
+        assert_variant_index(
+            StakePoolAccountAssertion::SolReferralFee {
+                value: 0,
+                operator: IntegerOperator::Equal,
+            },
+            15,
+        );
+        assert_variant_index(
+            TokenAccountAssertion::DelegatedAmountIn {
+                values: vec![0],
+                operator: SetOperator::In,
+            },
+            15,
+        );
+        assert_variant_index(
+            UpgradeableLoaderStateAssertion::Program(
+                UpgradeableProgramAssertion::ProgramDataAddress {
+                    value: Pubkey::new_unique(),
+                    operator: EquatableOperator::Equal,
+                },
+            ),
+            2,
+        );
+    }
+}
@@ -1,4 +1,7 @@
+use super::AssertionResult;
+use crate::{error::LighthouseError, utils::Result};
 use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{msg, pubkey::Pubkey};
 
 #[derive(BorshDeserialize, BorshSerialize, Copy, Clone, PartialEq)]
 #[repr(u8)]
@@ -7,6 +10,11 @@ pub enum LogLevel {
     PlaintextMessage = 1,
     EncodedMessage = 2,
     EncodedNoop = 3,
+    // Plaintext or encoded message for failing assertions only
+    FailedPlaintextMessage = 4,
+    FailedEncodedMessage = 5,
+    // Single message with the passed count and first failing index at the end of Multi instructions
+    Summary = 6,
 }
 
 impl LogLevel {
@@ -18,3 +26,166 @@ impl LogLevel {
         self == &LogLevel::PlaintextMessage
     }
 }
+
+///
+/// Log level of the instruction along with what is needed to describe an assertion in an event,
+/// threaded through evaluation so encoded results can be mapped back to the rule that produced them.
+///
+#[derive(Copy, Clone)]
+pub struct LogContext {
+    pub level: LogLevel,
+    // Lighthouse instruction discriminator
+    pub instruction: u8,
+    // Index of the assertion within Multi instructions, zero otherwise
+    pub assertion_index: u16,
+    pub target_account: Pubkey,
+    // Assertion variant index, or the data offset for account data assertions
+    pub field: u16,
+}
+
+impl LogContext {
+    pub fn new(level: LogLevel, instruction: u8) -> Self {
+        Self {
+            level,
+            instruction,
+            assertion_index: 0,
+            target_account: Pubkey::default(),
+            field: 0,
+        }
+    }
+
+    pub fn with_target_account(self, target_account: &Pubkey) -> Self {
+        Self {
+            target_account: *target_account,
+            ..self
+        }
+    }
+
+    pub fn with_assertion_index(self, assertion_index: usize) -> Result<Self> {
+        let assertion_index = u16::try_from(assertion_index).map_err(|_| {
+            msg!(
+                "Assertion index {} does not fit in an event",
+                assertion_index
+            );
+            LighthouseError::InvalidInstructionData
+        })?;
+
+        Ok(Self {
+            assertion_index,
+            ..self
+        })
+    }
+
+    pub fn with_field(self, field: u16) -> Self {
+        Self { field, ..self }
+    }
+
+    pub fn is_silent(&self) -> bool {
+        self.level.is_silent()
+    }
+
+    pub fn is_plaintext_message(&self) -> bool {
+        self.level.is_plaintext_message()
+    }
+
+    pub fn is_summary(&self) -> bool {
+        self.level == LogLevel::Summary
+    }
+
+    ///
+    /// Logs a single comparison according to the log level, the message and result are only built when logged.
+    ///
+    pub fn log_result<P: FnOnce(), R: FnOnce() -> AssertionResult>(
+        &self,
+        passed: bool,
+        log_plaintext: P,
+        result: R,
+    ) -> Result<()> {
+        match self.level {
+            LogLevel::Silent | LogLevel::Summary => Ok(()),
+            LogLevel::PlaintextMessage => {
+                log_plaintext();
+                Ok(())
+            }
+            LogLevel::FailedPlaintextMessage => {
+                if !passed {
+                    log_plaintext();
+                }
+                Ok(())
+            }
+            LogLevel::EncodedMessage => result().log_data(self),
+            LogLevel::FailedEncodedMessage => {
+                if passed {
+                    Ok(())
+                } else {
+                    result().log_data(self)
+                }
+            }
+            LogLevel::EncodedNoop => result().log_noop(self),
+        }
+    }
+}
+
+impl From<LogLevel> for LogContext {
+    fn from(level: LogLevel) -> Self {
+        LogContext::new(level, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::LighthouseError,
+        test_utils::assert_is_program_error,
+        types::assert::{LogContext, LogLevel},
+    };
+
+    #[test]
+    fn log_result_failures_only() {
+        for level in [
+            LogLevel::Silent,
+            LogLevel::Summary,
+            LogLevel::FailedPlaintextMessage,
+            LogLevel::FailedEncodedMessage,
+        ] {
+            LogContext::from(level)
+                .log_result(
+                    true,
+                    || panic!("Passing assertion should not be logged"),
+                    || panic!("Passing assertion should not be encoded"),
+                )
+                .unwrap();
+        }
+
+        let mut logged = false;
+        LogContext::from(LogLevel::FailedPlaintextMessage)
+            .log_result(
+                false,
+                || logged = true,
+                || panic!("Plaintext level should not be encoded"),
+            )
+            .unwrap();
+        assert!(logged);
+    }
+
+    #[test]
+    fn assertion_index_does_not_wrap() {
+        let log_context = LogContext::from(LogLevel::EncodedMessage);
+
+        assert_eq!(
+            log_context
+                .with_assertion_index(300)
+                .unwrap()
+                .assertion_index,
+            300
+        );
+
+        assert_is_program_error(
+            log_context
+                .with_assertion_index(u16::MAX as usize + 1)
+                .err()
+                .unwrap(),
+            LighthouseError::InvalidInstructionData.into(),
+        );
+    }
+}
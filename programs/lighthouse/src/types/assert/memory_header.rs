@@ -0,0 +1,203 @@
+use super::{Assert, EquatableOperator, Evaluate, IntegerOperator, LogContext};
+use crate::{
+    error::LighthouseError,
+    types::write::MemoryHeader,
+    utils::{keys_equal, Result},
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{account_info::AccountInfo, clock::Clock, msg, sysvar::Sysvar};
+
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub enum MemoryHeaderAssertion {
+    Slot {
+        value: u64,
+        operator: IntegerOperator,
+    },
+    // Compares the slot of the last write against the current clock slot.
+    WrittenInCurrentSlot {
+        value: bool,
+        operator: EquatableOperator,
+    },
+    InstructionIndex {
+        value: u16,
+        operator: IntegerOperator,
+    },
+    WriteCount {
+        value: u32,
+        operator: IntegerOperator,
+    },
+    IsLocked {
+        value: bool,
+        operator: EquatableOperator,
+    },
+}
+
+impl Assert<&AccountInfo<'_>> for MemoryHeaderAssertion {
+    fn evaluate(&self, account: &AccountInfo<'_>, log_context: LogContext) -> Result<()> {
+        if !keys_equal(account.owner, &crate::ID) {
+            return Err(LighthouseError::AccountOwnerMismatch.into());
+        }
+
+        let data = account
+            .try_borrow_data()
+            .map_err(LighthouseError::failed_borrow_err)?;
+
+        let header = MemoryHeader::load(&data)?.ok_or_else(|| {
+            msg!("Memory account has no header");
+            LighthouseError::AccountDiscriminatorValidationFailed
+        })?;
+
+        match self {
+            MemoryHeaderAssertion::Slot { value, operator } => {
+                u64::evaluate(&header.slot, value, operator, log_context)
+            }
+            MemoryHeaderAssertion::WrittenInCurrentSlot { value, operator } => {
+                let actual_written_in_current_slot =
+                    header.write_count > 0 && header.slot == Clock::get()?.slot;
+
+                bool::evaluate(
+                    &actual_written_in_current_slot,
+                    value,
+                    operator,
+                    log_context,
+                )
+            }
+            MemoryHeaderAssertion::InstructionIndex { value, operator } => {
+                u16::evaluate(&header.instruction_index, value, operator, log_context)
+            }
+            MemoryHeaderAssertion::WriteCount { value, operator } => {
+                u32::evaluate(&header.write_count, value, operator, log_context)
+            }
+            MemoryHeaderAssertion::IsLocked { value, operator } => {
+                bool::evaluate(&header.is_locked(), value, operator, log_context)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod evaluate {
+        use crate::{
+            error::LighthouseError,
+            test_utils::{assert_failed, assert_is_program_error, assert_passed},
+            types::{
+                assert::{
+                    Assert, EquatableOperator, IntegerOperator, LogLevel, MemoryHeaderAssertion,
+                },
+                write::{MemoryHeader, MEMORY_HEADER_FLAG_LOCKED},
+            },
+        };
+        use solana_sdk::{
+            account_info::AccountInfo, signature::Keypair, signer::EncodableKeypair, system_program,
+        };
+
+        #[test]
+        fn evaluate_memory_header() {
+            let key = Keypair::new().encodable_pubkey();
+            let lamports = &mut 0;
+            let data = &mut [0u8; 64];
+            MemoryHeader {
+                slot: 420,
+                instruction_index: 2,
+                write_count: 3,
+                flags: MEMORY_HEADER_FLAG_LOCKED,
+                ..MemoryHeader::default()
+            }
+            .store(data)
+            .unwrap();
+            let account_info =
+                AccountInfo::new(&key, false, false, lamports, data, &crate::ID, false, 0);
+
+            let assertions = vec![
+                (
+                    MemoryHeaderAssertion::Slot {
+                        value: 420,
+                        operator: IntegerOperator::Equal,
+                    },
+                    true,
+                ),
+                (
+                    MemoryHeaderAssertion::Slot {
+                        value: 421,
+                        operator: IntegerOperator::GreaterThanOrEqual,
+                    },
+                    false,
+                ),
+                (
+                    MemoryHeaderAssertion::InstructionIndex {
+                        value: 2,
+                        operator: IntegerOperator::Equal,
+                    },
+                    true,
+                ),
+                (
+                    MemoryHeaderAssertion::WriteCount {
+                        value: 3,
+                        operator: IntegerOperator::Equal,
+                    },
+                    true,
+                ),
+                (
+                    MemoryHeaderAssertion::WriteCount {
+                        value: 0,
+                        operator: IntegerOperator::Equal,
+                    },
+                    false,
+                ),
+                (
+                    MemoryHeaderAssertion::IsLocked {
+                        value: true,
+                        operator: EquatableOperator::Equal,
+                    },
+                    true,
+                ),
+            ];
+
+            for (assertion, should_pass) in assertions {
+                let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
+
+                if should_pass {
+                    assert_passed(result);
+                } else {
+                    assert_failed(result);
+                }
+            }
+        }
+
+        #[test]
+        fn evaluate_invalid_memory_account() {
+            let assertion = MemoryHeaderAssertion::WriteCount {
+                value: 0,
+                operator: IntegerOperator::Equal,
+            };
+
+            // Raw memory without a header
+            let key = Keypair::new().encodable_pubkey();
+            let lamports = &mut 0;
+            let data = &mut [0u8; 64];
+            let account_info =
+                AccountInfo::new(&key, false, false, lamports, data, &crate::ID, false, 0);
+
+            let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
+            assert_is_program_error(
+                result.unwrap_err(),
+                LighthouseError::AccountDiscriminatorValidationFailed.into(),
+            );
+
+            // Header owned by another program
+            let owner = system_program::id();
+            let lamports = &mut 0;
+            let data = &mut [0u8; 64];
+            MemoryHeader::default().store(data).unwrap();
+            let account_info =
+                AccountInfo::new(&key, false, false, lamports, data, &owner, false, 0);
+
+            let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
+            assert_is_program_error(
+                result.unwrap_err(),
+                LighthouseError::AccountOwnerMismatch.into(),
+            );
+        }
+    }
+}
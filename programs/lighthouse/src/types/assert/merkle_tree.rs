@@ -1,13 +1,16 @@
-use super::{Assert, LogLevel};
+use super::{Assert, LogContext};
 use crate::{
+    error::LighthouseError,
     processor::AssertMerkleTreeAccountContext,
     utils::{anchor_discriminator, Result},
-    validation::CheckedAccount,
+    validation::{CheckedAccount, MPL_BUBBLEGUM_ID},
 };
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
     instruction::{AccountMeta, Instruction},
+    keccak, msg,
     program::invoke,
+    pubkey::Pubkey,
 };
 use std::fmt::Debug;
 
@@ -22,43 +25,135 @@ pub enum MerkleTreeAssertion {
         leaf_index: u32,
         leaf_hash: [u8; 32],
     },
+    VerifyBubblegumLeaf {
+        owner: Pubkey,
+        delegate: Pubkey,
+        nonce: u64,
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+    },
+}
+
+// mpl-bubblegum `Version::V1`
+const BUBBLEGUM_LEAF_SCHEMA_V1: u8 = 1;
+
+///
+/// Hash of an mpl-bubblegum `LeafSchema::V1` leaf, the asset id is derived from the tree and nonce.
+///
+pub fn bubblegum_leaf_hash(
+    merkle_tree: &Pubkey,
+    owner: &Pubkey,
+    delegate: &Pubkey,
+    nonce: u64,
+    data_hash: &[u8; 32],
+    creator_hash: &[u8; 32],
+) -> [u8; 32] {
+    let (asset_id, _) = Pubkey::find_program_address(
+        &[b"asset", merkle_tree.as_ref(), &nonce.to_le_bytes()],
+        &MPL_BUBBLEGUM_ID,
+    );
+
+    keccak::hashv(&[
+        &[BUBBLEGUM_LEAF_SCHEMA_V1],
+        asset_id.as_ref(),
+        owner.as_ref(),
+        delegate.as_ref(),
+        &nonce.to_le_bytes(),
+        data_hash,
+        creator_hash,
+    ])
+    .to_bytes()
+}
+
+fn verify_leaf(
+    ctx: &AssertMerkleTreeAccountContext,
+    leaf_index: u32,
+    leaf_hash: &[u8; 32],
+) -> Result<()> {
+    let mut data = vec![0; 76];
+    data[..8].copy_from_slice(&(SplAccountCompressionInstruction::VerifyLeaf as u64).to_le_bytes());
+    data[8..40].copy_from_slice(&ctx.root.key.to_bytes());
+    data[40..72].copy_from_slice(leaf_hash);
+    data[72..76].copy_from_slice(&leaf_index.to_le_bytes());
+
+    let mut accounts = vec![ctx.merkle_tree.clone()];
+    accounts.extend_from_slice(ctx.proof_path);
+
+    let ix = Instruction {
+        program_id: ctx.spl_account_compression.key(),
+        accounts: accounts
+            .iter()
+            .map(|account| AccountMeta::new_readonly(*account.key, false))
+            .collect(),
+        data,
+    };
+
+    invoke(&ix, &accounts)
 }
 
 impl<'a, 'info> Assert<&AssertMerkleTreeAccountContext<'a, 'info>> for MerkleTreeAssertion {
     fn evaluate(
         &self,
         ctx: &AssertMerkleTreeAccountContext<'a, 'info>,
-        _log_level: LogLevel,
+        _log_context: LogContext,
     ) -> Result<()> {
         match self {
             &MerkleTreeAssertion::VerifyLeaf {
                 leaf_index,
                 leaf_hash,
+            } => verify_leaf(ctx, leaf_index, &leaf_hash),
+            MerkleTreeAssertion::VerifyBubblegumLeaf {
+                owner,
+                delegate,
+                nonce,
+                data_hash,
+                creator_hash,
             } => {
-                let mut data = vec![0; 76];
-                data[..8].copy_from_slice(
-                    &(SplAccountCompressionInstruction::VerifyLeaf as u64).to_le_bytes(),
+                // Bubblegum appends every minted leaf at the index equal to its nonce.
+                let leaf_index = u32::try_from(*nonce).map_err(|_| {
+                    msg!("Bubblegum nonce {} exceeds the maximum leaf index", nonce);
+                    LighthouseError::IndexOutOfBounds
+                })?;
+
+                let leaf_hash = bubblegum_leaf_hash(
+                    ctx.merkle_tree.key,
+                    owner,
+                    delegate,
+                    *nonce,
+                    data_hash,
+                    creator_hash,
                 );
-                data[8..40].copy_from_slice(&ctx.root.key.to_bytes());
-                data[40..72].copy_from_slice(&leaf_hash);
-                data[72..76].copy_from_slice(&leaf_index.to_le_bytes());
 
-                let mut accounts = vec![ctx.merkle_tree.clone()];
-                accounts.extend_from_slice(ctx.proof_path);
+                verify_leaf(ctx, leaf_index, &leaf_hash)
+            }
+        }
+    }
+}
 
-                let ix = Instruction {
-                    program_id: ctx.spl_account_compression.key(),
-                    accounts: accounts
-                        .iter()
-                        .map(|account| AccountMeta::new_readonly(*account.key, false))
-                        .collect(),
-                    data,
-                };
+#[cfg(test)]
+mod tests {
+    use super::bubblegum_leaf_hash;
+    use solana_program::pubkey::Pubkey;
 
-                invoke(&ix, &accounts)?;
+    #[test]
+    fn bubblegum_leaf_hash_known_vector() {
+        // Expected value produced by the mpl-bubblegum 1.4.0 Rust SDK (crates.io) from the same
+        // inputs: `LeafSchema::V1 { id: utils::get_asset_id(&tree, 42), .. }.hash()`.
+        let leaf_hash = bubblegum_leaf_hash(
+            &Pubkey::new_from_array([1; 32]),
+            &Pubkey::new_from_array([2; 32]),
+            &Pubkey::new_from_array([3; 32]),
+            42,
+            &[4; 32],
+            &[5; 32],
+        );
 
-                Ok(())
-            }
-        }
+        assert_eq!(
+            leaf_hash,
+            [
+                19, 46, 57, 206, 90, 104, 241, 10, 36, 57, 88, 157, 199, 32, 71, 118, 85, 159, 224,
+                139, 204, 65, 245, 24, 208, 100, 17, 189, 171, 78, 127, 1
+            ]
+        );
     }
 }
@@ -1,12 +1,24 @@
-use super::{Assert, LogLevel};
+use super::{Assert, LogContext};
 use crate::{
     err, err_msg,
-    types::assert::evaluate::{EquatableOperator, Evaluate, IntegerOperator},
+    types::assert::evaluate::{
+        EquatableOperator, Evaluate, EvaluateSet, IntegerOperator, SetOperator,
+    },
     utils::{keys_equal, Result},
 };
 use crate::{error::LighthouseError, utils::unpack_coption_key};
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+use std::ops::Range;
+
+pub const MINT_LEN: usize = 82;
+
+// spl-token mint field offsets, shared with mint memory writes
+pub const MINT_MINT_AUTHORITY_RANGE: Range<usize> = 0..36;
+pub const MINT_SUPPLY_RANGE: Range<usize> = 36..44;
+pub const MINT_DECIMALS_RANGE: Range<usize> = 44..45;
+pub const MINT_IS_INITIALIZED_RANGE: Range<usize> = 45..46;
+pub const MINT_FREEZE_AUTHORITY_RANGE: Range<usize> = 46..82;
 
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
 pub enum MintAccountAssertion {
@@ -30,10 +42,26 @@ pub enum MintAccountAssertion {
         value: Option<Pubkey>,
         operator: EquatableOperator,
     },
+    MintAuthorityIn {
+        values: Vec<Option<Pubkey>>,
+        operator: SetOperator,
+    },
+    DecimalsIn {
+        values: Vec<u8>,
+        operator: SetOperator,
+    },
+    FreezeAuthorityIn {
+        values: Vec<Option<Pubkey>>,
+        operator: SetOperator,
+    },
+    SupplyIn {
+        values: Vec<u64>,
+        operator: SetOperator,
+    },
 }
 
 impl Assert<&AccountInfo<'_>> for MintAccountAssertion {
-    fn evaluate(&self, account: &AccountInfo<'_>, log_level: LogLevel) -> Result<()> {
+    fn evaluate(&self, account: &AccountInfo<'_>, log_context: LogContext) -> Result<()> {
         if !keys_equal(account.owner, &spl_token::ID)
             && !keys_equal(account.owner, &spl_token_2022::ID)
         {
@@ -50,15 +78,15 @@ impl Assert<&AccountInfo<'_>> for MintAccountAssertion {
                 operator,
             } => {
                 let data_slice = data
-                    .get(0..36)
-                    .ok_or_else(|| LighthouseError::oob_err(0..36))?;
+                    .get(MINT_MINT_AUTHORITY_RANGE)
+                    .ok_or_else(|| LighthouseError::oob_err(MINT_MINT_AUTHORITY_RANGE))?;
                 let mint_authority = unpack_coption_key(data_slice)?;
 
                 <Option<&Pubkey>>::evaluate(
                     &mint_authority,
                     &assertion_value.as_ref(),
                     operator,
-                    log_level,
+                    log_context,
                 )
             }
             MintAccountAssertion::Supply {
@@ -66,56 +94,100 @@ impl Assert<&AccountInfo<'_>> for MintAccountAssertion {
                 operator,
             } => {
                 let data_slice = data
-                    .get(36..44)
-                    .ok_or_else(|| LighthouseError::oob_err(36..44))?;
+                    .get(MINT_SUPPLY_RANGE)
+                    .ok_or_else(|| LighthouseError::oob_err(MINT_SUPPLY_RANGE))?;
                 let actual_supply = u64::from_le_bytes(data_slice.try_into().map_err(|e| {
                     err_msg!("Failed to deserialize supply from account data", e);
                     err!(LighthouseError::FailedToDeserialize)
                 })?);
 
-                u64::evaluate(&actual_supply, assertion_value, operator, log_level)
+                u64::evaluate(&actual_supply, assertion_value, operator, log_context)
             }
             MintAccountAssertion::Decimals {
                 value: assertion_value,
                 operator,
             } => {
                 let data_slice = data
-                    .get(44..45)
-                    .ok_or_else(|| LighthouseError::oob_err(44..45))?;
+                    .get(MINT_DECIMALS_RANGE)
+                    .ok_or_else(|| LighthouseError::oob_err(MINT_DECIMALS_RANGE))?;
                 let actual_decimals = u8::from_le_bytes(data_slice.try_into().map_err(|e| {
                     err_msg!("Failed to deserialize decimals from account data", e);
                     err!(LighthouseError::FailedToDeserialize)
                 })?);
 
-                u8::evaluate(&actual_decimals, assertion_value, operator, log_level)
+                u8::evaluate(&actual_decimals, assertion_value, operator, log_context)
             }
             MintAccountAssertion::IsInitialized {
                 value: assertion_value,
                 operator,
             } => {
                 let actual_value = data
-                    .get(45)
-                    .ok_or_else(|| LighthouseError::oob_err(45..46))?;
+                    .get(MINT_IS_INITIALIZED_RANGE.start)
+                    .ok_or_else(|| LighthouseError::oob_err(MINT_IS_INITIALIZED_RANGE))?;
                 let actual_value = *actual_value != 0;
 
-                bool::evaluate(&actual_value, assertion_value, operator, log_level)
+                bool::evaluate(&actual_value, assertion_value, operator, log_context)
             }
             MintAccountAssertion::FreezeAuthority {
                 value: assertion_value,
                 operator,
             } => {
                 let data_slice = data
-                    .get(46..82)
-                    .ok_or_else(|| LighthouseError::oob_err(46..82))?;
+                    .get(MINT_FREEZE_AUTHORITY_RANGE)
+                    .ok_or_else(|| LighthouseError::oob_err(MINT_FREEZE_AUTHORITY_RANGE))?;
                 let freeze_authority = unpack_coption_key(data_slice)?;
 
                 <Option<&Pubkey>>::evaluate(
                     &freeze_authority,
                     &assertion_value.as_ref(),
                     operator,
-                    log_level,
+                    log_context,
+                )
+            }
+            MintAccountAssertion::MintAuthorityIn { values, operator } => {
+                let data_slice = data
+                    .get(MINT_MINT_AUTHORITY_RANGE)
+                    .ok_or_else(|| LighthouseError::oob_err(MINT_MINT_AUTHORITY_RANGE))?;
+                let mint_authority = unpack_coption_key(data_slice)?;
+
+                <Option<Pubkey>>::evaluate_set(
+                    &mint_authority.copied(),
+                    values,
+                    operator,
+                    log_context,
+                )
+            }
+            MintAccountAssertion::DecimalsIn { values, operator } => {
+                let actual_decimals = data
+                    .get(MINT_DECIMALS_RANGE.start)
+                    .ok_or_else(|| LighthouseError::oob_err(MINT_DECIMALS_RANGE))?;
+
+                u8::evaluate_set(actual_decimals, values, operator, log_context)
+            }
+            MintAccountAssertion::FreezeAuthorityIn { values, operator } => {
+                let data_slice = data
+                    .get(MINT_FREEZE_AUTHORITY_RANGE)
+                    .ok_or_else(|| LighthouseError::oob_err(MINT_FREEZE_AUTHORITY_RANGE))?;
+                let freeze_authority = unpack_coption_key(data_slice)?;
+
+                <Option<Pubkey>>::evaluate_set(
+                    &freeze_authority.copied(),
+                    values,
+                    operator,
+                    log_context,
                 )
             }
+            MintAccountAssertion::SupplyIn { values, operator } => {
+                let data_slice = data
+                    .get(MINT_SUPPLY_RANGE)
+                    .ok_or_else(|| LighthouseError::oob_err(MINT_SUPPLY_RANGE))?;
+                let actual_supply = u64::from_le_bytes(data_slice.try_into().map_err(|e| {
+                    err_msg!("Failed to deserialize supply from account data", e);
+                    err!(LighthouseError::FailedToDeserialize)
+                })?);
+
+                u64::evaluate_set(&actual_supply, values, operator, log_context)
+            }
         }
     }
 }
@@ -133,7 +205,7 @@ mod tests {
         use crate::{
             test_utils::{assert_failed, assert_passed},
             types::assert::{
-                evaluate::{EquatableOperator, IntegerOperator},
+                evaluate::{EquatableOperator, IntegerOperator, SetOperator},
                 Assert, LogLevel, MintAccountAssertion,
             },
         };
@@ -179,7 +251,7 @@ mod tests {
                 value: None,
                 operator: EquatableOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_passed(result);
 
@@ -187,7 +259,7 @@ mod tests {
                 value: Some(Keypair::new().encodable_pubkey()),
                 operator: EquatableOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_failed(result);
             //
@@ -198,7 +270,7 @@ mod tests {
                 value: 69,
                 operator: IntegerOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_passed(result);
 
@@ -206,7 +278,23 @@ mod tests {
                 value: 1600,
                 operator: IntegerOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
+
+            assert_failed(result);
+
+            let result = MintAccountAssertion::SupplyIn {
+                values: vec![1600, 69],
+                operator: SetOperator::In,
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
+
+            assert_passed(result);
+
+            let result = MintAccountAssertion::SupplyIn {
+                values: vec![69],
+                operator: SetOperator::NotIn,
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_failed(result);
             //
@@ -217,7 +305,7 @@ mod tests {
                 value: 2,
                 operator: IntegerOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_passed(result);
 
@@ -225,7 +313,7 @@ mod tests {
                 value: 3,
                 operator: IntegerOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_failed(result);
             //
@@ -236,7 +324,7 @@ mod tests {
                 value: true,
                 operator: EquatableOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_passed(result);
 
@@ -244,7 +332,7 @@ mod tests {
                 value: false,
                 operator: EquatableOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_failed(result);
             //
@@ -255,7 +343,7 @@ mod tests {
                 value: None,
                 operator: EquatableOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_passed(result);
 
@@ -263,7 +351,7 @@ mod tests {
                 value: Some(Keypair::new().encodable_pubkey()),
                 operator: EquatableOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_failed(result);
         }
@@ -311,14 +399,14 @@ mod tests {
                 value: None,
                 operator: EquatableOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_failed(result);
             let result = MintAccountAssertion::MintAuthority {
                 value: Some(freeze_authority.encodable_pubkey()),
                 operator: EquatableOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_failed(result);
             //
@@ -329,14 +417,14 @@ mod tests {
                 value: None,
                 operator: EquatableOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_failed(result);
             let result = MintAccountAssertion::FreezeAuthority {
                 value: Some(mint_authority.encodable_pubkey()),
                 operator: EquatableOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_failed(result);
         }
@@ -1,33 +1,51 @@
 pub mod account_data;
 pub mod account_delta;
 pub mod account_info;
+pub mod account_snapshot;
 pub mod bubblegum_tree_config;
 pub mod clock;
+pub mod concurrent_merkle_tree;
+pub mod data_path;
+pub mod error_code;
 pub mod evaluate;
+pub mod evaluation_mode;
+pub mod event;
 pub mod known_program;
 pub mod log_level;
+pub mod memory_header;
 pub mod merkle_tree;
 pub mod mint_account;
+pub mod numeric;
 pub mod stake_account;
+pub mod stake_pool_account;
 pub mod token_account;
 pub mod upgradable_loader_state;
 
 pub use account_data::*;
 pub use account_delta::*;
 pub use account_info::*;
+pub use account_snapshot::*;
 pub use bubblegum_tree_config::*;
 pub use clock::*;
+pub use concurrent_merkle_tree::*;
+pub use data_path::*;
+pub use error_code::*;
 pub use evaluate::*;
+pub use evaluation_mode::*;
+pub use event::*;
 pub use known_program::*;
 pub use log_level::*;
+pub use memory_header::*;
 pub use merkle_tree::*;
 pub use mint_account::*;
+pub use numeric::*;
 pub use stake_account::*;
+pub use stake_pool_account::*;
 pub use token_account::*;
 pub use upgradable_loader_state::*;
 
 use crate::utils::Result;
 
 pub trait Assert<T> {
-    fn evaluate(&self, parameters: T, log_level: LogLevel) -> Result<()>;
+    fn evaluate(&self, parameters: T, log_context: LogContext) -> Result<()>;
 }
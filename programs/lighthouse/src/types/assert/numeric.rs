@@ -0,0 +1,204 @@
+use crate::{error::LighthouseError, utils::Result};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::msg;
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endianness {
+    Little,
+    Big,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegerSize {
+    Bits8,
+    Bits16,
+    Bits32,
+    Bits64,
+    Bits128,
+}
+
+impl IntegerSize {
+    pub fn byte_length(&self) -> usize {
+        match self {
+            IntegerSize::Bits8 => 1,
+            IntegerSize::Bits16 => 2,
+            IntegerSize::Bits32 => 4,
+            IntegerSize::Bits64 => 8,
+            IntegerSize::Bits128 => 16,
+        }
+    }
+}
+
+///
+/// Implied scale of a fixed-point integer, the represented value is `raw / factor`.
+///
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixedPointScale {
+    // factor = 10^decimals (e.g. token amounts)
+    Decimal(u8),
+    // factor = 2^fractional_bits (e.g. Q64.64 sqrt prices)
+    Binary(u8),
+}
+
+impl FixedPointScale {
+    pub fn factor(&self) -> Result<u128> {
+        match self {
+            FixedPointScale::Decimal(decimals) => 10u128.checked_pow(*decimals as u32),
+            FixedPointScale::Binary(fractional_bits) => 1u128.checked_shl(*fractional_bits as u32),
+        }
+        .ok_or_else(|| {
+            msg!("Fixed point scale {:?} overflows u128", self);
+            LighthouseError::ArithmeticOverflow.into()
+        })
+    }
+}
+
+///
+/// Unsigned 256-bit product of two u128 values, stored as high and low limbs so that the
+/// derived ordering compares the full value.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct U256 {
+    high: u128,
+    low: u128,
+}
+
+impl U256 {
+    pub fn widening_mul(a: u128, b: u128) -> Self {
+        const LOW_MASK: u128 = u64::MAX as u128;
+
+        let (a_high, a_low) = (a >> 64, a & LOW_MASK);
+        let (b_high, b_low) = (b >> 64, b & LOW_MASK);
+
+        let low_low = a_low * b_low;
+        let low_high = a_low * b_high;
+        let high_low = a_high * b_low;
+        let high_high = a_high * b_high;
+
+        // Sum of three values below 2^64, cannot overflow
+        let middle = (low_low >> 64) + (low_high & LOW_MASK) + (high_low & LOW_MASK);
+
+        Self {
+            high: high_high + (low_high >> 64) + (high_low >> 64) + (middle >> 64),
+            low: (low_low & LOW_MASK) | (middle << 64),
+        }
+    }
+}
+
+fn read_bytes(
+    data: &[u8],
+    offset: usize,
+    size: IntegerSize,
+    endianness: Endianness,
+) -> Result<[u8; 16]> {
+    let length = size.byte_length();
+    let data_slice = data
+        .get(offset..offset + length)
+        .ok_or_else(|| LighthouseError::oob_err(offset..offset + length))?;
+
+    // Normalize to little-endian so the value occupies the low bytes
+    let mut bytes = [0u8; 16];
+    bytes[..length].copy_from_slice(data_slice);
+    if endianness == Endianness::Big {
+        bytes[..length].reverse();
+    }
+
+    Ok(bytes)
+}
+
+pub fn read_unsigned(
+    data: &[u8],
+    offset: usize,
+    size: IntegerSize,
+    endianness: Endianness,
+) -> Result<u128> {
+    Ok(u128::from_le_bytes(read_bytes(
+        data, offset, size, endianness,
+    )?))
+}
+
+pub fn read_signed(
+    data: &[u8],
+    offset: usize,
+    size: IntegerSize,
+    endianness: Endianness,
+) -> Result<i128> {
+    let mut bytes = read_bytes(data, offset, size, endianness)?;
+
+    let length = size.byte_length();
+    if bytes[length - 1] & 0x80 != 0 {
+        bytes[length..].fill(0xff);
+    }
+
+    Ok(i128::from_le_bytes(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_signed, read_unsigned, Endianness, FixedPointScale, IntegerSize, U256};
+
+    #[test]
+    fn read_integers() {
+        let data = [0x00, 0x01, 0x02, 0xff, 0xfe];
+
+        assert_eq!(
+            read_unsigned(&data, 1, IntegerSize::Bits16, Endianness::Little).unwrap(),
+            0x0201
+        );
+        assert_eq!(
+            read_unsigned(&data, 1, IntegerSize::Bits16, Endianness::Big).unwrap(),
+            0x0102
+        );
+        assert_eq!(
+            read_signed(&data, 3, IntegerSize::Bits16, Endianness::Little).unwrap(),
+            i16::from_le_bytes([0xff, 0xfe]) as i128
+        );
+        assert_eq!(
+            read_signed(&data, 3, IntegerSize::Bits16, Endianness::Big).unwrap(),
+            i16::from_be_bytes([0xff, 0xfe]) as i128
+        );
+        assert_eq!(
+            read_signed(&data, 1, IntegerSize::Bits8, Endianness::Big).unwrap(),
+            1
+        );
+
+        assert!(read_unsigned(&data, 2, IntegerSize::Bits32, Endianness::Big).is_err());
+    }
+
+    #[test]
+    fn fixed_point_factor() {
+        assert_eq!(FixedPointScale::Decimal(6).factor().unwrap(), 1_000_000);
+        assert_eq!(FixedPointScale::Binary(64).factor().unwrap(), 1 << 64);
+        assert!(FixedPointScale::Decimal(39).factor().is_err());
+        assert!(FixedPointScale::Binary(128).factor().is_err());
+    }
+
+    #[test]
+    fn widening_mul() {
+        assert_eq!(
+            U256::widening_mul(u128::MAX, u128::MAX),
+            U256 {
+                high: u128::MAX - 1,
+                low: 1
+            }
+        );
+        assert_eq!(
+            U256::widening_mul(1 << 64, 1 << 64),
+            U256 { high: 1, low: 0 }
+        );
+        assert_eq!(
+            U256::widening_mul(123_456_789, 987_654_321),
+            U256 {
+                high: 0,
+                low: 123_456_789 * 987_654_321
+            }
+        );
+
+        assert!(U256::widening_mul(u128::MAX, 2) > U256::widening_mul(u128::MAX, 1));
+        assert!(U256::widening_mul(1 << 127, 2) > U256::widening_mul(u128::MAX, 1));
+        assert_eq!(
+            U256::widening_mul(3 << 63, 10u128.pow(20)),
+            U256::widening_mul(150 * 10u128.pow(18), 1 << 64)
+        );
+    }
+}
@@ -1,5 +1,7 @@
-use super::{Assert, LogLevel};
-use crate::types::assert::evaluate::{EquatableOperator, Evaluate, IntegerOperator};
+use super::{Assert, LogContext};
+use crate::types::assert::evaluate::{
+    EquatableOperator, Evaluate, EvaluateSet, IntegerOperator, SetOperator,
+};
 use crate::utils::Result;
 use crate::{err, err_msg, error::LighthouseError};
 use borsh::{BorshDeserialize, BorshSerialize};
@@ -33,7 +35,7 @@ pub enum StakeAccountAssertion {
 }
 
 impl<'a> Assert<&'a StakeStateV2> for StakeAccountAssertion {
-    fn evaluate(&self, stake_account: &'a StakeStateV2, log_level: LogLevel) -> Result<()> {
+    fn evaluate(&self, stake_account: &'a StakeStateV2, log_context: LogContext) -> Result<()> {
         match self {
             StakeAccountAssertion::State {
                 value: assertion_value,
@@ -47,11 +49,16 @@ impl<'a> Assert<&'a StakeStateV2> for StakeAccountAssertion {
                 } as u8;
 
                 let casted_assertion_value = *assertion_value as u8;
-                u8::evaluate(&actual_state, &casted_assertion_value, operator, log_level)
+                u8::evaluate(
+                    &actual_state,
+                    &casted_assertion_value,
+                    operator,
+                    log_context,
+                )
             }
             StakeAccountAssertion::MetaAssertion(meta_assertion) => match stake_account {
                 StakeStateV2::Initialized(meta) | StakeStateV2::Stake(meta, _, _) => {
-                    meta_assertion.evaluate(meta, log_level)
+                    meta_assertion.evaluate(meta, log_context)
                 }
                 _ => {
                     msg!("Stake account is not in a state that has meta field");
@@ -59,7 +66,7 @@ impl<'a> Assert<&'a StakeStateV2> for StakeAccountAssertion {
                 }
             },
             StakeAccountAssertion::StakeAssertion(stake_assertion) => match stake_account {
-                StakeStateV2::Stake(_, stake, _) => stake_assertion.evaluate(stake, log_level),
+                StakeStateV2::Stake(_, stake, _) => stake_assertion.evaluate(stake, log_context),
                 _ => {
                     msg!("Stake account is not in a state that has stake field");
                     Err(LighthouseError::AssertionFailed.into())
@@ -77,7 +84,7 @@ impl<'a> Assert<&'a StakeStateV2> for StakeAccountAssertion {
 
                         let actual_stake_flag = serialized_stake_flag[0];
 
-                        u8::evaluate(&actual_stake_flag, value, operator, log_level)
+                        u8::evaluate(&actual_stake_flag, value, operator, log_context)
                     }
                     _ => {
                         msg!("Stake account is not in a state that has stake field");
@@ -115,10 +122,30 @@ pub enum MetaAssertion {
         value: Pubkey,
         operator: EquatableOperator,
     },
+    AuthorizedStakerIn {
+        values: Vec<Pubkey>,
+        operator: SetOperator,
+    },
+    AuthorizedWithdrawerIn {
+        values: Vec<Pubkey>,
+        operator: SetOperator,
+    },
+    RentExemptReserveIn {
+        values: Vec<u64>,
+        operator: SetOperator,
+    },
+    LockupUnixTimestampIn {
+        values: Vec<i64>,
+        operator: SetOperator,
+    },
+    LockupEpochIn {
+        values: Vec<u64>,
+        operator: SetOperator,
+    },
 }
 
 impl Assert<&StakeMeta> for MetaAssertion {
-    fn evaluate(&self, meta: &StakeMeta, log_level: LogLevel) -> Result<()> {
+    fn evaluate(&self, meta: &StakeMeta, log_context: LogContext) -> Result<()> {
         match self {
             MetaAssertion::RentExemptReserve {
                 value: assertion_value,
@@ -127,7 +154,7 @@ impl Assert<&StakeMeta> for MetaAssertion {
                 &meta.rent_exempt_reserve,
                 assertion_value,
                 operator,
-                log_level,
+                log_context,
             ),
             MetaAssertion::AuthorizedStaker {
                 value: assertion_value,
@@ -136,7 +163,7 @@ impl Assert<&StakeMeta> for MetaAssertion {
                 &meta.authorized.staker,
                 assertion_value,
                 operator,
-                log_level,
+                log_context,
             ),
             MetaAssertion::AuthorizedWithdrawer {
                 value: assertion_value,
@@ -145,7 +172,7 @@ impl Assert<&StakeMeta> for MetaAssertion {
                 &meta.authorized.withdrawer,
                 assertion_value,
                 operator,
-                log_level,
+                log_context,
             ),
             MetaAssertion::LockupUnixTimestamp {
                 value: assertion_value,
@@ -154,16 +181,36 @@ impl Assert<&StakeMeta> for MetaAssertion {
                 &meta.lockup.unix_timestamp,
                 assertion_value,
                 operator,
-                log_level,
+                log_context,
             ),
             MetaAssertion::LockupEpoch {
                 value: assertion_value,
                 operator,
-            } => u64::evaluate(&meta.lockup.epoch, assertion_value, operator, log_level),
+            } => u64::evaluate(&meta.lockup.epoch, assertion_value, operator, log_context),
             MetaAssertion::LockupCustodian {
                 value: assertion_value,
                 operator,
-            } => Pubkey::evaluate(&meta.lockup.custodian, assertion_value, operator, log_level),
+            } => Pubkey::evaluate(
+                &meta.lockup.custodian,
+                assertion_value,
+                operator,
+                log_context,
+            ),
+            MetaAssertion::AuthorizedStakerIn { values, operator } => {
+                Pubkey::evaluate_set(&meta.authorized.staker, values, operator, log_context)
+            }
+            MetaAssertion::AuthorizedWithdrawerIn { values, operator } => {
+                Pubkey::evaluate_set(&meta.authorized.withdrawer, values, operator, log_context)
+            }
+            MetaAssertion::RentExemptReserveIn { values, operator } => {
+                u64::evaluate_set(&meta.rent_exempt_reserve, values, operator, log_context)
+            }
+            MetaAssertion::LockupUnixTimestampIn { values, operator } => {
+                i64::evaluate_set(&meta.lockup.unix_timestamp, values, operator, log_context)
+            }
+            MetaAssertion::LockupEpochIn { values, operator } => {
+                u64::evaluate_set(&meta.lockup.epoch, values, operator, log_context)
+            }
         }
     }
 }
@@ -190,10 +237,30 @@ pub enum StakeAssertion {
         value: u64,
         operator: IntegerOperator,
     },
+    DelegationVoterPubkeyIn {
+        values: Vec<Pubkey>,
+        operator: SetOperator,
+    },
+    DelegationStakeIn {
+        values: Vec<u64>,
+        operator: SetOperator,
+    },
+    DelegationActivationEpochIn {
+        values: Vec<u64>,
+        operator: SetOperator,
+    },
+    DelegationDeactivationEpochIn {
+        values: Vec<u64>,
+        operator: SetOperator,
+    },
+    CreditsObservedIn {
+        values: Vec<u64>,
+        operator: SetOperator,
+    },
 }
 
 impl Assert<&StakeInfo> for StakeAssertion {
-    fn evaluate(&self, stake: &StakeInfo, log_level: LogLevel) -> Result<()> {
+    fn evaluate(&self, stake: &StakeInfo, log_context: LogContext) -> Result<()> {
         match self {
             StakeAssertion::DelegationVoterPubkey {
                 value: assertion_value,
@@ -202,7 +269,7 @@ impl Assert<&StakeInfo> for StakeAssertion {
                 &stake.delegation.voter_pubkey,
                 assertion_value,
                 operator,
-                log_level,
+                log_context,
             ),
             StakeAssertion::DelegationStake {
                 value: assertion_value,
@@ -211,7 +278,7 @@ impl Assert<&StakeInfo> for StakeAssertion {
                 &stake.delegation.stake,
                 assertion_value,
                 operator,
-                log_level,
+                log_context,
             ),
             StakeAssertion::DelegationActivationEpoch {
                 value: assertion_value,
@@ -220,7 +287,7 @@ impl Assert<&StakeInfo> for StakeAssertion {
                 &stake.delegation.activation_epoch,
                 assertion_value,
                 operator,
-                log_level,
+                log_context,
             ),
             StakeAssertion::DelegationDeactivationEpoch {
                 value: assertion_value,
@@ -229,7 +296,7 @@ impl Assert<&StakeInfo> for StakeAssertion {
                 &stake.delegation.deactivation_epoch,
                 assertion_value,
                 operator,
-                log_level,
+                log_context,
             ),
             StakeAssertion::CreditsObserved {
                 value: assertion_value,
@@ -238,8 +305,142 @@ impl Assert<&StakeInfo> for StakeAssertion {
                 &stake.credits_observed,
                 assertion_value,
                 operator,
-                log_level,
+                log_context,
             ),
+            StakeAssertion::DelegationVoterPubkeyIn { values, operator } => Pubkey::evaluate_set(
+                &stake.delegation.voter_pubkey,
+                values,
+                operator,
+                log_context,
+            ),
+            StakeAssertion::DelegationStakeIn { values, operator } => {
+                u64::evaluate_set(&stake.delegation.stake, values, operator, log_context)
+            }
+            StakeAssertion::DelegationActivationEpochIn { values, operator } => u64::evaluate_set(
+                &stake.delegation.activation_epoch,
+                values,
+                operator,
+                log_context,
+            ),
+            StakeAssertion::DelegationDeactivationEpochIn { values, operator } => {
+                u64::evaluate_set(
+                    &stake.delegation.deactivation_epoch,
+                    values,
+                    operator,
+                    log_context,
+                )
+            }
+            StakeAssertion::CreditsObservedIn { values, operator } => {
+                u64::evaluate_set(&stake.credits_observed, values, operator, log_context)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod evaluate {
+        use crate::{
+            test_utils::{assert_failed, assert_passed},
+            types::assert::{
+                evaluate::SetOperator, Assert, LogLevel, MetaAssertion, StakeAccountAssertion,
+                StakeAssertion,
+            },
+        };
+        use solana_program::stake::{
+            stake_flags::StakeFlags,
+            state::{Delegation, Lockup, Meta, Stake, StakeStateV2},
+        };
+
+        #[test]
+        fn evaluate_stake_set_membership() {
+            let stake_account = StakeStateV2::Stake(
+                Meta {
+                    rent_exempt_reserve: 2_282_880,
+                    lockup: Lockup {
+                        unix_timestamp: -1,
+                        epoch: 300,
+                        ..Lockup::default()
+                    },
+                    ..Meta::default()
+                },
+                Stake {
+                    delegation: Delegation {
+                        stake: 5_000,
+                        activation_epoch: 10,
+                        deactivation_epoch: u64::MAX,
+                        ..Delegation::default()
+                    },
+                    credits_observed: 77,
+                },
+                StakeFlags::empty(),
+            );
+
+            let assertions = vec![
+                (
+                    StakeAccountAssertion::MetaAssertion(MetaAssertion::RentExemptReserveIn {
+                        values: vec![2_282_880],
+                        operator: SetOperator::In,
+                    }),
+                    true,
+                ),
+                (
+                    StakeAccountAssertion::MetaAssertion(MetaAssertion::LockupUnixTimestampIn {
+                        values: vec![0, -1],
+                        operator: SetOperator::In,
+                    }),
+                    true,
+                ),
+                (
+                    StakeAccountAssertion::MetaAssertion(MetaAssertion::LockupEpochIn {
+                        values: vec![300],
+                        operator: SetOperator::NotIn,
+                    }),
+                    false,
+                ),
+                (
+                    StakeAccountAssertion::StakeAssertion(StakeAssertion::DelegationStakeIn {
+                        values: vec![1_000, 2_000],
+                        operator: SetOperator::In,
+                    }),
+                    false,
+                ),
+                (
+                    StakeAccountAssertion::StakeAssertion(
+                        StakeAssertion::DelegationActivationEpochIn {
+                            values: vec![10, 11],
+                            operator: SetOperator::In,
+                        },
+                    ),
+                    true,
+                ),
+                (
+                    StakeAccountAssertion::StakeAssertion(
+                        StakeAssertion::DelegationDeactivationEpochIn {
+                            values: vec![u64::MAX],
+                            operator: SetOperator::NotIn,
+                        },
+                    ),
+                    false,
+                ),
+                (
+                    StakeAccountAssertion::StakeAssertion(StakeAssertion::CreditsObservedIn {
+                        values: vec![76, 78],
+                        operator: SetOperator::NotIn,
+                    }),
+                    true,
+                ),
+            ];
+
+            for (assertion, should_pass) in assertions {
+                let result = assertion.evaluate(&stake_account, LogLevel::PlaintextMessage.into());
+
+                if should_pass {
+                    assert_passed(result);
+                } else {
+                    assert_failed(result);
+                }
+            }
         }
     }
 }
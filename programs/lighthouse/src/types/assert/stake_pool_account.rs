@@ -0,0 +1,403 @@
+use super::{Assert, LogContext};
+use crate::types::assert::evaluate::{EquatableOperator, Evaluate, IntegerOperator};
+use crate::utils::Result;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{pubkey::Pubkey, stake::state::Lockup};
+
+/// Exchange rates are expressed as lamports per 10^9 pool tokens.
+pub const STAKE_POOL_EXCHANGE_RATE_SCALE: u128 = 1_000_000_000;
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum StakePoolAccountType {
+    Uninitialized = 0,
+    StakePool = 1,
+    ValidatorList = 2,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy)]
+pub struct StakePoolFee {
+    pub denominator: u64,
+    pub numerator: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy)]
+pub enum FutureEpochFee {
+    None,
+    One(StakePoolFee),
+    Two(StakePoolFee),
+}
+
+///
+/// Mirror of `spl_stake_pool::state::StakePool`, the program is not a dependency.
+///
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub struct StakePool {
+    pub account_type: StakePoolAccountType,
+    pub manager: Pubkey,
+    pub staker: Pubkey,
+    pub stake_deposit_authority: Pubkey,
+    pub stake_withdraw_bump_seed: u8,
+    pub validator_list: Pubkey,
+    pub reserve_stake: Pubkey,
+    pub pool_mint: Pubkey,
+    pub manager_fee_account: Pubkey,
+    pub token_program_id: Pubkey,
+    pub total_lamports: u64,
+    pub pool_token_supply: u64,
+    pub last_update_epoch: u64,
+    pub lockup: Lockup,
+    pub epoch_fee: StakePoolFee,
+    pub next_epoch_fee: FutureEpochFee,
+    pub preferred_deposit_validator_vote_address: Option<Pubkey>,
+    pub preferred_withdraw_validator_vote_address: Option<Pubkey>,
+    pub stake_deposit_fee: StakePoolFee,
+    pub stake_withdrawal_fee: StakePoolFee,
+    pub next_stake_withdrawal_fee: FutureEpochFee,
+    pub stake_referral_fee: u8,
+    pub sol_deposit_authority: Option<Pubkey>,
+    pub sol_deposit_fee: StakePoolFee,
+    pub sol_referral_fee: u8,
+    pub sol_withdraw_authority: Option<Pubkey>,
+    pub sol_withdrawal_fee: StakePoolFee,
+    pub next_sol_withdrawal_fee: FutureEpochFee,
+    pub last_epoch_pool_token_supply: u64,
+    pub last_epoch_total_lamports: u64,
+}
+
+impl StakePool {
+    ///
+    /// Lamports per pool token scaled by `STAKE_POOL_EXCHANGE_RATE_SCALE`.
+    /// An empty pool trades 1:1, matching the stake pool program's deposit math.
+    ///
+    pub fn exchange_rate(&self) -> u128 {
+        if self.total_lamports == 0 || self.pool_token_supply == 0 {
+            return STAKE_POOL_EXCHANGE_RATE_SCALE;
+        }
+
+        (self.total_lamports as u128) * STAKE_POOL_EXCHANGE_RATE_SCALE
+            / (self.pool_token_supply as u128)
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub enum StakePoolAccountAssertion {
+    Manager {
+        value: Pubkey,
+        operator: EquatableOperator,
+    },
+    Staker {
+        value: Pubkey,
+        operator: EquatableOperator,
+    },
+    PoolMint {
+        value: Pubkey,
+        operator: EquatableOperator,
+    },
+    TotalLamports {
+        value: u64,
+        operator: IntegerOperator,
+    },
+    PoolTokenSupply {
+        value: u64,
+        operator: IntegerOperator,
+    },
+    ExchangeRate {
+        value: u128,
+        operator: IntegerOperator,
+    },
+    LastUpdateEpoch {
+        value: u64,
+        operator: IntegerOperator,
+    },
+    LastEpochTotalLamports {
+        value: u64,
+        operator: IntegerOperator,
+    },
+    LastEpochPoolTokenSupply {
+        value: u64,
+        operator: IntegerOperator,
+    },
+    EpochFee(StakePoolFeeAssertion),
+    StakeDepositFee(StakePoolFeeAssertion),
+    StakeWithdrawalFee(StakePoolFeeAssertion),
+    SolDepositFee(StakePoolFeeAssertion),
+    SolWithdrawalFee(StakePoolFeeAssertion),
+    StakeReferralFee {
+        value: u8,
+        operator: IntegerOperator,
+    },
+    SolReferralFee {
+        value: u8,
+        operator: IntegerOperator,
+    },
+}
+
+impl<'a> Assert<&'a StakePool> for StakePoolAccountAssertion {
+    fn evaluate(&self, stake_pool: &'a StakePool, log_context: LogContext) -> Result<()> {
+        match self {
+            StakePoolAccountAssertion::Manager {
+                value: assertion_value,
+                operator,
+            } => Pubkey::evaluate(&stake_pool.manager, assertion_value, operator, log_context),
+            StakePoolAccountAssertion::Staker {
+                value: assertion_value,
+                operator,
+            } => Pubkey::evaluate(&stake_pool.staker, assertion_value, operator, log_context),
+            StakePoolAccountAssertion::PoolMint {
+                value: assertion_value,
+                operator,
+            } => Pubkey::evaluate(
+                &stake_pool.pool_mint,
+                assertion_value,
+                operator,
+                log_context,
+            ),
+            StakePoolAccountAssertion::TotalLamports {
+                value: assertion_value,
+                operator,
+            } => u64::evaluate(
+                &stake_pool.total_lamports,
+                assertion_value,
+                operator,
+                log_context,
+            ),
+            StakePoolAccountAssertion::PoolTokenSupply {
+                value: assertion_value,
+                operator,
+            } => u64::evaluate(
+                &stake_pool.pool_token_supply,
+                assertion_value,
+                operator,
+                log_context,
+            ),
+            StakePoolAccountAssertion::ExchangeRate {
+                value: assertion_value,
+                operator,
+            } => u128::evaluate(
+                &stake_pool.exchange_rate(),
+                assertion_value,
+                operator,
+                log_context,
+            ),
+            StakePoolAccountAssertion::LastUpdateEpoch {
+                value: assertion_value,
+                operator,
+            } => u64::evaluate(
+                &stake_pool.last_update_epoch,
+                assertion_value,
+                operator,
+                log_context,
+            ),
+            StakePoolAccountAssertion::LastEpochTotalLamports {
+                value: assertion_value,
+                operator,
+            } => u64::evaluate(
+                &stake_pool.last_epoch_total_lamports,
+                assertion_value,
+                operator,
+                log_context,
+            ),
+            StakePoolAccountAssertion::LastEpochPoolTokenSupply {
+                value: assertion_value,
+                operator,
+            } => u64::evaluate(
+                &stake_pool.last_epoch_pool_token_supply,
+                assertion_value,
+                operator,
+                log_context,
+            ),
+            StakePoolAccountAssertion::EpochFee(fee_assertion) => {
+                fee_assertion.evaluate(&stake_pool.epoch_fee, log_context)
+            }
+            StakePoolAccountAssertion::StakeDepositFee(fee_assertion) => {
+                fee_assertion.evaluate(&stake_pool.stake_deposit_fee, log_context)
+            }
+            StakePoolAccountAssertion::StakeWithdrawalFee(fee_assertion) => {
+                fee_assertion.evaluate(&stake_pool.stake_withdrawal_fee, log_context)
+            }
+            StakePoolAccountAssertion::SolDepositFee(fee_assertion) => {
+                fee_assertion.evaluate(&stake_pool.sol_deposit_fee, log_context)
+            }
+            StakePoolAccountAssertion::SolWithdrawalFee(fee_assertion) => {
+                fee_assertion.evaluate(&stake_pool.sol_withdrawal_fee, log_context)
+            }
+            StakePoolAccountAssertion::StakeReferralFee {
+                value: assertion_value,
+                operator,
+            } => u8::evaluate(
+                &stake_pool.stake_referral_fee,
+                assertion_value,
+                operator,
+                log_context,
+            ),
+            StakePoolAccountAssertion::SolReferralFee {
+                value: assertion_value,
+                operator,
+            } => u8::evaluate(
+                &stake_pool.sol_referral_fee,
+                assertion_value,
+                operator,
+                log_context,
+            ),
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub enum StakePoolFeeAssertion {
+    Numerator {
+        value: u64,
+        operator: IntegerOperator,
+    },
+    Denominator {
+        value: u64,
+        operator: IntegerOperator,
+    },
+}
+
+impl Assert<&StakePoolFee> for StakePoolFeeAssertion {
+    fn evaluate(&self, fee: &StakePoolFee, log_context: LogContext) -> Result<()> {
+        match self {
+            StakePoolFeeAssertion::Numerator {
+                value: assertion_value,
+                operator,
+            } => u64::evaluate(&fee.numerator, assertion_value, operator, log_context),
+            StakePoolFeeAssertion::Denominator {
+                value: assertion_value,
+                operator,
+            } => u64::evaluate(&fee.denominator, assertion_value, operator, log_context),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod evaluate {
+        use crate::{
+            test_utils::{assert_failed, assert_passed},
+            types::assert::{
+                Assert, FutureEpochFee, IntegerOperator, LogLevel, StakePool,
+                StakePoolAccountAssertion, StakePoolAccountType, StakePoolFee,
+                StakePoolFeeAssertion, STAKE_POOL_EXCHANGE_RATE_SCALE,
+            },
+        };
+        use borsh::{BorshDeserialize, BorshSerialize};
+        use solana_sdk::{signature::Keypair, signer::EncodableKeypair, stake::state::Lockup};
+
+        fn create_stake_pool(total_lamports: u64, pool_token_supply: u64) -> StakePool {
+            StakePool {
+                account_type: StakePoolAccountType::StakePool,
+                manager: Keypair::new().encodable_pubkey(),
+                staker: Keypair::new().encodable_pubkey(),
+                stake_deposit_authority: Keypair::new().encodable_pubkey(),
+                stake_withdraw_bump_seed: 255,
+                validator_list: Keypair::new().encodable_pubkey(),
+                reserve_stake: Keypair::new().encodable_pubkey(),
+                pool_mint: Keypair::new().encodable_pubkey(),
+                manager_fee_account: Keypair::new().encodable_pubkey(),
+                token_program_id: spl_token::id(),
+                total_lamports,
+                pool_token_supply,
+                last_update_epoch: 500,
+                lockup: Lockup::default(),
+                epoch_fee: StakePoolFee {
+                    denominator: 100,
+                    numerator: 5,
+                },
+                next_epoch_fee: FutureEpochFee::One(StakePoolFee {
+                    denominator: 100,
+                    numerator: 6,
+                }),
+                preferred_deposit_validator_vote_address: None,
+                preferred_withdraw_validator_vote_address: Some(Keypair::new().encodable_pubkey()),
+                stake_deposit_fee: StakePoolFee {
+                    denominator: 1000,
+                    numerator: 1,
+                },
+                stake_withdrawal_fee: StakePoolFee {
+                    denominator: 1000,
+                    numerator: 2,
+                },
+                next_stake_withdrawal_fee: FutureEpochFee::None,
+                stake_referral_fee: 50,
+                sol_deposit_authority: None,
+                sol_deposit_fee: StakePoolFee {
+                    denominator: 1000,
+                    numerator: 3,
+                },
+                sol_referral_fee: 25,
+                sol_withdraw_authority: None,
+                sol_withdrawal_fee: StakePoolFee {
+                    denominator: 1000,
+                    numerator: 4,
+                },
+                next_sol_withdrawal_fee: FutureEpochFee::Two(StakePoolFee {
+                    denominator: 1000,
+                    numerator: 5,
+                }),
+                last_epoch_pool_token_supply: pool_token_supply,
+                last_epoch_total_lamports: total_lamports,
+            }
+        }
+
+        #[test]
+        fn evaluate_stake_pool() {
+            let stake_pool = create_stake_pool(110_000_000_000, 100_000_000_000);
+
+            // Round trip through borsh so the variable length fields are exercised
+            let data = stake_pool.try_to_vec().unwrap();
+            let stake_pool = StakePool::deserialize(&mut data.as_slice()).unwrap();
+
+            let result = StakePoolAccountAssertion::ExchangeRate {
+                value: 1_100_000_000,
+                operator: IntegerOperator::Equal,
+            }
+            .evaluate(&stake_pool, LogLevel::PlaintextMessage.into());
+            assert_passed(result);
+
+            let result = StakePoolAccountAssertion::ExchangeRate {
+                value: 1_050_000_000,
+                operator: IntegerOperator::LessThan,
+            }
+            .evaluate(&stake_pool, LogLevel::PlaintextMessage.into());
+            assert_failed(result);
+
+            let result = StakePoolAccountAssertion::TotalLamports {
+                value: 110_000_000_000,
+                operator: IntegerOperator::Equal,
+            }
+            .evaluate(&stake_pool, LogLevel::PlaintextMessage.into());
+            assert_passed(result);
+
+            let result =
+                StakePoolAccountAssertion::SolWithdrawalFee(StakePoolFeeAssertion::Numerator {
+                    value: 4,
+                    operator: IntegerOperator::Equal,
+                })
+                .evaluate(&stake_pool, LogLevel::PlaintextMessage.into());
+            assert_passed(result);
+
+            let result = StakePoolAccountAssertion::SolReferralFee {
+                value: 25,
+                operator: IntegerOperator::NotEqual,
+            }
+            .evaluate(&stake_pool, LogLevel::PlaintextMessage.into());
+            assert_failed(result);
+
+            let result = StakePoolAccountAssertion::LastEpochPoolTokenSupply {
+                value: 100_000_000_000,
+                operator: IntegerOperator::Equal,
+            }
+            .evaluate(&stake_pool, LogLevel::PlaintextMessage.into());
+            assert_passed(result);
+        }
+
+        #[test]
+        fn exchange_rate_empty_pool() {
+            let stake_pool = create_stake_pool(0, 0);
+
+            assert_eq!(stake_pool.exchange_rate(), STAKE_POOL_EXCHANGE_RATE_SCALE);
+        }
+    }
+}
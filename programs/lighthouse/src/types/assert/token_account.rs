@@ -1,14 +1,27 @@
-use super::{Assert, Evaluate, LogLevel};
+use super::{Assert, Evaluate, EvaluateSet, LogContext};
 use crate::{
     err, err_msg,
     error::LighthouseError,
-    types::assert::evaluate::{EquatableOperator, IntegerOperator},
+    types::assert::evaluate::{EquatableOperator, IntegerOperator, SetOperator},
     utils::{unpack_coption_key, unpack_coption_u64, Result},
 };
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 use spl_associated_token_account::get_associated_token_address_with_program_id;
 use spl_token_2022::state::AccountState;
+use std::ops::Range;
+
+pub const TOKEN_ACCOUNT_LEN: usize = 165;
+
+// spl-token account field offsets, shared with token account memory writes
+pub const TOKEN_ACCOUNT_MINT_RANGE: Range<usize> = 0..32;
+pub const TOKEN_ACCOUNT_OWNER_RANGE: Range<usize> = 32..64;
+pub const TOKEN_ACCOUNT_AMOUNT_RANGE: Range<usize> = 64..72;
+pub const TOKEN_ACCOUNT_DELEGATE_RANGE: Range<usize> = 72..108;
+pub const TOKEN_ACCOUNT_STATE_RANGE: Range<usize> = 108..109;
+pub const TOKEN_ACCOUNT_IS_NATIVE_RANGE: Range<usize> = 109..121;
+pub const TOKEN_ACCOUNT_DELEGATED_AMOUNT_RANGE: Range<usize> = 121..129;
+pub const TOKEN_ACCOUNT_CLOSE_AUTHORITY_RANGE: Range<usize> = 129..165;
 
 #[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
 pub enum TokenAccountAssertion {
@@ -46,6 +59,34 @@ pub enum TokenAccountAssertion {
         operator: EquatableOperator,
     },
     TokenAccountOwnerIsDerived,
+    MintIn {
+        values: Vec<Pubkey>,
+        operator: SetOperator,
+    },
+    OwnerIn {
+        values: Vec<Pubkey>,
+        operator: SetOperator,
+    },
+    DelegateIn {
+        values: Vec<Option<Pubkey>>,
+        operator: SetOperator,
+    },
+    StateIn {
+        values: Vec<u8>,
+        operator: SetOperator,
+    },
+    CloseAuthorityIn {
+        values: Vec<Option<Pubkey>>,
+        operator: SetOperator,
+    },
+    AmountIn {
+        values: Vec<u64>,
+        operator: SetOperator,
+    },
+    DelegatedAmountIn {
+        values: Vec<u64>,
+        operator: SetOperator,
+    },
 }
 
 pub fn account_state_from_u8(value: u8) -> AccountState {
@@ -66,7 +107,7 @@ pub fn u8_from_account_state(state: AccountState) -> u8 {
 }
 
 impl Assert<&AccountInfo<'_>> for TokenAccountAssertion {
-    fn evaluate(&self, account: &AccountInfo<'_>, log_level: LogLevel) -> Result<()> {
+    fn evaluate(&self, account: &AccountInfo<'_>, log_context: LogContext) -> Result<()> {
         let data = account
             .try_borrow_data()
             .map_err(LighthouseError::failed_borrow_err)?;
@@ -77,51 +118,51 @@ impl Assert<&AccountInfo<'_>> for TokenAccountAssertion {
                 operator,
             } => {
                 let data_slice = data
-                    .get(0..32)
-                    .ok_or_else(|| LighthouseError::oob_err(0..32))?;
+                    .get(TOKEN_ACCOUNT_MINT_RANGE)
+                    .ok_or_else(|| LighthouseError::oob_err(TOKEN_ACCOUNT_MINT_RANGE))?;
                 let actual_mint = bytemuck::from_bytes::<Pubkey>(data_slice);
 
-                Pubkey::evaluate(actual_mint, assertion_value, operator, log_level)
+                Pubkey::evaluate(actual_mint, assertion_value, operator, log_context)
             }
             TokenAccountAssertion::Owner {
                 value: assertion_value,
                 operator,
             } => {
                 let data_slice = data
-                    .get(32..64)
-                    .ok_or_else(|| LighthouseError::oob_err(32..64))?;
+                    .get(TOKEN_ACCOUNT_OWNER_RANGE)
+                    .ok_or_else(|| LighthouseError::oob_err(TOKEN_ACCOUNT_OWNER_RANGE))?;
                 let actual_owner = bytemuck::from_bytes::<Pubkey>(data_slice);
 
-                Pubkey::evaluate(actual_owner, assertion_value, operator, log_level)
+                Pubkey::evaluate(actual_owner, assertion_value, operator, log_context)
             }
             TokenAccountAssertion::Amount {
                 value: assertion_value,
                 operator,
             } => {
                 let data_slice = data
-                    .get(64..72)
-                    .ok_or_else(|| LighthouseError::oob_err(64..72))?;
+                    .get(TOKEN_ACCOUNT_AMOUNT_RANGE)
+                    .ok_or_else(|| LighthouseError::oob_err(TOKEN_ACCOUNT_AMOUNT_RANGE))?;
                 let actual_amount = u64::from_le_bytes(data_slice.try_into().map_err(|e| {
                     err_msg!("Failed to deserialize amount from account data", e);
                     err!(LighthouseError::FailedToDeserialize)
                 })?);
 
-                u64::evaluate(&actual_amount, assertion_value, operator, log_level)
+                u64::evaluate(&actual_amount, assertion_value, operator, log_context)
             }
             TokenAccountAssertion::Delegate {
                 value: assertion_value,
                 operator,
             } => {
                 let data_slice = data
-                    .get(72..108)
-                    .ok_or_else(|| LighthouseError::oob_err(72..108))?;
+                    .get(TOKEN_ACCOUNT_DELEGATE_RANGE)
+                    .ok_or_else(|| LighthouseError::oob_err(TOKEN_ACCOUNT_DELEGATE_RANGE))?;
                 let delegate = unpack_coption_key(data_slice)?;
 
                 <Option<&Pubkey>>::evaluate(
                     &delegate,
                     &assertion_value.as_ref(),
                     operator,
-                    log_level,
+                    log_context,
                 )
             }
             TokenAccountAssertion::State {
@@ -129,27 +170,29 @@ impl Assert<&AccountInfo<'_>> for TokenAccountAssertion {
                 operator,
             } => {
                 let actual_state = data
-                    .get(108)
-                    .ok_or_else(|| LighthouseError::oob_err(108..109))?;
+                    .get(TOKEN_ACCOUNT_STATE_RANGE.start)
+                    .ok_or_else(|| LighthouseError::oob_err(TOKEN_ACCOUNT_STATE_RANGE))?;
 
-                u8::evaluate(actual_state, assertion_value, operator, log_level)
+                u8::evaluate(actual_state, assertion_value, operator, log_context)
             }
             TokenAccountAssertion::IsNative { value, operator } => {
                 let data_slice = data
-                    .get(109..121)
-                    .ok_or_else(|| LighthouseError::oob_err(109..121))?;
+                    .get(TOKEN_ACCOUNT_IS_NATIVE_RANGE)
+                    .ok_or_else(|| LighthouseError::oob_err(TOKEN_ACCOUNT_IS_NATIVE_RANGE))?;
 
                 let actual_is_native = unpack_coption_u64(data_slice)?;
 
-                <Option<u64>>::evaluate(&actual_is_native, value, operator, log_level)
+                <Option<u64>>::evaluate(&actual_is_native, value, operator, log_context)
             }
             TokenAccountAssertion::DelegatedAmount {
                 value: assertion_value,
                 operator,
             } => {
-                let data_slice = data
-                    .get(121..129)
-                    .ok_or_else(|| LighthouseError::oob_err(121..129))?;
+                let data_slice =
+                    data.get(TOKEN_ACCOUNT_DELEGATED_AMOUNT_RANGE)
+                        .ok_or_else(|| {
+                            LighthouseError::oob_err(TOKEN_ACCOUNT_DELEGATED_AMOUNT_RANGE)
+                        })?;
 
                 let actual_delegated_amount =
                     u64::from_le_bytes(data_slice.try_into().map_err(|e| {
@@ -161,26 +204,100 @@ impl Assert<&AccountInfo<'_>> for TokenAccountAssertion {
                     &actual_delegated_amount,
                     assertion_value,
                     operator,
-                    log_level,
+                    log_context,
                 )
             }
             TokenAccountAssertion::CloseAuthority { value, operator } => {
                 let data_slice = data
-                    .get(129..165)
-                    .ok_or_else(|| LighthouseError::oob_err(129..165))?;
+                    .get(TOKEN_ACCOUNT_CLOSE_AUTHORITY_RANGE)
+                    .ok_or_else(|| LighthouseError::oob_err(TOKEN_ACCOUNT_CLOSE_AUTHORITY_RANGE))?;
                 let close_authority = unpack_coption_key(data_slice)?;
 
-                <Option<&Pubkey>>::evaluate(&close_authority, &value.as_ref(), operator, log_level)
+                <Option<&Pubkey>>::evaluate(
+                    &close_authority,
+                    &value.as_ref(),
+                    operator,
+                    log_context,
+                )
+            }
+            TokenAccountAssertion::MintIn { values, operator } => {
+                let data_slice = data
+                    .get(TOKEN_ACCOUNT_MINT_RANGE)
+                    .ok_or_else(|| LighthouseError::oob_err(TOKEN_ACCOUNT_MINT_RANGE))?;
+                let actual_mint = bytemuck::from_bytes::<Pubkey>(data_slice);
+
+                Pubkey::evaluate_set(actual_mint, values, operator, log_context)
+            }
+            TokenAccountAssertion::OwnerIn { values, operator } => {
+                let data_slice = data
+                    .get(TOKEN_ACCOUNT_OWNER_RANGE)
+                    .ok_or_else(|| LighthouseError::oob_err(TOKEN_ACCOUNT_OWNER_RANGE))?;
+                let actual_owner = bytemuck::from_bytes::<Pubkey>(data_slice);
+
+                Pubkey::evaluate_set(actual_owner, values, operator, log_context)
+            }
+            TokenAccountAssertion::DelegateIn { values, operator } => {
+                let data_slice = data
+                    .get(TOKEN_ACCOUNT_DELEGATE_RANGE)
+                    .ok_or_else(|| LighthouseError::oob_err(TOKEN_ACCOUNT_DELEGATE_RANGE))?;
+                let delegate = unpack_coption_key(data_slice)?;
+
+                <Option<Pubkey>>::evaluate_set(&delegate.copied(), values, operator, log_context)
+            }
+            TokenAccountAssertion::StateIn { values, operator } => {
+                let actual_state = data
+                    .get(TOKEN_ACCOUNT_STATE_RANGE.start)
+                    .ok_or_else(|| LighthouseError::oob_err(TOKEN_ACCOUNT_STATE_RANGE))?;
+
+                u8::evaluate_set(actual_state, values, operator, log_context)
+            }
+            TokenAccountAssertion::CloseAuthorityIn { values, operator } => {
+                let data_slice = data
+                    .get(TOKEN_ACCOUNT_CLOSE_AUTHORITY_RANGE)
+                    .ok_or_else(|| LighthouseError::oob_err(TOKEN_ACCOUNT_CLOSE_AUTHORITY_RANGE))?;
+                let close_authority = unpack_coption_key(data_slice)?;
+
+                <Option<Pubkey>>::evaluate_set(
+                    &close_authority.copied(),
+                    values,
+                    operator,
+                    log_context,
+                )
+            }
+            TokenAccountAssertion::AmountIn { values, operator } => {
+                let data_slice = data
+                    .get(TOKEN_ACCOUNT_AMOUNT_RANGE)
+                    .ok_or_else(|| LighthouseError::oob_err(TOKEN_ACCOUNT_AMOUNT_RANGE))?;
+                let actual_amount = u64::from_le_bytes(data_slice.try_into().map_err(|e| {
+                    err_msg!("Failed to deserialize amount from account data", e);
+                    err!(LighthouseError::FailedToDeserialize)
+                })?);
+
+                u64::evaluate_set(&actual_amount, values, operator, log_context)
+            }
+            TokenAccountAssertion::DelegatedAmountIn { values, operator } => {
+                let data_slice =
+                    data.get(TOKEN_ACCOUNT_DELEGATED_AMOUNT_RANGE)
+                        .ok_or_else(|| {
+                            LighthouseError::oob_err(TOKEN_ACCOUNT_DELEGATED_AMOUNT_RANGE)
+                        })?;
+                let actual_delegated_amount =
+                    u64::from_le_bytes(data_slice.try_into().map_err(|e| {
+                        err_msg!("Failed to deserialize delegatedamount from account data", e);
+                        err!(LighthouseError::FailedToDeserialize)
+                    })?);
+
+                u64::evaluate_set(&actual_delegated_amount, values, operator, log_context)
             }
             TokenAccountAssertion::TokenAccountOwnerIsDerived => {
                 let mint_data = data
-                    .get(0..32)
-                    .ok_or_else(|| LighthouseError::oob_err(0..32))?;
+                    .get(TOKEN_ACCOUNT_MINT_RANGE)
+                    .ok_or_else(|| LighthouseError::oob_err(TOKEN_ACCOUNT_MINT_RANGE))?;
                 let mint = bytemuck::from_bytes::<Pubkey>(mint_data);
 
                 let owner_data = data
-                    .get(32..64)
-                    .ok_or_else(|| LighthouseError::oob_err(32..64))?;
+                    .get(TOKEN_ACCOUNT_OWNER_RANGE)
+                    .ok_or_else(|| LighthouseError::oob_err(TOKEN_ACCOUNT_OWNER_RANGE))?;
                 let owner = bytemuck::from_bytes::<Pubkey>(owner_data);
 
                 let expected_ata =
@@ -190,7 +307,7 @@ impl Assert<&AccountInfo<'_>> for TokenAccountAssertion {
                     account.key,
                     &expected_ata,
                     &EquatableOperator::Equal,
-                    log_level,
+                    log_context,
                 )
             }
         }
@@ -209,9 +326,10 @@ mod tests {
         use std::{cell::RefCell, rc::Rc};
 
         use crate::{
-            test_utils::{assert_failed, assert_passed},
+            error::LighthouseError,
+            test_utils::{assert_failed, assert_is_program_error, assert_passed},
             types::assert::{
-                evaluate::{EquatableOperator, IntegerOperator},
+                evaluate::{EquatableOperator, IntegerOperator, SetOperator, MAX_SET_VALUES},
                 Assert, LogLevel, TokenAccountAssertion,
             },
         };
@@ -262,7 +380,7 @@ mod tests {
                 value: 69,
                 operator: IntegerOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_passed(result);
 
@@ -270,7 +388,7 @@ mod tests {
                 value: 1600,
                 operator: IntegerOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_failed(result);
 
@@ -281,7 +399,7 @@ mod tests {
                 value: mint.encodable_pubkey(),
                 operator: EquatableOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_passed(result);
 
@@ -292,7 +410,7 @@ mod tests {
                 value: owner.encodable_pubkey(),
                 operator: EquatableOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_passed(result);
 
@@ -304,7 +422,7 @@ mod tests {
                 value: None,
                 operator: EquatableOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_passed(result);
 
@@ -312,7 +430,7 @@ mod tests {
                 value: Some(owner.encodable_pubkey()),
                 operator: EquatableOperator::NotEqual,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_passed(result);
 
@@ -324,7 +442,7 @@ mod tests {
                 value: AccountState::Initialized as u8,
                 operator: IntegerOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_passed(result);
 
@@ -332,7 +450,7 @@ mod tests {
                 value: AccountState::Frozen as u8,
                 operator: IntegerOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_failed(result);
 
@@ -340,7 +458,7 @@ mod tests {
                 value: AccountState::Uninitialized as u8,
                 operator: IntegerOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_failed(result);
 
@@ -352,7 +470,7 @@ mod tests {
                 value: Some(1),
                 operator: EquatableOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_passed(result);
 
@@ -363,7 +481,7 @@ mod tests {
                 value: 42,
                 operator: IntegerOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_passed(result);
 
@@ -375,7 +493,7 @@ mod tests {
                 value: None,
                 operator: EquatableOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_passed(result);
 
@@ -383,7 +501,7 @@ mod tests {
                 value: Some(owner.encodable_pubkey()),
                 operator: EquatableOperator::NotEqual,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_passed(result);
         }
@@ -435,7 +553,7 @@ mod tests {
                 value: None,
                 operator: EquatableOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_failed(result);
 
@@ -443,7 +561,7 @@ mod tests {
                 value: Some(delegate.encodable_pubkey()),
                 operator: EquatableOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_passed(result);
 
@@ -454,7 +572,7 @@ mod tests {
                 value: None,
                 operator: EquatableOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_failed(result);
 
@@ -462,7 +580,7 @@ mod tests {
                 value: Some(close_authority.encodable_pubkey()),
                 operator: EquatableOperator::Equal,
             }
-            .evaluate(&account_info, LogLevel::PlaintextMessage);
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
             assert_passed(result);
         }
@@ -512,7 +630,7 @@ mod tests {
 
                 // assert on TokenAccountOwnerIsDerived
                 let result = TokenAccountAssertion::TokenAccountOwnerIsDerived
-                    .evaluate(&account_info, LogLevel::PlaintextMessage);
+                    .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
                 assert_passed(result);
             }
@@ -551,7 +669,7 @@ mod tests {
 
                 // assert on TokenAccountOwnerIsDerived
                 let result = TokenAccountAssertion::TokenAccountOwnerIsDerived
-                    .evaluate(&account_info, LogLevel::PlaintextMessage);
+                    .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
                 assert_failed(result);
             }
@@ -599,7 +717,7 @@ mod tests {
                     value: Some(1),
                     operator: EquatableOperator::Equal,
                 }
-                .evaluate(&account_info, LogLevel::PlaintextMessage);
+                .evaluate(&account_info, LogLevel::PlaintextMessage.into());
 
                 assert_passed(result);
 
@@ -608,8 +726,133 @@ mod tests {
                     operator: EquatableOperator::Equal,
                 };
 
-                assert_failed(result.evaluate(&account_info, LogLevel::PlaintextMessage));
+                assert_failed(result.evaluate(&account_info, LogLevel::PlaintextMessage.into()));
+            }
+        }
+
+        #[test]
+        fn evaluate_token_account_set_membership() {
+            let mint = Keypair::new();
+            let owner = Keypair::new();
+            let delegate = Keypair::new();
+
+            let serialized_token_account: &mut [u8; Account::LEN] = &mut [0u8; Account::LEN];
+            Account::pack(
+                Account {
+                    mint: mint.encodable_pubkey(),
+                    owner: owner.encodable_pubkey(),
+                    amount: 69,
+                    delegate: COption::Some(delegate.encodable_pubkey()),
+                    state: AccountState::Frozen,
+                    is_native: COption::None,
+                    delegated_amount: 42,
+                    close_authority: COption::None,
+                },
+                serialized_token_account,
+            )
+            .unwrap();
+
+            let lamports_data: &mut u64 = &mut 0;
+            let lamports: RefCell<&mut u64> = RefCell::new(lamports_data);
+            let data: Rc<RefCell<&mut [u8]>> = Rc::new(RefCell::new(serialized_token_account));
+
+            let account_info = AccountInfo {
+                key: &Pubkey::default(),
+                is_signer: false,
+                is_writable: false,
+                owner: &spl_token::ID,
+                lamports: Rc::new(lamports),
+                rent_epoch: 0,
+                data,
+                executable: false,
+            };
+
+            let wallets = vec![
+                Keypair::new().encodable_pubkey(),
+                owner.encodable_pubkey(),
+                Keypair::new().encodable_pubkey(),
+            ];
+
+            let assertions = vec![
+                (
+                    TokenAccountAssertion::OwnerIn {
+                        values: wallets.clone(),
+                        operator: SetOperator::In,
+                    },
+                    true,
+                ),
+                (
+                    TokenAccountAssertion::OwnerIn {
+                        values: wallets,
+                        operator: SetOperator::NotIn,
+                    },
+                    false,
+                ),
+                (
+                    TokenAccountAssertion::MintIn {
+                        values: vec![owner.encodable_pubkey()],
+                        operator: SetOperator::In,
+                    },
+                    false,
+                ),
+                (
+                    TokenAccountAssertion::StateIn {
+                        values: vec![AccountState::Initialized as u8, AccountState::Frozen as u8],
+                        operator: SetOperator::In,
+                    },
+                    true,
+                ),
+                (
+                    TokenAccountAssertion::DelegateIn {
+                        values: vec![None, Some(delegate.encodable_pubkey())],
+                        operator: SetOperator::In,
+                    },
+                    true,
+                ),
+                (
+                    TokenAccountAssertion::CloseAuthorityIn {
+                        values: vec![None],
+                        operator: SetOperator::NotIn,
+                    },
+                    false,
+                ),
+                (
+                    TokenAccountAssertion::AmountIn {
+                        values: vec![0, 69],
+                        operator: SetOperator::In,
+                    },
+                    true,
+                ),
+                (
+                    TokenAccountAssertion::DelegatedAmountIn {
+                        values: vec![42],
+                        operator: SetOperator::NotIn,
+                    },
+                    false,
+                ),
+            ];
+
+            for (assertion, should_pass) in assertions {
+                let result = assertion.evaluate(&account_info, LogLevel::PlaintextMessage.into());
+
+                if should_pass {
+                    assert_passed(result);
+                } else {
+                    assert_failed(result);
+                }
             }
+
+            // Candidate lists are bounded
+            let result = TokenAccountAssertion::StateIn {
+                values: vec![0; MAX_SET_VALUES + 1],
+                operator: SetOperator::In,
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage.into());
+
+            assert_is_program_error(
+                result.unwrap_err(),
+                LighthouseError::InvalidInstructionData.into(),
+            );
         }
     }
 }
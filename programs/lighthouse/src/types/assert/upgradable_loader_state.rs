@@ -1,4 +1,4 @@
-use super::{Assert, LogLevel};
+use super::{Assert, LogContext};
 use crate::{
     error::LighthouseError,
     types::assert::evaluate::{EquatableOperator, Evaluate, IntegerOperator},
@@ -30,7 +30,7 @@ pub enum UpgradeableLoaderStateAssertion {
 }
 
 impl Assert<&UpgradeableLoaderState> for UpgradeableLoaderStateAssertion {
-    fn evaluate(&self, state: &UpgradeableLoaderState, log_level: LogLevel) -> Result<()> {
+    fn evaluate(&self, state: &UpgradeableLoaderState, log_context: LogContext) -> Result<()> {
         match &self {
             UpgradeableLoaderStateAssertion::State {
                 value: assertion_value,
@@ -48,16 +48,21 @@ impl Assert<&UpgradeableLoaderState> for UpgradeableLoaderStateAssertion {
                 } as u8;
                 let casted_assertion_value = (*assertion_value) as u8;
 
-                u8::evaluate(&actual_state, &casted_assertion_value, operator, log_level)
+                u8::evaluate(
+                    &actual_state,
+                    &casted_assertion_value,
+                    operator,
+                    log_context,
+                )
             }
             UpgradeableLoaderStateAssertion::Buffer(assertion) => {
-                assertion.evaluate(state, log_level)
+                assertion.evaluate(state, log_context)
             }
             UpgradeableLoaderStateAssertion::Program(assertion) => {
-                assertion.evaluate(state, log_level)
+                assertion.evaluate(state, log_context)
             }
             UpgradeableLoaderStateAssertion::ProgramData(assertion) => {
-                assertion.evaluate(state, log_level)
+                assertion.evaluate(state, log_context)
             }
         }
     }
@@ -75,7 +80,7 @@ impl Assert<&UpgradeableLoaderState> for UpgradableBufferAssertion {
     fn evaluate(
         &self,
         upgradable_loader_state: &UpgradeableLoaderState,
-        log_level: LogLevel,
+        log_context: LogContext,
     ) -> Result<()> {
         match &upgradable_loader_state {
             UpgradeableLoaderState::Buffer { authority_address } => match &self {
@@ -86,7 +91,7 @@ impl Assert<&UpgradeableLoaderState> for UpgradableBufferAssertion {
                     &authority_address.as_ref(),
                     &assertion_value.as_ref(),
                     operator,
-                    log_level,
+                    log_context,
                 ),
             },
             _ => {
@@ -112,7 +117,7 @@ impl Assert<&UpgradeableLoaderState> for UpgradeableProgramAssertion {
     fn evaluate(
         &self,
         upgradable_loader_state: &UpgradeableLoaderState,
-        log_level: LogLevel,
+        log_context: LogContext,
     ) -> Result<()> {
         match &upgradable_loader_state {
             UpgradeableLoaderState::Program {
@@ -121,7 +126,7 @@ impl Assert<&UpgradeableLoaderState> for UpgradeableProgramAssertion {
                 UpgradeableProgramAssertion::ProgramDataAddress {
                     value: assertion_value,
                     operator,
-                } => Pubkey::evaluate(programdata_address, assertion_value, operator, log_level),
+                } => Pubkey::evaluate(programdata_address, assertion_value, operator, log_context),
             },
             _ => {
                 msg!(
@@ -150,7 +155,7 @@ impl Assert<&UpgradeableLoaderState> for UpgradeableProgramDataAssertion {
     fn evaluate(
         &self,
         upgradable_loader_state: &UpgradeableLoaderState,
-        log_level: LogLevel,
+        log_context: LogContext,
     ) -> Result<()> {
         match &upgradable_loader_state {
             UpgradeableLoaderState::ProgramData {
@@ -164,12 +169,12 @@ impl Assert<&UpgradeableLoaderState> for UpgradeableProgramDataAssertion {
                     &upgrade_authority_address.as_ref(),
                     &assertion_value.as_ref(),
                     operator,
-                    log_level,
+                    log_context,
                 ),
                 UpgradeableProgramDataAssertion::Slot {
                     value: assertion_value,
                     operator,
-                } => u64::evaluate(slot, assertion_value, operator, log_level),
+                } => u64::evaluate(slot, assertion_value, operator, log_context),
             },
             _ => {
                 msg!(
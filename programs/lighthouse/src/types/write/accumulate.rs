@@ -0,0 +1,111 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+
+// An accumulator is stored as its little-endian value followed by a flag byte that is set by the
+// first accumulate write, so an unset accumulator is never mistaken for a tracked value of 0.
+pub const ACCUMULATOR_INITIALIZED_FLAG_LENGTH: usize = 1;
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy)]
+pub enum AccumulateOperation {
+    // Checked add, fails on overflow
+    Add,
+    // The first write to an unset accumulator stores the value as is, so Min needs no seed
+    Min,
+    Max,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy)]
+pub enum AccumulatorType {
+    U64,
+    U128,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy)]
+pub enum IntegerDataType {
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub enum AccumulateSource {
+    Lamports,
+    // Amount of an spl-token or spl-token-2022 token account
+    TokenAmount,
+    AccountData {
+        offset: u16,
+        data_type: IntegerDataType,
+    },
+    Value(u128),
+}
+
+impl AccumulateOperation {
+    pub fn apply(&self, accumulator: Option<u128>, value: u128) -> Option<u128> {
+        let accumulator = match accumulator {
+            Some(accumulator) => accumulator,
+            None => return Some(value),
+        };
+
+        match self {
+            AccumulateOperation::Add => accumulator.checked_add(value),
+            AccumulateOperation::Min => Some(accumulator.min(value)),
+            AccumulateOperation::Max => Some(accumulator.max(value)),
+        }
+    }
+}
+
+impl AccumulatorType {
+    pub fn size(&self) -> usize {
+        match self {
+            AccumulatorType::U64 => 8,
+            AccumulatorType::U128 => 16,
+        }
+    }
+
+    pub fn data_length(&self) -> usize {
+        self.size() + ACCUMULATOR_INITIALIZED_FLAG_LENGTH
+    }
+
+    pub fn max_value(&self) -> u128 {
+        match self {
+            AccumulatorType::U64 => u64::MAX as u128,
+            AccumulatorType::U128 => u128::MAX,
+        }
+    }
+}
+
+impl IntegerDataType {
+    pub fn size(&self) -> usize {
+        match self {
+            IntegerDataType::U8 => 1,
+            IntegerDataType::U16 => 2,
+            IntegerDataType::U32 => 4,
+            IntegerDataType::U64 => 8,
+            IntegerDataType::U128 => 16,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AccumulateOperation;
+
+    #[test]
+    fn apply() {
+        assert_eq!(AccumulateOperation::Add.apply(Some(40), 2), Some(42));
+        assert_eq!(AccumulateOperation::Add.apply(Some(u128::MAX), 1), None);
+        assert_eq!(AccumulateOperation::Min.apply(Some(40), 2), Some(2));
+        assert_eq!(AccumulateOperation::Min.apply(Some(0), 2), Some(0));
+        assert_eq!(AccumulateOperation::Max.apply(Some(40), 2), Some(40));
+
+        // An unset accumulator takes the first value for every operation
+        for operation in [
+            AccumulateOperation::Add,
+            AccumulateOperation::Min,
+            AccumulateOperation::Max,
+        ] {
+            assert_eq!(operation.apply(None, 2), Some(2));
+        }
+    }
+}
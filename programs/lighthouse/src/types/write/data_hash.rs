@@ -0,0 +1,17 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{hash, keccak};
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy)]
+pub enum DataHashType {
+    Keccak256,
+    Sha256,
+}
+
+impl DataHashType {
+    pub fn hash(&self, data: &[u8]) -> [u8; 32] {
+        match self {
+            DataHashType::Keccak256 => keccak::hashv(&[data]).0,
+            DataHashType::Sha256 => hash::hashv(&[data]).to_bytes(),
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use crate::{err, err_msg, error::LighthouseError, utils::Result};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::msg;
+
+pub const MEMORY_HEADER_DISCRIMINATOR: [u8; 8] = *b"LHMEMHDR";
+pub const MEMORY_HEADER_FLAG_LOCKED: u8 = 1;
+pub const MEMORY_HEADER_MAX_LOCKED_RANGES: usize = 8;
+
+///
+/// Half-open byte range `[start, end)` of memory written while the header is locked.
+///
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LockedRange {
+    pub start: u16,
+    pub end: u16,
+}
+
+impl LockedRange {
+    pub fn overlaps(&self, start: usize, end: usize) -> bool {
+        (self.start as usize) < end && start < (self.end as usize)
+    }
+}
+
+///
+/// Optional header at the start of a memory account, recording when it was last written.
+/// Memory without this discriminator is treated as a raw byte buffer.
+///
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct MemoryHeader {
+    pub discriminator: [u8; 8],
+    pub slot: u64,
+    // Top-level instruction index of the last write in the transaction.
+    pub instruction_index: u16,
+    pub write_count: u32,
+    pub flags: u8,
+    // In lock mode every write records the range it covered, and a write, zero or shrink that
+    // touches a recorded range fails until the memory is closed. Ranges are kept sorted and
+    // adjacent or overlapping ranges are merged, so bytes skipped by a write stay writable.
+    pub locked_range_count: u8,
+    pub locked_ranges: [LockedRange; 8],
+    // Slot the lock was set or last written in. Locked memory cannot be closed in this slot, so it
+    // cannot be closed, recreated and rewritten within the transaction that relies on it.
+    pub locked_slot: u64,
+}
+
+impl Default for MemoryHeader {
+    fn default() -> Self {
+        Self {
+            discriminator: MEMORY_HEADER_DISCRIMINATOR,
+            slot: 0,
+            instruction_index: 0,
+            write_count: 0,
+            flags: 0,
+            locked_range_count: 0,
+            locked_ranges: [LockedRange::default(); MEMORY_HEADER_MAX_LOCKED_RANGES],
+            locked_slot: 0,
+        }
+    }
+}
+
+impl MemoryHeader {
+    pub const SIZE: usize = 64;
+
+    pub fn is_initialized(data: &[u8]) -> bool {
+        data.get(0..8) == Some(MEMORY_HEADER_DISCRIMINATOR.as_slice())
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.flags & MEMORY_HEADER_FLAG_LOCKED != 0
+    }
+
+    pub fn locked_ranges(&self) -> &[LockedRange] {
+        let count = (self.locked_range_count as usize).min(MEMORY_HEADER_MAX_LOCKED_RANGES);
+
+        &self.locked_ranges[..count]
+    }
+
+    pub fn find_locked_range(&self, start: usize, end: usize) -> Option<&LockedRange> {
+        self.locked_ranges()
+            .iter()
+            .find(|range| range.overlaps(start, end))
+    }
+
+    ///
+    /// Records `[start, end)` as locked, merging it with any adjacent or overlapping range.
+    ///
+    pub fn lock_range(&mut self, start: u64, end: u64) -> Result<()> {
+        let (mut start, mut end) = match (u16::try_from(start), u16::try_from(end)) {
+            (Ok(start), Ok(end)) => (start, end),
+            _ => {
+                msg!(
+                    "Memory range {}..{} is beyond the lockable range",
+                    start,
+                    end
+                );
+                return Err(LighthouseError::RangeOutOfBounds.into());
+            }
+        };
+
+        let mut ranges = Vec::with_capacity(MEMORY_HEADER_MAX_LOCKED_RANGES + 1);
+        for range in self.locked_ranges() {
+            if range.start <= end && start <= range.end {
+                start = start.min(range.start);
+                end = end.max(range.end);
+            } else {
+                ranges.push(*range);
+            }
+        }
+
+        if ranges.len() == MEMORY_HEADER_MAX_LOCKED_RANGES {
+            msg!(
+                "Memory header cannot track more than {} locked ranges",
+                MEMORY_HEADER_MAX_LOCKED_RANGES
+            );
+            return Err(LighthouseError::MemoryLocked.into());
+        }
+
+        let index = ranges.partition_point(|range| range.start < start);
+        ranges.insert(index, LockedRange { start, end });
+
+        self.locked_ranges = [LockedRange::default(); MEMORY_HEADER_MAX_LOCKED_RANGES];
+        self.locked_ranges[..ranges.len()].copy_from_slice(&ranges);
+        self.locked_range_count = ranges.len() as u8;
+
+        Ok(())
+    }
+
+    pub fn load(data: &[u8]) -> Result<Option<Self>> {
+        if !Self::is_initialized(data) {
+            return Ok(None);
+        }
+
+        let data_slice = data
+            .get(0..Self::SIZE)
+            .ok_or_else(|| LighthouseError::oob_err(0..Self::SIZE))?;
+
+        Self::try_from_slice(data_slice).map(Some).map_err(|e| {
+            err_msg!("Failed to deserialize memory header", e);
+            err!(LighthouseError::FailedToDeserialize)
+        })
+    }
+
+    pub fn store(&self, data: &mut [u8]) -> Result<()> {
+        let data_slice = data
+            .get_mut(0..Self::SIZE)
+            .ok_or_else(|| LighthouseError::oob_err(0..Self::SIZE))?;
+
+        let bytes = self.try_to_vec().map_err(|e| {
+            err_msg!("Failed to serialize memory header", e);
+            err!(LighthouseError::FailedToSerialize)
+        })?;
+
+        data_slice.copy_from_slice(&bytes);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        LockedRange, MemoryHeader, MEMORY_HEADER_DISCRIMINATOR, MEMORY_HEADER_MAX_LOCKED_RANGES,
+    };
+    use crate::{error::LighthouseError, test_utils::assert_is_program_error};
+    use borsh::BorshSerialize;
+
+    #[test]
+    fn load_and_store() {
+        assert_eq!(
+            MemoryHeader::default().try_to_vec().unwrap().len(),
+            MemoryHeader::SIZE
+        );
+
+        let data = &mut [0u8; 72];
+        assert_eq!(MemoryHeader::load(data).unwrap(), None);
+
+        let header = MemoryHeader {
+            slot: 420,
+            instruction_index: 3,
+            write_count: 2,
+            ..MemoryHeader::default()
+        };
+        header.store(data).unwrap();
+
+        assert_eq!(data[0..8], MEMORY_HEADER_DISCRIMINATOR);
+        assert_eq!(MemoryHeader::load(data).unwrap(), Some(header));
+
+        // Discriminator without room for the rest of the header
+        assert!(MemoryHeader::load(&data[0..16]).is_err());
+    }
+
+    fn ranges(header: &MemoryHeader) -> Vec<(u16, u16)> {
+        header
+            .locked_ranges()
+            .iter()
+            .map(|range| (range.start, range.end))
+            .collect()
+    }
+
+    #[test]
+    fn lock_range() {
+        let mut header = MemoryHeader::default();
+
+        header.lock_range(96, 104).unwrap();
+        header.lock_range(64, 72).unwrap();
+        assert_eq!(ranges(&header), vec![(64, 72), (96, 104)]);
+
+        // Adjacent ranges merge, the gap between them stays unlocked
+        header.lock_range(72, 80).unwrap();
+        assert_eq!(ranges(&header), vec![(64, 80), (96, 104)]);
+        assert_eq!(header.find_locked_range(80, 96), None);
+        assert_eq!(
+            header.find_locked_range(76, 84),
+            Some(&LockedRange { start: 64, end: 80 })
+        );
+
+        // Filling the gap joins both neighbours
+        header.lock_range(80, 96).unwrap();
+        assert_eq!(ranges(&header), vec![(64, 104)]);
+
+        assert_is_program_error(
+            header
+                .lock_range(u16::MAX as u64, u16::MAX as u64 + 8)
+                .unwrap_err(),
+            LighthouseError::RangeOutOfBounds.into(),
+        );
+    }
+
+    #[test]
+    fn lock_range_capacity() {
+        let mut header = MemoryHeader::default();
+
+        for i in 0..MEMORY_HEADER_MAX_LOCKED_RANGES as u64 {
+            header.lock_range(64 + i * 16, 72 + i * 16).unwrap();
+        }
+
+        assert_is_program_error(
+            header.lock_range(512, 520).unwrap_err(),
+            LighthouseError::MemoryLocked.into(),
+        );
+
+        // A range that merges into an existing one still fits
+        header.lock_range(72, 80).unwrap();
+        assert_eq!(ranges(&header)[0], (64, 88));
+    }
+}
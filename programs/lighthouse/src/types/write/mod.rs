@@ -1,9 +1,19 @@
 pub mod account_info;
+pub mod accumulate;
 pub mod clock;
+pub mod data_hash;
 pub mod data_value;
+pub mod memory_header;
+pub mod sysvar;
+pub mod token;
 pub mod write_type;
 
 pub use account_info::*;
+pub use accumulate::*;
 pub use clock::*;
+pub use data_hash::*;
 pub use data_value::*;
+pub use memory_header::*;
+pub use sysvar::*;
+pub use token::*;
 pub use write_type::*;
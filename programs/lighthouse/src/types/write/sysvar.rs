@@ -0,0 +1,18 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub enum RentField {
+    LamportsPerByteYear,
+    // Written as little-endian f64 bytes
+    ExemptionThreshold,
+    BurnPercent,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub enum EpochScheduleField {
+    SlotsPerEpoch,
+    LeaderScheduleSlotOffset,
+    Warmup,
+    FirstNormalEpoch,
+    FirstNormalSlot,
+}
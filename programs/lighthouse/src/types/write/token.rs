@@ -0,0 +1,58 @@
+use crate::types::assert::{
+    MINT_DECIMALS_RANGE, MINT_FREEZE_AUTHORITY_RANGE, MINT_IS_INITIALIZED_RANGE,
+    MINT_MINT_AUTHORITY_RANGE, MINT_SUPPLY_RANGE, TOKEN_ACCOUNT_AMOUNT_RANGE,
+    TOKEN_ACCOUNT_CLOSE_AUTHORITY_RANGE, TOKEN_ACCOUNT_DELEGATED_AMOUNT_RANGE,
+    TOKEN_ACCOUNT_DELEGATE_RANGE, TOKEN_ACCOUNT_IS_NATIVE_RANGE, TOKEN_ACCOUNT_MINT_RANGE,
+    TOKEN_ACCOUNT_OWNER_RANGE, TOKEN_ACCOUNT_STATE_RANGE,
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use std::ops::Range;
+
+// Fields are copied as raw bytes, COption fields keep their 4 byte tag.
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub enum TokenAccountField {
+    Mint,
+    Owner,
+    Amount,
+    Delegate,
+    State,
+    IsNative,
+    DelegatedAmount,
+    CloseAuthority,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub enum MintAccountField {
+    MintAuthority,
+    Supply,
+    Decimals,
+    IsInitialized,
+    FreezeAuthority,
+}
+
+impl TokenAccountField {
+    pub fn range(&self) -> Range<usize> {
+        match self {
+            TokenAccountField::Mint => TOKEN_ACCOUNT_MINT_RANGE,
+            TokenAccountField::Owner => TOKEN_ACCOUNT_OWNER_RANGE,
+            TokenAccountField::Amount => TOKEN_ACCOUNT_AMOUNT_RANGE,
+            TokenAccountField::Delegate => TOKEN_ACCOUNT_DELEGATE_RANGE,
+            TokenAccountField::State => TOKEN_ACCOUNT_STATE_RANGE,
+            TokenAccountField::IsNative => TOKEN_ACCOUNT_IS_NATIVE_RANGE,
+            TokenAccountField::DelegatedAmount => TOKEN_ACCOUNT_DELEGATED_AMOUNT_RANGE,
+            TokenAccountField::CloseAuthority => TOKEN_ACCOUNT_CLOSE_AUTHORITY_RANGE,
+        }
+    }
+}
+
+impl MintAccountField {
+    pub fn range(&self) -> Range<usize> {
+        match self {
+            MintAccountField::MintAuthority => MINT_MINT_AUTHORITY_RANGE,
+            MintAccountField::Supply => MINT_SUPPLY_RANGE,
+            MintAccountField::Decimals => MINT_DECIMALS_RANGE,
+            MintAccountField::IsInitialized => MINT_IS_INITIALIZED_RANGE,
+            MintAccountField::FreezeAuthority => MINT_FREEZE_AUTHORITY_RANGE,
+        }
+    }
+}
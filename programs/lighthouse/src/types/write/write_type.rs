@@ -1,12 +1,52 @@
-use super::{AccountInfoField, ClockField, DataValue};
+use super::{
+    AccountInfoField, AccumulateOperation, AccumulateSource, AccumulatorType, ClockField,
+    DataHashType, DataValue, EpochScheduleField, MintAccountField, RentField, TokenAccountField,
+};
+use crate::types::assert::ACCOUNT_SNAPSHOT_KEY_LENGTH;
 use borsh::{BorshDeserialize, BorshSerialize};
 
 #[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
 pub enum WriteType {
-    AccountData { offset: u16, data_length: u16 },
+    AccountData {
+        offset: u16,
+        data_length: u16,
+    },
     AccountInfoField(AccountInfoField),
     DataValue(DataValue),
     Clock(ClockField),
+    // Hash of the source account data range, defaults to the whole account data
+    AccountDataHash {
+        hash_type: DataHashType,
+        start: Option<u16>,
+        length: Option<u16>,
+    },
+    Rent(RentField),
+    EpochSchedule(EpochScheduleField),
+    RemainingComputeUnits,
+    // Current top-level instruction index, the source account must be the instructions sysvar
+    InstructionIndex,
+    // Slot and hash of the most recent SlotHashes entry, the source account must be the SlotHashes sysvar
+    LatestSlotHash,
+    // Combines the source value with the little-endian accumulator already in memory
+    Accumulate {
+        operation: AccumulateOperation,
+        accumulator: AccumulatorType,
+        source: AccumulateSource,
+    },
+    TokenAccountField(TokenAccountField),
+    MintAccountField(MintAccountField),
+    // Source account key followed by the first data_length bytes of its data, read by AssertAccountSnapshot
+    AccountSnapshot {
+        data_length: u16,
+    },
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub struct MemoryWriteEntry {
+    pub write_offset: u16,
+    // Index into the source accounts passed after the memory account.
+    pub source_account_index: u8,
+    pub write_type: WriteType,
 }
 
 impl WriteType {
@@ -40,6 +80,28 @@ impl WriteType {
                 ClockField::LeaderScheduleEpoch => 8,
                 ClockField::UnixTimestamp => 8,
             },
+            WriteType::AccountDataHash { .. } => 32,
+            WriteType::Rent(field) => match field {
+                RentField::LamportsPerByteYear => 8,
+                RentField::ExemptionThreshold => 8,
+                RentField::BurnPercent => 1,
+            },
+            WriteType::EpochSchedule(field) => match field {
+                EpochScheduleField::SlotsPerEpoch => 8,
+                EpochScheduleField::LeaderScheduleSlotOffset => 8,
+                EpochScheduleField::Warmup => 1,
+                EpochScheduleField::FirstNormalEpoch => 8,
+                EpochScheduleField::FirstNormalSlot => 8,
+            },
+            WriteType::RemainingComputeUnits => 8,
+            WriteType::InstructionIndex => 2,
+            WriteType::LatestSlotHash => 40,
+            WriteType::Accumulate { accumulator, .. } => accumulator.data_length() as u64,
+            WriteType::TokenAccountField(field) => field.range().len() as u64,
+            WriteType::MintAccountField(field) => field.range().len() as u64,
+            WriteType::AccountSnapshot { data_length } => {
+                (ACCOUNT_SNAPSHOT_KEY_LENGTH + *data_length as usize) as u64
+            }
         }
     }
 }
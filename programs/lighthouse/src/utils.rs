@@ -1,20 +1,27 @@
 use std::any::type_name;
 
-use crate::error::LighthouseError;
+use crate::{
+    error::LighthouseError,
+    types::assert::{MINT_LEN, TOKEN_ACCOUNT_LEN},
+};
 use borsh::BorshDeserialize;
 use sha2_const_stable::Sha256;
 use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
+    hash::hashv,
     msg,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
     program_memory::sol_memcmp,
+    program_pack::Pack,
     pubkey::Pubkey,
     pubkey::PUBKEY_BYTES,
     rent::Rent,
     system_instruction, system_program,
 };
+use spl_token_2022::{extension::AccountType, state::Multisig};
+use std::cell::Ref;
 
 pub type Result<T> = std::result::Result<T, ProgramError>;
 
@@ -145,6 +152,71 @@ pub fn is_closed(info: &AccountInfo) -> bool {
     keys_equal(info.owner, &system_program::id()) && info.data_is_empty()
 }
 
+pub fn data_hash_range(data: &[u8], start: Option<u16>, length: Option<u16>) -> Result<&[u8]> {
+    let start = start.unwrap_or(0) as usize;
+    let length = match length {
+        Some(length) => length as usize,
+        None => data
+            .len()
+            .checked_sub(start)
+            .ok_or(LighthouseError::RangeOutOfBounds)?,
+    };
+
+    let hash_range = start..(start + length);
+    data.get(hash_range.clone()).ok_or_else(|| {
+        msg!(
+            "Failed to hash data, range {:?} was out of bounds",
+            hash_range
+        );
+        LighthouseError::RangeOutOfBounds.into()
+    })
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TokenAccountType {
+    Mint,
+    Account,
+}
+
+///
+/// Borrows the data of an spl-token or spl-token-2022 account after checking it is a mint or token account.
+///
+pub fn borrow_token_program_data<'a>(
+    account: &'a AccountInfo,
+    account_type: TokenAccountType,
+) -> Result<Ref<'a, &'a mut [u8]>> {
+    if !keys_equal(account.owner, &spl_token::ID) && !keys_equal(account.owner, &spl_token_2022::ID)
+    {
+        return Err(LighthouseError::AccountOwnerMismatch.into());
+    }
+
+    let data = account
+        .try_borrow_data()
+        .map_err(LighthouseError::failed_borrow_err)?;
+
+    // Token-2022 accounts with extensions store the account type after the base token account length,
+    // multisig accounts are the only other token program accounts longer than a token account.
+    let is_expected_type = match (account_type, data.len()) {
+        (TokenAccountType::Mint, MINT_LEN) | (TokenAccountType::Account, TOKEN_ACCOUNT_LEN) => true,
+        (_, Multisig::LEN) => false,
+        (_, len) if len > TOKEN_ACCOUNT_LEN => {
+            data[TOKEN_ACCOUNT_LEN]
+                == match account_type {
+                    TokenAccountType::Mint => AccountType::Mint,
+                    TokenAccountType::Account => AccountType::Account,
+                } as u8
+        }
+        _ => false,
+    };
+
+    if !is_expected_type {
+        msg!("Account is not a token program account of the expected type");
+        return Err(LighthouseError::AccountDiscriminatorValidationFailed.into());
+    }
+
+    Ok(data)
+}
+
 pub fn keys_equal(key_a: &Pubkey, key_b: &Pubkey) -> bool {
     sol_memcmp(key_a.as_ref(), key_b.as_ref(), PUBKEY_BYTES) == 0
 }
@@ -160,6 +232,16 @@ pub const fn anchor_discriminator(preimage: &'static str) -> u64 {
     ])
 }
 
+///
+/// Runtime counterpart of `anchor_discriminator` for account names that are not known at compile time.
+///
+pub fn anchor_account_discriminator(account_name: &str) -> [u8; 8] {
+    let hash = hashv(&[b"account:", account_name.as_bytes()]).to_bytes();
+    [
+        hash[0], hash[1], hash[2], hash[3], hash[4], hash[5], hash[6], hash[7],
+    ]
+}
+
 pub trait Key {
     fn key(&self) -> Pubkey;
 }
@@ -320,7 +320,7 @@ mod tests {
     use super::{AccountValidation, CheckedAccount};
     use crate::{
         error::LighthouseError,
-        validation::{DerivedAddress, Memory, MemorySeeds},
+        validation::{AuthorityMemorySeeds, DerivedAddress, Memory, MemorySeeds},
         Result,
     };
     use solana_sdk::{
@@ -491,4 +491,54 @@ mod tests {
             panic!("expected error");
         }
     }
+
+    #[test]
+    fn memory_seeds_namespace() {
+        let authority = Keypair::new().encodable_pubkey();
+
+        let find_key = |seeds: Vec<Vec<u8>>| {
+            Pubkey::find_program_address(
+                seeds
+                    .iter()
+                    .map(|seed| seed.as_slice())
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+                &crate::id(),
+            )
+            .0
+        };
+        let find_authority_key = |memory_id: u16, top_level_only: bool| {
+            find_key(Memory::get_seeds(AuthorityMemorySeeds {
+                authority: &authority,
+                memory_id,
+                top_level_only,
+                bump: None,
+            }))
+        };
+
+        assert_ne!(find_authority_key(0, true), find_authority_key(0, false));
+        assert_ne!(find_authority_key(1, true), find_authority_key(256, true));
+
+        // Payer memory keeps its original address, distinct from authority memory of the same signer
+        assert_eq!(
+            find_key(Memory::get_seeds(MemorySeeds {
+                payer: &authority,
+                memory_id: 1,
+                bump: None,
+            })),
+            find_key(vec![
+                b"memory".to_vec(),
+                authority.to_bytes().to_vec(),
+                vec![1]
+            ])
+        );
+        assert_ne!(
+            find_key(Memory::get_seeds(MemorySeeds {
+                payer: &authority,
+                memory_id: 1,
+                bump: None,
+            })),
+            find_authority_key(1, true)
+        );
+    }
 }
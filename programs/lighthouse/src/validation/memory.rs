@@ -29,6 +29,81 @@ impl<'a, 'info> DerivedAddress<MemorySeeds<'a>> for Memory<'a, 'info> {
     }
 }
 
+pub struct AuthorityMemorySeeds<'a> {
+    pub authority: &'a Pubkey,
+    pub memory_id: u16,
+    // Part of the namespace so memory restricted to top-level writes cannot be touched through CPI.
+    pub top_level_only: bool,
+    pub bump: Option<u8>,
+}
+
+impl<'a, 'info> DerivedAddress<AuthorityMemorySeeds<'a>> for Memory<'a, 'info> {
+    fn get_seeds(seeds: AuthorityMemorySeeds<'a>) -> Vec<Vec<u8>> {
+        let AuthorityMemorySeeds {
+            authority,
+            memory_id,
+            top_level_only,
+            bump,
+        } = seeds;
+
+        vec![
+            b"memory".to_vec(),
+            authority.to_bytes().to_vec(),
+            memory_id.to_le_bytes().to_vec(),
+            vec![top_level_only as u8],
+            bump.map_or_else(std::vec::Vec::new, |b| vec![b]),
+        ]
+    }
+}
+
+///
+/// Selects how a memory account is derived. Payer memory is the original layout of MemoryWrite
+/// and MemoryClose and only accepts top-level instructions, authority memory is namespaced by a
+/// separate signer which may be a program PDA writing through CPI.
+///
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum MemoryNamespace {
+    Payer {
+        memory_id: u8,
+    },
+    Authority {
+        memory_id: u16,
+        top_level_only: bool,
+    },
+}
+
+impl MemoryNamespace {
+    pub(crate) fn has_authority_account(&self) -> bool {
+        matches!(self, MemoryNamespace::Authority { .. })
+    }
+
+    pub(crate) fn top_level_only(&self) -> bool {
+        match self {
+            MemoryNamespace::Payer { .. } => true,
+            MemoryNamespace::Authority { top_level_only, .. } => *top_level_only,
+        }
+    }
+
+    pub(crate) fn get_seeds(&self, authority: &Pubkey, bump: u8) -> Vec<Vec<u8>> {
+        match *self {
+            MemoryNamespace::Payer { memory_id } => Memory::get_seeds(MemorySeeds {
+                payer: authority,
+                memory_id,
+                bump: Some(bump),
+            }),
+            MemoryNamespace::Authority {
+                memory_id,
+                top_level_only,
+            } => Memory::get_seeds(AuthorityMemorySeeds {
+                authority,
+                memory_id,
+                top_level_only,
+                bump: Some(bump),
+            }),
+        }
+    }
+}
+
 impl<'a, 'info> CheckedAccount<'a, 'info> for Memory<'a, 'info> {
     fn info(&self) -> &'a AccountInfo<'info> {
         self.info
@@ -63,6 +63,11 @@ pub const SPL_NOOP_ID: Pubkey = Pubkey::new_from_array([
     220, 23, 234, 203, 3, 205, 26, 35, 205, 126, 120, 124,
 ]);
 
+pub const SPL_STAKE_POOL_ID: Pubkey = Pubkey::new_from_array([
+    6, 129, 78, 212, 202, 246, 138, 23, 70, 114, 253, 172, 134, 3, 26, 99, 232, 78, 161, 94, 250,
+    29, 68, 183, 34, 147, 246, 219, 219, 0, 22, 80,
+]);
+
 pub struct SplAccountCompressionProgram;
 impl Id for SplAccountCompressionProgram {
     fn id() -> Pubkey {
@@ -6,7 +6,9 @@ use crate::utils::{
 };
 use lighthouse_sdk::errors::LighthouseError;
 use lighthouse_sdk::instructions::AssertAccountDataBuilder;
-use lighthouse_sdk::types::{DataValueAssertion, EquatableOperator, IntegerOperator};
+use lighthouse_sdk::types::{
+    BytesOperator, DataValueAssertion, EquatableOperator, IntegerOperator,
+};
 use solana_program_test::tokio;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::EncodableKeypair;
@@ -120,7 +122,7 @@ async fn simple() {
                 .log_level(lighthouse_sdk::types::LogLevel::Silent)
                 .assertion(DataValueAssertion::Bytes {
                     value: vec![u8::MAX; 32],
-                    operator: EquatableOperator::Equal,
+                    operator: BytesOperator::Equal,
                 })
                 .offset(70)
                 .instruction(),
@@ -176,7 +178,7 @@ async fn simple() {
                 .log_level(lighthouse_sdk::types::LogLevel::Silent)
                 .assertion(DataValueAssertion::Bytes {
                     value: [1, 255, 255].to_vec(),
-                    operator: EquatableOperator::Equal,
+                    operator: BytesOperator::Equal,
                 })
                 .offset(107)
                 .instruction(),
@@ -185,7 +187,7 @@ async fn simple() {
                 .log_level(lighthouse_sdk::types::LogLevel::Silent)
                 .assertion(DataValueAssertion::Bytes {
                     value: [0].to_vec(),
-                    operator: EquatableOperator::Equal,
+                    operator: BytesOperator::Equal,
                 })
                 .offset(110)
                 .instruction(),
@@ -207,7 +209,7 @@ async fn simple() {
                         .cloned()
                         .chain(vec![255; 32])
                         .collect::<Vec<u8>>(),
-                    operator: EquatableOperator::Equal,
+                    operator: BytesOperator::Equal,
                 })
                 .offset(143)
                 .instruction(),
@@ -306,3 +308,89 @@ async fn empty_account_fail() {
     .await
     .unwrap();
 }
+
+#[tokio::test]
+async fn bytes_operators() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user_with_balance(context, 10e9 as u64)
+        .await
+        .unwrap();
+
+    let test_account = create_test_account(context, &user, false).await.unwrap();
+
+    let tx = TxBuilder {
+        ixs: vec![
+            // The 32 byte array at offset 70 is found when searching from the start of the account
+            AssertAccountDataBuilder::new()
+                .target_account(test_account.encodable_pubkey())
+                .log_level(lighthouse_sdk::types::LogLevel::PlaintextMessage)
+                .assertion(DataValueAssertion::Bytes {
+                    value: vec![u8::MAX; 32],
+                    operator: BytesOperator::Contains,
+                })
+                .offset(0)
+                .instruction(),
+            AssertAccountDataBuilder::new()
+                .target_account(test_account.encodable_pubkey())
+                .log_level(lighthouse_sdk::types::LogLevel::PlaintextMessage)
+                .assertion(DataValueAssertion::Bytes {
+                    value: vec![0x0F; 32],
+                    operator: BytesOperator::MaskedEqual {
+                        mask: vec![0x0F; 32],
+                    },
+                })
+                .offset(70)
+                .instruction(),
+            AssertAccountDataBuilder::new()
+                .target_account(test_account.encodable_pubkey())
+                .log_level(lighthouse_sdk::types::LogLevel::PlaintextMessage)
+                .assertion(DataValueAssertion::Bytes {
+                    value: vec![u8::MAX; 4],
+                    operator: BytesOperator::StartsWith,
+                })
+                .offset(70)
+                .instruction(),
+        ],
+        look_up_tables: None,
+    }
+    .to_transaction_and_sign(
+        vec![&user],
+        user.encodable_pubkey(),
+        context.get_blockhash().await,
+    )
+    .unwrap();
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    let tx = TxBuilder {
+        ixs: vec![AssertAccountDataBuilder::new()
+            .target_account(test_account.encodable_pubkey())
+            .log_level(lighthouse_sdk::types::LogLevel::PlaintextMessage)
+            .assertion(DataValueAssertion::Bytes {
+                value: vec![0; 32],
+                operator: BytesOperator::MaskedEqual {
+                    mask: vec![0x0F; 32],
+                },
+            })
+            .offset(70)
+            .instruction()],
+        look_up_tables: None,
+    }
+    .to_transaction_and_sign(
+        vec![&user],
+        user.encodable_pubkey(),
+        context.get_blockhash().await,
+    )
+    .unwrap();
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(0, LighthouseError::AssertionFailed),
+        None,
+    )
+    .await
+    .unwrap();
+}
@@ -0,0 +1,264 @@
+use crate::utils::context::TestContext;
+use crate::utils::{create_user, set_account_from_refs};
+use crate::utils::{
+    process_transaction_assert_failure, process_transaction_assert_success, to_transaction_error,
+};
+use borsh::BorshSerialize;
+use lighthouse_sdk::errors::LighthouseError;
+use lighthouse_sdk::instructions::AssertAccountDataPathBuilder;
+use lighthouse_sdk::types::{
+    BorshFieldType, DataPathSegment, DataValueAssertion, IntegerOperator, LogLevel, PointerSize,
+};
+use solana_program_test::tokio;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::EncodableKeypair;
+use solana_sdk::transaction::Transaction;
+
+#[derive(BorshSerialize)]
+struct DynamicAccount {
+    version: u8,
+    name: String,
+    balances: Vec<u64>,
+    tags: Vec<String>,
+    delegate: Option<[u8; 32]>,
+    limit: Option<u32>,
+}
+
+async fn create_dynamic_account(ctx: &mut TestContext) -> Pubkey {
+    let account = Keypair::new().encodable_pubkey();
+    let data = DynamicAccount {
+        version: 1,
+        name: "lighthouse".to_string(),
+        balances: vec![10, 20, 30],
+        tags: vec!["a".to_string(), "bb".to_string()],
+        delegate: None,
+        limit: Some(7),
+    }
+    .try_to_vec()
+    .unwrap();
+
+    set_account_from_refs(ctx, &account, &data, &Pubkey::new_unique()).await;
+
+    account
+}
+
+#[tokio::test]
+async fn simple() {
+    let ctx = &mut TestContext::new().await.unwrap();
+    let user = create_user(ctx).await.unwrap();
+    let account = create_dynamic_account(ctx).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            // name length prefix
+            AssertAccountDataPathBuilder::new()
+                .target_account(account)
+                .log_level(LogLevel::PlaintextMessage)
+                .offset(0)
+                .path(vec![DataPathSegment::Skip(vec![BorshFieldType::Fixed(1)])])
+                .assertion(DataValueAssertion::U32 {
+                    value: 10,
+                    operator: IntegerOperator::Equal,
+                })
+                .instruction(),
+            // balances[2]
+            AssertAccountDataPathBuilder::new()
+                .target_account(account)
+                .log_level(LogLevel::PlaintextMessage)
+                .offset(1)
+                .path(vec![
+                    DataPathSegment::Skip(vec![BorshFieldType::String]),
+                    DataPathSegment::VecElement {
+                        element: BorshFieldType::Fixed(8),
+                        index: 2,
+                    },
+                ])
+                .assertion(DataValueAssertion::U64 {
+                    value: 30,
+                    operator: IntegerOperator::Equal,
+                })
+                .instruction(),
+            // tags[1] length prefix
+            AssertAccountDataPathBuilder::new()
+                .target_account(account)
+                .log_level(LogLevel::PlaintextMessage)
+                .offset(1)
+                .path(vec![
+                    DataPathSegment::Skip(vec![
+                        BorshFieldType::String,
+                        BorshFieldType::FixedVec(8),
+                    ]),
+                    DataPathSegment::VecElement {
+                        element: BorshFieldType::String,
+                        index: 1,
+                    },
+                ])
+                .assertion(DataValueAssertion::U32 {
+                    value: 2,
+                    operator: IntegerOperator::Equal,
+                })
+                .instruction(),
+            // limit, skipping the empty delegate
+            AssertAccountDataPathBuilder::new()
+                .target_account(account)
+                .log_level(LogLevel::PlaintextMessage)
+                .offset(1)
+                .path(vec![
+                    DataPathSegment::Skip(vec![
+                        BorshFieldType::String,
+                        BorshFieldType::FixedVec(8),
+                        BorshFieldType::StringVec,
+                        BorshFieldType::FixedOption(32),
+                    ]),
+                    DataPathSegment::OptionSome,
+                ])
+                .assertion(DataValueAssertion::U32 {
+                    value: 7,
+                    operator: IntegerOperator::Equal,
+                })
+                .instruction(),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        ctx.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(ctx, tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn path_failures() {
+    let ctx = &mut TestContext::new().await.unwrap();
+    let user = create_user(ctx).await.unwrap();
+    let account = create_dynamic_account(ctx).await;
+
+    let failures = vec![
+        // Value mismatch at balances[0]
+        (
+            vec![
+                DataPathSegment::Skip(vec![BorshFieldType::String]),
+                DataPathSegment::VecElement {
+                    element: BorshFieldType::Fixed(8),
+                    index: 0,
+                },
+            ],
+            LighthouseError::AssertionFailed,
+        ),
+        // balances only has 3 elements
+        (
+            vec![
+                DataPathSegment::Skip(vec![BorshFieldType::String]),
+                DataPathSegment::VecElement {
+                    element: BorshFieldType::Fixed(8),
+                    index: 3,
+                },
+            ],
+            LighthouseError::IndexOutOfBounds,
+        ),
+        // delegate is None
+        (
+            vec![
+                DataPathSegment::Skip(vec![
+                    BorshFieldType::String,
+                    BorshFieldType::FixedVec(8),
+                    BorshFieldType::StringVec,
+                ]),
+                DataPathSegment::OptionSome,
+            ],
+            LighthouseError::AssertionFailed,
+        ),
+    ];
+
+    for (path, error) in failures {
+        let tx = Transaction::new_signed_with_payer(
+            &[AssertAccountDataPathBuilder::new()
+                .target_account(account)
+                .log_level(LogLevel::PlaintextMessage)
+                .offset(1)
+                .path(path)
+                .assertion(DataValueAssertion::U64 {
+                    value: 1,
+                    operator: IntegerOperator::Equal,
+                })
+                .instruction()],
+            Some(&user.encodable_pubkey()),
+            &[&user],
+            ctx.get_blockhash().await,
+        );
+
+        process_transaction_assert_failure(ctx, tx, to_transaction_error(0, error), None)
+            .await
+            .unwrap();
+    }
+}
+
+// u16 head index followed by a ring buffer of four u64 entries
+async fn create_ring_buffer_account(ctx: &mut TestContext, head: u16) -> Pubkey {
+    let account = Keypair::new().encodable_pubkey();
+
+    let mut data = head.to_le_bytes().to_vec();
+    for entry in [100u64, 200, 300, 400] {
+        data.extend_from_slice(&entry.to_le_bytes());
+    }
+
+    set_account_from_refs(ctx, &account, &data, &Pubkey::new_unique()).await;
+
+    account
+}
+
+#[tokio::test]
+async fn pointer() {
+    let ctx = &mut TestContext::new().await.unwrap();
+    let user = create_user(ctx).await.unwrap();
+    let account = create_ring_buffer_account(ctx, 1).await;
+    let out_of_range_account = create_ring_buffer_account(ctx, 4).await;
+
+    let pointer_ix = |target_account: Pubkey, value: u64| {
+        AssertAccountDataPathBuilder::new()
+            .target_account(target_account)
+            .log_level(LogLevel::PlaintextMessage)
+            .offset(2)
+            .path(vec![DataPathSegment::Pointer {
+                at: 0,
+                size: PointerSize::U16,
+                stride: 8,
+            }])
+            .assertion(DataValueAssertion::U64 {
+                value,
+                operator: IntegerOperator::Equal,
+            })
+            .instruction()
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[pointer_ix(account, 200)],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        ctx.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(ctx, tx).await.unwrap();
+
+    let failures = vec![
+        (pointer_ix(account, 100), LighthouseError::AssertionFailed),
+        // Head index 4 points past the last entry
+        (
+            pointer_ix(out_of_range_account, 100),
+            LighthouseError::RangeOutOfBounds,
+        ),
+    ];
+
+    for (ix, error) in failures {
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&user.encodable_pubkey()),
+            &[&user],
+            ctx.get_blockhash().await,
+        );
+
+        process_transaction_assert_failure(ctx, tx, to_transaction_error(0, error), None)
+            .await
+            .unwrap();
+    }
+}
@@ -9,6 +9,7 @@ use borsh::BorshSerialize;
 use lighthouse_sdk::errors::LighthouseError;
 use lighthouse_sdk::find_memory_pda;
 use lighthouse_sdk::instructions::{AssertAccountDeltaBuilder, MemoryWriteBuilder};
+use lighthouse_sdk::types::BytesOperator;
 use lighthouse_sdk::types::DataValueDeltaAssertion;
 use lighthouse_sdk::types::EquatableOperator;
 use lighthouse_sdk::types::IntegerOperator;
@@ -230,7 +231,7 @@ async fn test_data_delta() {
                 62,
                 DataValueDeltaAssertion::Bytes {
                     length: 32,
-                    operator: EquatableOperator::Equal,
+                    operator: BytesOperator::Equal,
                 },
             ),
         ],
@@ -349,7 +350,7 @@ async fn test_data_delta() {
                 62,
                 DataValueDeltaAssertion::Bytes {
                     length: 32,
-                    operator: EquatableOperator::NotEqual,
+                    operator: BytesOperator::NotEqual,
                 },
             ),
         ],
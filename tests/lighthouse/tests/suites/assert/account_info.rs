@@ -8,7 +8,7 @@ use crate::utils::{
 use lighthouse_sdk::errors::LighthouseError;
 use lighthouse_sdk::instructions::AssertAccountInfoBuilder;
 use lighthouse_sdk::types::{
-    AccountInfoAssertion, EquatableOperator, IntegerOperator, KnownProgram,
+    AccountInfoAssertion, EquatableOperator, IntegerOperator, KnownProgram, SetOperator,
 };
 use solana_program_test::tokio;
 use solana_sdk::signer::{EncodableKeypair, Signer};
@@ -1098,3 +1098,64 @@ async fn verify_hash() {
 
     process_transaction_assert_success(ctx, tx).await.unwrap();
 }
+
+#[tokio::test]
+async fn owner_set_membership() {
+    let ctx = &mut TestContext::new().await.unwrap();
+    let user = create_user(ctx).await.unwrap();
+
+    let owners = vec![
+        spl_token::id(),
+        system_program::id(),
+        bpf_loader_upgradeable::id(),
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            AssertAccountInfoBuilder::new()
+                .target_account(user.encodable_pubkey())
+                .log_level(lighthouse_sdk::types::LogLevel::PlaintextMessage)
+                .assertion(AccountInfoAssertion::OwnerIn {
+                    values: owners.clone(),
+                    operator: SetOperator::In,
+                })
+                .instruction(),
+            AssertAccountInfoBuilder::new()
+                .target_account(user.encodable_pubkey())
+                .log_level(lighthouse_sdk::types::LogLevel::PlaintextMessage)
+                .assertion(AccountInfoAssertion::OwnerIn {
+                    values: vec![spl_token::id()],
+                    operator: SetOperator::NotIn,
+                })
+                .instruction(),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        ctx.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(ctx, tx).await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[AssertAccountInfoBuilder::new()
+            .target_account(user.encodable_pubkey())
+            .log_level(lighthouse_sdk::types::LogLevel::PlaintextMessage)
+            .assertion(AccountInfoAssertion::OwnerIn {
+                values: owners,
+                operator: SetOperator::NotIn,
+            })
+            .instruction()],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        ctx.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        ctx,
+        tx,
+        to_transaction_error(0, LighthouseError::AssertionFailed),
+        None,
+    )
+    .await
+    .unwrap();
+}
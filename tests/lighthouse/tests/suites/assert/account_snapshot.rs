@@ -0,0 +1,317 @@
+use crate::utils::context::TestContext;
+use crate::utils::{
+    create_and_transfer_token_account_ix, create_mint, create_user, CreateMintParameters,
+};
+use crate::utils::{
+    process_transaction_assert_failure, process_transaction_assert_success, to_transaction_error,
+};
+use lighthouse_sdk::errors::LighthouseError;
+use lighthouse_sdk::find_memory_pda;
+use lighthouse_sdk::instructions::{AssertAccountSnapshotBuilder, MemoryWriteBuilder};
+use lighthouse_sdk::types::{
+    AccountSnapshotAssertion, EquatableOperator, IntegerOperator, LogLevel,
+    MintAccountSnapshotAssertion, TokenAccountSnapshotAssertion, WriteType,
+};
+use solana_program_test::tokio;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::{EncodableKeypair, Signer};
+use solana_sdk::transaction::Transaction;
+use spl_associated_token_account::get_associated_token_address;
+
+const TOKEN_ACCOUNT_LEN: u16 = 165;
+
+fn snapshot_ix(payer: Pubkey, account: Pubkey, write_offset: u16, length: u16) -> Instruction {
+    let (memory, memory_bump) = find_memory_pda(payer, 0);
+
+    MemoryWriteBuilder::new()
+        .payer(payer)
+        .source_account(account)
+        .memory(memory)
+        .program_id(lighthouse_sdk::ID)
+        .memory_id(0)
+        .memory_bump(memory_bump)
+        .write_offset(write_offset)
+        .write_type(WriteType::AccountSnapshot {
+            data_length: length,
+        })
+        .instruction()
+}
+
+fn assert_snapshot_ix(
+    payer: Pubkey,
+    account: Pubkey,
+    assertion: AccountSnapshotAssertion,
+) -> Instruction {
+    let (memory, _) = find_memory_pda(payer, 0);
+
+    AssertAccountSnapshotBuilder::new()
+        .memory(memory)
+        .target_account(account)
+        .log_level(LogLevel::PlaintextMessage)
+        .assertion(assertion)
+        .instruction()
+}
+
+async fn setup_mint(context: &mut TestContext, user: &Keypair) -> (Pubkey, Pubkey) {
+    let (tx, mint) = create_mint(
+        context,
+        user,
+        CreateMintParameters {
+            token_program: spl_token::id(),
+            mint_authority: Some(Some(user.pubkey())),
+            freeze_authority: None,
+            mint_to: Some((user.pubkey(), 100)),
+            decimals: 9,
+        },
+    )
+    .await
+    .unwrap();
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    let token_account = get_associated_token_address(&user.pubkey(), &mint.pubkey());
+
+    (mint.pubkey(), token_account)
+}
+
+#[tokio::test]
+async fn token_account_snapshot() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+    let dest = Keypair::new();
+
+    let (mint, token_account) = setup_mint(context, &user).await;
+
+    let mut ixs = vec![snapshot_ix(
+        user.encodable_pubkey(),
+        token_account,
+        0,
+        TOKEN_ACCOUNT_LEN,
+    )];
+    ixs.extend(
+        create_and_transfer_token_account_ix(
+            context,
+            &user.encodable_pubkey(),
+            &mint,
+            &dest.encodable_pubkey(),
+            69,
+        )
+        .await
+        .unwrap(),
+    );
+    ixs.extend(vec![
+        assert_snapshot_ix(
+            user.encodable_pubkey(),
+            token_account,
+            AccountSnapshotAssertion::TokenAccount {
+                memory_offset: 0,
+                assertion: TokenAccountSnapshotAssertion::Amount {
+                    value: -69,
+                    operator: IntegerOperator::Equal,
+                },
+            },
+        ),
+        assert_snapshot_ix(
+            user.encodable_pubkey(),
+            token_account,
+            AccountSnapshotAssertion::TokenAccount {
+                memory_offset: 0,
+                assertion: TokenAccountSnapshotAssertion::Owner {
+                    operator: EquatableOperator::Equal,
+                },
+            },
+        ),
+        assert_snapshot_ix(
+            user.encodable_pubkey(),
+            token_account,
+            AccountSnapshotAssertion::TokenAccount {
+                memory_offset: 0,
+                assertion: TokenAccountSnapshotAssertion::Delegate {
+                    operator: EquatableOperator::Equal,
+                },
+            },
+        ),
+    ]);
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn mint_account_snapshot() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+
+    let (mint, _) = setup_mint(context, &user).await;
+
+    let token_account = get_associated_token_address(&user.pubkey(), &mint);
+    let mint_to_ix = spl_token::instruction::mint_to(
+        &spl_token::id(),
+        &mint,
+        &token_account,
+        &user.pubkey(),
+        &[],
+        50,
+    )
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            snapshot_ix(user.encodable_pubkey(), mint, 0, 82),
+            mint_to_ix,
+            assert_snapshot_ix(
+                user.encodable_pubkey(),
+                mint,
+                AccountSnapshotAssertion::MintAccount {
+                    memory_offset: 0,
+                    assertion: MintAccountSnapshotAssertion::Supply {
+                        value: 50,
+                        operator: IntegerOperator::Equal,
+                    },
+                },
+            ),
+            assert_snapshot_ix(
+                user.encodable_pubkey(),
+                mint,
+                AccountSnapshotAssertion::MintAccount {
+                    memory_offset: 0,
+                    assertion: MintAccountSnapshotAssertion::MintAuthority {
+                        operator: EquatableOperator::Equal,
+                    },
+                },
+            ),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn snapshot_failures() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+    let dest = Keypair::new();
+
+    let (mint, token_account) = setup_mint(context, &user).await;
+
+    // The balance moved, so a zero delta fails
+    let mut ixs = vec![snapshot_ix(
+        user.encodable_pubkey(),
+        token_account,
+        0,
+        TOKEN_ACCOUNT_LEN,
+    )];
+    ixs.extend(
+        create_and_transfer_token_account_ix(
+            context,
+            &user.encodable_pubkey(),
+            &mint,
+            &dest.encodable_pubkey(),
+            69,
+        )
+        .await
+        .unwrap(),
+    );
+    let assert_index = ixs.len() as u8;
+    ixs.push(assert_snapshot_ix(
+        user.encodable_pubkey(),
+        token_account,
+        AccountSnapshotAssertion::TokenAccount {
+            memory_offset: 0,
+            assertion: TokenAccountSnapshotAssertion::Amount {
+                value: 0,
+                operator: IntegerOperator::Equal,
+            },
+        },
+    ));
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(assert_index, LighthouseError::AssertionFailed),
+        None,
+    )
+    .await
+    .unwrap();
+
+    // A snapshot of the token account cannot be compared against the mint
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            snapshot_ix(user.encodable_pubkey(), token_account, 0, TOKEN_ACCOUNT_LEN),
+            assert_snapshot_ix(
+                user.encodable_pubkey(),
+                mint,
+                AccountSnapshotAssertion::MintAccount {
+                    memory_offset: 0,
+                    assertion: MintAccountSnapshotAssertion::MintAuthority {
+                        operator: EquatableOperator::Equal,
+                    },
+                },
+            ),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(1, LighthouseError::AccountKeyMismatch),
+        None,
+    )
+    .await
+    .unwrap();
+
+    // A mint is not a token account
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            snapshot_ix(user.encodable_pubkey(), mint, 0, 82),
+            assert_snapshot_ix(
+                user.encodable_pubkey(),
+                mint,
+                AccountSnapshotAssertion::TokenAccount {
+                    memory_offset: 0,
+                    assertion: TokenAccountSnapshotAssertion::Owner {
+                        operator: EquatableOperator::Equal,
+                    },
+                },
+            ),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(1, LighthouseError::AccountDiscriminatorValidationFailed),
+        None,
+    )
+    .await
+    .unwrap();
+}
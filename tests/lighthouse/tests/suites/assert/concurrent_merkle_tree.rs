@@ -0,0 +1,187 @@
+use crate::suites::assert::merkle_tree::context_tree_and_leaves;
+use crate::utils::context::TestContext;
+use crate::utils::{create_user, set_account_from_refs};
+use crate::utils::{
+    process_transaction_assert_failure, process_transaction_assert_success, to_transaction_error,
+    to_transaction_error_u8,
+};
+use lighthouse_sdk::errors::LighthouseError;
+use lighthouse_sdk::instructions::{
+    AssertConcurrentMerkleTreeAccountBuilder, AssertConcurrentMerkleTreeAccountMultiBuilder,
+};
+use lighthouse_sdk::types::{
+    ConcurrentMerkleTreeAssertion, EquatableOperator, IntegerOperator, LogLevel,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::EncodableKeypair;
+use solana_sdk::transaction::Transaction;
+
+///
+/// Tests the header and tree state of a bubblegum tree with 10 leaves minted.
+///
+#[tokio::test]
+async fn simple() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+
+    let (_, tree, leaves) = context_tree_and_leaves(&mut context.program_context)
+        .await
+        .unwrap();
+
+    let tree_pubkey = tree.tree_pubkey();
+
+    let assertions = vec![
+        ConcurrentMerkleTreeAssertion::MaxDepth {
+            value: 14,
+            operator: IntegerOperator::Equal,
+        },
+        ConcurrentMerkleTreeAssertion::MaxBufferSize {
+            value: 64,
+            operator: IntegerOperator::Equal,
+        },
+        ConcurrentMerkleTreeAssertion::Authority {
+            value: tree.authority(),
+            operator: EquatableOperator::Equal,
+        },
+        ConcurrentMerkleTreeAssertion::SequenceNumber {
+            value: leaves.len() as u64,
+            operator: IntegerOperator::Equal,
+        },
+        ConcurrentMerkleTreeAssertion::RightmostIndex {
+            value: leaves.len() as u32,
+            operator: IntegerOperator::Equal,
+        },
+        ConcurrentMerkleTreeAssertion::IsFull {
+            value: false,
+            operator: EquatableOperator::Equal,
+        },
+    ];
+
+    let mut ixs = assertions
+        .iter()
+        .map(|assertion| {
+            AssertConcurrentMerkleTreeAccountBuilder::new()
+                .target_merkle_tree(tree_pubkey)
+                .log_level(LogLevel::PlaintextMessage)
+                .assertion(assertion.clone())
+                .instruction()
+        })
+        .collect::<Vec<_>>();
+
+    ixs.push(
+        AssertConcurrentMerkleTreeAccountMultiBuilder::new()
+            .target_merkle_tree(tree_pubkey)
+            .log_level(LogLevel::PlaintextMessage)
+            .assertions(assertions)
+            .instruction(),
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn assertion_failures() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+
+    let (_, tree, leaves) = context_tree_and_leaves(&mut context.program_context)
+        .await
+        .unwrap();
+
+    let tree_pubkey = tree.tree_pubkey();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[AssertConcurrentMerkleTreeAccountBuilder::new()
+            .target_merkle_tree(tree_pubkey)
+            .log_level(LogLevel::PlaintextMessage)
+            .assertion(ConcurrentMerkleTreeAssertion::IsFull {
+                value: true,
+                operator: EquatableOperator::Equal,
+            })
+            .instruction()],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(0, LighthouseError::AssertionFailed),
+        None,
+    )
+    .await
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[AssertConcurrentMerkleTreeAccountMultiBuilder::new()
+            .target_merkle_tree(tree_pubkey)
+            .log_level(LogLevel::PlaintextMessage)
+            .assertions(vec![
+                ConcurrentMerkleTreeAssertion::MaxDepth {
+                    value: 14,
+                    operator: IntegerOperator::Equal,
+                },
+                ConcurrentMerkleTreeAssertion::SequenceNumber {
+                    value: leaves.len() as u64,
+                    operator: IntegerOperator::LessThan,
+                },
+            ])
+            .instruction()],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(context, tx, to_transaction_error_u8(0, 0x1900 + 1), None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn unsupported_account_type() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+
+    let not_a_tree = Keypair::new().encodable_pubkey();
+    set_account_from_refs(
+        context,
+        &not_a_tree,
+        &[0u8; 128],
+        &spl_account_compression::id(),
+    )
+    .await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[AssertConcurrentMerkleTreeAccountBuilder::new()
+            .target_merkle_tree(not_a_tree)
+            .log_level(LogLevel::PlaintextMessage)
+            .assertion(ConcurrentMerkleTreeAssertion::MaxDepth {
+                value: 14,
+                operator: IntegerOperator::Equal,
+            })
+            .instruction()],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(0, LighthouseError::AccountDiscriminatorValidationFailed),
+        None,
+    )
+    .await
+    .unwrap();
+}
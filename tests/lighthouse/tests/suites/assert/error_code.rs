@@ -0,0 +1,139 @@
+use crate::utils::context::TestContext;
+use crate::utils::create_user;
+use crate::utils::{
+    process_transaction_assert_failure, process_transaction_assert_success, to_transaction_error,
+};
+use lighthouse_sdk::cpi::AssertAccountInfoMultiBuilder;
+use lighthouse_sdk::errors::LighthouseError;
+use lighthouse_sdk::instructions::AssertAccountInfoBuilder;
+use lighthouse_sdk::types::{AccountInfoAssertion, EquatableOperator, IntegerOperator, LogLevel};
+use solana_program_test::tokio;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signer::EncodableKeypair;
+use solana_sdk::system_program;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+const CUSTOM_ERROR_CODE_OFFSET: u32 = 0x10000;
+
+fn to_custom_transaction_error(ix_index: u8, error_code: u16) -> TransactionError {
+    TransactionError::InstructionError(
+        ix_index,
+        InstructionError::Custom(CUSTOM_ERROR_CODE_OFFSET + error_code as u32),
+    )
+}
+
+#[tokio::test]
+async fn passing_assertions_ignore_error_codes() {
+    let ctx = &mut TestContext::new().await.unwrap();
+    let user = create_user(ctx).await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            AssertAccountInfoBuilder::new()
+                .target_account(user.encodable_pubkey())
+                .log_level(LogLevel::PlaintextMessage)
+                .assertion(AccountInfoAssertion::Owner {
+                    value: system_program::id(),
+                    operator: EquatableOperator::Equal,
+                })
+                .error_code(7)
+                .instruction(),
+            AssertAccountInfoMultiBuilder::new()
+                .target_account(user.encodable_pubkey())
+                .log_level(LogLevel::PlaintextMessage)
+                .assertions(vec![
+                    AccountInfoAssertion::Owner {
+                        value: system_program::id(),
+                        operator: EquatableOperator::Equal,
+                    },
+                    AccountInfoAssertion::Lamports {
+                        value: 0,
+                        operator: IntegerOperator::GreaterThan,
+                    },
+                ])
+                .error_codes(vec![1, 2])
+                .instruction(),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        ctx.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(ctx, tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn custom_error_codes() {
+    let ctx = &mut TestContext::new().await.unwrap();
+    let user = create_user(ctx).await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[AssertAccountInfoBuilder::new()
+            .target_account(user.encodable_pubkey())
+            .log_level(LogLevel::PlaintextMessage)
+            .assertion(AccountInfoAssertion::Owner {
+                value: system_program::id(),
+                operator: EquatableOperator::NotEqual,
+            })
+            .error_code(42)
+            .instruction()],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        ctx.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(ctx, tx, to_custom_transaction_error(0, 42), None)
+        .await
+        .unwrap();
+
+    // The code of the failing assertion is returned instead of 0x1900 + i
+    let tx = Transaction::new_signed_with_payer(
+        &[AssertAccountInfoMultiBuilder::new()
+            .target_account(user.encodable_pubkey())
+            .log_level(LogLevel::PlaintextMessage)
+            .assertions(vec![
+                AccountInfoAssertion::Owner {
+                    value: system_program::id(),
+                    operator: EquatableOperator::Equal,
+                },
+                AccountInfoAssertion::Lamports {
+                    value: 0,
+                    operator: IntegerOperator::Equal,
+                },
+            ])
+            .error_codes(vec![1, u16::MAX])
+            .instruction()],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        ctx.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(ctx, tx, to_custom_transaction_error(0, u16::MAX), None)
+        .await
+        .unwrap();
+
+    // Multi instructions need exactly one error code per assertion
+    let tx = Transaction::new_signed_with_payer(
+        &[AssertAccountInfoMultiBuilder::new()
+            .target_account(user.encodable_pubkey())
+            .log_level(LogLevel::PlaintextMessage)
+            .assertions(vec![AccountInfoAssertion::Owner {
+                value: system_program::id(),
+                operator: EquatableOperator::Equal,
+            }])
+            .error_codes(vec![1, 2])
+            .instruction()],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        ctx.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        ctx,
+        tx,
+        to_transaction_error(0, LighthouseError::InvalidInstructionData),
+        None,
+    )
+    .await
+    .unwrap();
+}
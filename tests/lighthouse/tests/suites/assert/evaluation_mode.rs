@@ -0,0 +1,93 @@
+use crate::utils::context::TestContext;
+use crate::utils::create_user;
+use crate::utils::{
+    process_transaction_assert_failure, process_transaction_assert_success, to_transaction_error_u8,
+};
+use lighthouse_sdk::cpi::AssertAccountInfoMultiBuilder;
+use lighthouse_sdk::types::{
+    AccountInfoAssertion, EquatableOperator, EvaluationMode, IntegerOperator, LogLevel,
+};
+use solana_program_test::tokio;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::EncodableKeypair;
+use solana_sdk::system_program;
+use solana_sdk::transaction::Transaction;
+
+// Passes, then fails twice
+fn assertions() -> Vec<AccountInfoAssertion> {
+    vec![
+        AccountInfoAssertion::Owner {
+            value: system_program::id(),
+            operator: EquatableOperator::Equal,
+        },
+        AccountInfoAssertion::Lamports {
+            value: 0,
+            operator: IntegerOperator::Equal,
+        },
+        AccountInfoAssertion::Executable {
+            value: true,
+            operator: EquatableOperator::Equal,
+        },
+    ]
+}
+
+fn multi_ix(target_account: Pubkey, evaluation_mode: EvaluationMode) -> Instruction {
+    AssertAccountInfoMultiBuilder::new()
+        .target_account(target_account)
+        .log_level(LogLevel::PlaintextMessage)
+        .assertions(assertions())
+        .evaluation_mode(evaluation_mode)
+        .instruction()
+}
+
+#[tokio::test]
+async fn soft_evaluation() {
+    let ctx = &mut TestContext::new().await.unwrap();
+    let user = create_user(ctx).await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            multi_ix(
+                user.encodable_pubkey(),
+                EvaluationMode::Soft { max_failures: None },
+            ),
+            multi_ix(
+                user.encodable_pubkey(),
+                EvaluationMode::Soft {
+                    max_failures: Some(2),
+                },
+            ),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        ctx.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(ctx, tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn failure_threshold() {
+    let ctx = &mut TestContext::new().await.unwrap();
+    let user = create_user(ctx).await.unwrap();
+
+    // Both modes report the first failing assertion
+    for evaluation_mode in [
+        EvaluationMode::Soft {
+            max_failures: Some(1),
+        },
+        EvaluationMode::Strict,
+    ] {
+        let tx = Transaction::new_signed_with_payer(
+            &[multi_ix(user.encodable_pubkey(), evaluation_mode)],
+            Some(&user.encodable_pubkey()),
+            &[&user],
+            ctx.get_blockhash().await,
+        );
+
+        process_transaction_assert_failure(ctx, tx, to_transaction_error_u8(0, 0x1900 + 1), None)
+            .await
+            .unwrap();
+    }
+}
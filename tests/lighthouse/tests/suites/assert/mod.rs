@@ -12,4 +12,10 @@ pub mod token_account;
 pub mod token_account_multi;
 pub mod upgradeable_loader;
 pub mod upgradeable_loader_multi;
-pub mod bubblegum_tree_config;
\ No newline at end of file
+pub mod bubblegum_tree_config;
+pub mod stake_pool_account;
+pub mod concurrent_merkle_tree;
+pub mod account_data_path;
+pub mod account_snapshot;
+pub mod error_code;
+pub mod evaluation_mode;
\ No newline at end of file
@@ -0,0 +1,284 @@
+use crate::utils::context::TestContext;
+use crate::utils::{create_user, set_account_from_refs};
+use crate::utils::{
+    process_transaction_assert_failure, process_transaction_assert_success, to_transaction_error,
+    to_transaction_error_u8,
+};
+use lighthouse_sdk::errors::LighthouseError;
+use lighthouse_sdk::instructions::{
+    AssertStakePoolAccountBuilder, AssertStakePoolAccountMultiBuilder,
+};
+use lighthouse_sdk::types::{
+    EquatableOperator, IntegerOperator, LogLevel, StakePoolAccountAssertion, StakePoolFeeAssertion,
+};
+use solana_program_test::tokio;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::EncodableKeypair;
+use solana_sdk::transaction::Transaction;
+use std::str::FromStr;
+
+const SPL_STAKE_POOL_ID: &str = "SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy";
+
+struct TestStakePool {
+    manager: Pubkey,
+    staker: Pubkey,
+    pool_mint: Pubkey,
+    total_lamports: u64,
+    pool_token_supply: u64,
+    last_update_epoch: u64,
+    epoch_fee: (u64, u64),
+}
+
+///
+/// Borsh encoding of `spl_stake_pool::state::StakePool`, the program is not a test dependency.
+///
+fn stake_pool_data(pool: &TestStakePool) -> Vec<u8> {
+    let fee = |(denominator, numerator): (u64, u64)| {
+        [denominator.to_le_bytes(), numerator.to_le_bytes()].concat()
+    };
+
+    let mut data = vec![1u8]; // AccountType::StakePool
+    data.extend_from_slice(pool.manager.as_ref());
+    data.extend_from_slice(pool.staker.as_ref());
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // stake_deposit_authority
+    data.push(255); // stake_withdraw_bump_seed
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // validator_list
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // reserve_stake
+    data.extend_from_slice(pool.pool_mint.as_ref());
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // manager_fee_account
+    data.extend_from_slice(spl_token::id().as_ref());
+    data.extend_from_slice(&pool.total_lamports.to_le_bytes());
+    data.extend_from_slice(&pool.pool_token_supply.to_le_bytes());
+    data.extend_from_slice(&pool.last_update_epoch.to_le_bytes());
+    data.extend_from_slice(&0i64.to_le_bytes()); // lockup.unix_timestamp
+    data.extend_from_slice(&0u64.to_le_bytes()); // lockup.epoch
+    data.extend_from_slice(Pubkey::default().as_ref()); // lockup.custodian
+    data.extend(fee(pool.epoch_fee));
+    data.push(0); // next_epoch_fee
+    data.push(0); // preferred_deposit_validator_vote_address
+    data.push(0); // preferred_withdraw_validator_vote_address
+    data.extend(fee((100, 1))); // stake_deposit_fee
+    data.extend(fee((100, 2))); // stake_withdrawal_fee
+    data.push(0); // next_stake_withdrawal_fee
+    data.push(10); // stake_referral_fee
+    data.push(0); // sol_deposit_authority
+    data.extend(fee((100, 3))); // sol_deposit_fee
+    data.push(20); // sol_referral_fee
+    data.push(0); // sol_withdraw_authority
+    data.extend(fee((100, 4))); // sol_withdrawal_fee
+    data.push(0); // next_sol_withdrawal_fee
+    data.extend_from_slice(&pool.pool_token_supply.to_le_bytes()); // last_epoch_pool_token_supply
+    data.extend_from_slice(&pool.total_lamports.to_le_bytes()); // last_epoch_total_lamports
+
+    data
+}
+
+async fn create_stake_pool(ctx: &mut TestContext, pool: &TestStakePool) -> Pubkey {
+    let stake_pool = Keypair::new().encodable_pubkey();
+    set_account_from_refs(
+        ctx,
+        &stake_pool,
+        &stake_pool_data(pool),
+        &Pubkey::from_str(SPL_STAKE_POOL_ID).unwrap(),
+    )
+    .await;
+
+    stake_pool
+}
+
+fn test_stake_pool() -> TestStakePool {
+    TestStakePool {
+        manager: Pubkey::new_unique(),
+        staker: Pubkey::new_unique(),
+        pool_mint: Pubkey::new_unique(),
+        total_lamports: 2_000_000_000,
+        pool_token_supply: 1_000_000_000,
+        last_update_epoch: 42,
+        epoch_fee: (100, 5),
+    }
+}
+
+#[tokio::test]
+async fn simple() {
+    let ctx = &mut TestContext::new().await.unwrap();
+    let user = create_user(ctx).await.unwrap();
+
+    let pool = test_stake_pool();
+    let stake_pool = create_stake_pool(ctx, &pool).await;
+
+    let assertions = vec![
+        StakePoolAccountAssertion::Manager {
+            value: pool.manager,
+            operator: EquatableOperator::Equal,
+        },
+        StakePoolAccountAssertion::Staker {
+            value: pool.staker,
+            operator: EquatableOperator::Equal,
+        },
+        StakePoolAccountAssertion::PoolMint {
+            value: pool.pool_mint,
+            operator: EquatableOperator::Equal,
+        },
+        StakePoolAccountAssertion::TotalLamports {
+            value: pool.total_lamports,
+            operator: IntegerOperator::Equal,
+        },
+        StakePoolAccountAssertion::PoolTokenSupply {
+            value: pool.pool_token_supply,
+            operator: IntegerOperator::Equal,
+        },
+        // 2 lamports per pool token, scaled by 10^9
+        StakePoolAccountAssertion::ExchangeRate {
+            value: 2_000_000_000,
+            operator: IntegerOperator::Equal,
+        },
+        StakePoolAccountAssertion::LastUpdateEpoch {
+            value: pool.last_update_epoch,
+            operator: IntegerOperator::Equal,
+        },
+        StakePoolAccountAssertion::EpochFee(StakePoolFeeAssertion::Numerator {
+            value: 5,
+            operator: IntegerOperator::Equal,
+        }),
+        StakePoolAccountAssertion::EpochFee(StakePoolFeeAssertion::Denominator {
+            value: 100,
+            operator: IntegerOperator::Equal,
+        }),
+        StakePoolAccountAssertion::StakeReferralFee {
+            value: 10,
+            operator: IntegerOperator::Equal,
+        },
+        StakePoolAccountAssertion::SolReferralFee {
+            value: 20,
+            operator: IntegerOperator::Equal,
+        },
+        StakePoolAccountAssertion::SolWithdrawalFee(StakePoolFeeAssertion::Numerator {
+            value: 4,
+            operator: IntegerOperator::Equal,
+        }),
+    ];
+
+    let mut ixs = assertions
+        .iter()
+        .map(|assertion| {
+            AssertStakePoolAccountBuilder::new()
+                .target_account(stake_pool)
+                .log_level(LogLevel::PlaintextMessage)
+                .assertion(assertion.clone())
+                .instruction()
+        })
+        .collect::<Vec<_>>();
+
+    ixs.push(
+        AssertStakePoolAccountMultiBuilder::new()
+            .target_account(stake_pool)
+            .log_level(LogLevel::PlaintextMessage)
+            .assertions(assertions)
+            .instruction(),
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        ctx.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(ctx, tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn assertion_failures() {
+    let ctx = &mut TestContext::new().await.unwrap();
+    let user = create_user(ctx).await.unwrap();
+
+    let pool = test_stake_pool();
+    let stake_pool = create_stake_pool(ctx, &pool).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[AssertStakePoolAccountBuilder::new()
+            .target_account(stake_pool)
+            .log_level(LogLevel::PlaintextMessage)
+            .assertion(StakePoolAccountAssertion::TotalLamports {
+                value: pool.total_lamports,
+                operator: IntegerOperator::NotEqual,
+            })
+            .instruction()],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        ctx.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        ctx,
+        tx,
+        to_transaction_error(0, LighthouseError::AssertionFailed),
+        None,
+    )
+    .await
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[AssertStakePoolAccountMultiBuilder::new()
+            .target_account(stake_pool)
+            .log_level(LogLevel::PlaintextMessage)
+            .assertions(vec![
+                StakePoolAccountAssertion::Manager {
+                    value: pool.manager,
+                    operator: EquatableOperator::Equal,
+                },
+                StakePoolAccountAssertion::Staker {
+                    value: pool.manager,
+                    operator: EquatableOperator::Equal,
+                },
+            ])
+            .instruction()],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        ctx.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(ctx, tx, to_transaction_error_u8(0, 0x1900 + 1), None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn account_owner_mismatch() {
+    let ctx = &mut TestContext::new().await.unwrap();
+    let user = create_user(ctx).await.unwrap();
+
+    let pool = test_stake_pool();
+    let not_stake_pool = Keypair::new().encodable_pubkey();
+    set_account_from_refs(
+        ctx,
+        &not_stake_pool,
+        &stake_pool_data(&pool),
+        &spl_token::id(),
+    )
+    .await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[AssertStakePoolAccountBuilder::new()
+            .target_account(not_stake_pool)
+            .log_level(LogLevel::PlaintextMessage)
+            .assertion(StakePoolAccountAssertion::Manager {
+                value: pool.manager,
+                operator: EquatableOperator::Equal,
+            })
+            .instruction()],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        ctx.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        ctx,
+        tx,
+        to_transaction_error(0, LighthouseError::AccountOwnerMismatch),
+        None,
+    )
+    .await
+    .unwrap();
+}
@@ -915,7 +915,8 @@ async fn encoded_message() {
 
     let payloads = parse_evaluation_payloads_from_logs(logs.to_vec()).unwrap();
     for (i, payload) in payloads.iter().enumerate() {
-        assert_eq!(expected_payloads[i], *payload);
+        assert_eq!(payload.target_account, user_ata);
+        assert_eq!(expected_payloads[i], payload.result);
     }
 
     // parse failed assertion
@@ -957,7 +958,8 @@ async fn encoded_message() {
     let payloads = parse_evaluation_payloads_from_logs(logs.to_vec()).unwrap();
 
     for (i, payload) in payloads.iter().enumerate() {
-        assert_eq!(expected_payloads[i], *payload);
+        assert_eq!(payload.target_account, user_ata);
+        assert_eq!(expected_payloads[i], payload.result);
     }
 
     let failed_assertions = vec![
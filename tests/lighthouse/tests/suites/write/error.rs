@@ -421,9 +421,11 @@ pub mod memory_write {
         create_test_account, process_transaction_assert_failure, to_transaction_error,
     };
     use lighthouse_sdk::errors::LighthouseError;
-    use lighthouse_sdk::instructions::MemoryWriteBuilder;
+    use lighthouse_sdk::instructions::{MemoryWriteBuilder, MemoryWriteWithAuthorityBuilder};
     use lighthouse_sdk::types::{AccountInfoField, WriteType};
-    use lighthouse_sdk::{find_memory_pda, find_memory_pda_bump_iterate};
+    use lighthouse_sdk::{
+        find_authority_memory_pda, find_memory_pda, find_memory_pda_bump_iterate,
+    };
     use solana_program_test::tokio;
     use solana_sdk::instruction::InstructionError;
     use solana_sdk::signature::Keypair;
@@ -566,6 +568,52 @@ pub mod memory_write {
         .unwrap();
     }
 
+    #[tokio::test]
+    async fn authority_not_signer() {
+        let context = &mut TestContext::new().await.unwrap();
+        let user = create_user(context).await.unwrap();
+        let authority = Keypair::new();
+
+        let (memory, memory_bump) =
+            find_authority_memory_pda(authority.encodable_pubkey(), 0, false);
+
+        let mut ix = MemoryWriteWithAuthorityBuilder::new()
+            .payer(user.encodable_pubkey())
+            .authority(authority.encodable_pubkey())
+            .source_account(user.encodable_pubkey())
+            .program_id(lighthouse_sdk::ID)
+            .memory(memory)
+            .memory_id(0)
+            .write_offset(0)
+            .memory_bump(memory_bump)
+            .top_level_only(false)
+            .write_type(WriteType::AccountInfoField(AccountInfoField::Lamports))
+            .instruction();
+
+        // find authority account and set signer to false
+        for account in ix.accounts.iter_mut() {
+            if account.pubkey == authority.encodable_pubkey() {
+                account.is_signer = false;
+            }
+        }
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&user.encodable_pubkey()),
+            &[&user],
+            context.get_blockhash().await,
+        );
+
+        process_transaction_assert_failure(
+            context,
+            tx,
+            to_transaction_error(0, LighthouseError::AccountValidationFailed),
+            None,
+        )
+        .await
+        .unwrap();
+    }
+
     #[tokio::test]
     async fn write_offset_too_large() {
         let context = &mut TestContext::new().await.unwrap();
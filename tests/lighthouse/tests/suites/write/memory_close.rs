@@ -0,0 +1,249 @@
+use crate::utils::context::TestContext;
+use crate::utils::create_user;
+use crate::utils::{
+    process_transaction_assert_failure, process_transaction_assert_success, to_transaction_error,
+};
+use lighthouse_sdk::errors::LighthouseError;
+use lighthouse_sdk::find_authority_memory_pda;
+use lighthouse_sdk::instructions::{
+    MemoryCloseToRecipientBuilder, MemoryShrinkBuilder, MemoryWriteWithAuthorityBuilder,
+    MemoryZeroBuilder,
+};
+use lighthouse_sdk::types::{DataValue, WriteType};
+use solana_program_test::tokio;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::EncodableKeypair;
+use solana_sdk::transaction::Transaction;
+
+fn write_ix(payer: Pubkey, memory: Pubkey, memory_bump: u8, write_offset: u16) -> Instruction {
+    MemoryWriteWithAuthorityBuilder::new()
+        .payer(payer)
+        .authority(payer)
+        .source_account(payer)
+        .memory(memory)
+        .program_id(lighthouse_sdk::ID)
+        .memory_id(0)
+        .memory_bump(memory_bump)
+        .top_level_only(false)
+        .write_offset(write_offset)
+        .write_type(WriteType::DataValue(DataValue::U64(u64::MAX)))
+        .instruction()
+}
+
+fn close_to_recipient_ix(
+    payer: Pubkey,
+    memory: Pubkey,
+    memory_bump: u8,
+    recipient: Pubkey,
+) -> Instruction {
+    MemoryCloseToRecipientBuilder::new()
+        .payer(payer)
+        .authority(payer)
+        .memory(memory)
+        .recipient(recipient)
+        .program_id(lighthouse_sdk::ID)
+        .memory_id(0)
+        .memory_bump(memory_bump)
+        .top_level_only(false)
+        .instruction()
+}
+
+fn zero_ix(
+    payer: Pubkey,
+    memory: Pubkey,
+    memory_bump: u8,
+    offset: u16,
+    length: Option<u16>,
+) -> Instruction {
+    let mut builder = MemoryZeroBuilder::new();
+    builder
+        .payer(payer)
+        .authority(payer)
+        .memory(memory)
+        .program_id(lighthouse_sdk::ID)
+        .memory_id(0)
+        .memory_bump(memory_bump)
+        .top_level_only(false)
+        .offset(offset);
+
+    if let Some(length) = length {
+        builder.length(length);
+    }
+
+    builder.instruction()
+}
+
+fn shrink_ix(payer: Pubkey, memory: Pubkey, memory_bump: u8, new_size: u16) -> Instruction {
+    MemoryShrinkBuilder::new()
+        .payer(payer)
+        .authority(payer)
+        .memory(memory)
+        .program_id(lighthouse_sdk::ID)
+        .memory_id(0)
+        .memory_bump(memory_bump)
+        .top_level_only(false)
+        .new_size(new_size)
+        .instruction()
+}
+
+#[tokio::test]
+async fn close_to_recipient() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+    let recipient = Keypair::new().encodable_pubkey();
+
+    let (memory, memory_bump) = find_authority_memory_pda(user.encodable_pubkey(), 0, false);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[write_ix(user.encodable_pubkey(), memory, memory_bump, 0)],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    let memory_lamports = context.get_account(memory).await.unwrap().lamports;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_to_recipient_ix(
+            user.encodable_pubkey(),
+            memory,
+            memory_bump,
+            recipient,
+        )],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    assert!(context.get_account(memory).await.is_none());
+    assert_eq!(
+        context.get_account(recipient).await.unwrap().lamports,
+        memory_lamports
+    );
+}
+
+#[tokio::test]
+async fn zero_and_shrink() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+
+    let (memory, memory_bump) = find_authority_memory_pda(user.encodable_pubkey(), 0, false);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            write_ix(user.encodable_pubkey(), memory, memory_bump, 0),
+            write_ix(user.encodable_pubkey(), memory, memory_bump, 8),
+            write_ix(user.encodable_pubkey(), memory, memory_bump, 16),
+            write_ix(user.encodable_pubkey(), memory, memory_bump, 24),
+            zero_ix(user.encodable_pubkey(), memory, memory_bump, 8, Some(8)),
+            zero_ix(user.encodable_pubkey(), memory, memory_bump, 24, None),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    let memory_data = context.get_account(memory).await.unwrap().data;
+    assert_eq!(memory_data[0..8], u64::MAX.to_le_bytes());
+    assert_eq!(memory_data[8..16], [0; 8]);
+    assert_eq!(memory_data[16..24], u64::MAX.to_le_bytes());
+    assert_eq!(memory_data[24..32], [0; 8]);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[shrink_ix(user.encodable_pubkey(), memory, memory_bump, 16)],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    let memory_account = context.get_account(memory).await.unwrap();
+    assert_eq!(memory_account.data.len(), 16);
+    assert_eq!(memory_account.data[0..8], u64::MAX.to_le_bytes());
+}
+
+#[tokio::test]
+async fn close_failures() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+    let recipient = Keypair::new().encodable_pubkey();
+
+    let (memory, memory_bump) = find_authority_memory_pda(user.encodable_pubkey(), 0, false);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[write_ix(user.encodable_pubkey(), memory, memory_bump, 0)],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    // Rent recipient must be writable
+    let mut ix = close_to_recipient_ix(user.encodable_pubkey(), memory, memory_bump, recipient);
+    for account in ix.accounts.iter_mut() {
+        if account.pubkey == recipient {
+            account.is_writable = false;
+        }
+    }
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(0, LighthouseError::AccountValidationFailed),
+        None,
+    )
+    .await
+    .unwrap();
+
+    let failures = vec![
+        // Zero range runs past the end of memory
+        zero_ix(user.encodable_pubkey(), memory, memory_bump, 4, Some(8)),
+        // Shrink cannot grow memory
+        shrink_ix(user.encodable_pubkey(), memory, memory_bump, 9),
+    ];
+
+    for ix in failures {
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&user.encodable_pubkey()),
+            &[&user],
+            context.get_blockhash().await,
+        );
+
+        process_transaction_assert_failure(
+            context,
+            tx,
+            to_transaction_error(0, LighthouseError::RangeOutOfBounds),
+            None,
+        )
+        .await
+        .unwrap();
+    }
+}
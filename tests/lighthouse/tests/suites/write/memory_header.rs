@@ -0,0 +1,353 @@
+use crate::utils::context::TestContext;
+use crate::utils::create_user;
+use crate::utils::{
+    process_transaction_assert_failure, process_transaction_assert_success, to_transaction_error,
+};
+use lighthouse_sdk::errors::LighthouseError;
+use lighthouse_sdk::find_authority_memory_pda;
+use lighthouse_sdk::instructions::{
+    AssertMemoryHeaderBuilder, MemoryCloseWithAuthorityBuilder, MemoryInitHeaderBuilder,
+    MemoryWriteWithAuthorityBuilder,
+};
+use lighthouse_sdk::types::{
+    DataValue, EquatableOperator, IntegerOperator, LogLevel, MemoryHeaderAssertion, WriteType,
+};
+use solana_program_test::tokio;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::EncodableKeypair;
+use solana_sdk::sysvar;
+use solana_sdk::transaction::Transaction;
+
+fn init_header_ix(payer: Pubkey, memory: Pubkey, memory_bump: u8, locked: bool) -> Instruction {
+    MemoryInitHeaderBuilder::new()
+        .payer(payer)
+        .authority(payer)
+        .memory(memory)
+        .program_id(lighthouse_sdk::ID)
+        .memory_id(0)
+        .memory_bump(memory_bump)
+        .top_level_only(false)
+        .locked(locked)
+        .instruction()
+}
+
+fn write_ix(payer: Pubkey, memory: Pubkey, memory_bump: u8, write_offset: u16) -> Instruction {
+    // The instructions sysvar is passed as the source account so the header can record the
+    // instruction index of the write.
+    MemoryWriteWithAuthorityBuilder::new()
+        .payer(payer)
+        .authority(payer)
+        .source_account(sysvar::instructions::ID)
+        .memory(memory)
+        .program_id(lighthouse_sdk::ID)
+        .memory_id(0)
+        .memory_bump(memory_bump)
+        .top_level_only(false)
+        .write_offset(write_offset)
+        .write_type(WriteType::DataValue(DataValue::U64(420)))
+        .instruction()
+}
+
+fn close_ix(payer: Pubkey, memory: Pubkey, memory_bump: u8) -> Instruction {
+    MemoryCloseWithAuthorityBuilder::new()
+        .payer(payer)
+        .authority(payer)
+        .memory(memory)
+        .program_id(lighthouse_sdk::ID)
+        .memory_id(0)
+        .memory_bump(memory_bump)
+        .top_level_only(false)
+        .instruction()
+}
+
+fn assert_header_ix(memory: Pubkey, assertion: MemoryHeaderAssertion) -> Instruction {
+    AssertMemoryHeaderBuilder::new()
+        .target_account(memory)
+        .log_level(LogLevel::PlaintextMessage)
+        .assertion(assertion)
+        .instruction()
+}
+
+#[tokio::test]
+async fn write_provenance() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+
+    let (memory, memory_bump) = find_authority_memory_pda(user.encodable_pubkey(), 0, false);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            init_header_ix(user.encodable_pubkey(), memory, memory_bump, false),
+            write_ix(user.encodable_pubkey(), memory, memory_bump, 64),
+            assert_header_ix(
+                memory,
+                MemoryHeaderAssertion::WriteCount {
+                    value: 1,
+                    operator: IntegerOperator::Equal,
+                },
+            ),
+            assert_header_ix(
+                memory,
+                MemoryHeaderAssertion::InstructionIndex {
+                    value: 1,
+                    operator: IntegerOperator::Equal,
+                },
+            ),
+            assert_header_ix(
+                memory,
+                MemoryHeaderAssertion::WrittenInCurrentSlot {
+                    value: true,
+                    operator: EquatableOperator::Equal,
+                },
+            ),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    let memory_data = context.get_account(memory).await.unwrap().data;
+    assert_eq!(memory_data[64..72], 420u64.to_le_bytes());
+
+    // A write in a later transaction bumps the count and records its own instruction index
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            write_ix(user.encodable_pubkey(), memory, memory_bump, 72),
+            assert_header_ix(
+                memory,
+                MemoryHeaderAssertion::WriteCount {
+                    value: 2,
+                    operator: IntegerOperator::Equal,
+                },
+            ),
+            assert_header_ix(
+                memory,
+                MemoryHeaderAssertion::InstructionIndex {
+                    value: 0,
+                    operator: IntegerOperator::Equal,
+                },
+            ),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn header_failures() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+
+    let (memory, memory_bump) = find_authority_memory_pda(user.encodable_pubkey(), 0, false);
+
+    // Raw memory has no header to assert against
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            write_ix(user.encodable_pubkey(), memory, memory_bump, 0),
+            assert_header_ix(
+                memory,
+                MemoryHeaderAssertion::WriteCount {
+                    value: 0,
+                    operator: IntegerOperator::Equal,
+                },
+            ),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(1, LighthouseError::AccountDiscriminatorValidationFailed),
+        None,
+    )
+    .await
+    .unwrap();
+
+    // Writes cannot overlap the header
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            init_header_ix(user.encodable_pubkey(), memory, memory_bump, false),
+            write_ix(user.encodable_pubkey(), memory, memory_bump, 8),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(1, LighthouseError::RangeOutOfBounds),
+        None,
+    )
+    .await
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            init_header_ix(user.encodable_pubkey(), memory, memory_bump, false),
+            assert_header_ix(
+                memory,
+                MemoryHeaderAssertion::WrittenInCurrentSlot {
+                    value: true,
+                    operator: EquatableOperator::Equal,
+                },
+            ),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(1, LighthouseError::AssertionFailed),
+        None,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn locked_memory() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+
+    let (memory, memory_bump) = find_authority_memory_pda(user.encodable_pubkey(), 0, false);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            init_header_ix(user.encodable_pubkey(), memory, memory_bump, true),
+            write_ix(user.encodable_pubkey(), memory, memory_bump, 64),
+            write_ix(user.encodable_pubkey(), memory, memory_bump, 80),
+            assert_header_ix(
+                memory,
+                MemoryHeaderAssertion::IsLocked {
+                    value: true,
+                    operator: EquatableOperator::Equal,
+                },
+            ),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    // Written regions cannot be overwritten and the header cannot be reset
+    let failures = vec![
+        write_ix(user.encodable_pubkey(), memory, memory_bump, 64),
+        write_ix(user.encodable_pubkey(), memory, memory_bump, 76),
+        init_header_ix(user.encodable_pubkey(), memory, memory_bump, false),
+    ];
+
+    for ix in failures {
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&user.encodable_pubkey()),
+            &[&user],
+            context.get_blockhash().await,
+        );
+
+        process_transaction_assert_failure(
+            context,
+            tx,
+            to_transaction_error(0, LighthouseError::MemoryLocked),
+            None,
+        )
+        .await
+        .unwrap();
+    }
+
+    // The gap skipped between the two writes is still writable
+    let tx = Transaction::new_signed_with_payer(
+        &[write_ix(user.encodable_pubkey(), memory, memory_bump, 72)],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn close_locked_memory() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+
+    let (memory, memory_bump) = find_authority_memory_pda(user.encodable_pubkey(), 0, false);
+
+    // Closing, recreating and rewriting locked memory within one transaction would defeat the lock
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            init_header_ix(user.encodable_pubkey(), memory, memory_bump, true),
+            write_ix(user.encodable_pubkey(), memory, memory_bump, 64),
+            close_ix(user.encodable_pubkey(), memory, memory_bump),
+            init_header_ix(user.encodable_pubkey(), memory, memory_bump, false),
+            write_ix(user.encodable_pubkey(), memory, memory_bump, 64),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(2, LighthouseError::MemoryLocked),
+        None,
+    )
+    .await
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            init_header_ix(user.encodable_pubkey(), memory, memory_bump, true),
+            write_ix(user.encodable_pubkey(), memory, memory_bump, 64),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    // Once the locking slot has passed the memory can be closed and reused
+    context.warp_to_slot(1_000).expect("warp to slot failed");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            close_ix(user.encodable_pubkey(), memory, memory_bump),
+            init_header_ix(user.encodable_pubkey(), memory, memory_bump, false),
+            write_ix(user.encodable_pubkey(), memory, memory_bump, 64),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+}
@@ -1,2 +1,5 @@
 pub mod error;
 pub mod simple;
+pub mod multi;
+pub mod memory_header;
+pub mod memory_close;
\ No newline at end of file
@@ -0,0 +1,118 @@
+use crate::utils::context::TestContext;
+use crate::utils::{create_test_account, create_user};
+use crate::utils::{process_transaction_assert_failure, process_transaction_assert_success};
+use lighthouse_sdk::find_authority_memory_pda;
+use lighthouse_sdk::instructions::MemoryWriteMultiBuilder;
+use lighthouse_sdk::types::{AccountInfoField, DataValue, MemoryWriteEntry, WriteType};
+use solana_program_test::tokio;
+use solana_sdk::instruction::{AccountMeta, InstructionError};
+use solana_sdk::signer::EncodableKeypair;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+#[tokio::test]
+async fn write_multiple_source_accounts() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+    let test_account = create_test_account(context, &user, false).await.unwrap();
+
+    let (memory, memory_bump) = find_authority_memory_pda(user.encodable_pubkey(), 0, false);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[MemoryWriteMultiBuilder::new()
+            .payer(user.encodable_pubkey())
+            .authority(user.encodable_pubkey())
+            .source_account(user.encodable_pubkey())
+            .memory(memory)
+            .program_id(lighthouse_sdk::ID)
+            .memory_id(0)
+            .memory_bump(memory_bump)
+            .top_level_only(false)
+            .entries(vec![
+                MemoryWriteEntry {
+                    write_offset: 0,
+                    source_account_index: 0,
+                    write_type: WriteType::AccountInfoField(AccountInfoField::Key),
+                },
+                MemoryWriteEntry {
+                    write_offset: 32,
+                    source_account_index: 1,
+                    write_type: WriteType::AccountInfoField(AccountInfoField::Key),
+                },
+                MemoryWriteEntry {
+                    write_offset: 64,
+                    source_account_index: 0,
+                    write_type: WriteType::DataValue(DataValue::U64(u64::MAX)),
+                },
+            ])
+            .add_remaining_account(AccountMeta::new_readonly(
+                test_account.encodable_pubkey(),
+                false,
+            ))
+            .instruction()],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    let memory_data = context.get_account(memory).await.unwrap().data;
+
+    assert_eq!(memory_data.len(), 72);
+    assert_eq!(memory_data[0..32], user.encodable_pubkey().to_bytes());
+    assert_eq!(
+        memory_data[32..64],
+        test_account.encodable_pubkey().to_bytes()
+    );
+    assert_eq!(memory_data[64..72], u64::MAX.to_le_bytes());
+}
+
+#[tokio::test]
+async fn missing_source_account() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+
+    let (memory, memory_bump) = find_authority_memory_pda(user.encodable_pubkey(), 0, false);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[MemoryWriteMultiBuilder::new()
+            .payer(user.encodable_pubkey())
+            .authority(user.encodable_pubkey())
+            .source_account(user.encodable_pubkey())
+            .memory(memory)
+            .program_id(lighthouse_sdk::ID)
+            .memory_id(0)
+            .memory_bump(memory_bump)
+            .top_level_only(false)
+            .entries(vec![
+                MemoryWriteEntry {
+                    write_offset: 0,
+                    source_account_index: 0,
+                    write_type: WriteType::AccountInfoField(AccountInfoField::Key),
+                },
+                MemoryWriteEntry {
+                    write_offset: 32,
+                    source_account_index: 1,
+                    write_type: WriteType::AccountInfoField(AccountInfoField::Key),
+                },
+            ])
+            .instruction()],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys),
+        None,
+    )
+    .await
+    .unwrap();
+
+    // Nothing is written when any entry fails to resolve
+    assert!(context.get_account(memory).await.is_none());
+}
@@ -8,12 +8,13 @@ use borsh::BorshSerialize;
 use lighthouse_sdk::errors::LighthouseError;
 use lighthouse_sdk::instructions::{
     AssertAccountDataBuilder, AssertAccountDeltaBuilder, MemoryCloseBuilder, MemoryWriteBuilder,
+    MemoryWriteWithAuthorityBuilder,
 };
 use lighthouse_sdk::types::{
-    AccountDeltaAssertion, AccountInfoField, DataValue, DataValueAssertion,
+    AccountDeltaAssertion, AccountInfoField, BytesOperator, DataValue, DataValueAssertion,
     DataValueDeltaAssertion, EquatableOperator, IntegerOperator, LogLevel, WriteType,
 };
-use lighthouse_sdk::{find_memory_pda, find_memory_pda_bump_iterate};
+use lighthouse_sdk::{find_authority_memory_pda, find_memory_pda, find_memory_pda_bump_iterate};
 use solana_program_test::tokio;
 use solana_sdk::instruction::Instruction;
 use solana_sdk::pubkey::Pubkey;
@@ -174,7 +175,7 @@ async fn write_account_data() {
                 .log_level(lighthouse_sdk::types::LogLevel::Silent)
                 .assertion(DataValueAssertion::Bytes {
                     value: vec![u8::MAX; 32],
-                    operator: EquatableOperator::Equal,
+                    operator: BytesOperator::Equal,
                 })
                 .offset(62)
                 .instruction(),
@@ -230,7 +231,7 @@ async fn write_account_data() {
                 .log_level(lighthouse_sdk::types::LogLevel::Silent)
                 .assertion(DataValueAssertion::Bytes {
                     value: [1, 255, 255].to_vec(),
-                    operator: EquatableOperator::Equal,
+                    operator: BytesOperator::Equal,
                 })
                 .offset(99)
                 .instruction(),
@@ -239,7 +240,7 @@ async fn write_account_data() {
                 .log_level(lighthouse_sdk::types::LogLevel::Silent)
                 .assertion(DataValueAssertion::Bytes {
                     value: [0].to_vec(),
-                    operator: EquatableOperator::Equal,
+                    operator: BytesOperator::Equal,
                 })
                 .offset(102)
                 .instruction(),
@@ -261,7 +262,7 @@ async fn write_account_data() {
                         .cloned()
                         .chain(vec![255; 32])
                         .collect::<Vec<u8>>(),
-                    operator: EquatableOperator::Equal,
+                    operator: BytesOperator::Equal,
                 })
                 .offset(135)
                 .instruction(),
@@ -325,7 +326,7 @@ async fn write_account_type() {
                 .log_level(lighthouse_sdk::types::LogLevel::Silent)
                 .assertion(DataValueAssertion::Bytes {
                     value: expected_blob.clone(),
-                    operator: EquatableOperator::Equal,
+                    operator: BytesOperator::Equal,
                 })
                 .offset(0)
                 .instruction(),
@@ -516,7 +517,7 @@ async fn token_transfer() {
                 a_offset: 0,
                 b_offset: 0,
                 assertion: DataValueDeltaAssertion::Bytes {
-                    operator: EquatableOperator::Equal,
+                    operator: BytesOperator::Equal,
                     length: 64,
                 },
             })
@@ -600,7 +601,7 @@ async fn write_to_another_memory_index() {
                 .log_level(lighthouse_sdk::types::LogLevel::Silent)
                 .assertion(DataValueAssertion::Bytes {
                     value: vec![MAX; 94],
-                    operator: EquatableOperator::Equal,
+                    operator: BytesOperator::Equal,
                 })
                 .offset(0)
                 .instruction(),
@@ -660,7 +661,7 @@ async fn write_to_another_bump() {
                 .log_level(lighthouse_sdk::types::LogLevel::Silent)
                 .assertion(DataValueAssertion::Bytes {
                     value: vec![MAX; 94],
-                    operator: EquatableOperator::Equal,
+                    operator: BytesOperator::Equal,
                 })
                 .offset(0)
                 .instruction(),
@@ -849,3 +850,51 @@ async fn account_info_program() {
     let actual_value = Pubkey::try_from_slice(&account_data[64..96]).unwrap();
     assert_eq!(actual_value, lighthouse_sdk::ID);
 }
+
+#[tokio::test]
+async fn write_with_separate_authority() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+    let authority = Keypair::new();
+
+    let (memory, memory_bump) = find_authority_memory_pda(authority.encodable_pubkey(), 300, true);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            MemoryWriteWithAuthorityBuilder::new()
+                .payer(user.encodable_pubkey())
+                .authority(authority.encodable_pubkey())
+                .source_account(user.encodable_pubkey())
+                .memory(memory)
+                .program_id(lighthouse_sdk::ID)
+                .memory_id(300)
+                .memory_bump(memory_bump)
+                .top_level_only(true)
+                .write_offset(0)
+                .write_type(WriteType::AccountInfoField(AccountInfoField::Key))
+                .instruction(),
+            AssertAccountDataBuilder::new()
+                .target_account(memory)
+                .log_level(LogLevel::PlaintextMessage)
+                .assertion(DataValueAssertion::Pubkey {
+                    value: user.encodable_pubkey(),
+                    operator: EquatableOperator::Equal,
+                })
+                .offset(0)
+                .instruction(),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user, &authority],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    // The top-level-only flag is part of the namespace
+    assert_ne!(
+        memory,
+        find_authority_memory_pda(authority.encodable_pubkey(), 300, false).0
+    );
+}
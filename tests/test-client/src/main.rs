@@ -7,9 +7,9 @@ use lighthouse_sdk::instructions::{
     MemoryCloseBuilder, MemoryWriteBuilder,
 };
 use lighthouse_sdk::types::{
-    AccountDeltaAssertion, AccountInfoAssertion, DataValueDeltaAssertion, EquatableOperator,
-    IntegerOperator, KnownProgram, LogLevel, MetaAssertion, StakeAccountAssertion, StakeStateType,
-    WriteType,
+    AccountDeltaAssertion, AccountInfoAssertion, BytesOperator, DataValueDeltaAssertion,
+    EquatableOperator, IntegerOperator, KnownProgram, LogLevel, MetaAssertion,
+    StakeAccountAssertion, StakeStateType, WriteType,
 };
 use solana_client::rpc_client::RpcClient;
 use solana_client::rpc_config::RpcSendTransactionConfig;
@@ -262,7 +262,7 @@ fn build_safe_send_token_transaction(
                     a_offset: 0,
                     b_offset: 0,
                     assertion: DataValueDeltaAssertion::Bytes {
-                        operator: EquatableOperator::Equal,
+                        operator: BytesOperator::Equal,
                         length: 64,
                     },
                 })